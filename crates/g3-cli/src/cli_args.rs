@@ -149,6 +149,11 @@ pub struct Cli {
     #[arg(long)]
     pub list_modes: bool,
 
+    /// Evaluate search quality against a labeled query file and exit.
+    /// The file is a JSON array of {"query", "relevant": [chunk ids]}
+    #[arg(long, value_name = "PATH")]
+    pub search_eval: Option<PathBuf>,
+
     /// Skip session resumption and force a new session (for agent mode)
     #[arg(long)]
     pub new_session: bool,
@@ -30,7 +30,8 @@ pub enum CommandResult {
 
 fn format_research_task_summary(task: &g3_core::pending_research::ResearchTask) -> String {
     let status_emoji = match task.status {
-        g3_core::pending_research::ResearchStatus::Pending => "🔄",
+        g3_core::pending_research::ResearchStatus::Pending => "⏳",
+        g3_core::pending_research::ResearchStatus::Running => "🔄",
         g3_core::pending_research::ResearchStatus::Complete => "✅",
         g3_core::pending_research::ResearchStatus::Failed => "❌",
     };
@@ -190,7 +191,7 @@ pub async fn handle_command<W: UiWriter>(
             } else if arg == "latest" {
                 let all_tasks = manager.list_all();
                 let latest = all_tasks.iter()
-                    .filter(|t| t.status != g3_core::pending_research::ResearchStatus::Pending)
+                    .filter(|t| t.status.is_terminal())
                     .min_by_key(|t| t.started_at.elapsed());
                 
                 match latest {
@@ -208,7 +209,7 @@ pub async fn handle_command<W: UiWriter>(
                         output.print(&format_research_report_header(&task));
                         let content = if let Some(ref result) = task.result {
                             result.as_str()
-                        } else if task.status == g3_core::pending_research::ResearchStatus::Pending {
+                        } else if !task.status.is_terminal() {
                             "(Research still in progress...)"
                         } else {
                             "(No report content available)"
@@ -92,6 +92,13 @@ pub async fn run() -> Result<()> {
         return Ok(());
     }
 
+    // Check if --search-eval was requested
+    if let Some(ref eval_path) = cli.search_eval {
+        let workspace_dir = cli.workspace.clone().unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+        let config = load_config_with_cli_overrides(&cli)?;
+        return run_search_eval(eval_path, &config, &workspace_dir).await;
+    }
+
     // Check if dytopo mode is enabled
     if cli.dytopo {
         let workspace_dir = cli.workspace.clone().unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
@@ -344,6 +351,59 @@ pub fn can_run_tui() -> bool {
     crate::tui::can_run_tui()
 }
 
+/// Evaluate search quality against a labeled query file and print the report.
+///
+/// The file is a JSON array of `{"query": "...", "relevant": ["chunk id", ...]}`
+/// entries, where chunk ids are the stable ids reported by semantic_search.
+/// Runs each query through the configured search pipeline and prints MRR,
+/// recall@k, and nDCG@k, so retrieval tuning can be compared on numbers.
+async fn run_search_eval(
+    eval_path: &std::path::Path,
+    config: &g3_config::Config,
+    workspace_dir: &std::path::Path,
+) -> Result<()> {
+    use anyhow::Context;
+    use g3_core::index_client::IndexClient;
+
+    if !config.index.enabled {
+        anyhow::bail!("Search evaluation requires indexing to be enabled ([index] in config)");
+    }
+
+    #[derive(serde::Deserialize)]
+    struct LabeledQuery {
+        query: String,
+        relevant: Vec<String>,
+    }
+
+    let raw = std::fs::read_to_string(eval_path)
+        .with_context(|| format!("Failed to read query file {:?}", eval_path))?;
+    let labeled: Vec<LabeledQuery> = serde_json::from_str(&raw)
+        .with_context(|| format!("Failed to parse query file {:?}", eval_path))?;
+    let queries: Vec<(String, Vec<String>)> = labeled
+        .into_iter()
+        .map(|q| (q.query, q.relevant))
+        .collect();
+
+    let client = IndexClient::new(&config.index, workspace_dir).await?;
+    let report = client.evaluate_search(&queries, 10).await?;
+
+    println!(
+        "Search quality over {} queries (k={}):",
+        report.queries, report.k
+    );
+    println!("  MRR:       {:.3}", report.mrr);
+    println!("  recall@{}: {:.3}", report.k, report.recall_at_k);
+    println!("  nDCG@{}:   {:.3}", report.k, report.ndcg_at_k);
+    println!();
+    for q in &report.per_query {
+        println!(
+            "  rr={:.3} recall={:.3} ndcg={:.3}  {}",
+            q.reciprocal_rank, q.recall_at_k, q.ndcg_at_k, q.query
+        );
+    }
+    Ok(())
+}
+
 /// Print a list of all execution modes with descriptions.
 pub fn print_modes() {
     println!("g3 Execution Modes");
@@ -1,7 +1,8 @@
 //! TUI application state and main loop.
 
-use crate::tui::events::has_minimum_size;
+use crate::tui::events::{self, has_minimum_size};
 use crate::tui::subagent_monitor::SubagentEntry;
+use crate::tui::subagent_panel::SubagentFilter;
 use crate::tui::team_monitor::TeamState;
 use crate::tui::tui_ui_writer::TuiEvent;
 use crate::tui::ui::{self, Colors};
@@ -11,6 +12,11 @@ use std::io::Stdout;
 use std::time::Duration;
 use tokio::sync::{mpsc, oneshot};
 
+/// Maximum number of chat messages retained in the scrollback buffer.
+/// Old messages are dropped once the buffer exceeds this, keeping memory
+/// bounded on very long sessions.
+pub const MAX_SCROLLBACK_MESSAGES: usize = 2000;
+
 /// Which pane has focus.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Pane {
@@ -75,6 +81,103 @@ pub struct ChatMessage {
     pub content: ChatContent,
 }
 
+/// Drop the oldest messages once the scrollback exceeds `max`.
+fn trim_scrollback(messages: &mut Vec<ChatMessage>, max: usize) {
+    if messages.len() > max {
+        let excess = messages.len() - max;
+        messages.drain(..excess);
+    }
+}
+
+/// Render the scrollback (including tool calls and results) as a markdown
+/// transcript suitable for saving and sharing.
+pub fn transcript_markdown(messages: &[ChatMessage]) -> String {
+    let mut out = String::new();
+    out.push_str("# g3 session transcript
+
+");
+
+    for msg in messages {
+        match msg.role {
+            MessageRole::User => {
+                out.push_str("## You
+
+");
+                out.push_str(msg.content.as_text());
+                out.push_str("
+
+");
+            }
+            MessageRole::Assistant => {
+                out.push_str("## g3
+
+");
+                out.push_str(msg.content.as_text());
+                out.push_str("
+
+");
+            }
+            MessageRole::Error => {
+                out.push_str("## Error
+
+");
+                out.push_str(msg.content.as_text());
+                out.push_str("
+
+");
+            }
+            MessageRole::Tool => match &msg.content {
+                ChatContent::Text(text) => {
+                    out.push_str(&format!("- Tool: {}
+
+", text));
+                }
+                ChatContent::ToolCompact {
+                    name,
+                    path,
+                    summary,
+                    tokens,
+                    duration_secs,
+                    ..
+                } => {
+                    out.push_str(&format!(
+                        "- Tool: {} {} — {} ({} tokens, {:.1}s)
+
+",
+                        name, path, summary, tokens, duration_secs
+                    ));
+                }
+                ChatContent::ToolVerbose {
+                    name,
+                    path,
+                    lines,
+                    tokens,
+                    duration_secs,
+                    ..
+                } => {
+                    out.push_str(&format!(
+                        "- Tool: {} {} ({} tokens, {:.1}s)
+
+```
+{}
+```
+
+",
+                        name,
+                        path,
+                        tokens,
+                        duration_secs,
+                        lines.join("
+")
+                    ));
+                }
+            },
+        }
+    }
+
+    out
+}
+
 /// A pending prompt from the agent that needs user input.
 #[derive(Debug)]
 pub enum PendingPrompt {
@@ -145,11 +248,17 @@ pub struct App {
     pub split_ratio: f32,
     pub subagent_entries: Vec<SubagentEntry>,
     pub subagent_scroll: usize,
+    pub subagent_filter: SubagentFilter,
+    pub subagent_search: String,
+    /// Whether the subagent panel search prompt is capturing keystrokes.
+    subagent_search_active: bool,
     pub team_state: Option<TeamState>,
     pub team_scroll: usize,
     pub model_name: String,
     pub cost_dollars: f64,
     pub is_thinking: bool,
+    /// Minimum terminal size; below this we render a "terminal too small" notice.
+    min_size: (u16, u16),
     agent_input_tx: mpsc::UnboundedSender<String>,
     tui_event_rx: mpsc::UnboundedReceiver<TuiEvent>,
     subagent_rx: mpsc::UnboundedReceiver<Vec<SubagentEntry>>,
@@ -175,8 +284,13 @@ impl App {
         subagent_rx: mpsc::UnboundedReceiver<Vec<SubagentEntry>>,
         team_rx: Option<mpsc::UnboundedReceiver<TeamState>>,
     ) -> anyhow::Result<Self> {
-        if !has_minimum_size(80, 24) {
-            anyhow::bail!("Terminal too small. Minimum required: 80x24");
+        let min_size = events::min_terminal_size();
+        if !has_minimum_size(min_size.0, min_size.1) {
+            anyhow::bail!(
+                "Terminal too small. Minimum required: {}x{}",
+                min_size.0,
+                min_size.1
+            );
         }
 
         let backend = CrosstermBackend::new(std::io::stdout());
@@ -197,11 +311,15 @@ impl App {
             split_ratio: 0.7,
             subagent_entries: Vec::new(),
             subagent_scroll: 0,
+            subagent_filter: SubagentFilter::default(),
+            subagent_search: String::new(),
+            subagent_search_active: false,
             team_state: None,
             team_scroll: 0,
             model_name: String::new(),
             cost_dollars: 0.0,
             is_thinking: false,
+            min_size,
             agent_input_tx,
             tui_event_rx,
             subagent_rx,
@@ -272,6 +390,16 @@ impl App {
     }
 
     fn draw(&mut self) -> anyhow::Result<()> {
+        let (min_width, min_height) = self.min_size;
+        let size = self.terminal.size()?;
+        if events::is_too_small(size.width, size.height, min_width, min_height) {
+            let colors = self.colors.clone();
+            self.terminal.draw(|frame| {
+                ui::render_too_small(frame, &colors, min_width, min_height);
+            })?;
+            return Ok(());
+        }
+
         let colors = self.colors.clone();
         let messages = self.messages.clone();
         let input_buffer = self.input_buffer.clone();
@@ -283,6 +411,8 @@ impl App {
         let split_ratio = self.split_ratio;
         let subagent_entries = self.subagent_entries.clone();
         let subagent_scroll = self.subagent_scroll;
+        let subagent_filter = self.subagent_filter;
+        let subagent_search = self.subagent_search.clone();
         let team_state = self.team_state.clone();
         let team_scroll = self.team_scroll;
         let model_name = self.model_name.clone();
@@ -304,6 +434,8 @@ impl App {
                 split_ratio,
                 subagent_entries: &subagent_entries,
                 subagent_scroll,
+                subagent_filter,
+                subagent_search: &subagent_search,
                 team_state: &team_state,
                 team_scroll,
                 model_name: &model_name,
@@ -449,6 +581,9 @@ impl App {
                 }
             }
         }
+        if changed {
+            trim_scrollback(&mut self.messages, MAX_SCROLLBACK_MESSAGES);
+        }
         changed
     }
 
@@ -494,7 +629,24 @@ impl App {
 
         // Pane-specific keys when subagent panel is focused
         if self.active_pane == Pane::Subagent {
+            if self.subagent_search_active {
+                self.handle_subagent_search_key(key);
+                return;
+            }
             match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.running = false;
+                    return;
+                }
+                KeyCode::Char('f') => {
+                    self.subagent_filter = self.subagent_filter.next();
+                    self.subagent_scroll = 0;
+                    return;
+                }
+                KeyCode::Char('/') => {
+                    self.subagent_search_active = true;
+                    return;
+                }
                 KeyCode::Char('j') => {
                     if self.subagent_scroll < self.subagent_entries.len().saturating_sub(1) {
                         self.subagent_scroll += 1;
@@ -509,10 +661,6 @@ impl App {
                     self.active_pane = Pane::Main;
                     return;
                 }
-                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.running = false;
-                    return;
-                }
                 KeyCode::Esc => {
                     self.active_pane = Pane::Main;
                     return;
@@ -538,6 +686,10 @@ impl App {
             KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.split_ratio = (self.split_ratio + 0.05).min(0.85);
             }
+            // Ctrl+E: export the transcript to a markdown file
+            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.export_transcript();
+            }
             KeyCode::Enter => {
                 self.submit_input();
             }
@@ -596,6 +748,32 @@ impl App {
         }
     }
 
+    /// Handle keystrokes while the subagent search prompt is active.
+    fn handle_subagent_search_key(&mut self, key: event::KeyEvent) {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.running = false;
+            }
+            KeyCode::Enter => {
+                self.subagent_search_active = false;
+            }
+            KeyCode::Esc => {
+                self.subagent_search.clear();
+                self.subagent_search_active = false;
+                self.subagent_scroll = 0;
+            }
+            KeyCode::Backspace => {
+                self.subagent_search.pop();
+                self.subagent_scroll = 0;
+            }
+            KeyCode::Char(c) => {
+                self.subagent_search.push(c);
+                self.subagent_scroll = 0;
+            }
+            _ => {}
+        }
+    }
+
     fn handle_prompt_key(&mut self, key: event::KeyEvent) {
         let prompt = match self.pending_prompt.take() {
             Some(p) => p,
@@ -637,6 +815,40 @@ impl App {
         }
     }
 
+    /// Export the full scrollback to `tmp/tui_transcript_<timestamp>.md`.
+    /// The result (path or error) is reported as a message in the chat.
+    fn export_transcript(&mut self) {
+        let export_dir = std::path::Path::new("tmp");
+        if let Err(e) = std::fs::create_dir_all(export_dir) {
+            self.messages.push(ChatMessage {
+                role: MessageRole::Error,
+                content: ChatContent::Text(format!("Failed to create tmp directory: {}", e)),
+            });
+            return;
+        }
+
+        let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+        let export_path = export_dir.join(format!("tui_transcript_{}.md", timestamp));
+
+        match std::fs::write(&export_path, transcript_markdown(&self.messages)) {
+            Ok(_) => {
+                self.messages.push(ChatMessage {
+                    role: MessageRole::Tool,
+                    content: ChatContent::Text(format!(
+                        "Transcript exported to {}",
+                        export_path.display()
+                    )),
+                });
+            }
+            Err(e) => {
+                self.messages.push(ChatMessage {
+                    role: MessageRole::Error,
+                    content: ChatContent::Text(format!("Failed to export transcript: {}", e)),
+                });
+            }
+        }
+    }
+
     fn submit_input(&mut self) {
         let text = self.input_buffer.trim().to_string();
         if text.is_empty() {
@@ -667,3 +879,79 @@ impl Drop for App {
         let _ = self.terminal.show_cursor();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_message(role: MessageRole, text: &str) -> ChatMessage {
+        ChatMessage {
+            role,
+            content: ChatContent::Text(text.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_trim_scrollback_retains_most_recent_messages() {
+        let mut messages: Vec<ChatMessage> = (0..50)
+            .map(|i| text_message(MessageRole::Assistant, &i.to_string()))
+            .collect();
+
+        trim_scrollback(&mut messages, 10);
+
+        assert_eq!(messages.len(), 10);
+        assert_eq!(messages[0].content.as_text(), "40");
+        assert_eq!(messages[9].content.as_text(), "49");
+
+        // A buffer under the limit is left untouched
+        trim_scrollback(&mut messages, 10);
+        assert_eq!(messages.len(), 10);
+    }
+
+    #[test]
+    fn test_transcript_markdown_covers_all_message_kinds() {
+        let messages = vec![
+            text_message(MessageRole::User, "list the files"),
+            text_message(MessageRole::Assistant, "Sure, listing them now."),
+            ChatMessage {
+                role: MessageRole::Tool,
+                content: ChatContent::ToolCompact {
+                    name: "shell".to_string(),
+                    path: "ls -la".to_string(),
+                    summary: "4 files".to_string(),
+                    tokens: 120,
+                    duration_secs: 0.3,
+                    context_pct: 12.0,
+                },
+            },
+            ChatMessage {
+                role: MessageRole::Tool,
+                content: ChatContent::ToolVerbose {
+                    name: "read_file".to_string(),
+                    path: "src/main.rs".to_string(),
+                    lines: vec!["fn main() {".to_string(), "}".to_string()],
+                    tokens: 45,
+                    duration_secs: 0.1,
+                    context_pct: 13.0,
+                },
+            },
+            text_message(MessageRole::Error, "agent timed out"),
+        ];
+
+        let md = transcript_markdown(&messages);
+
+        assert!(md.starts_with("# g3 session transcript"));
+        assert!(md.contains("## You
+
+list the files"));
+        assert!(md.contains("## g3
+
+Sure, listing them now."));
+        assert!(md.contains("shell ls -la — 4 files (120 tokens, 0.3s)"));
+        assert!(md.contains("read_file src/main.rs (45 tokens, 0.1s)"));
+        assert!(md.contains("fn main() {"));
+        assert!(md.contains("## Error
+
+agent timed out"));
+    }
+}
@@ -1,9 +1,36 @@
 //! Terminal size utilities for the TUI.
 
+/// Default minimum terminal width for the TUI.
+pub const DEFAULT_MIN_WIDTH: u16 = 80;
+
+/// Default minimum terminal height for the TUI.
+pub const DEFAULT_MIN_HEIGHT: u16 = 24;
+
+/// The minimum terminal size required by the TUI.
+///
+/// Defaults to 80x24, overridable via the `G3_TUI_MIN_WIDTH` and
+/// `G3_TUI_MIN_HEIGHT` environment variables for small/split terminals.
+pub fn min_terminal_size() -> (u16, u16) {
+    let width = std::env::var("G3_TUI_MIN_WIDTH")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MIN_WIDTH);
+    let height = std::env::var("G3_TUI_MIN_HEIGHT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MIN_HEIGHT);
+    (width, height)
+}
+
+/// Check whether the given dimensions fall below the given minimum.
+pub fn is_too_small(width: u16, height: u16, min_width: u16, min_height: u16) -> bool {
+    width < min_width || height < min_height
+}
+
 /// Check if the terminal has a minimum required size.
 pub fn has_minimum_size(min_width: u16, min_height: u16) -> bool {
     if let Ok((width, height)) = crossterm::terminal::size() {
-        width >= min_width && height >= min_height
+        !is_too_small(width, height, min_width, min_height)
     } else {
         false
     }
@@ -24,4 +51,20 @@ mod tests {
         assert!(w > 0);
         assert!(h > 0);
     }
+
+    #[test]
+    fn test_is_too_small_reports_each_dimension() {
+        // Exactly at the minimum is fine
+        assert!(!is_too_small(80, 24, 80, 24));
+        // Comfortably above
+        assert!(!is_too_small(120, 40, 80, 24));
+        // Too narrow
+        assert!(is_too_small(79, 24, 80, 24));
+        // Too short
+        assert!(is_too_small(80, 23, 80, 24));
+        // Both dimensions too small
+        assert!(is_too_small(40, 10, 80, 24));
+        // Degenerate size after an aggressive split
+        assert!(is_too_small(0, 0, 80, 24));
+    }
 }
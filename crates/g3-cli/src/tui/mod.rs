@@ -78,11 +78,8 @@ pub fn run_tui() -> anyhow::Result<()> {
 
 /// Check if the TUI can run in the current environment.
 pub fn can_run_tui() -> bool {
-    if let Ok((width, height)) = crossterm::terminal::size() {
-        width >= 80 && height >= 24
-    } else {
-        false
-    }
+    let (min_width, min_height) = events::min_terminal_size();
+    events::has_minimum_size(min_width, min_height)
 }
 
 /// Run the agent on a separate thread with its own tokio runtime.
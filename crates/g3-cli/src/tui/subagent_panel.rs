@@ -10,6 +10,73 @@ use ratatui::{
 
 use super::subagent_monitor::{AgentStatus, SubagentEntry};
 
+/// Which subset of subagents to show in the panel.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SubagentFilter {
+    #[default]
+    All,
+    RunningOnly,
+    ErrorsOnly,
+}
+
+impl SubagentFilter {
+    /// Cycle to the next filter (All -> RunningOnly -> ErrorsOnly -> All).
+    pub fn next(self) -> Self {
+        match self {
+            SubagentFilter::All => SubagentFilter::RunningOnly,
+            SubagentFilter::RunningOnly => SubagentFilter::ErrorsOnly,
+            SubagentFilter::ErrorsOnly => SubagentFilter::All,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SubagentFilter::All => "all",
+            SubagentFilter::RunningOnly => "running",
+            SubagentFilter::ErrorsOnly => "errors",
+        }
+    }
+}
+
+/// Check whether an entry passes the status filter and the text search.
+/// The search is a case-insensitive substring match over the agent ID,
+/// agent type, model name and last tool.
+pub fn entry_matches(entry: &SubagentEntry, filter: SubagentFilter, search: &str) -> bool {
+    let status_ok = match filter {
+        SubagentFilter::All => true,
+        SubagentFilter::RunningOnly => entry.status == AgentStatus::Running,
+        SubagentFilter::ErrorsOnly => entry.status == AgentStatus::Failed,
+    };
+    if !status_ok {
+        return false;
+    }
+
+    if search.is_empty() {
+        return true;
+    }
+
+    let needle = search.to_lowercase();
+    entry.agent_id.to_lowercase().contains(&needle)
+        || entry.agent_type.to_lowercase().contains(&needle)
+        || entry.model.to_lowercase().contains(&needle)
+        || entry
+            .last_tool
+            .as_ref()
+            .is_some_and(|t| t.to_lowercase().contains(&needle))
+}
+
+/// Filter entries down to those matching the filter and search.
+pub fn filter_entries<'a>(
+    entries: &'a [SubagentEntry],
+    filter: SubagentFilter,
+    search: &str,
+) -> Vec<&'a SubagentEntry> {
+    entries
+        .iter()
+        .filter(|e| entry_matches(e, filter, search))
+        .collect()
+}
+
 /// Render the subagent panel in the given area.
 pub fn render_subagent_panel(
     frame: &mut Frame,
@@ -17,31 +84,48 @@ pub fn render_subagent_panel(
     entries: &[SubagentEntry],
     focused: bool,
     scroll_offset: usize,
+    filter: SubagentFilter,
+    search: &str,
 ) {
     let active_count = entries
         .iter()
         .filter(|e| e.status == AgentStatus::Running)
         .count();
 
+    let visible = filter_entries(entries, filter, search);
+
     let border_color = if focused {
         Color::Magenta
     } else {
         Color::DarkGray
     };
 
+    let mut title = format!(" Subagents ({} active) ", active_count);
+    if filter != SubagentFilter::All {
+        title.push_str(&format!("[{}] ", filter.label()));
+    }
+    if !search.is_empty() {
+        title.push_str(&format!("/{} ", search));
+    }
+
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
         .title(Span::styled(
-            format!(" Subagents ({} active) ", active_count),
+            title,
             Style::default()
                 .fg(Color::Magenta)
                 .add_modifier(Modifier::BOLD),
         ));
 
-    if entries.is_empty() {
+    if visible.is_empty() {
+        let message = if entries.is_empty() {
+            "No subagents"
+        } else {
+            "No subagents match the filter"
+        };
         let paragraph = Paragraph::new(Line::from(Span::styled(
-            "No subagents",
+            message,
             Style::default().fg(Color::DarkGray),
         )))
         .block(block);
@@ -51,7 +135,7 @@ pub fn render_subagent_panel(
 
     let mut lines: Vec<Line> = Vec::new();
 
-    for entry in entries.iter().skip(scroll_offset) {
+    for entry in visible.iter().skip(scroll_offset) {
         // Status icon + agent ID + model
         let status_icon = match entry.status {
             AgentStatus::Running => Span::styled("■ ", Style::default().fg(Color::Green)),
@@ -139,6 +223,68 @@ fn context_gauge_color(pct: f32) -> Color {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::SystemTime;
+
+    fn make_entry(id: &str, agent_type: &str, status: AgentStatus, tool: Option<&str>) -> SubagentEntry {
+        SubagentEntry {
+            agent_id: id.to_string(),
+            agent_type: agent_type.to_string(),
+            status,
+            context_pct: 0.0,
+            model: "Opus 4.6".to_string(),
+            last_tool: tool.map(String::from),
+            last_activity: SystemTime::now(),
+        }
+    }
+
+    #[test]
+    fn test_filter_cycles_through_all_states() {
+        let f = SubagentFilter::All;
+        assert_eq!(f.next(), SubagentFilter::RunningOnly);
+        assert_eq!(f.next().next(), SubagentFilter::ErrorsOnly);
+        assert_eq!(f.next().next().next(), SubagentFilter::All);
+    }
+
+    #[test]
+    fn test_entry_matches_status_filter() {
+        let running = make_entry("abc1234", "Explore", AgentStatus::Running, None);
+        let failed = make_entry("def5678", "Plan", AgentStatus::Failed, None);
+        let done = make_entry("ghi9012", "Build", AgentStatus::Complete, None);
+
+        assert!(entry_matches(&running, SubagentFilter::All, ""));
+        assert!(entry_matches(&done, SubagentFilter::All, ""));
+
+        assert!(entry_matches(&running, SubagentFilter::RunningOnly, ""));
+        assert!(!entry_matches(&failed, SubagentFilter::RunningOnly, ""));
+
+        assert!(entry_matches(&failed, SubagentFilter::ErrorsOnly, ""));
+        assert!(!entry_matches(&running, SubagentFilter::ErrorsOnly, ""));
+        assert!(!entry_matches(&done, SubagentFilter::ErrorsOnly, ""));
+    }
+
+    #[test]
+    fn test_entry_matches_search_is_case_insensitive() {
+        let entry = make_entry("abc1234", "Explore", AgentStatus::Running, Some("read_file"));
+
+        assert!(entry_matches(&entry, SubagentFilter::All, "ABC"));
+        assert!(entry_matches(&entry, SubagentFilter::All, "explore"));
+        assert!(entry_matches(&entry, SubagentFilter::All, "read_file"));
+        assert!(entry_matches(&entry, SubagentFilter::All, "opus"));
+        assert!(!entry_matches(&entry, SubagentFilter::All, "webdriver"));
+    }
+
+    #[test]
+    fn test_filter_entries_combines_status_and_search() {
+        let entries = vec![
+            make_entry("abc1234", "Explore", AgentStatus::Running, None),
+            make_entry("def5678", "Explore", AgentStatus::Failed, None),
+            make_entry("ghi9012", "Plan", AgentStatus::Running, None),
+        ];
+
+        let visible = filter_entries(&entries, SubagentFilter::RunningOnly, "explore");
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].agent_id, "abc1234");
+    }
 
     #[test]
     fn test_render_context_gauge_zero() {
@@ -11,7 +11,7 @@ use ratatui::{
 use crate::tui::app::{ChatContent, ChatMessage, MessageRole, Pane, PendingPromptView};
 use crate::tui::markdown;
 use crate::tui::subagent_monitor::SubagentEntry;
-use crate::tui::subagent_panel;
+use crate::tui::subagent_panel::{self, SubagentFilter};
 use crate::tui::team_monitor::TeamState;
 use crate::tui::team_panel;
 use crate::tui::tool_display;
@@ -61,6 +61,8 @@ pub struct AppView<'a> {
     pub split_ratio: f32,
     pub subagent_entries: &'a [SubagentEntry],
     pub subagent_scroll: usize,
+    pub subagent_filter: SubagentFilter,
+    pub subagent_search: &'a str,
     pub team_state: &'a Option<TeamState>,
     pub team_scroll: usize,
     pub model_name: &'a str,
@@ -104,6 +106,8 @@ pub fn render(frame: &mut Frame, app: &AppView) {
                 app.subagent_entries,
                 *app.active_pane == Pane::Subagent,
                 app.subagent_scroll,
+                app.subagent_filter,
+                app.subagent_search,
             );
         }
     } else {
@@ -115,6 +119,38 @@ pub fn render(frame: &mut Frame, app: &AppView) {
     }
 }
 
+/// Render a full-screen notice when the terminal is below the minimum size.
+/// Shown instead of the normal UI until the terminal is enlarged again.
+pub fn render_too_small(frame: &mut Frame, colors: &Colors, min_width: u16, min_height: u16) {
+    let size = frame.area();
+    let lines = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default()
+                .fg(colors.error)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "Need at least {}x{}, currently {}x{}",
+                min_width, min_height, size.width, size.height
+            ),
+            Style::default().fg(colors.text),
+        )),
+        Line::from(Span::styled(
+            "Enlarge the terminal to continue",
+            Style::default().fg(colors.secondary),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(Text::from(lines))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, size);
+    frame.render_widget(paragraph, size);
+}
+
 /// Render the main pane (chat + status bar + input).
 fn render_main_pane(frame: &mut Frame, area: Rect, app: &AppView) {
     let chunks = Layout::default()
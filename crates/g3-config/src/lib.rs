@@ -134,6 +134,9 @@ pub struct ZaiConfig {
     pub enable_thinking: Option<bool>,
     /// Preserve reasoning content across conversation turns
     pub preserve_thinking: Option<bool>,
+    /// Strip `<think>` blocks that leak into content instead of
+    /// reasoning_content (default: true)
+    pub strip_leaked_thinking: Option<bool>,
     /// Enable web search tool injection in chat completions
     pub enable_web_search_in_chat: Option<bool>,
     /// Search engine to use: "bing" or "google" (default: "bing")
@@ -188,6 +191,10 @@ pub struct IndexConfig {
     /// Whether indexing is enabled
     #[serde(default)]
     pub enabled: bool,
+    /// Vector storage backend: "qdrant" (default) or "memory" for an
+    /// in-memory store that needs no external service
+    #[serde(default = "default_vector_backend")]
+    pub backend: String,
     /// Qdrant server URL
     #[serde(default = "default_qdrant_url")]
     pub qdrant_url: String,
@@ -196,6 +203,19 @@ pub struct IndexConfig {
     /// Collection name in Qdrant
     #[serde(default = "default_collection_name")]
     pub collection_name: String,
+    /// Maximum total chunks to keep in the index (unset = unbounded).
+    /// When exceeded, the least-recently-searched files are evicted
+    #[serde(default)]
+    pub max_chunks: Option<usize>,
+    /// Fall back to a plain-text scan of the workspace when semantic
+    /// search is called before the index exists (default true), so the
+    /// tool stays useful out-of-the-box
+    #[serde(default = "default_fallback_to_text")]
+    pub fallback_to_text: bool,
+    /// Annotate graph symbols with their most recent author and commit
+    /// via `git blame` during indexing (default false - blame is slow)
+    #[serde(default)]
+    pub enrich_blame: bool,
     /// Embedding configuration
     #[serde(default)]
     pub embeddings: EmbeddingsConfig,
@@ -210,6 +230,10 @@ pub struct IndexConfig {
     pub watcher: WatcherConfig,
 }
 
+fn default_vector_backend() -> String {
+    "qdrant".to_string()
+}
+
 fn default_qdrant_url() -> String {
     "http://localhost:6334".to_string()
 }
@@ -218,6 +242,10 @@ fn default_collection_name() -> String {
     "g3-codebase".to_string()
 }
 
+fn default_fallback_to_text() -> bool {
+    true
+}
+
 /// Embedding provider configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmbeddingsConfig {
@@ -283,6 +311,9 @@ pub struct SearchConfig {
     pub reranker_api_key: Option<String>,
     /// Number of top candidates to send to the reranker (default: 2x limit)
     pub rerank_top_n: Option<usize>,
+    /// Ranking fusion method: "rrf" (default), "weighted-sum", or "combsum"
+    #[serde(default = "default_fusion")]
+    pub fusion: String,
 }
 
 fn default_bm25_weight() -> f32 {
@@ -293,6 +324,10 @@ fn default_vector_weight() -> f32 {
     0.7
 }
 
+fn default_fusion() -> String {
+    "rrf".to_string()
+}
+
 impl Default for SearchConfig {
     fn default() -> Self {
         Self {
@@ -304,6 +339,7 @@ impl Default for SearchConfig {
             reranker_model: None,
             reranker_api_key: None,
             rerank_top_n: None,
+            fusion: default_fusion(),
         }
     }
 }
@@ -317,6 +353,9 @@ pub struct ChunkingConfig {
     /// Include context (scope, imports) in chunks
     #[serde(default = "default_true")]
     pub include_context: bool,
+    /// Prepend the file's import statements to each chunk's embedded text
+    #[serde(default)]
+    pub include_imports: bool,
     /// Supported languages
     #[serde(default = "default_languages")]
     pub languages: Vec<String>,
@@ -341,6 +380,7 @@ impl Default for ChunkingConfig {
         Self {
             max_chunk_tokens: default_max_chunk_tokens(),
             include_context: true,
+            include_imports: false,
             languages: default_languages(),
         }
     }
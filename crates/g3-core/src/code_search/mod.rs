@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 mod searcher;
-pub use searcher::TreeSitterSearcher;
+pub use searcher::{QueryValidation, TreeSitterSearcher};
 
 /// Request for batch code searches
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -97,7 +97,7 @@ pub struct SearchResult {
 }
 
 /// A single match
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Match {
     pub file: String,
     pub line: usize,
@@ -109,12 +109,55 @@ pub struct Match {
     pub context: Option<String>,
 }
 
+/// A single line of streamed code-search output (JSON-lines mode).
+///
+/// Emitted in order: one `match` event per match as it is found, one
+/// `search_complete` event when each search finishes, and a final `done`
+/// event carrying the same totals as the batch response. Collecting the
+/// events reconstructs exactly what [`execute_code_search`] would return.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum CodeSearchEvent {
+    Match {
+        /// Name of the search this match belongs to
+        search: String,
+        #[serde(rename = "match")]
+        matched: Match,
+    },
+    SearchComplete {
+        search: String,
+        match_count: usize,
+        files_searched: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    Done {
+        total_matches: usize,
+        total_files_searched: usize,
+    },
+}
+
 /// Main entry point for code search
 pub async fn execute_code_search(request: CodeSearchRequest) -> Result<CodeSearchResponse> {
     let mut searcher = TreeSitterSearcher::new()?;
     searcher.execute_search(request).await
 }
 
+/// Streaming variant of [`execute_code_search`].
+///
+/// Instead of buffering the full response in memory, each match is handed
+/// to `on_event` as soon as it is found, so consumers can serialize events
+/// as JSON lines and process them incrementally. The batch API remains the
+/// default; use this when a query may match thousands of results or when
+/// piping output into another tool.
+pub async fn execute_code_search_streaming(
+    request: CodeSearchRequest,
+    on_event: impl FnMut(CodeSearchEvent),
+) -> Result<()> {
+    let mut searcher = TreeSitterSearcher::new()?;
+    searcher.execute_search_streaming(request, on_event).await
+}
+
 impl Default for SearchSpec {
     fn default() -> Self {
         Self {
@@ -1,12 +1,71 @@
-use super::{CodeSearchRequest, CodeSearchResponse, Match, SearchResult, SearchSpec};
+use super::{CodeSearchEvent, CodeSearchRequest, CodeSearchResponse, Match, SearchResult, SearchSpec};
 use anyhow::{anyhow, Result};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use streaming_iterator::StreamingIterator;
-use tree_sitter::{Language, Parser, Query, QueryCursor};
+use tree_sitter::{Language, Parser, Query, QueryCursor, QueryError, QueryErrorKind};
 use walkdir::WalkDir;
 
+/// Outcome of compiling a tree-sitter query against a language grammar.
+///
+/// Produced without running the query, so malformed S-expressions can be
+/// caught up-front with a position and a hint instead of failing opaquely
+/// mid-search.
+#[derive(Debug, Serialize)]
+pub struct QueryValidation {
+    pub valid: bool,
+    pub language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 1-indexed line of the offending token in the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row: Option<usize>,
+    /// 1-indexed column of the offending token in the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+/// Map a tree-sitter query error to a human-readable message and a hint
+/// about the most likely fix.
+fn describe_query_error(error: &QueryError) -> (String, String) {
+    let (what, hint) = match error.kind {
+        QueryErrorKind::Syntax => (
+            "syntax error".to_string(),
+            "Check for unbalanced parentheses or a stray character near this position.",
+        ),
+        QueryErrorKind::NodeType => (
+            format!("unknown node type `{}`", error.message),
+            "This node type does not exist in the grammar. Node names differ per language \
+             (e.g. Rust uses `function_item`, Python uses `function_definition`).",
+        ),
+        QueryErrorKind::Field => (
+            format!("unknown field `{}`", error.message),
+            "This field name does not exist on the parent node in the grammar.",
+        ),
+        QueryErrorKind::Capture => (
+            format!("undeclared capture `{}`", error.message),
+            "Captures must be declared in a pattern before being referenced in a predicate.",
+        ),
+        QueryErrorKind::Predicate => (
+            format!("invalid predicate: {}", error.message),
+            "Check the predicate name and its arguments.",
+        ),
+        QueryErrorKind::Structure => (
+            "invalid pattern structure".to_string(),
+            "The pattern nests nodes in a way the grammar does not allow.",
+        ),
+        QueryErrorKind::Language => (
+            "incompatible language".to_string(),
+            "The grammar version does not support this query.",
+        ),
+    };
+    (what, hint.to_string())
+}
+
 pub struct TreeSitterSearcher {
     parsers: HashMap<String, Parser>,
     languages: HashMap<String, Language>,
@@ -163,6 +222,40 @@ impl TreeSitterSearcher {
         Ok(Self { parsers, languages })
     }
 
+    /// Compile a query against a language grammar without running it.
+    ///
+    /// Returns `Err` only for unsupported languages; a malformed query is
+    /// reported in the returned [`QueryValidation`] with its position and
+    /// a hint.
+    pub fn validate_query(&self, language: &str, query: &str) -> Result<QueryValidation> {
+        let lang = self
+            .languages
+            .get(language)
+            .ok_or_else(|| anyhow!("Unsupported language: {}", language))?;
+
+        match Query::new(lang, query) {
+            Ok(_) => Ok(QueryValidation {
+                valid: true,
+                language: language.to_string(),
+                error: None,
+                row: None,
+                column: None,
+                hint: None,
+            }),
+            Err(e) => {
+                let (what, hint) = describe_query_error(&e);
+                Ok(QueryValidation {
+                    valid: false,
+                    language: language.to_string(),
+                    error: Some(what),
+                    row: Some(e.row + 1),
+                    column: Some(e.column + 1),
+                    hint: Some(hint),
+                })
+            }
+        }
+    }
+
     pub async fn execute_search(
         &mut self,
         request: CodeSearchRequest,
@@ -201,10 +294,78 @@ impl TreeSitterSearcher {
         })
     }
 
+    /// Streaming counterpart of [`execute_search`](Self::execute_search).
+    ///
+    /// Emits a [`CodeSearchEvent`] per match as it is found, per search as
+    /// it completes, and a final `done` event with the totals, without
+    /// accumulating the full response in memory first. Event order matches
+    /// the batch response exactly.
+    pub async fn execute_search_streaming<F>(
+        &mut self,
+        request: CodeSearchRequest,
+        mut on_event: F,
+    ) -> Result<()>
+    where
+        F: FnMut(CodeSearchEvent),
+    {
+        let mut total_matches = 0;
+        let mut total_files = 0;
+
+        for spec in request.searches {
+            let result = self
+                .search_single_observed(&spec, request.max_matches_per_search, &mut |m| {
+                    on_event(CodeSearchEvent::Match {
+                        search: spec.name.clone(),
+                        matched: m.clone(),
+                    });
+                })
+                .await;
+            match result {
+                Ok(search_result) => {
+                    total_matches += search_result.match_count;
+                    total_files += search_result.files_searched;
+                    on_event(CodeSearchEvent::SearchComplete {
+                        search: search_result.name,
+                        match_count: search_result.match_count,
+                        files_searched: search_result.files_searched,
+                        error: None,
+                    });
+                }
+                Err(e) => {
+                    on_event(CodeSearchEvent::SearchComplete {
+                        search: spec.name.clone(),
+                        match_count: 0,
+                        files_searched: 0,
+                        error: Some(e.to_string()),
+                    });
+                }
+            }
+        }
+
+        on_event(CodeSearchEvent::Done {
+            total_matches,
+            total_files_searched: total_files,
+        });
+        Ok(())
+    }
+
     async fn search_single(
         &mut self,
         spec: &SearchSpec,
         max_matches: usize,
+    ) -> Result<SearchResult> {
+        self.search_single_observed(spec, max_matches, &mut |_| {})
+            .await
+    }
+
+    /// Like [`search_single`](Self::search_single), but invokes `on_match`
+    /// for each match as it is found so streaming callers don't have to
+    /// wait for the search to finish.
+    async fn search_single_observed(
+        &mut self,
+        spec: &SearchSpec,
+        max_matches: usize,
+        on_match: &mut dyn FnMut(&Match),
     ) -> Result<SearchResult> {
         // Get parser and language
         let parser = self
@@ -216,9 +377,17 @@ impl TreeSitterSearcher {
             .get(&spec.language)
             .ok_or_else(|| anyhow!("Language not found: {}", spec.language))?;
 
-        // Parse query
-        let query =
-            Query::new(language, &spec.query).map_err(|e| anyhow!("Invalid query: {}", e))?;
+        // Parse query, surfacing the position and a hint on failure
+        let query = Query::new(language, &spec.query).map_err(|e| {
+            let (what, hint) = describe_query_error(&e);
+            anyhow!(
+                "Invalid query at line {}, column {}: {}. Hint: {}",
+                e.row + 1,
+                e.column + 1,
+                what,
+                hint
+            )
+        })?;
 
         let mut matches = Vec::new();
         let mut files_searched = 0;
@@ -327,14 +496,16 @@ impl TreeSitterSearcher {
                                 match_text
                             };
 
-                            matches.push(Match {
+                            let found = Match {
                                 file: path.display().to_string(),
                                 line: match_line,
                                 column: match_column,
                                 text: truncated_text,
                                 captures: captures_map,
                                 context,
-                            });
+                            };
+                            on_match(&found);
+                            matches.push(found);
 
                             query_matches.advance();
                         }
@@ -391,3 +562,144 @@ impl TreeSitterSearcher {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_query_valid() {
+        let searcher = TreeSitterSearcher::new().unwrap();
+        let validation = searcher
+            .validate_query("rust", "(function_item name: (identifier) @name)")
+            .unwrap();
+
+        assert!(validation.valid);
+        assert!(validation.error.is_none());
+        assert!(validation.hint.is_none());
+    }
+
+    #[test]
+    fn test_validate_query_unknown_node_type() {
+        let searcher = TreeSitterSearcher::new().unwrap();
+        let validation = searcher
+            .validate_query("rust", "(function_itemz) @fn")
+            .unwrap();
+
+        assert!(!validation.valid);
+        let error = validation.error.unwrap();
+        assert!(error.contains("function_itemz"), "error was: {}", error);
+        assert_eq!(validation.row, Some(1));
+        assert!(validation.column.is_some());
+        assert!(validation.hint.unwrap().contains("node type"));
+    }
+
+    #[test]
+    fn test_validate_query_unbalanced_paren() {
+        let searcher = TreeSitterSearcher::new().unwrap();
+        let validation = searcher
+            .validate_query("rust", "(function_item name: (identifier) @name")
+            .unwrap();
+
+        assert!(!validation.valid);
+        assert!(validation.error.unwrap().contains("syntax error"));
+        assert!(validation.row.is_some());
+        assert!(validation.hint.unwrap().contains("parentheses"));
+    }
+
+    #[test]
+    fn test_validate_query_unsupported_language() {
+        let searcher = TreeSitterSearcher::new().unwrap();
+        assert!(searcher.validate_query("cobol", "(anything) @x").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_streamed_events_reconstruct_batch_response() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp.path().join("sample.rs"),
+            "pub struct Config;\n\npub fn load() {}\n\nfn parse() {}\n",
+        )
+        .unwrap();
+
+        let request = CodeSearchRequest {
+            searches: vec![
+                SearchSpec {
+                    name: "functions".to_string(),
+                    query: "(function_item name: (identifier) @name)".to_string(),
+                    language: "rust".to_string(),
+                    paths: vec![temp.path().display().to_string()],
+                    ..Default::default()
+                },
+                SearchSpec {
+                    name: "structs".to_string(),
+                    query: "(struct_item name: (type_identifier) @name)".to_string(),
+                    language: "rust".to_string(),
+                    paths: vec![temp.path().display().to_string()],
+                    ..Default::default()
+                },
+            ],
+            max_concurrency: 1,
+            max_matches_per_search: 20,
+        };
+
+        let mut searcher = TreeSitterSearcher::new().unwrap();
+        let batch = searcher.execute_search(request.clone()).await.unwrap();
+        assert_eq!(batch.total_matches, 3);
+
+        // Stream the same request as JSON lines, then parse them back
+        let mut lines: Vec<String> = Vec::new();
+        let mut searcher = TreeSitterSearcher::new().unwrap();
+        searcher
+            .execute_search_streaming(request, |event| {
+                lines.push(serde_json::to_string(&event).unwrap());
+            })
+            .await
+            .unwrap();
+        let events: Vec<CodeSearchEvent> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        // Matches stream before their search_complete, done comes last
+        assert!(matches!(events.last(), Some(CodeSearchEvent::Done { .. })));
+
+        // Collecting the events yields exactly the batch response
+        let mut searches: Vec<SearchResult> = Vec::new();
+        let mut pending: Vec<Match> = Vec::new();
+        let mut totals = (0, 0);
+        for event in events {
+            match event {
+                CodeSearchEvent::Match { matched, .. } => pending.push(matched),
+                CodeSearchEvent::SearchComplete {
+                    search,
+                    match_count,
+                    files_searched,
+                    error,
+                } => {
+                    searches.push(SearchResult {
+                        name: search,
+                        matches: std::mem::take(&mut pending),
+                        match_count,
+                        files_searched,
+                        error,
+                    });
+                }
+                CodeSearchEvent::Done {
+                    total_matches,
+                    total_files_searched,
+                } => totals = (total_matches, total_files_searched),
+            }
+        }
+        let collected = CodeSearchResponse {
+            searches,
+            total_matches: totals.0,
+            total_files_searched: totals.1,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&collected).unwrap(),
+            serde_json::to_value(&batch).unwrap()
+        );
+    }
+}
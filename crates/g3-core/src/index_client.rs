@@ -7,17 +7,22 @@
 //! - State persistence (manifest + BM25 to .g3-index/)
 //! - API key environment variable resolution
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use g3_config::IndexConfig;
 use g3_index::{
+    chunk_report::{ChunkReportEntry, ChunkSortKey},
     embeddings::OpenRouterEmbeddings,
+    integration::{EnrichmentConfig, IndexConnector},
     indexer::{Indexer, IndexerConfig, IndexStats},
     manifest::IndexManifest,
-    qdrant::{QdrantClient, QdrantConfig, SearchFilter},
-    search::{BM25Index, HybridSearcher, SearchConfig, SearchResult},
+    memory_store::InMemoryVectorStore,
+    qdrant::{QdrantClient, QdrantConfig, SearchFilter, VectorBackend},
+    search::{BM25Index, HybridSearcher, SearchConfig, SearchPreset, SearchResult},
+    unified_index::UnifiedSearchResult,
 };
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
@@ -44,6 +49,9 @@ const MANIFEST_FILE: &str = "manifest.json";
 /// BM25 index file name
 const BM25_FILE: &str = "bm25_index.json";
 
+/// Maximum graph traversal depth allowed for enriched search
+const MAX_ENRICHMENT_DEPTH: usize = 5;
+
 /// Client for codebase indexing and semantic search.
 ///
 /// Wraps the g3-index library types and provides a high-level API
@@ -57,6 +65,13 @@ pub struct IndexClient {
     state_dir: PathBuf,
     /// Working directory being indexed
     working_dir: PathBuf,
+    /// Shared flag that is true while an indexing run is in progress
+    indexing_flag: Arc<std::sync::atomic::AtomicBool>,
+    /// True when a reranking stage is configured for searches
+    rerank_enabled: bool,
+    /// Cached repo map, keyed by token budget; dropped after each
+    /// indexing run so the map tracks the current graph
+    repo_map_cache: Arc<RwLock<HashMap<usize, String>>>,
 }
 
 impl IndexClient {
@@ -75,78 +90,127 @@ impl IndexClient {
         let api_key = resolve_api_key(&config.embeddings.api_key)
             .context("Failed to resolve embedding API key")?;
 
+        // Embedding HTTP timeouts (defaults; carried in IndexerConfig below)
+        let embedding_request_timeout_secs = g3_index::embeddings::DEFAULT_REQUEST_TIMEOUT_SECS;
+        let embedding_connect_timeout_secs = g3_index::embeddings::DEFAULT_CONNECT_TIMEOUT_SECS;
+
         // Create embeddings provider
-        let embeddings = Arc::new(OpenRouterEmbeddings::new(
-            api_key,
-            Some(config.embeddings.model.clone()),
-            Some(config.embeddings.dimensions),
-        ));
-
-        // Create Qdrant config and connect
-        let qdrant_config = QdrantConfig {
-            url: config.qdrant_url.clone(),
-            api_key: resolve_api_key_optional(&config.qdrant_api_key),
-            collection_name: config.collection_name.clone(),
-            dimensions: config.embeddings.dimensions,
+        let embeddings = Arc::new(
+            OpenRouterEmbeddings::new(
+                api_key,
+                Some(config.embeddings.model.clone()),
+                Some(config.embeddings.dimensions),
+            )
+            .with_timeouts(
+                std::time::Duration::from_secs(embedding_request_timeout_secs),
+                std::time::Duration::from_secs(embedding_connect_timeout_secs),
+            ),
+        );
+
+        // Resolve the vector backend: Qdrant by default, in-memory when
+        // requested (empty means the config predates the selector)
+        let backend = if config.backend.is_empty() {
+            VectorBackend::Qdrant
+        } else {
+            match VectorBackend::parse(&config.backend) {
+                Some(b) => b,
+                None => {
+                    warn!(
+                        "Unknown vector backend '{}', falling back to Qdrant",
+                        config.backend
+                    );
+                    VectorBackend::Qdrant
+                }
+            }
         };
 
-// Connect to Qdrant with retry logic (create two clients - one for indexer, one for searcher)
-        // Retry with exponential backoff: 100ms, 200ms, 400ms (3 attempts total)
-        const MAX_RETRIES: u32 = 3;
-        const INITIAL_DELAY_MS: u64 = 100;
-
-        let qdrant_for_indexer = {
-            let mut last_error = None;
-            let client = 'outer: {
-                for attempt in 1..=MAX_RETRIES {
-                    match QdrantClient::from_config(&qdrant_config).await {
-                        Ok(c) => {
-                            info!("Connected to Qdrant for indexer on attempt {}/{}", attempt, MAX_RETRIES);
-                            break 'outer c;
-                        }
-                        Err(e) => {
-                            last_error = Some(format!("{}", e));
-                            if attempt < MAX_RETRIES {
-                                let delay_ms = INITIAL_DELAY_MS * (1 << (attempt - 1)); // 100, 200, 400
-                                warn!(
-                                    "Qdrant connection attempt {}/{} failed (retrying in {}ms): {}",
-                                    attempt, MAX_RETRIES, delay_ms, e
-                                );
-                                tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
+        let (qdrant_for_indexer, qdrant_for_searcher) = if backend == VectorBackend::InMemory {
+            // No external service: indexer and searcher share one
+            // RAM-held store so searches see freshly indexed points
+            info!("Using in-memory vector store (no Qdrant required)");
+            let store = Arc::new(RwLock::new(InMemoryVectorStore::new(
+                config.embeddings.dimensions,
+            )));
+            (
+                QdrantClient::with_store(
+                    &config.collection_name,
+                    config.embeddings.dimensions,
+                    store.clone(),
+                ),
+                QdrantClient::with_store(
+                    &config.collection_name,
+                    config.embeddings.dimensions,
+                    store,
+                ),
+            )
+        } else {
+            // Create Qdrant config and connect
+            let qdrant_config = QdrantConfig {
+                url: config.qdrant_url.clone(),
+                api_key: resolve_api_key_optional(&config.qdrant_api_key),
+                collection_name: config.collection_name.clone(),
+                dimensions: config.embeddings.dimensions,
+            };
+
+            // Connect to Qdrant with retry logic (create two clients - one for indexer, one for searcher)
+            // Retry with exponential backoff: 100ms, 200ms, 400ms (3 attempts total)
+            const MAX_RETRIES: u32 = 3;
+            const INITIAL_DELAY_MS: u64 = 100;
+
+            let qdrant_for_indexer = {
+                let mut last_error = None;
+                let client = 'outer: {
+                    for attempt in 1..=MAX_RETRIES {
+                        match QdrantClient::from_config(&qdrant_config).await {
+                            Ok(c) => {
+                                info!("Connected to Qdrant for indexer on attempt {}/{}", attempt, MAX_RETRIES);
+                                break 'outer c;
+                            }
+                            Err(e) => {
+                                last_error = Some(format!("{}", e));
+                                if attempt < MAX_RETRIES {
+                                    let delay_ms = INITIAL_DELAY_MS * (1 << (attempt - 1)); // 100, 200, 400
+                                    warn!(
+                                        "Qdrant connection attempt {}/{} failed (retrying in {}ms): {}",
+                                        attempt, MAX_RETRIES, delay_ms, e
+                                    );
+                                    tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
+                                }
                             }
                         }
                     }
-                }
-                return Err(anyhow::anyhow!("Qdrant connection failed after {} attempts: {}", MAX_RETRIES, last_error.unwrap()));
+                    return Err(anyhow::anyhow!("Qdrant connection failed after {} attempts: {}", MAX_RETRIES, last_error.unwrap()));
+                };
+                client
             };
-            client
-        };
 
-        let qdrant_for_searcher = {
-            let mut last_error = None;
-            let client = 'outer: {
-                for attempt in 1..=MAX_RETRIES {
-                    match QdrantClient::from_config(&qdrant_config).await {
-                        Ok(c) => {
-                            info!("Connected to Qdrant for searcher on attempt {}/{}", attempt, MAX_RETRIES);
-                            break 'outer c;
-                        }
-                        Err(e) => {
-                            last_error = Some(format!("{}", e));
-                            if attempt < MAX_RETRIES {
-                                let delay_ms = INITIAL_DELAY_MS * (1 << (attempt - 1)); // 100, 200, 400
-                                warn!(
-                                    "Qdrant connection attempt {}/{} failed (retrying in {}ms): {}",
-                                    attempt, MAX_RETRIES, delay_ms, e
-                                );
-                                tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
+            let qdrant_for_searcher = {
+                let mut last_error = None;
+                let client = 'outer: {
+                    for attempt in 1..=MAX_RETRIES {
+                        match QdrantClient::from_config(&qdrant_config).await {
+                            Ok(c) => {
+                                info!("Connected to Qdrant for searcher on attempt {}/{}", attempt, MAX_RETRIES);
+                                break 'outer c;
+                            }
+                            Err(e) => {
+                                last_error = Some(format!("{}", e));
+                                if attempt < MAX_RETRIES {
+                                    let delay_ms = INITIAL_DELAY_MS * (1 << (attempt - 1)); // 100, 200, 400
+                                    warn!(
+                                        "Qdrant connection attempt {}/{} failed (retrying in {}ms): {}",
+                                        attempt, MAX_RETRIES, delay_ms, e
+                                    );
+                                    tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
+                                }
                             }
                         }
                     }
-                }
-                return Err(anyhow::anyhow!("Qdrant connection failed after {} attempts: {}", MAX_RETRIES, last_error.unwrap()));
+                    return Err(anyhow::anyhow!("Qdrant connection failed after {} attempts: {}", MAX_RETRIES, last_error.unwrap()));
+                };
+                client
             };
-            client
+            (qdrant_for_indexer, qdrant_for_searcher)
         };
 
         // Set up state directory
@@ -208,8 +272,24 @@ impl IndexClient {
             embedding_batch_size: 32,
             respect_gitignore: true,
             extensions,
+            // Extensions above already restrict to the configured languages
+            languages: None,
             max_chunk_tokens: config.chunking.max_chunk_tokens,
             include_context: config.chunking.include_context,
+            include_imports: config.chunking.include_imports,
+            index_docstrings: false,
+            max_file_bytes: g3_index::indexer::DEFAULT_MAX_FILE_BYTES,
+            max_chunks_per_file: Some(g3_index::indexer::DEFAULT_MAX_CHUNKS_PER_FILE),
+            store_content: true,
+            redact_secrets: true,
+            redact_content_in_logs: true,
+            embedding_request_timeout_secs,
+            embedding_connect_timeout_secs,
+            graph_checkpoint_interval: g3_index::graph_builder::DEFAULT_GRAPH_CHECKPOINT_INTERVAL,
+            backend,
+            max_chunks: config.max_chunks,
+            enrich_blame: config.enrich_blame,
+            normalize_vectors: true,
         };
 
         // Create indexer with existing state
@@ -228,26 +308,86 @@ impl IndexClient {
             vector_weight: config.search.vector_weight,
             bm25_weight: config.search.bm25_weight,
             min_score: 0.0,
+            min_vector_score: None,
             hybrid: config.search.hybrid,
             rrf_k: 60.0,
+            fusion: g3_index::FusionMethod::parse(&config.search.fusion).unwrap_or_else(|| {
+                warn!(
+                    "Unknown fusion method '{}', falling back to RRF",
+                    config.search.fusion
+                );
+                g3_index::FusionMethod::default()
+            }),
+            dedupe_by_file: false,
+            recency_weight: 0.0,
+            rerank_top_n: config.search.rerank_top_n,
+            explain: false,
         };
 
-        // Create searcher sharing the BM25 index with indexer
-        let searcher = HybridSearcher::new(
+        // Optional reranking stage from [index.search] config. When the
+        // rerank flag is set without a reranker service configured, search
+        // proceeds without one.
+        let reranker: Option<Arc<dyn g3_index::Reranker>> = if config.search.rerank {
+            match (&config.search.reranker_url, &config.search.reranker_model) {
+                (Some(url), Some(model)) => {
+                    info!("Reranking enabled: model '{}' at {}", model, url);
+                    Some(Arc::new(g3_index::ChatReranker::new(
+                        url.clone(),
+                        model.clone(),
+                        resolve_api_key_optional(&config.search.reranker_api_key),
+                    )))
+                }
+                _ => {
+                    warn!("Reranking enabled but reranker_url/reranker_model not set; searching without reranker");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let rerank_enabled = reranker.is_some();
+
+        // Create searcher sharing the BM25 index with indexer.
+        // The content root enables disk fallback for indexes built with
+        // store_content: false.
+        let mut searcher = HybridSearcher::new(
             search_config,
             embeddings,
             qdrant_for_searcher,
             indexer.bm25_index().clone(),
-        );
+        )
+        .with_content_root(working_dir.to_path_buf());
+        if let Some(reranker) = reranker {
+            searcher = searcher.with_reranker(reranker);
+        }
+
+        // Grab the flag before the indexer goes behind the lock, so
+        // searches can check it while an indexing run holds the write lock
+        let indexing_flag = indexer.indexing_flag();
 
         Ok(Self {
             indexer: Arc::new(RwLock::new(indexer)),
             searcher,
             state_dir,
             working_dir: working_dir.to_path_buf(),
+            indexing_flag,
+            rerank_enabled,
+            repo_map_cache: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
+    /// Check whether searches run through a configured reranking stage.
+    pub fn rerank_enabled(&self) -> bool {
+        self.rerank_enabled
+    }
+
+    /// Check whether an indexing run is currently in progress.
+    ///
+    /// Search results may be incomplete while this returns true.
+    pub fn is_indexing(&self) -> bool {
+        self.indexing_flag.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
     /// Index the codebase.
     ///
     /// # Arguments
@@ -274,6 +414,9 @@ impl IndexClient {
         // Save state after indexing
         self.save_state().await?;
 
+        // The graph changed, so any cached repo map is stale
+        self.repo_map_cache.write().await.clear();
+
         info!(
             "Indexing complete: {} files, {} chunks in {}ms",
             stats.files_processed, stats.chunks_created, stats.duration_ms
@@ -284,10 +427,15 @@ impl IndexClient {
 
     /// Search the codebase.
     ///
+    /// When reranking is enabled in `[index.search]` config and a reranker
+    /// is configured, retrieved candidates are reranked before truncation
+    /// to the requested limit.
+    ///
     /// # Arguments
     /// * `query` - Natural language or code query
     /// * `limit` - Maximum number of results
     /// * `file_filter` - Optional file path prefix filter
+    /// * `public_only` - Restrict results to public symbols (API surface)
     ///
     /// # Returns
     /// A vector of search results sorted by relevance.
@@ -296,16 +444,26 @@ impl IndexClient {
         query: &str,
         limit: usize,
         file_filter: Option<&str>,
+        public_only: bool,
     ) -> Result<Vec<SearchResult>> {
         debug!(
-            "Searching for '{}' (limit={}, filter={:?})",
-            query, limit, file_filter
+            "Searching for '{}' (limit={}, filter={:?}, public_only={})",
+            query, limit, file_filter, public_only
         );
 
         // Build filter if provided
-        let filter = file_filter.map(|prefix| {
-            SearchFilter::new().with_path_prefix(prefix.to_string())
-        });
+        let filter = if file_filter.is_some() || public_only {
+            let mut f = SearchFilter::new();
+            if let Some(prefix) = file_filter {
+                f = f.with_path_prefix(prefix.to_string());
+            }
+            if public_only {
+                f = f.public_only();
+            }
+            Some(f)
+        } else {
+            None
+        };
 
         // Create a new searcher with the specified limit
         // Note: We can't easily change the limit on the existing searcher,
@@ -313,12 +471,153 @@ impl IndexClient {
         let results = self.searcher.search(query, filter).await?;
 
         // Truncate to requested limit (searcher may return more for RRF fusion)
-        let results: Vec<SearchResult> = results.into_iter().take(limit).collect();
+        let mut results: Vec<SearchResult> = results.into_iter().take(limit).collect();
+        self.attach_symbol_ids(&mut results).await;
+
+        debug!("Found {} search results", results.len());
+        Ok(results)
+    }
+
+    /// Semantic search using a tuned preset (see [`SearchPreset`]).
+    ///
+    /// Applies the preset's weights and its implied filter (docstring-only
+    /// chunks for `DocSearch`) on top of an optional file path filter.
+    pub async fn search_with_preset(
+        &self,
+        query: &str,
+        limit: usize,
+        file_filter: Option<&str>,
+        public_only: bool,
+        preset: SearchPreset,
+    ) -> Result<Vec<SearchResult>> {
+        debug!(
+            "Searching for '{}' with preset {:?} (limit={}, filter={:?}, public_only={})",
+            query, preset, limit, file_filter, public_only
+        );
+
+        let config = SearchConfig::preset(preset);
+
+        let mut filter = preset.filter().unwrap_or_default();
+        if let Some(prefix) = file_filter {
+            filter = filter.with_path_prefix(prefix.to_string());
+        }
+        if public_only {
+            filter = filter.public_only();
+        }
+
+        let results = self
+            .searcher
+            .search_with_weights(
+                query,
+                Some(filter),
+                config.vector_weight,
+                config.bm25_weight,
+                config.rerank_top_n,
+            )
+            .await?;
+
+        let mut results: Vec<SearchResult> = results.into_iter().take(limit).collect();
+        self.attach_symbol_ids(&mut results).await;
 
         debug!("Found {} search results", results.len());
         Ok(results)
     }
 
+    /// Measure search quality against a labeled query set.
+    ///
+    /// Each entry pairs a query with the chunk ids considered relevant.
+    /// Delegates to [`HybridSearcher::evaluate`]; the cutoff `k` applies
+    /// to recall@k and nDCG@k.
+    pub async fn evaluate_search(
+        &self,
+        queries: &[(String, Vec<String>)],
+        k: usize,
+    ) -> Result<g3_index::search::EvalReport> {
+        self.searcher.evaluate(queries, k).await
+    }
+
+    /// Attach the covering graph symbol (id, qualified name, signature) to
+    /// each result so the agent can understand a fragment from deep inside a
+    /// method body and pivot straight to graph tools (callers, references)
+    /// without a separate symbol lookup.
+    async fn attach_symbol_ids(&self, results: &mut [SearchResult]) {
+        let indexer = self.indexer.read().await;
+
+        // Keep LRU access times fresh for eviction under a chunk budget
+        let accessed: Vec<PathBuf> = results
+            .iter()
+            .map(|r| PathBuf::from(&r.file_path))
+            .collect();
+        indexer.mark_accessed(&accessed).await;
+
+        if let Some(gb) = indexer.graph_builder() {
+            let gb_read = gb.read().await;
+            for result in results.iter_mut() {
+                result.attach_enclosing_symbol(gb_read.graph());
+            }
+        }
+    }
+
+    /// Semantic search with graph-enriched results.
+    ///
+    /// Runs the normal hybrid search, then attaches each result's direct
+    /// callers and callees from the knowledge graph to its metadata. Saves
+    /// a round-trip for the common "find it then explore it" flow.
+    pub async fn search_enriched(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<UnifiedSearchResult>> {
+        self.search_enriched_with_config(query, limit, EnrichmentConfig::default())
+            .await
+    }
+
+    /// Semantic search with graph enrichment using a custom configuration.
+    ///
+    /// The configured graph depth is clamped to `MAX_ENRICHMENT_DEPTH` so a
+    /// misconfigured depth can't make enrichment unbounded.
+    pub async fn search_enriched_with_config(
+        &self,
+        query: &str,
+        limit: usize,
+        mut config: EnrichmentConfig,
+    ) -> Result<Vec<UnifiedSearchResult>> {
+        config.graph_context_depth = config.graph_context_depth.clamp(1, MAX_ENRICHMENT_DEPTH);
+
+        let results = self.search(query, limit, None, false).await?;
+        let unified: Vec<UnifiedSearchResult> = results
+            .into_iter()
+            .map(|r| {
+                let symbol_id = r.symbol_id.clone();
+                let mut unified = UnifiedSearchResult::from_vector(
+                    r.id,
+                    r.file_path,
+                    r.start_line,
+                    r.end_line,
+                    r.content,
+                    r.kind,
+                    r.name,
+                    r.signature,
+                    r.scope,
+                    r.score,
+                );
+                unified.symbol_id = symbol_id;
+                unified
+            })
+            .collect();
+
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(unified);
+        };
+        let gb_read = gb.read().await;
+        let connector = IndexConnector::new(config, gb_read.graph().clone());
+        Ok(unified
+            .into_iter()
+            .map(|r| connector.enrich_result(r, false))
+            .collect())
+    }
+
     /// Get current index statistics.
     pub async fn get_stats(&self) -> IndexStats {
         let indexer = self.indexer.read().await;
@@ -364,8 +663,25 @@ impl IndexClient {
 
     /// Find all symbols with a given name.
     ///
-    /// Returns symbols that match the exact name across all files.
-    pub async fn find_symbols_by_name(&self, name: &str) -> Result<Vec<SymbolInfo>> {
+    /// Matches case-insensitively and returns symbols ranked by definition
+    /// quality: exact-case matches, definitions with bodies, and signed or
+    /// highly-referenced symbols come first. `kind` restricts results to a
+    /// symbol kind (e.g. "struct", "function") and `file_prefix` to a
+    /// file-path prefix; both are optional.
+    pub async fn find_symbols_by_name(
+        &self,
+        name: &str,
+        kind: Option<&str>,
+        file_prefix: Option<&str>,
+    ) -> Result<Vec<SymbolInfo>> {
+        let kind_filter = match kind {
+            Some(k) => Some(
+                g3_index::SymbolKind::parse(k)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown symbol kind: {}", k))?,
+            ),
+            None => None,
+        };
+
         let indexer = self.indexer.read().await;
         let Some(gb) = indexer.graph_builder() else {
             return Ok(Vec::new());
@@ -373,7 +689,7 @@ impl IndexClient {
 
         let gb_read = gb.read().await;
         let symbols: Vec<SymbolInfo> = gb_read
-            .find_symbols_by_name(name)
+            .find_symbols_ranked(name, kind_filter, file_prefix)
             .into_iter()
             .map(SymbolInfo::from)
             .collect();
@@ -382,6 +698,28 @@ impl IndexClient {
         Ok(symbols)
     }
 
+    /// Find the program's entrypoints from the knowledge graph.
+    ///
+    /// Each entry pairs the symbol with a classification label ("main",
+    /// "test", or "public_root"). Returns an empty list when no graph is
+    /// available.
+    pub async fn find_entrypoints(&self) -> Result<Vec<(SymbolInfo, String)>> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(Vec::new());
+        };
+
+        let gb_read = gb.read().await;
+        let entrypoints: Vec<(SymbolInfo, String)> = gb_read
+            .find_entrypoints()
+            .into_iter()
+            .map(|(symbol, kind)| (SymbolInfo::from(symbol), kind.label().to_string()))
+            .collect();
+
+        debug!("Found {} entrypoints", entrypoints.len());
+        Ok(entrypoints)
+    }
+
     /// Get all symbols in a file.
     pub async fn get_file_symbols(&self, file_path: &str) -> Result<Vec<SymbolInfo>> {
         let indexer = self.indexer.read().await;
@@ -400,6 +738,90 @@ impl IndexClient {
         Ok(symbols)
     }
 
+    /// Import edges for a file: what it imports (outgoing `Imports` edges)
+    /// and who imports its symbols (incoming `Imports` edges).
+    pub async fn file_imports(
+        &self,
+        file_path: &str,
+    ) -> Result<(Vec<g3_index::Edge>, Vec<g3_index::Edge>)> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok((Vec::new(), Vec::new()));
+        };
+
+        let gb_read = gb.read().await;
+        let imports = gb_read.file_imports(file_path);
+        let importers = gb_read.file_importers(file_path);
+
+        debug!(
+            "Found {} imports and {} importers for '{}'",
+            imports.len(),
+            importers.len(),
+            file_path
+        );
+        Ok((imports, importers))
+    }
+
+    /// Nested symbol outline for a file (modules → impls → methods), like
+    /// an editor's outline view.
+    pub async fn file_outline(&self, file_path: &str) -> Result<Vec<g3_index::OutlineNode>> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(Vec::new());
+        };
+
+        let gb_read = gb.read().await;
+        let outline = gb_read.file_outline(file_path);
+
+        debug!(
+            "Built outline with {} top-level symbols for '{}'",
+            outline.len(),
+            file_path
+        );
+        Ok(outline)
+    }
+
+    /// Resolve a reference position to its defining symbol from the
+    /// knowledge graph, without consulting a language server.
+    pub async fn resolve_definition(
+        &self,
+        file: &str,
+        line: usize,
+        col: usize,
+    ) -> Result<Option<g3_index::graph::SymbolNode>> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(None);
+        };
+
+        Ok(gb.read().await.resolve_definition(file, line, col))
+    }
+
+    /// Compact textual map of the repository for priming context:
+    /// directories and files ranked by graph centrality, with their
+    /// most-referenced symbols, kept within `max_tokens` (~4 chars/token).
+    ///
+    /// Cached per budget and invalidated after each indexing run.
+    pub async fn repo_map(&self, max_tokens: usize) -> Result<String> {
+        if let Some(cached) = self.repo_map_cache.read().await.get(&max_tokens) {
+            debug!("Serving repo map for {} tokens from cache", max_tokens);
+            return Ok(cached.clone());
+        }
+
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(String::new());
+        };
+
+        let map = gb.read().await.repo_map(max_tokens);
+
+        self.repo_map_cache
+            .write()
+            .await
+            .insert(max_tokens, map.clone());
+        Ok(map)
+    }
+
     /// Find all callers of a symbol.
     ///
     /// Returns the IDs of symbols that call the given symbol.
@@ -416,6 +838,22 @@ impl IndexClient {
         Ok(callers)
     }
 
+    /// Find tests covering a symbol.
+    ///
+    /// Returns the IDs of test functions that statically call the symbol.
+    pub async fn find_tests(&self, symbol_id: &str) -> Result<Vec<String>> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(Vec::new());
+        };
+
+        let gb_read = gb.read().await;
+        let tests = gb_read.find_tests(symbol_id);
+
+        debug!("Found {} tests for symbol '{}'", tests.len(), symbol_id);
+        Ok(tests)
+    }
+
     /// Find all callees of a symbol.
     ///
     /// Returns the IDs of symbols called by the given symbol.
@@ -454,6 +892,119 @@ impl IndexClient {
         Ok(refs)
     }
 
+    /// Get the one-hop neighborhood of a symbol.
+    ///
+    /// Returns the direct callers, callees, references, and implements
+    /// relationships grouped by edge kind.
+    pub async fn graph_neighbors(&self, symbol_id: &str) -> Result<g3_index::Neighborhood> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(g3_index::Neighborhood::default());
+        };
+
+        let gb_read = gb.read().await;
+        let neighborhood = gb_read.neighbors(symbol_id);
+
+        debug!(
+            "Neighborhood of '{}': {} callers, {} callees, {} references, {} implements",
+            symbol_id,
+            neighborhood.callers.len(),
+            neighborhood.callees.len(),
+            neighborhood.references.len(),
+            neighborhood.implements.len()
+        );
+        Ok(neighborhood)
+    }
+
+    /// Run a graph query DSL string against the knowledge graph.
+    ///
+    /// Supports `callers()`, `callees()`, `references()`, `implements()`,
+    /// a `file()` path filter, and intersection with `&`. Parse errors
+    /// surface as `Err` with a message describing the problem.
+    pub async fn graph_query(&self, query: &str) -> Result<Vec<String>> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(Vec::new());
+        };
+
+        let gb_read = gb.read().await;
+        let results = gb_read.query(query)?;
+
+        debug!("Graph query '{}' matched {} symbols", query, results.len());
+        Ok(results)
+    }
+
+    /// Find the file-level dependency path between two files.
+    ///
+    /// Returns the chain of files connecting `from_file` to `to_file`
+    /// through cross-file edges, or None if the files are independent.
+    pub async fn file_dependency_path(
+        &self,
+        from_file: &str,
+        to_file: &str,
+    ) -> Result<Option<Vec<String>>> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(None);
+        };
+
+        let gb_read = gb.read().await;
+        let path = gb_read.file_dependency_path(from_file, to_file);
+
+        debug!(
+            "File dependency path from '{}' to '{}': {:?}",
+            from_file, to_file, path
+        );
+        Ok(path)
+    }
+
+    /// Diff the current knowledge graph against the saved baseline.
+    ///
+    /// Returns None when the graph is disabled or no baseline has been saved.
+    pub async fn graph_diff(&self) -> Result<Option<g3_index::GraphDiff>> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(None);
+        };
+
+        let gb_read = gb.read().await;
+        let diff = gb_read.diff_against_baseline()?;
+
+        if let Some(ref d) = diff {
+            debug!(
+                "Graph diff: +{} symbols, -{} symbols, {} signature changes",
+                d.added.len(),
+                d.removed.len(),
+                d.signature_changes.len()
+            );
+        }
+        Ok(diff)
+    }
+
+    /// Save the current knowledge graph as the baseline for future diffs.
+    ///
+    /// Returns false when the graph is disabled.
+    pub async fn save_graph_baseline(&self) -> Result<bool> {
+        let indexer = self.indexer.read().await;
+        let Some(gb) = indexer.graph_builder() else {
+            return Ok(false);
+        };
+
+        let gb_read = gb.read().await;
+        gb_read.save_diff_baseline()?;
+        Ok(true)
+    }
+
+    /// Report the largest stored chunks, ranked by byte size or line count.
+    pub async fn get_chunk_report(
+        &self,
+        limit: usize,
+        sort: ChunkSortKey,
+    ) -> Result<Vec<ChunkReportEntry>> {
+        let indexer = self.indexer.read().await;
+        indexer.chunk_report(limit, sort).await
+    }
+
     /// Get knowledge graph statistics.
     pub async fn get_graph_stats(&self) -> Result<GraphStats> {
         let indexer = self.indexer.read().await;
@@ -462,9 +1013,12 @@ impl IndexClient {
         };
 
         let gb_read = gb.read().await;
+        let stats = gb_read.stats();
         Ok(GraphStats {
-            symbol_count: gb_read.symbol_count(),
-            file_count: gb_read.file_count(),
+            symbol_count: stats.symbol_count,
+            file_count: stats.file_count,
+            symbols_by_kind: stats.symbols_by_kind,
+            files_by_language: stats.files_by_language,
         })
     }
 }
@@ -479,6 +1033,9 @@ pub struct SymbolInfo {
     pub line_start: usize,
     pub line_end: usize,
     pub signature: Option<String>,
+    /// Language-specific metadata carried over from the graph node
+    /// (e.g. blame authorship, test flags)
+    pub metadata: Option<serde_json::Value>,
 }
 
 impl From<&g3_index::SymbolNode> for SymbolInfo {
@@ -491,6 +1048,7 @@ impl From<&g3_index::SymbolNode> for SymbolInfo {
             line_start: node.line_start,
             line_end: node.line_end,
             signature: node.signature.clone(),
+            metadata: node.metadata.clone(),
         }
     }
 }
@@ -520,6 +1078,10 @@ impl From<g3_index::Edge> for ReferenceInfo {
 pub struct GraphStats {
     pub symbol_count: usize,
     pub file_count: usize,
+    /// Symbol counts keyed by kind label ("fn", "struct", ...)
+    pub symbols_by_kind: std::collections::BTreeMap<String, usize>,
+    /// File counts keyed by language
+    pub files_by_language: std::collections::BTreeMap<String, usize>,
 }
 
 /// Resolve an API key value, expanding ${ENV_VAR} syntax.
@@ -686,9 +1248,13 @@ mod tests {
         // Create a minimal config
         let config = IndexConfig {
             enabled: true,
+            backend: "qdrant".to_string(),
             qdrant_url: "http://localhost:6334".to_string(),
             qdrant_api_key: None,
             collection_name: "g3-test-collection".to_string(),
+            max_chunks: None,
+            fallback_to_text: true,
+            enrich_blame: false,
             embeddings: g3_config::EmbeddingsConfig {
                 provider: "openrouter".to_string(),
                 api_key: Some("${OPENROUTER_API_KEY}".to_string()),
@@ -721,7 +1287,7 @@ mod tests {
             stats.files_processed, stats.chunks_created, stats.duration_ms);
 
         // Try searching
-        let results = client.search("IndexClient", 5, None).await;
+        let results = client.search("IndexClient", 5, None, false).await;
         assert!(results.is_ok(), "Search failed: {:?}", results.err());
 
         let results = results.unwrap();
@@ -51,6 +51,9 @@ pub use pending_research::{PendingResearchManager, ResearchCompletionNotificatio
 // Export agent prompt generation for CLI use
 pub use prompts::get_agent_system_prompt;
 
+// Re-export the file write guard trait for multi-agent coordinators
+pub use tools::executor::FileWriteGuard;
+
 #[cfg(test)]
 mod task_result_comprehensive_tests;
 use crate::ui_writer::UiWriter;
@@ -178,6 +181,8 @@ pub struct Agent<W: UiWriter> {
     beads_context_injected: bool,
     /// Active persona data from agent front matter (for scope enforcement and tool overrides)
     active_persona: Option<persona::PersonaData>,
+    /// Guard consulted before file-writing tools run (for coordinated multi-agent runs)
+    file_write_guard: Option<std::sync::Arc<dyn tools::executor::FileWriteGuard>>,
     /// Team name (set via --team flag)
     team_name: Option<String>,
     /// Agent's role name within the team (set via --team-role flag)
@@ -250,6 +255,7 @@ impl<W: UiWriter> Agent<W> {
             lsp_manager,
             beads_context_injected,
             active_persona: None,
+            file_write_guard: None,
             team_name: None,
             team_role: None,
             is_team_lead: false,
@@ -1342,7 +1348,8 @@ impl<W: UiWriter> Agent<W> {
                         task.result.as_deref().unwrap_or("Unknown error")
                     )
                 }
-                pending_research::ResearchStatus::Pending => continue, // Skip pending tasks
+                pending_research::ResearchStatus::Pending
+                | pending_research::ResearchStatus::Running => continue, // Not finished yet
             };
             
             // Inject as a user message so the agent sees and responds to it
@@ -1833,6 +1840,16 @@ impl<W: UiWriter> Agent<W> {
         self.active_persona = Some(persona);
     }
 
+    /// Install a guard consulted before file-writing tools run.
+    ///
+    /// Used by multi-agent coordinators to serialize writes across agents
+    /// working in the same tree: a vetoed write is returned to the model as
+    /// the tool result instead of touching disk.
+    pub fn set_file_write_guard(&mut self, guard: std::sync::Arc<dyn tools::executor::FileWriteGuard>) {
+        debug!("File write guard installed");
+        self.file_write_guard = Some(guard);
+    }
+
     /// Replace the system message (first message in conversation history).
     /// Used by runtime `/agent` switching to change personas mid-session.
     pub fn replace_system_message(&mut self, new_prompt: String) {
@@ -3425,6 +3442,7 @@ Skip if nothing new was discovered. Do NOT repeat information already in memory.
             index_client: self.index_client.read().await.clone(),
             lsp_manager: self.lsp_manager.clone(),
             active_persona: self.active_persona.as_ref(),
+            file_write_guard: self.file_write_guard.as_deref(),
         };
 
         // Dispatch to the appropriate tool handler
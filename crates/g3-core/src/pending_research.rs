@@ -6,7 +6,7 @@
 //! are sent via a channel for real-time UI updates.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tracing::debug;
@@ -17,18 +17,28 @@ pub type ResearchId = String;
 /// Status of a research task
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ResearchStatus {
-    /// Research is in progress
+    /// Research is registered but the scout agent hasn't started yet
     Pending,
+    /// The scout agent is actively working on the query
+    Running,
     /// Research completed successfully
     Complete,
     /// Research failed with an error
     Failed,
 }
 
+impl ResearchStatus {
+    /// Whether the task has finished (successfully or not).
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, ResearchStatus::Complete | ResearchStatus::Failed)
+    }
+}
+
 impl std::fmt::Display for ResearchStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ResearchStatus::Pending => write!(f, "pending"),
+            ResearchStatus::Running => write!(f, "running"),
             ResearchStatus::Complete => write!(f, "complete"),
             ResearchStatus::Failed => write!(f, "failed"),
         }
@@ -96,6 +106,11 @@ pub struct ResearchCompletionNotification {
 #[derive(Debug, Clone)]
 pub struct PendingResearchManager {
     tasks: Arc<Mutex<HashMap<ResearchId, ResearchTask>>>,
+    /// IDs in the order their tasks finished, so injection is deterministic
+    completion_order: Arc<Mutex<Vec<ResearchId>>>,
+    /// IDs whose results have already been injected - survives task removal
+    /// so a retried completion can never be injected a second time
+    injected_ids: Arc<Mutex<HashSet<ResearchId>>>,
     /// Channel sender for completion notifications (optional, for UI updates)
     completion_tx: Option<tokio::sync::broadcast::Sender<ResearchCompletionNotification>>,
 }
@@ -111,6 +126,8 @@ impl PendingResearchManager {
     pub fn new() -> Self {
         Self {
             tasks: Arc::new(Mutex::new(HashMap::new())),
+            completion_order: Arc::new(Mutex::new(Vec::new())),
+            injected_ids: Arc::new(Mutex::new(HashSet::new())),
             completion_tx: None,
         }
     }
@@ -124,6 +141,8 @@ impl PendingResearchManager {
         let (tx, rx) = tokio::sync::broadcast::channel(16);
         let manager = Self {
             tasks: Arc::new(Mutex::new(HashMap::new())),
+            completion_order: Arc::new(Mutex::new(Vec::new())),
+            injected_ids: Arc::new(Mutex::new(HashSet::new())),
             completion_tx: Some(tx),
         };
         (manager, rx)
@@ -164,42 +183,47 @@ impl PendingResearchManager {
         id
     }
 
-    /// Update a research task with its result
-    pub fn complete(&self, id: &ResearchId, result: String) {
-        let notification = {
-            let mut tasks = self.tasks.lock().unwrap();
-            if let Some(task) = tasks.get_mut(id) {
-                task.status = ResearchStatus::Complete;
-                task.result = Some(result);
-                debug!("Research task {} completed successfully", id);
-                Some(ResearchCompletionNotification {
-                    id: id.clone(),
-                    status: ResearchStatus::Complete,
-                    query: task.query.clone(),
-                })
-            } else {
-                None
+    /// Mark a research task as actively running (the scout agent has started)
+    pub fn mark_running(&self, id: &ResearchId) {
+        let mut tasks = self.tasks.lock().unwrap();
+        if let Some(task) = tasks.get_mut(id) {
+            if task.status == ResearchStatus::Pending {
+                task.status = ResearchStatus::Running;
             }
-        };
-        // Send notification outside the lock to avoid potential deadlocks
-        if let (Some(notification), Some(tx)) = (notification, &self.completion_tx) {
-            let _ = tx.send(notification); // Ignore error if no receivers
         }
     }
 
+    /// Update a research task with its result
+    ///
+    /// A retried completion for an already-finished task is ignored, so each
+    /// task enters the completion order (and the conversation) exactly once.
+    pub fn complete(&self, id: &ResearchId, result: String) {
+        self.finish(id, ResearchStatus::Complete, result);
+    }
+
     /// Mark a research task as failed
     pub fn fail(&self, id: &ResearchId, error: String) {
+        self.finish(id, ResearchStatus::Failed, error);
+    }
+
+    fn finish(&self, id: &ResearchId, status: ResearchStatus, result: String) {
         let notification = {
             let mut tasks = self.tasks.lock().unwrap();
             if let Some(task) = tasks.get_mut(id) {
-                task.status = ResearchStatus::Failed;
-                task.result = Some(error);
-                debug!("Research task {} failed", id);
-                Some(ResearchCompletionNotification {
-                    id: id.clone(),
-                    status: ResearchStatus::Failed,
-                    query: task.query.clone(),
-                })
+                if task.status.is_terminal() {
+                    debug!("Ignoring duplicate completion for research task {}", id);
+                    None
+                } else {
+                    task.status = status.clone();
+                    task.result = Some(result);
+                    self.completion_order.lock().unwrap().push(id.clone());
+                    debug!("Research task {} finished: {}", id, status);
+                    Some(ResearchCompletionNotification {
+                        id: id.clone(),
+                        status,
+                        query: task.query.clone(),
+                    })
+                }
             } else {
                 None
             }
@@ -243,7 +267,7 @@ impl PendingResearchManager {
         let tasks = self.tasks.lock().unwrap();
         tasks
             .values()
-            .filter(|t| t.status == ResearchStatus::Pending)
+            .filter(|t| !t.status.is_terminal())
             .count()
     }
 
@@ -252,24 +276,34 @@ impl PendingResearchManager {
         let tasks = self.tasks.lock().unwrap();
         tasks
             .values()
-            .filter(|t| !t.injected && t.status != ResearchStatus::Pending)
+            .filter(|t| !t.injected && t.status.is_terminal())
             .count()
     }
 
     /// Take all completed research tasks that haven't been injected yet
     ///
-    /// Marks them as injected so they won't be returned again
+    /// Tasks are returned in the order they finished, and each ID is recorded
+    /// in the injected registry so it can never be handed out again - even if
+    /// a retry completes the same task after a cleanup.
     pub fn take_completed(&self) -> Vec<ResearchTask> {
         let mut tasks = self.tasks.lock().unwrap();
+        let order = self.completion_order.lock().unwrap();
+        let mut injected_ids = self.injected_ids.lock().unwrap();
         let mut completed = Vec::new();
-        
-        for task in tasks.values_mut() {
-            if !task.injected && task.status != ResearchStatus::Pending {
-                task.injected = true;
-                completed.push(task.clone());
+
+        for id in order.iter() {
+            if injected_ids.contains(id) {
+                continue;
+            }
+            if let Some(task) = tasks.get_mut(id) {
+                if !task.injected && task.status.is_terminal() {
+                    task.injected = true;
+                    injected_ids.insert(id.clone());
+                    completed.push(task.clone());
+                }
             }
         }
-        
+
         debug!("Took {} completed research tasks for injection", completed.len());
         completed
     }
@@ -284,6 +318,13 @@ impl PendingResearchManager {
     pub fn cleanup_injected(&self) {
         let mut tasks = self.tasks.lock().unwrap();
         tasks.retain(|_, t| !t.injected);
+        // Drop order entries that no longer have a live task; the injected
+        // registry is kept so retries can't resurrect them
+        let injected_ids = self.injected_ids.lock().unwrap();
+        self.completion_order
+            .lock()
+            .unwrap()
+            .retain(|id| !injected_ids.contains(id));
     }
 
     /// Check if there are any tasks (pending or ready)
@@ -296,8 +337,8 @@ impl PendingResearchManager {
     pub fn format_status_summary(&self) -> Option<String> {
         let tasks = self.tasks.lock().unwrap();
         
-        let pending: Vec<_> = tasks.values().filter(|t| t.status == ResearchStatus::Pending).collect();
-        let ready: Vec<_> = tasks.values().filter(|t| !t.injected && t.status != ResearchStatus::Pending).collect();
+        let pending: Vec<_> = tasks.values().filter(|t| !t.status.is_terminal()).collect();
+        let ready: Vec<_> = tasks.values().filter(|t| !t.injected && t.status.is_terminal()).collect();
         
         if pending.is_empty() && ready.is_empty() {
             return None;
@@ -492,6 +533,61 @@ mod tests {
         assert_eq!(manager.list_all().len(), 0);
     }
 
+    #[test]
+    fn test_out_of_order_completion_injects_once_in_completion_order() {
+        let manager = PendingResearchManager::new();
+
+        let id1 = manager.register("Query 1");
+        let id2 = manager.register("Query 2");
+
+        // The second task finishes first
+        manager.complete(&id2, "Report 2".to_string());
+        manager.complete(&id1, "Report 1".to_string());
+
+        let completed = manager.take_completed();
+        assert_eq!(completed.len(), 2);
+        assert_eq!(completed[0].id, id2);
+        assert_eq!(completed[1].id, id1);
+
+        // A retry completing an already-injected task must not re-inject it
+        manager.complete(&id1, "Report 1 (retry)".to_string());
+        assert!(manager.take_completed().is_empty());
+
+        // Even after cleanup, the injected registry blocks resurrection
+        manager.cleanup_injected();
+        manager.complete(&id1, "Report 1 (late retry)".to_string());
+        assert!(manager.take_completed().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_completion_is_ignored() {
+        let manager = PendingResearchManager::new();
+
+        let id = manager.register("Query");
+        manager.complete(&id, "First report".to_string());
+        manager.complete(&id, "Second report".to_string());
+
+        let (status, result) = manager.get_status(&id).unwrap();
+        assert_eq!(status, ResearchStatus::Complete);
+        assert_eq!(result.unwrap(), "First report");
+
+        // Only one entry in the completion order
+        assert_eq!(manager.take_completed().len(), 1);
+    }
+
+    #[test]
+    fn test_mark_running_transitions_only_from_pending() {
+        let manager = PendingResearchManager::new();
+
+        let id = manager.register("Query");
+        manager.mark_running(&id);
+        assert_eq!(manager.get(&id).unwrap().status, ResearchStatus::Running);
+
+        manager.complete(&id, "Report".to_string());
+        manager.mark_running(&id);
+        assert_eq!(manager.get(&id).unwrap().status, ResearchStatus::Complete);
+    }
+
     #[test]
     fn test_generate_id_uniqueness() {
         let ids: Vec<_> = (0..100).map(|_| PendingResearchManager::generate_id()).collect();
@@ -207,7 +207,8 @@ fn register_zai_providers(
                 zai_config.web_search_count,
                 zai_config.web_search_recency.clone(),
                 zai_config.web_search_content_size.clone(),
-            )?;
+            )?
+            .with_leaked_thinking_stripping(zai_config.strip_leaked_thinking.unwrap_or(true));
             registry.register(zai_provider);
         }
     }
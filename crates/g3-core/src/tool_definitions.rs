@@ -312,6 +312,18 @@ fn create_core_tools(exclude_research: bool) -> Vec<Tool> {
                 "required": ["searches"]
             }),
         },
+        Tool {
+            name: "validate_query".to_string(),
+            description: "Validate a tree-sitter query against a language grammar without running it. Compiles the S-expression query and, if malformed, reports the offending position and a hint (e.g. unknown node type, unbalanced parenthesis). Use this before code_search when unsure about a grammar's node names.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "tree-sitter query in S-expression format to validate." },
+                    "language": { "type": "string", "enum": ["rust", "python", "javascript", "typescript", "go", "java", "c", "cpp", "racket"], "description": "Language grammar to validate against." }
+                },
+                "required": ["query", "language"]
+            }),
+        },
     ];
 
     // Conditionally add the research tool (excluded for scout agent to prevent recursion)
@@ -998,6 +1010,29 @@ fn create_index_tools() -> Vec<Tool> {
                     "file_filter": {
                         "type": "string",
                         "description": "Glob pattern to filter files (e.g., 'src/**/*.rs', '*.py')"
+                    },
+                    "mode": {
+                        "type": "string",
+                        "enum": ["code", "keyword", "docs"],
+                        "description": "Tuned search profile: 'code' for navigating by meaning, 'keyword' for exact identifiers/phrases, 'docs' to search doc comments. Omit for balanced defaults."
+                    },
+                    "public_only": {
+                        "type": "boolean",
+                        "description": "Only return public symbols (pub in Rust, exported in JS/TS). Use for API surface and external-consumer questions. Default: false."
+                    },
+                    "max_total_chars": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Cap on the combined size of all result content. After ranking, results are included until the budget is used up (the last one truncated to fit); the rest are omitted and reported. Use to keep output within a predictable token footprint."
+                    },
+                    "snippet_max_chars": {
+                        "type": "integer",
+                        "minimum": 50,
+                        "description": "Per-result content size (default: 500). Code snippets are cut at the nearest statement or block boundary within this budget."
+                    },
+                    "signatures_only": {
+                        "type": "boolean",
+                        "description": "Return only names, kinds, files, and signatures, omitting chunk bodies. Far cheaper in tokens; use to scan many candidates before reading specific ones. Default: false."
                     }
                 },
                 "required": ["query"]
@@ -1012,6 +1047,28 @@ fn create_index_tools() -> Vec<Tool> {
                 "required": []
             }),
         },
+        Tool {
+            name: "index_chunk_report".to_string(),
+            description: "List the largest indexed chunks by byte size or line count. Use to spot chunking pathologies - e.g. one giant function chunk dominating search results - and tune max_chunk settings.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "limit": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 50,
+                        "default": 10,
+                        "description": "Number of chunks to report (default: 10)"
+                    },
+                    "sort_by": {
+                        "type": "string",
+                        "enum": ["bytes", "lines"],
+                        "description": "Rank by byte size or line count (default: bytes)"
+                    }
+                },
+                "required": []
+            }),
+        },
         // Self-improvement tools
         Tool {
             name: "scan_folder".to_string(),
@@ -1043,7 +1100,7 @@ fn create_index_tools() -> Vec<Tool> {
         },
         Tool {
             name: "list_directory".to_string(),
-            description: "List files in a directory with metadata (name, size, line count, test flag). Useful for exploring codebase structure and finding files of interest.".to_string(),
+            description: "List files in a directory with metadata (name, size, line count, test flag). Useful for exploring codebase structure and finding files of interest. Pass max_depth for a recursive tree-like overview with per-directory aggregate sizes and file counts.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
@@ -1054,6 +1111,12 @@ fn create_index_tools() -> Vec<Tool> {
                     "include_hidden": {
                         "type": "boolean",
                         "description": "Include hidden files (default: false)"
+                    },
+                    "max_depth": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 10,
+                        "description": "Recurse into subdirectories up to this depth, returning a nested tree with per-directory totals. Omit for the flat single-level listing."
                     }
                 },
                 "required": ["path"]
@@ -1061,7 +1124,7 @@ fn create_index_tools() -> Vec<Tool> {
         },
         Tool {
             name: "preview_file".to_string(),
-            description: "Preview the first N lines of a file without reading the entire content. Useful for getting a quick overview of a file's purpose and structure.".to_string(),
+            description: "Preview the first N lines of a file without reading the entire content, or a line-numbered window around a specific line via around_line. Useful for getting a quick overview of a file, or for seeing the context around a search result.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
@@ -1071,7 +1134,15 @@ fn create_index_tools() -> Vec<Tool> {
                     },
                     "num_lines": {
                         "type": "integer",
-                        "description": "Number of lines to preview (default: 50)"
+                        "description": "Number of lines to preview from the top (default: 50); ignored when around_line is set"
+                    },
+                    "around_line": {
+                        "type": "integer",
+                        "description": "Center the preview on this 1-indexed line instead of the top of the file"
+                    },
+                    "context": {
+                        "type": "integer",
+                        "description": "Lines shown before and after around_line (default: 5)"
                     }
                 },
                 "required": ["path"]
@@ -1139,6 +1210,14 @@ fn create_index_tools() -> Vec<Tool> {
                     "name": {
                         "type": "string",
                         "description": "The symbol name to search for (e.g., 'process_file', 'Config', 'handle_error')"
+                    },
+                    "kind": {
+                        "type": "string",
+                        "description": "Optional symbol kind filter (e.g., 'function', 'struct', 'trait', 'method'). Useful for common names like 'new'."
+                    },
+                    "file_prefix": {
+                        "type": "string",
+                        "description": "Optional file-path prefix to scope results (e.g., 'src/auth')"
                     }
                 },
                 "required": ["name"]
@@ -1158,6 +1237,58 @@ fn create_index_tools() -> Vec<Tool> {
                 "required": ["file_path"]
             }),
         },
+        Tool {
+            name: "file_outline".to_string(),
+            description: "Nested symbol outline for a file (modules → impls → methods), like an editor's outline view. Friendlier than graph_file_symbols' flat list for navigating a file's structure.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "file_path": {
+                        "type": "string",
+                        "description": "Path to the file (relative to workspace root)"
+                    }
+                },
+                "required": ["file_path"]
+            }),
+        },
+        Tool {
+            name: "repo_map".to_string(),
+            description: "Compact token-budgeted map of the repository: directories and files ranked by graph centrality, each listing its most-referenced symbols. Good first call for orienting in an unfamiliar codebase.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "max_tokens": {
+                        "type": "integer",
+                        "description": "Token budget for the map (default: 2000)"
+                    }
+                },
+                "required": []
+            }),
+        },
+        Tool {
+            name: "find_definition".to_string(),
+            description: "Jump to the definition of the symbol at a position (1-indexed line and character). Answered instantly from the knowledge graph when one is available, falling back to a language server otherwise. Much faster than lsp_goto_definition for repeated navigation in indexed code.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "file_path": {
+                        "type": "string",
+                        "description": "Path to the source file"
+                    },
+                    "line": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Line number (1-indexed)"
+                    },
+                    "character": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Character position on the line (1-indexed)"
+                    }
+                },
+                "required": ["file_path", "line", "character"]
+            }),
+        },
         Tool {
             name: "graph_find_callers".to_string(),
             description: "Find all callers of a function or method. Returns symbols that call the given symbol. Useful for understanding code dependencies and impact analysis.".to_string(),
@@ -1172,6 +1303,20 @@ fn create_index_tools() -> Vec<Tool> {
                 "required": ["symbol_id"]
             }),
         },
+        Tool {
+            name: "graph_find_tests".to_string(),
+            description: "Find tests covering a function or method, approximated from static calls: test functions that call the symbol. Useful for answering 'what tests cover this code' before changing it.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "symbol_id": {
+                        "type": "string",
+                        "description": "The symbol ID to find covering tests for (from graph_find_symbol results)"
+                    }
+                },
+                "required": ["symbol_id"]
+            }),
+        },
         Tool {
             name: "graph_find_references".to_string(),
             description: "Find all references to a symbol. Returns locations where the symbol is used. More comprehensive than find_callers as it includes all usage types.".to_string(),
@@ -1186,6 +1331,66 @@ fn create_index_tools() -> Vec<Tool> {
                 "required": ["symbol_id"]
             }),
         },
+        Tool {
+            name: "graph_neighbors".to_string(),
+            description: "Get the immediate neighborhood of a symbol in one call: direct callers, callees, references, and implements relationships grouped by kind. A cheaper alternative to separate caller/reference lookups for quick orientation around a symbol.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "symbol_id": {
+                        "type": "string",
+                        "description": "The symbol ID to inspect (from graph_find_symbol results)"
+                    }
+                },
+                "required": ["symbol_id"]
+            }),
+        },
+        Tool {
+            name: "graph_query".to_string(),
+            description: "Run a composable query against the knowledge graph using a small DSL. Supported operations: callers(x), callees(x), references(x), implements(x), and a file(path) filter, combined with '&' for intersection. Arguments accept a symbol name or full symbol ID. Example: 'callers(handle_request) & file(src/auth)' finds callers of handle_request defined under src/auth. Returns matching symbol IDs.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Query string, e.g. 'callers(my_func) & file(src/auth)'"
+                    }
+                },
+                "required": ["query"]
+            }),
+        },
+        Tool {
+            name: "graph_file_path".to_string(),
+            description: "Trace how one file depends on another. Finds the shortest chain of cross-file dependencies connecting two files, answering questions like 'does the UI layer reach into the database layer and through what?'. Returns the chain of intermediate files or reports the files as independent.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "from_file": {
+                        "type": "string",
+                        "description": "Source file path (relative to workspace root)"
+                    },
+                    "to_file": {
+                        "type": "string",
+                        "description": "Target file path (relative to workspace root)"
+                    }
+                },
+                "required": ["from_file", "to_file"]
+            }),
+        },
+        Tool {
+            name: "graph_diff".to_string(),
+            description: "Compare the current knowledge graph against a saved baseline snapshot. Reports symbols that were added or removed and functions whose signatures changed - a semantic changelog beyond `git diff`. Call with save_baseline=true before a refactor to record the baseline, then call again afterwards to see what changed.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "save_baseline": {
+                        "type": "boolean",
+                        "description": "If true, save the current graph as the new baseline instead of diffing (default: false)"
+                    }
+                },
+                "required": []
+            }),
+        },
         Tool {
             name: "graph_stats".to_string(),
             description: "Get knowledge graph statistics including total symbols and files. Useful to verify the graph is populated after indexing.".to_string(),
@@ -1195,6 +1400,29 @@ fn create_index_tools() -> Vec<Tool> {
                 "required": []
             }),
         },
+        Tool {
+            name: "graph_entrypoints".to_string(),
+            description: "List the program's entrypoints from the knowledge graph: main functions, test functions, and public functions with no inbound call edges (likely HTTP handlers or CLI subcommands). Useful to understand where execution starts and to seed reachability or dead-code analysis.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {},
+                "required": []
+            }),
+        },
+        Tool {
+            name: "graph_imports".to_string(),
+            description: "Show a file's import relationships from the knowledge graph: what it imports (use/import/require statements, resolved to workspace symbols where possible) and which other files import its symbols. Useful for understanding module coupling before refactoring a file.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "file_path": {
+                        "type": "string",
+                        "description": "Path to the file, relative to the workspace root (e.g., 'src/main.rs')"
+                    }
+                },
+                "required": ["file_path"]
+            }),
+        },
         Tool {
             name: "code_intelligence".to_string(),
             description: "Advanced code intelligence tool with subcommands for codebase analysis. Provides find, refs, callers, callees, similar, graph, and query operations. Use to explore code relationships, find usages, and understand dependencies.".to_string(),
@@ -1301,6 +1529,34 @@ pub fn create_lsp_tools() -> Vec<Tool> {
                 "required": ["file_path", "line", "character"]
             }),
         },
+        Tool {
+            name: "rename_symbol".to_string(),
+            description: "Rename the symbol at the given position across the whole workspace using the language server. All file edits are applied transactionally: if any write fails, every already-written file is rolled back. Returns the list of changed files.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "file_path": {
+                        "type": "string",
+                        "description": "Path to the source file containing the symbol"
+                    },
+                    "line": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Line number (1-indexed)"
+                    },
+                    "character": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "description": "Character position on the line (1-indexed)"
+                    },
+                    "new_name": {
+                        "type": "string",
+                        "description": "The new name for the symbol"
+                    }
+                },
+                "required": ["file_path", "line", "character", "new_name"]
+            }),
+        },
         Tool {
             name: "lsp_hover".to_string(),
             description: "Get type information and documentation for the symbol at the given position. Returns type signatures, doc comments, and other relevant information.".to_string(),
@@ -1341,7 +1597,7 @@ pub fn create_lsp_tools() -> Vec<Tool> {
         },
         Tool {
             name: "lsp_workspace_symbols".to_string(),
-            description: "Search for symbols across the entire workspace by name. Returns matching functions, types, and other symbols from all indexed files.".to_string(),
+            description: "Search for symbols across the entire workspace by name. Returns matching functions, types, and other symbols from all indexed files, ranked by match quality. Sets 'truncated' when more symbols matched than the limit.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
@@ -1353,6 +1609,10 @@ pub fn create_lsp_tools() -> Vec<Tool> {
                         "type": "string",
                         "enum": ["rust", "typescript", "javascript", "python", "go"],
                         "description": "Language server to use (default: rust)"
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "description": "Maximum number of symbols to return (default: 50)"
                     }
                 },
                 "required": ["query"]
@@ -1857,8 +2117,8 @@ mod tests {
         // research, research_status, codebase_scout, codebase_scout_status,
         // remember, memory_compact, plan_read, plan_write, plan_approve,
         // todo_read, todo_write, rehydrate, switch_mode, rg (22 total)
-        // Now 23 total with memory_compact
-        assert_eq!(tools.len(), 23);
+        // Now 24 total with memory_compact and validate_query
+        assert_eq!(tools.len(), 24);
     }
 
     #[test]
@@ -1887,23 +2147,23 @@ mod tests {
         let config = ToolConfig::default();
         let tools = create_tool_definitions(config);
         // Default config has beads_tools: false (from derive Default)
-        assert_eq!(tools.len(), 23);
+        assert_eq!(tools.len(), 24);
     }
 
     #[test]
     fn test_create_tool_definitions_all_enabled() {
         let config = ToolConfig::new(true, true, true, false);
         let tools = create_tool_definitions(config);
-        // 23 core + 15 webdriver + 3 zai + 15 beads = 56
-        assert_eq!(tools.len(), 56);
+        // 24 core + 15 webdriver + 3 zai + 15 beads = 57
+        assert_eq!(tools.len(), 57);
     }
 
     #[test]
     fn test_create_tool_definitions_with_zai_tools() {
         let config = ToolConfig::new(false, false, true, false);
         let tools = create_tool_definitions(config);
-        // 23 core + 3 zai + 15 beads = 40
-        assert_eq!(tools.len(), 41);
+        // 24 core + 3 zai + 15 beads = 42
+        assert_eq!(tools.len(), 42);
 
         // Verify Z.ai tools are present
         assert!(tools.iter().any(|t| t.name == "zai_web_search"));
@@ -1936,8 +2196,8 @@ mod tests {
         let tools_with_research = create_core_tools(false);
         let tools_without_research = create_core_tools(true);
 
-        assert_eq!(tools_with_research.len(), 23);
-        assert_eq!(tools_without_research.len(), 19);  // research, research_status, codebase_scout, codebase_scout_status all excluded
+        assert_eq!(tools_with_research.len(), 24);
+        assert_eq!(tools_without_research.len(), 20);  // research, research_status, codebase_scout, codebase_scout_status all excluded
 
         assert!(tools_with_research.iter().any(|t| t.name == "research"));
         assert!(!tools_without_research.iter().any(|t| t.name == "research"));
@@ -1954,8 +2214,8 @@ mod tests {
     fn test_create_tool_definitions_with_mcp_tools() {
         let config = ToolConfig::default().with_mcp_tools();
         let tools = create_tool_definitions(config);
-        // 23 core + 5 mcp = 27 (default has beads_tools: false)
-        assert_eq!(tools.len(), 28);
+        // 24 core + 5 mcp = 29 (default has beads_tools: false)
+        assert_eq!(tools.len(), 29);
 
         // Verify MCP tools are present
         assert!(tools.iter().any(|t| t.name == "mcp_web_search"));
@@ -1979,8 +2239,8 @@ mod tests {
     fn test_create_tool_definitions_all_enabled_with_mcp() {
         let config = ToolConfig::new(true, true, true, false).with_mcp_tools();
         let tools = create_tool_definitions(config);
-        // 23 core + 15 webdriver + 3 zai + 5 mcp + 15 beads = 60
-        assert_eq!(tools.len(), 61);
+        // 24 core + 15 webdriver + 3 zai + 5 mcp + 15 beads = 62
+        assert_eq!(tools.len(), 62);
     }
 
     #[test]
@@ -2004,8 +2264,8 @@ mod tests {
     fn test_create_tool_definitions_with_beads_tools() {
         let config = ToolConfig::new(false, false, false, false);
         let tools = create_tool_definitions(config);
-        // 23 core + 15 beads = 37
-        assert_eq!(tools.len(), 38);
+        // 24 core + 15 beads = 39
+        assert_eq!(tools.len(), 39);
 
         // Verify Beads tools are present
         assert!(tools.iter().any(|t| t.name == "beads_ready"));
@@ -2017,8 +2277,8 @@ mod tests {
     fn test_create_tool_definitions_without_beads_tools() {
         let config = ToolConfig::new(false, false, false, false).without_beads_tools();
         let tools = create_tool_definitions(config);
-        // 23 core only (beads disabled)
-        assert_eq!(tools.len(), 23);
+        // 24 core only (beads disabled)
+        assert_eq!(tools.len(), 24);
 
         // Verify Beads tools are NOT present
         assert!(!tools.iter().any(|t| t.name == "beads_ready"));
@@ -2027,8 +2287,8 @@ mod tests {
     #[test]
     fn test_index_tools_count() {
         let tools = create_index_tools();
-        // 9 index tools + 5 self-improvement + 1 scan_folder = 15
-        assert_eq!(tools.len(), 15);
+        // 20 index tools + 5 self-improvement + 1 scan_folder = 26
+        assert_eq!(tools.len(), 26);
     }
 
     #[test]
@@ -2045,8 +2305,8 @@ mod tests {
     fn test_create_tool_definitions_with_index_tools() {
         let config = ToolConfig::new(false, false, false, true);
         let tools = create_tool_definitions(config);
-        // 23 core + 15 beads + 15 index = 52
-        assert_eq!(tools.len(), 53);
+        // 24 core + 15 beads + 26 index = 65
+        assert_eq!(tools.len(), 65);
 
         // Verify index tools are present
         assert!(tools.iter().any(|t| t.name == "index_codebase"));
@@ -2057,9 +2317,15 @@ mod tests {
         // Verify graph tools are present
         assert!(tools.iter().any(|t| t.name == "graph_find_symbol"));
         assert!(tools.iter().any(|t| t.name == "graph_file_symbols"));
+        assert!(tools.iter().any(|t| t.name == "file_outline"));
+        assert!(tools.iter().any(|t| t.name == "repo_map"));
+        assert!(tools.iter().any(|t| t.name == "find_definition"));
         assert!(tools.iter().any(|t| t.name == "graph_find_callers"));
+        assert!(tools.iter().any(|t| t.name == "graph_find_tests"));
         assert!(tools.iter().any(|t| t.name == "graph_find_references"));
         assert!(tools.iter().any(|t| t.name == "graph_stats"));
+        assert!(tools.iter().any(|t| t.name == "graph_entrypoints"));
+        assert!(tools.iter().any(|t| t.name == "graph_imports"));
         assert!(tools.iter().any(|t| t.name == "code_intelligence"));
         assert!(tools.iter().any(|t| t.name == "scan_folder"));
     }
@@ -2068,16 +2334,17 @@ mod tests {
     fn test_create_tool_definitions_all_enabled_with_index() {
         let config = ToolConfig::new(true, true, true, true).with_mcp_tools();
         let tools = create_tool_definitions(config);
-        // 23 core + 15 webdriver + 3 zai + 5 mcp + 15 beads + 15 index = 75
-        assert_eq!(tools.len(), 76);
+        // 24 core + 15 webdriver + 3 zai + 5 mcp + 15 beads + 26 index = 88
+        assert_eq!(tools.len(), 88);
     }
 
     #[test]
     fn test_lsp_tools_count() {
         let tools = create_lsp_tools();
-        // 9 LSP tools: goto_definition, find_references, hover, document_symbols,
-        // workspace_symbols, goto_implementation, call_hierarchy, diagnostics, status
-        assert_eq!(tools.len(), 9);
+        // 10 LSP tools: goto_definition, find_references, rename_symbol, hover,
+        // document_symbols, workspace_symbols, goto_implementation, call_hierarchy,
+        // diagnostics, status
+        assert_eq!(tools.len(), 10);
     }
 
     #[test]
@@ -2094,12 +2361,13 @@ mod tests {
     fn test_create_tool_definitions_with_lsp_tools() {
         let config = ToolConfig::default().with_lsp_tools();
         let tools = create_tool_definitions(config);
-        // 23 core + 9 lsp = 31 (default has beads_tools: false)
-        assert_eq!(tools.len(), 32);
+        // 24 core + 10 lsp = 34 (default has beads_tools: false)
+        assert_eq!(tools.len(), 34);
 
         // Verify LSP tools are present
         assert!(tools.iter().any(|t| t.name == "lsp_goto_definition"));
         assert!(tools.iter().any(|t| t.name == "lsp_find_references"));
+        assert!(tools.iter().any(|t| t.name == "rename_symbol"));
         assert!(tools.iter().any(|t| t.name == "lsp_hover"));
         assert!(tools.iter().any(|t| t.name == "lsp_document_symbols"));
         assert!(tools.iter().any(|t| t.name == "lsp_workspace_symbols"));
@@ -2113,7 +2381,7 @@ mod tests {
     fn test_create_tool_definitions_all_enabled_with_lsp() {
         let config = ToolConfig::new(true, true, true, true).with_mcp_tools().with_lsp_tools();
         let tools = create_tool_definitions(config);
-        // 23 core + 15 webdriver + 3 zai + 5 mcp + 15 beads + 15 index + 9 lsp = 84
-        assert_eq!(tools.len(), 85);
+        // 24 core + 15 webdriver + 3 zai + 5 mcp + 15 beads + 26 index + 10 lsp = 98
+        assert_eq!(tools.len(), 98);
     }
 }
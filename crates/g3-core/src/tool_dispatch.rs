@@ -55,6 +55,19 @@ pub async fn dispatch_tool<W: UiWriter>(
         }
     }
 
+    // Coordinated-write enforcement: an installed guard can veto file writes.
+    // Used by parallel multi-agent runs to serialize edits to shared files.
+    if let Some(guard) = ctx.file_write_guard {
+        if matches!(tool_call.tool.as_str(), "write_file" | "str_replace") {
+            if let Some(path) = file_ops::write_target_path(tool_call) {
+                if let Some(reason) = guard.check_write(std::path::Path::new(&path)) {
+                    debug!("Write to {} vetoed by file write guard", path);
+                    return Ok(reason);
+                }
+            }
+        }
+    }
+
     match tool_call.tool.as_str() {
         // Shell tools
         "shell" => shell::execute_shell(tool_call, ctx).await,
@@ -75,6 +88,7 @@ pub async fn dispatch_tool<W: UiWriter>(
         "screenshot" => misc::execute_take_screenshot(tool_call, ctx).await,
         "coverage" => misc::execute_code_coverage(tool_call, ctx).await,
         "code_search" => misc::execute_code_search(tool_call, ctx).await,
+        "validate_query" => misc::execute_validate_query(tool_call, ctx).await,
 
         // Mode switching
         "switch_mode" => misc::execute_switch_mode(tool_call, ctx).await,
@@ -146,6 +160,7 @@ pub async fn dispatch_tool<W: UiWriter>(
         "index_codebase" => index::execute_index_codebase(tool_call, ctx).await,
         "semantic_search" => index::execute_semantic_search(tool_call, ctx).await,
         "index_status" => index::execute_index_status(tool_call, ctx).await,
+        "index_chunk_report" => index::execute_index_chunk_report(tool_call, ctx).await,
 
         // Folder scanning
         "scan_folder" => file_ops::execute_scan_folder(tool_call, ctx).await,
@@ -161,9 +176,19 @@ pub async fn dispatch_tool<W: UiWriter>(
         "list_files" => index::execute_list_files(tool_call, ctx).await,
         "graph_find_symbol" => index::execute_graph_find_symbol(tool_call, ctx).await,
         "graph_file_symbols" => index::execute_graph_file_symbols(tool_call, ctx).await,
+        "file_outline" => index::execute_file_outline(tool_call, ctx).await,
+        "repo_map" => index::execute_repo_map(tool_call, ctx).await,
+        "find_definition" => lsp::execute_find_definition(tool_call, ctx).await,
         "graph_find_callers" => index::execute_graph_find_callers(tool_call, ctx).await,
+        "graph_find_tests" => index::execute_graph_find_tests(tool_call, ctx).await,
         "graph_find_references" => index::execute_graph_find_references(tool_call, ctx).await,
+        "graph_neighbors" => index::execute_graph_neighbors(tool_call, ctx).await,
+        "graph_query" => index::execute_graph_query(tool_call, ctx).await,
+        "graph_file_path" => index::execute_graph_file_path(tool_call, ctx).await,
+        "graph_diff" => index::execute_graph_diff(tool_call, ctx).await,
         "graph_stats" => index::execute_graph_stats(tool_call, ctx).await,
+        "graph_entrypoints" => index::execute_graph_entrypoints(tool_call, ctx).await,
+        "graph_imports" => index::execute_graph_imports(tool_call, ctx).await,
 
         // Code Intelligence tool
         "code_intelligence" => intelligence::execute_code_intelligence(tool_call, ctx).await,
@@ -171,6 +196,7 @@ pub async fn dispatch_tool<W: UiWriter>(
         // LSP tools (code intelligence)
         "lsp_goto_definition" => lsp::execute_goto_definition(tool_call, ctx).await,
         "lsp_find_references" => lsp::execute_find_references(tool_call, ctx).await,
+        "rename_symbol" => lsp::execute_rename_symbol(tool_call, ctx).await,
         "lsp_hover" => lsp::execute_hover(tool_call, ctx).await,
         "lsp_document_symbols" => lsp::execute_document_symbols(tool_call, ctx).await,
         "lsp_workspace_symbols" => lsp::execute_workspace_symbols(tool_call, ctx).await,
@@ -209,3 +235,160 @@ pub async fn dispatch_tool<W: UiWriter>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::executor::FileWriteGuard;
+    use serde_json::json;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+    use tokio::sync::RwLock;
+
+    /// Minimal UiWriter for testing
+    struct TestUiWriter;
+    impl UiWriter for TestUiWriter {
+        fn print(&self, _: &str) {}
+        fn println(&self, _: &str) {}
+        fn print_inline(&self, _: &str) {}
+        fn print_system_prompt(&self, _: &str) {}
+        fn print_context_status(&self, _: &str) {}
+        fn print_g3_progress(&self, _: &str) {}
+        fn print_g3_status(&self, _: &str, _: &str) {}
+        fn print_thin_result(&self, _: &crate::ThinResult) {}
+        fn print_tool_header(&self, _: &str, _: Option<&serde_json::Value>) {}
+        fn print_tool_arg(&self, _: &str, _: &str) {}
+        fn print_tool_output_header(&self) {}
+        fn update_tool_output_line(&self, _: &str) {}
+        fn print_tool_output_line(&self, _: &str) {}
+        fn print_tool_output_summary(&self, _: usize) {}
+        fn print_tool_timing(&self, _: &str, _: u32, _: f32) {}
+        fn print_agent_prompt(&self) {}
+        fn print_agent_response(&self, _: &str) {}
+        fn notify_sse_received(&self) {}
+        fn print_tool_streaming_hint(&self, _: &str) {}
+        fn print_tool_streaming_active(&self) {}
+        fn flush(&self) {}
+        fn prompt_user_yes_no(&self, _: &str) -> bool { true }
+        fn prompt_user_choice(&self, _: &str, _: &[&str]) -> usize { 0 }
+    }
+
+    /// Guard that vetoes writes to a single path.
+    struct SinglePathGuard {
+        blocked: PathBuf,
+    }
+
+    impl FileWriteGuard for SinglePathGuard {
+        fn check_write(&self, path: &Path) -> Option<String> {
+            (path == self.blocked)
+                .then(|| format!("Blocked: '{}' is locked by another agent.", path.display()))
+        }
+    }
+
+    /// State backing a ToolContext for dispatch tests.
+    struct TestContext {
+        _tmp: TempDir,
+        tmp_path: PathBuf,
+        todo_content: Arc<RwLock<String>>,
+        webdriver_session: Arc<RwLock<Option<Arc<tokio::sync::Mutex<crate::webdriver_session::WebDriverSession>>>>>,
+        webdriver_process: Arc<RwLock<Option<tokio::process::Child>>>,
+        background_process_manager: Arc<crate::background_process::BackgroundProcessManager>,
+        pending_images: Vec<g3_providers::ImageContent>,
+        config: g3_config::Config,
+        pending_research_manager: crate::pending_research::PendingResearchManager,
+    }
+
+    impl TestContext {
+        fn new() -> Self {
+            let tmp = TempDir::new().unwrap();
+            let tmp_path = tmp.path().to_path_buf();
+            Self {
+                _tmp: tmp,
+                tmp_path,
+                todo_content: Arc::new(RwLock::new(String::new())),
+                webdriver_session: Arc::new(RwLock::new(None)),
+                webdriver_process: Arc::new(RwLock::new(None)),
+                background_process_manager: Arc::new(
+                    crate::background_process::BackgroundProcessManager::new(
+                        std::path::PathBuf::from("/tmp"),
+                    ),
+                ),
+                pending_images: Vec::new(),
+                config: g3_config::Config::default(),
+                pending_research_manager: crate::pending_research::PendingResearchManager::new(),
+            }
+        }
+
+        fn ctx<'a>(
+            &'a mut self,
+            guard: Option<&'a dyn FileWriteGuard>,
+        ) -> ToolContext<'a, TestUiWriter> {
+            ToolContext {
+                config: &self.config,
+                ui_writer: &TestUiWriter,
+                session_id: None,
+                working_dir: None,
+                computer_controller: None,
+                webdriver_session: &self.webdriver_session,
+                webdriver_process: &self.webdriver_process,
+                background_process_manager: &self.background_process_manager,
+                todo_content: &self.todo_content,
+                pending_images: &mut self.pending_images,
+                is_autonomous: false,
+                requirements_sha: None,
+                context_total_tokens: 0,
+                context_used_tokens: 0,
+                pending_research_manager: &self.pending_research_manager,
+                zai_tools_client: None,
+                mcp_clients: None,
+                index_client: None,
+                lsp_manager: None,
+                active_persona: None,
+                file_write_guard: guard,
+            }
+        }
+    }
+
+    fn write_call(path: &Path) -> ToolCall {
+        ToolCall {
+            tool: "write_file".to_string(),
+            args: json!({ "file_path": path.to_string_lossy(), "content": "hello" }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_guard_vetoes_and_allows_file_writes() {
+        let mut test_ctx = TestContext::new();
+        let locked = test_ctx.tmp_path.join("locked.rs");
+        let free = test_ctx.tmp_path.join("free.rs");
+        let guard = SinglePathGuard { blocked: locked.clone() };
+
+        // A vetoed write returns the guard's reason and never touches disk
+        let mut ctx = test_ctx.ctx(Some(&guard));
+        let result = dispatch_tool(&write_call(&locked), &mut ctx).await.unwrap();
+        assert!(result.contains("locked by another agent"));
+        assert!(!locked.exists());
+
+        // A write the guard allows goes through unchanged
+        let mut ctx = test_ctx.ctx(Some(&guard));
+        let result = dispatch_tool(&write_call(&free), &mut ctx).await.unwrap();
+        assert!(result.contains("wrote"));
+        assert_eq!(std::fs::read_to_string(&free).unwrap(), "hello");
+
+        // str_replace on the locked file is vetoed before reading the file
+        let replace_call = ToolCall {
+            tool: "str_replace".to_string(),
+            args: json!({ "file_path": locked.to_string_lossy(), "diff": "" }),
+        };
+        let mut ctx = test_ctx.ctx(Some(&guard));
+        let result = dispatch_tool(&replace_call, &mut ctx).await.unwrap();
+        assert!(result.contains("locked by another agent"));
+
+        // Without a guard the same write is unrestricted
+        let mut ctx = test_ctx.ctx(None);
+        let result = dispatch_tool(&write_call(&locked), &mut ctx).await.unwrap();
+        assert!(result.contains("wrote"));
+        assert!(locked.exists());
+    }
+}
@@ -178,6 +178,7 @@ mod tests {
             index_client: None,
             lsp_manager: None,
             active_persona: None,
+            file_write_guard: None,
         };
 
         let tool_call = ToolCall {
@@ -214,6 +215,7 @@ mod tests {
             index_client: None,
             lsp_manager: None,
             active_persona: None,
+            file_write_guard: None,
         };
 
         let tool_call = ToolCall {
@@ -250,6 +252,7 @@ mod tests {
             index_client: None,
             lsp_manager: None,
             active_persona: None,
+            file_write_guard: None,
         };
 
         let tool_call = ToolCall {
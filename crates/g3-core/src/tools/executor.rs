@@ -1,6 +1,7 @@
 //! Tool executor trait and context for tool execution.
 
 use anyhow::Result;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -15,6 +16,17 @@ use crate::webdriver_session::WebDriverSession;
 use crate::ToolCall;
 use g3_config::Config;
 
+/// Veto point consulted before file-writing tools touch disk.
+///
+/// Installed per agent (see `Agent::set_file_write_guard`) so an external
+/// coordinator can serialize writes across agents running in parallel.
+/// Returning `Some(reason)` blocks the write; the reason is returned to the
+/// model as the tool result so it can react (e.g. work on another file).
+pub trait FileWriteGuard: Send + Sync {
+    /// Check whether a write to `path` may proceed.
+    fn check_write(&self, path: &Path) -> Option<String>;
+}
+
 /// Context passed to tool executors containing shared state.
 pub struct ToolContext<'a, W: UiWriter> {
     pub config: &'a Config,
@@ -41,6 +53,8 @@ pub struct ToolContext<'a, W: UiWriter> {
     pub lsp_manager: Option<Arc<LspManager>>,
     /// Active persona scope boundaries (for read-only enforcement)
     pub active_persona: Option<&'a crate::persona::PersonaData>,
+    /// Guard consulted before write_file/str_replace (None = writes unrestricted)
+    pub file_write_guard: Option<&'a dyn FileWriteGuard>,
 }
 
 impl<'a, W: UiWriter> ToolContext<'a, W> {
@@ -777,6 +777,19 @@ const PATH_CONTENT_KEYS: &[(&str, &str)] = &[
     ("file", "data"),          // Alternative naming
 ];
 
+/// Target path of a file-writing tool call (`write_file` or `str_replace`),
+/// tilde-expanded. `None` when no path argument can be extracted; the
+/// executor will then fail the call with its own argument error.
+pub(crate) fn write_target_path(tool_call: &ToolCall) -> Option<String> {
+    let raw = tool_call
+        .args
+        .as_object()
+        .and_then(|obj| obj.get("file_path"))
+        .and_then(|v| v.as_str())
+        .or_else(|| extract_path_and_content(&tool_call.args).0)?;
+    Some(shellexpand::tilde(raw).into_owned())
+}
+
 /// Extract path and content from various argument formats.
 fn extract_path_and_content(args: &serde_json::Value) -> (Option<&str>, Option<&str>) {
     match args {
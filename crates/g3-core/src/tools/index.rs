@@ -72,18 +72,35 @@ pub async fn execute_index_codebase<W: UiWriter>(
     // Perform indexing
     match client.index(force).await {
         Ok(stats) => {
-            let result = json!({
+            let mut result = json!({
                 "status": "success",
                 "files_processed": stats.files_processed,
                 "chunks_created": stats.chunks_created,
                 "chunks_updated": stats.chunks_updated,
                 "chunks_deleted": stats.chunks_deleted,
                 "files_skipped": stats.files_skipped,
+                "files_skipped_oversize": stats.files_skipped_oversize,
+                "files_skipped_binary": stats.files_skipped_binary,
                 "duration_ms": stats.duration_ms,
                 "embedding_calls": stats.embedding_calls,
+                "chunks_resumed": stats.chunks_resumed,
                 "working_dir": work_dir,
                 "force": force
             });
+
+            // Surface per-file parse failures; they were skipped, not fatal
+            if !stats.files_failed.is_empty() {
+                let failed: Vec<serde_json::Value> = stats
+                    .files_failed
+                    .iter()
+                    .map(|f| json!({
+                        "file": f.path.to_string_lossy(),
+                        "error": f.error
+                    }))
+                    .collect();
+                result["files_failed"] = json!(failed);
+            }
+
             Ok(serde_json::to_string_pretty(&result)?)
         }
         Err(e) => {
@@ -302,6 +319,8 @@ pub async fn execute_list_directory<W: UiWriter>(
         .and_then(|v| v.as_bool())
         .unwrap_or(false);
 
+    let max_depth = args.get("max_depth").and_then(|v| v.as_u64());
+
     let work_dir = ctx.working_dir.unwrap_or(".");
     let work_path = Path::new(work_dir);
     let dir_path = work_path.join(path);
@@ -313,6 +332,22 @@ pub async fn execute_list_directory<W: UiWriter>(
         }).to_string());
     }
 
+    // Recursive tree mode: one tree-like overview instead of many calls
+    if let Some(depth) = max_depth {
+        let depth = depth.clamp(1, 10);
+        let (entries, total_size, file_count) =
+            build_directory_tree(&dir_path, depth, include_hidden)?;
+        return Ok(json!({
+            "status": "success",
+            "path": path,
+            "include_hidden": include_hidden,
+            "max_depth": depth,
+            "entries": entries,
+            "total_size": total_size,
+            "file_count": file_count
+        }).to_string());
+    }
+
     let mut entries: Vec<serde_json::Value> = Vec::new();
 
     for entry in dir_path.read_dir()? {
@@ -360,6 +395,73 @@ pub async fn execute_list_directory<W: UiWriter>(
     }).to_string())
 }
 
+/// Directories never descended into by the recursive tree mode.
+const TREE_IGNORED_DIRS: &[&str] = &["node_modules", "target", "__pycache__"];
+
+/// Build a nested directory tree, listing entries up to `remaining` more
+/// levels.
+///
+/// Returns `(entries, total_size, file_count)`. Aggregates always cover
+/// the whole subtree — recursion continues past the listing cutoff so a
+/// truncated directory node still reports accurate totals. Hidden entries
+/// (unless included) and [`TREE_IGNORED_DIRS`] are skipped entirely.
+fn build_directory_tree(
+    dir: &Path,
+    remaining: u64,
+    include_hidden: bool,
+) -> Result<(Vec<serde_json::Value>, u64, usize)> {
+    let mut listed = Vec::new();
+    let mut total_size = 0u64;
+    let mut file_count = 0usize;
+
+    let mut dir_entries: Vec<_> = dir.read_dir()?.filter_map(|e| e.ok()).collect();
+    dir_entries.sort_by_key(|e| e.file_name());
+
+    for entry in dir_entries {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !include_hidden && name.starts_with('.') {
+            continue;
+        }
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            if TREE_IGNORED_DIRS.contains(&name.as_str()) {
+                continue;
+            }
+            let (child_entries, child_size, child_files) =
+                build_directory_tree(&entry.path(), remaining.saturating_sub(1), include_hidden)?;
+            total_size += child_size;
+            file_count += child_files;
+            if remaining >= 1 {
+                let mut node = json!({
+                    "name": name,
+                    "is_dir": true,
+                    "total_size": child_size,
+                    "file_count": child_files
+                });
+                // Below the cutoff the node stays a leaf with totals only
+                if remaining >= 2 {
+                    node["entries"] = json!(child_entries);
+                }
+                listed.push(node);
+            }
+        } else if file_type.is_file() {
+            let size = entry.metadata()?.len();
+            total_size += size;
+            file_count += 1;
+            if remaining >= 1 {
+                listed.push(json!({
+                    "name": name,
+                    "is_dir": false,
+                    "size": size
+                }));
+            }
+        }
+    }
+
+    Ok((listed, total_size, file_count))
+}
+
 /// Execute the preview_file tool.
 pub async fn execute_preview_file<W: UiWriter>(
     tool_call: &ToolCall,
@@ -377,6 +479,16 @@ pub async fn execute_preview_file<W: UiWriter>(
         .and_then(|v| v.as_u64())
         .unwrap_or(50) as usize;
 
+    let around_line = args
+        .get("around_line")
+        .and_then(|v| v.as_u64())
+        .map(|v| v as usize);
+
+    let context = args
+        .get("context")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(5) as usize;
+
     let work_dir = ctx.working_dir.unwrap_or(".");
     let work_path = Path::new(work_dir);
     let file_path = work_path.join(path);
@@ -391,6 +503,33 @@ pub async fn execute_preview_file<W: UiWriter>(
     match std::fs::read_to_string(&file_path) {
         Ok(content) => {
             let lines: Vec<&str> = content.lines().collect();
+
+            // Centered mode: a numbered window around the target line,
+            // for jumping straight from a search hit to its surroundings
+            if let Some(around_line) = around_line {
+                if around_line == 0 || around_line > lines.len() {
+                    return Ok(json!({
+                        "status": "error",
+                        "message": format!(
+                            "around_line {} is out of range (file has {} lines)",
+                            around_line,
+                            lines.len()
+                        )
+                    }).to_string());
+                }
+                let (line_start, line_end, preview) = preview_window(&lines, around_line, context);
+                return Ok(json!({
+                    "status": "success",
+                    "path": path,
+                    "around_line": around_line,
+                    "context": context,
+                    "line_start": line_start,
+                    "line_end": line_end,
+                    "total_lines": lines.len(),
+                    "preview": preview
+                }).to_string());
+            }
+
             let preview_lines = lines.iter().take(num_lines).cloned().collect::<Vec<&str>>();
 
             Ok(json!({
@@ -408,6 +547,20 @@ pub async fn execute_preview_file<W: UiWriter>(
     }
 }
 
+/// Numbered preview window of `context` lines either side of `around_line`
+/// (1-indexed), clamped to the file. Returns the window's first and last
+/// line numbers plus the line-numbered text.
+fn preview_window(lines: &[&str], around_line: usize, context: usize) -> (usize, usize, String) {
+    let line_start = around_line.saturating_sub(context).max(1);
+    let line_end = (around_line + context).min(lines.len());
+    let width = line_end.to_string().len();
+    let preview = (line_start..=line_end)
+        .map(|n| format!("{:>width$} | {}", n, lines[n - 1]))
+        .collect::<Vec<_>>()
+        .join("\n");
+    (line_start, line_end, preview)
+}
+
 /// Execute the semantic_search tool.
 pub async fn execute_semantic_search<W: UiWriter>(
     tool_call: &ToolCall,
@@ -431,8 +584,47 @@ pub async fn execute_semantic_search<W: UiWriter>(
         .get("file_filter")
         .and_then(|v| v.as_str());
 
+    let public_only = args
+        .get("public_only")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let max_total_chars = args
+        .get("max_total_chars")
+        .and_then(|v| v.as_u64())
+        .map(|v| v as usize);
+
+    let snippet_max_chars = args
+        .get("snippet_max_chars")
+        .and_then(|v| v.as_u64())
+        .map(|v| v as usize)
+        .unwrap_or(500)
+        .max(50);
+
+    let signatures_only = args
+        .get("signatures_only")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let mode = args.get("mode").and_then(|v| v.as_str());
+    let preset = match mode {
+        Some(m) => match g3_index::search::SearchPreset::parse(m) {
+            Some(p) => Some(p),
+            None => {
+                return Ok(json!({
+                    "status": "error",
+                    "message": format!("Unknown mode '{}'. Use 'code', 'keyword', or 'docs'.", m)
+                }).to_string());
+            }
+        },
+        None => None,
+    };
+
     // Check if indexing is enabled
     if !ctx.config.index.enabled {
+        if ctx.config.index.fallback_to_text {
+            return text_fallback_response(ctx, query, limit, file_filter);
+        }
         return Ok(json!({
             "status": "error",
             "message": "Semantic search requires indexing to be enabled. Set `index.enabled = true` in your config."
@@ -441,6 +633,9 @@ pub async fn execute_semantic_search<W: UiWriter>(
 
     // Check if index_client is None and we can't auto-initialize
     if ctx.index_client.is_none() {
+        if ctx.config.index.fallback_to_text {
+            return text_fallback_response(ctx, query, limit, file_filter);
+        }
         return Ok(json!({
             "status": "error",
             "message": "Index client not initialized. Run `index_codebase` to build the index, or set `index.auto_init = true` in your config to enable auto-initialization."
@@ -448,8 +643,8 @@ pub async fn execute_semantic_search<W: UiWriter>(
     }
 
     debug!(
-        "Semantic search: query='{}', limit={}, filter={:?}",
-        query, limit, file_filter
+        "Semantic search: query='{}', limit={}, filter={:?}, public_only={}, mode={:?}",
+        query, limit, file_filter, public_only, mode
     );
 
     // Get index client with caching
@@ -463,29 +658,60 @@ pub async fn execute_semantic_search<W: UiWriter>(
         }
     };
 
-    // Perform search
-    match client.search(query, limit, file_filter).await {
+    // Perform search, with a tuned preset when a mode was requested
+    let search_result = match preset {
+        Some(preset) => client.search_with_preset(query, limit, file_filter, public_only, preset).await,
+        None => client.search(query, limit, file_filter, public_only).await,
+    };
+    match search_result {
         Ok(results) => {
-            let formatted_results: Vec<serde_json::Value> = results
-                .iter()
-                .map(|r| {
-                    json!({
-                        "file": r.file_path,
-                        "lines": format!("{}-{}", r.start_line, r.end_line),
-                        "kind": r.kind,
-                        "name": r.name,
-                        "score": format!("{:.3}", r.score),
-                        "content": truncate_content(&r.content, 500)
-                    })
-                })
-                .collect();
+            let (formatted_results, omitted) = if signatures_only {
+                let formatted: Vec<serde_json::Value> = results
+                    .iter()
+                    .map(|r| format_search_result(r, None))
+                    .collect();
+                (formatted, 0)
+            } else {
+                let contents: Vec<String> = results
+                    .iter()
+                    .map(|r| truncate_content_ast(&r.content, snippet_max_chars, &r.file_path))
+                    .collect();
+                let (budgeted, omitted) = match max_total_chars {
+                    Some(budget) => apply_content_budget(&contents, budget),
+                    None => (contents, 0),
+                };
 
-            let result = json!({
+                let formatted: Vec<serde_json::Value> = results
+                    .iter()
+                    .zip(budgeted.iter())
+                    .map(|(r, content)| format_search_result(r, Some(content)))
+                    .collect();
+                (formatted, omitted)
+            };
+
+            let mut result = json!({
                 "status": "success",
                 "query": query,
-                "count": results.len(),
+                "count": formatted_results.len(),
                 "results": formatted_results
             });
+
+            if omitted > 0 {
+                result["omitted_by_budget"] = json!(omitted);
+            }
+
+            // Surface when results went through the reranking stage
+            if client.rerank_enabled() {
+                result["reranked"] = json!(true);
+            }
+
+            // Warn when the index is still being built
+            if client.is_indexing() {
+                result["warning"] = json!(
+                    "Indexing is still in progress; results may be incomplete."
+                );
+            }
+
             Ok(serde_json::to_string_pretty(&result)?)
         }
         Err(e) => {
@@ -498,6 +724,189 @@ pub async fn execute_semantic_search<W: UiWriter>(
     }
 }
 
+/// Format one search hit for tool output.
+///
+/// `content` carries the truncated, budget-limited snippet; `None` omits
+/// the content field entirely (signatures-only mode) and surfaces the
+/// signature instead, so a candidate list stays cheap in tokens.
+fn format_search_result(
+    r: &g3_index::SearchResult,
+    content: Option<&str>,
+) -> serde_json::Value {
+    let mut formatted = json!({
+        "file": r.file_path,
+        "lines": format!("{}-{}", r.start_line, r.end_line),
+        "columns": format!("{}-{}", r.start_col, r.end_col),
+        "kind": r.kind,
+        "name": r.name,
+        "qualified_name": r.qualified_name,
+        "symbol_id": r.symbol_id,
+        "score": format!("{:.3}", r.score)
+    });
+    match content {
+        Some(content) => formatted["content"] = json!(content),
+        None => formatted["signature"] = json!(r.signature),
+    }
+    formatted
+}
+
+/// Apply a total content budget across ranked results.
+///
+/// Keeps results in rank order until `max_total_chars` of content has been
+/// used, truncating the last included result to fit. Returns the budgeted
+/// contents (a prefix of the input) and how many results were dropped.
+fn apply_content_budget(contents: &[String], max_total_chars: usize) -> (Vec<String>, usize) {
+    let mut kept: Vec<String> = Vec::new();
+    let mut used = 0usize;
+
+    for content in contents {
+        let remaining = max_total_chars.saturating_sub(used);
+        if remaining == 0 {
+            break;
+        }
+
+        let char_count = content.chars().count();
+        if char_count <= remaining {
+            used += char_count;
+            kept.push(content.clone());
+        } else {
+            // Char-based truncation: byte slicing would panic on multi-byte text
+            let truncated: String = content.chars().take(remaining).collect();
+            used += remaining;
+            kept.push(format!("{}...", truncated));
+            break;
+        }
+    }
+
+    let omitted = contents.len() - kept.len();
+    (kept, omitted)
+}
+
+/// Build the semantic_search response for the plain-text fallback path.
+fn text_fallback_response<W: UiWriter>(
+    ctx: &ToolContext<'_, W>,
+    query: &str,
+    limit: usize,
+    file_filter: Option<&str>,
+) -> Result<String> {
+    let root = Path::new(ctx.working_dir.unwrap_or("."));
+    let results = text_fallback_search(root, query, limit, file_filter);
+
+    let result = json!({
+        "status": "success",
+        "query": query,
+        "count": results.len(),
+        "results": results,
+        "source": "text_fallback",
+        "note": "Semantic index not initialized - returned plain-text matches instead. Run index_codebase for semantic results."
+    });
+    Ok(serde_json::to_string_pretty(&result)?)
+}
+
+/// Ripgrep-style scan of the workspace used when no semantic index exists.
+///
+/// The query is treated as a case-insensitive regex, falling back to a
+/// literal match when it doesn't parse. Hidden directories and common
+/// build-output noise are skipped.
+fn text_fallback_search(
+    root: &Path,
+    query: &str,
+    limit: usize,
+    file_filter: Option<&str>,
+) -> Vec<serde_json::Value> {
+    let pattern = regex::RegexBuilder::new(query)
+        .case_insensitive(true)
+        .build()
+        .unwrap_or_else(|_| {
+            regex::RegexBuilder::new(&regex::escape(query))
+                .case_insensitive(true)
+                .build()
+                .expect("escaped pattern is valid")
+        });
+
+    let walker = walkdir::WalkDir::new(root).into_iter().filter_entry(|e| {
+        let name = e.file_name().to_string_lossy();
+        e.depth() == 0
+            || (!name.starts_with('.')
+                && name != "node_modules"
+                && name != "target"
+                && name != "__pycache__")
+    });
+
+    let mut results = Vec::new();
+    for entry in walker.filter_map(|e| e.ok()) {
+        if results.len() >= limit {
+            break;
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry
+            .path()
+            .strip_prefix(root)
+            .unwrap_or(entry.path())
+            .to_string_lossy()
+            .to_string();
+        if let Some(filter) = file_filter {
+            if !matches_file_filter(&relative, filter) {
+                continue;
+            }
+        }
+
+        // Skip unreadable and binary files
+        let Ok(content) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+
+        for (line_idx, line) in content.lines().enumerate() {
+            if pattern.is_match(line) {
+                results.push(json!({
+                    "file": relative,
+                    "lines": format!("{}-{}", line_idx + 1, line_idx + 1),
+                    "score": "n/a",
+                    "content": truncate_content(line.trim(), 500),
+                    "source": "text_fallback"
+                }));
+                if results.len() >= limit {
+                    break;
+                }
+            }
+        }
+    }
+
+    results
+}
+
+/// Simplified glob match for the text fallback: `*` segments may match
+/// anything, the rest must appear in order (anchored at the ends when the
+/// pattern doesn't start/end with `*`).
+fn matches_file_filter(path: &str, filter: &str) -> bool {
+    // Collapse `**` globs: `*` here already crosses directory boundaries
+    let filter = filter.replace("**/", "*").replace("**", "*");
+    let pieces: Vec<&str> = filter.split('*').collect();
+    let mut rest = path;
+    for (i, piece) in pieces.iter().enumerate() {
+        if piece.is_empty() {
+            continue;
+        }
+        match rest.find(piece) {
+            Some(pos) => {
+                if i == 0 && pos != 0 {
+                    return false;
+                }
+                rest = &rest[pos + piece.len()..];
+            }
+            None => return false,
+        }
+    }
+    // Anchor the tail unless the pattern ends with a wildcard
+    if !filter.ends_with('*') && !pieces.last().map_or(true, |p| p.is_empty()) {
+        return path.ends_with(pieces.last().unwrap());
+    }
+    true
+}
+
 /// Execute the index_status tool.
 pub async fn execute_index_status<W: UiWriter>(
     _tool_call: &ToolCall,
@@ -565,7 +974,147 @@ pub async fn execute_index_status<W: UiWriter>(
     }
 }
 
+/// Execute the index_chunk_report tool.
+pub async fn execute_index_chunk_report<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Chunk report requires indexing to be enabled."
+        }).to_string());
+    }
+
+    let args = &tool_call.args;
+
+    let limit = args
+        .get("limit")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(10)
+        .clamp(1, 50) as usize;
+
+    let sort = match args.get("sort_by").and_then(|v| v.as_str()) {
+        Some(s) => match g3_index::chunk_report::ChunkSortKey::parse(s) {
+            Some(sort) => sort,
+            None => {
+                return Ok(json!({
+                    "status": "error",
+                    "message": format!("Unknown sort_by '{}'. Use 'bytes' or 'lines'.", s)
+                }).to_string());
+            }
+        },
+        None => g3_index::chunk_report::ChunkSortKey::default(),
+    };
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    match client.get_chunk_report(limit, sort).await {
+        Ok(entries) => {
+            let chunks: Vec<_> = entries
+                .iter()
+                .map(|e| {
+                    json!({
+                        "file_path": e.file_path,
+                        "name": e.name,
+                        "chunk_type": e.chunk_type,
+                        "line_start": e.line_start,
+                        "line_end": e.line_end,
+                        "line_count": e.line_count,
+                        "byte_size": e.byte_size
+                    })
+                })
+                .collect();
+
+            let result = json!({
+                "status": "success",
+                "count": chunks.len(),
+                "chunks": chunks
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Chunk report failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Failed to build chunk report: {}", e)
+            }).to_string())
+        }
+    }
+}
+
 /// Truncate content to a maximum length, preserving word boundaries.
+/// Tree-sitter language for a file extension, used for AST-aware truncation.
+fn truncation_language(file_path: &str) -> Option<tree_sitter::Language> {
+    let ext = Path::new(file_path).extension()?.to_str()?;
+    let language = match ext {
+        "rs" => tree_sitter_rust::LANGUAGE.into(),
+        "py" => tree_sitter_python::LANGUAGE.into(),
+        "js" | "jsx" => tree_sitter_javascript::LANGUAGE.into(),
+        "ts" => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        "tsx" => tree_sitter_typescript::LANGUAGE_TSX.into(),
+        "go" => tree_sitter_go::LANGUAGE.into(),
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// Whether a node kind is a statement-level construct that a snippet can
+/// safely end after without splitting an expression.
+fn is_statement_boundary(kind: &str) -> bool {
+    kind.ends_with("_statement")
+        || kind.ends_with("_declaration")
+        || kind.ends_with("_item")
+        || kind.ends_with("_definition")
+        || kind == "block"
+}
+
+/// Truncate a code snippet at the nearest statement or block boundary
+/// within the byte budget, so results read as valid-looking fragments.
+/// Falls back to the line/space heuristic for unparseable or non-code files.
+fn truncate_content_ast(content: &str, max_len: usize, file_path: &str) -> String {
+    if content.len() <= max_len {
+        return content.to_string();
+    }
+
+    let Some(language) = truncation_language(file_path) else {
+        return truncate_content(content, max_len);
+    };
+
+    let mut parser = tree_sitter::Parser::new();
+    if parser.set_language(&language).is_err() {
+        return truncate_content(content, max_len);
+    }
+    let Some(tree) = parser.parse(content, None) else {
+        return truncate_content(content, max_len);
+    };
+
+    // Find the furthest statement/block end that still fits the budget
+    let mut best = 0usize;
+    let mut stack = vec![tree.root_node()];
+    while let Some(node) = stack.pop() {
+        if node.start_byte() >= max_len {
+            continue;
+        }
+        if node.is_named() && node.end_byte() <= max_len && is_statement_boundary(node.kind()) {
+            best = best.max(node.end_byte());
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            stack.push(child);
+        }
+    }
+
+    if best == 0 {
+        return truncate_content(content, max_len);
+    }
+
+    // Node boundaries are always char boundaries, so byte slicing is safe here
+    format!("{}...", content[..best].trim_end())
+}
+
 fn truncate_content(content: &str, max_len: usize) -> String {
     if content.len() <= max_len {
         return content.to_string();
@@ -582,27 +1131,456 @@ fn truncate_content(content: &str, max_len: usize) -> String {
     }
 }
 
-// ============================================================================
-// Knowledge Graph Tools
-// ============================================================================
-
-/// Execute the graph_find_symbol tool.
-pub async fn execute_graph_find_symbol<W: UiWriter>(
+// ============================================================================
+// Knowledge Graph Tools
+// ============================================================================
+
+/// Execute the graph_find_symbol tool.
+pub async fn execute_graph_find_symbol<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let name = args
+        .get("name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?;
+
+    let kind = args.get("kind").and_then(|v| v.as_str());
+    let file_prefix = args.get("file_prefix").and_then(|v| v.as_str());
+
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Graph search requires indexing to be enabled. Set `index.enabled = true` in your config."
+        }).to_string());
+    }
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    // Check if graph is available
+    if !client.has_graph().await {
+        return Ok(json!({
+            "status": "error",
+            "message": "Knowledge graph not available. Run `index_codebase` first to build the graph."
+        }).to_string());
+    }
+
+    // Find symbols
+    match client.find_symbols_by_name(name, kind, file_prefix).await {
+        Ok(symbols) => {
+            let formatted: Vec<serde_json::Value> = symbols
+                .iter()
+                .map(|s| {
+                    json!({
+                        "id": s.id,
+                        "name": s.name,
+                        "kind": s.kind,
+                        "file": s.file_id,
+                        "lines": format!("{}-{}", s.line_start, s.line_end),
+                        "signature": s.signature,
+                        "last_author": s.metadata.as_ref()
+                            .and_then(|m| m.get("last_author"))
+                            .cloned()
+                            .unwrap_or(serde_json::Value::Null),
+                        "last_commit": s.metadata.as_ref()
+                            .and_then(|m| m.get("last_commit"))
+                            .cloned()
+                            .unwrap_or(serde_json::Value::Null)
+                    })
+                })
+                .collect();
+
+            let result = json!({
+                "status": "success",
+                "name": name,
+                "count": symbols.len(),
+                "symbols": formatted
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Graph find_symbol failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Failed to find symbols: {}", e)
+            }).to_string())
+        }
+    }
+}
+
+/// Execute the graph_entrypoints tool.
+pub async fn execute_graph_entrypoints<W: UiWriter>(
+    _tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Graph search requires indexing to be enabled. Set `index.enabled = true` in your config."
+        }).to_string());
+    }
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    // Check if graph is available
+    if !client.has_graph().await {
+        return Ok(json!({
+            "status": "error",
+            "message": "Knowledge graph not available. Run `index_codebase` first to build the graph."
+        }).to_string());
+    }
+
+    // Find entrypoints
+    match client.find_entrypoints().await {
+        Ok(entrypoints) => {
+            let formatted: Vec<serde_json::Value> = entrypoints
+                .iter()
+                .map(|(s, kind)| {
+                    json!({
+                        "id": s.id,
+                        "name": s.name,
+                        "kind": s.kind,
+                        "entrypoint_kind": kind,
+                        "file": s.file_id,
+                        "lines": format!("{}-{}", s.line_start, s.line_end),
+                        "signature": s.signature
+                    })
+                })
+                .collect();
+
+            let result = json!({
+                "status": "success",
+                "count": entrypoints.len(),
+                "entrypoints": formatted
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Graph entrypoints failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Failed to find entrypoints: {}", e)
+            }).to_string())
+        }
+    }
+}
+
+/// Execute the graph_file_symbols tool.
+pub async fn execute_graph_file_symbols<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let file_path = args
+        .get("file_path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: file_path"))?;
+
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Graph search requires indexing to be enabled."
+        }).to_string());
+    }
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    // Check if graph is available
+    if !client.has_graph().await {
+        return Ok(json!({
+            "status": "error",
+            "message": "Knowledge graph not available. Run `index_codebase` first."
+        }).to_string());
+    }
+
+    // Get symbols in file
+    match client.get_file_symbols(file_path).await {
+        Ok(symbols) => {
+            let formatted: Vec<serde_json::Value> = symbols
+                .iter()
+                .map(|s| {
+                    json!({
+                        "id": s.id,
+                        "name": s.name,
+                        "kind": s.kind,
+                        "lines": format!("{}-{}", s.line_start, s.line_end),
+                        "signature": s.signature
+                    })
+                })
+                .collect();
+
+            let result = json!({
+                "status": "success",
+                "file": file_path,
+                "count": symbols.len(),
+                "symbols": formatted
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Graph file_symbols failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Failed to get file symbols: {}", e)
+            }).to_string())
+        }
+    }
+}
+
+/// Execute the graph_imports tool.
+pub async fn execute_graph_imports<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let file_path = args
+        .get("file_path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: file_path"))?;
+
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Graph search requires indexing to be enabled."
+        }).to_string());
+    }
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    // Check if graph is available
+    if !client.has_graph().await {
+        return Ok(json!({
+            "status": "error",
+            "message": "Knowledge graph not available. Run `index_codebase` first."
+        }).to_string());
+    }
+
+    // Get import edges in both directions
+    match client.file_imports(file_path).await {
+        Ok((imports, importers)) => {
+            let imports_json: Vec<serde_json::Value> = imports
+                .iter()
+                .map(|e| {
+                    json!({
+                        "target": e.target,
+                        "line": e.location_line
+                    })
+                })
+                .collect();
+            let importers_json: Vec<serde_json::Value> = importers
+                .iter()
+                .map(|e| {
+                    json!({
+                        "file": e.source,
+                        "imports": e.target,
+                        "line": e.location_line
+                    })
+                })
+                .collect();
+
+            let result = json!({
+                "status": "success",
+                "file": file_path,
+                "imports_count": imports.len(),
+                "imported_by_count": importers.len(),
+                "imports": imports_json,
+                "imported_by": importers_json
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Graph imports failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Failed to get file imports: {}", e)
+            }).to_string())
+        }
+    }
+}
+
+/// Execute the file_outline tool.
+pub async fn execute_file_outline<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let file_path = args
+        .get("file_path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: file_path"))?;
+
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Graph search requires indexing to be enabled."
+        }).to_string());
+    }
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    // Check if graph is available
+    if !client.has_graph().await {
+        return Ok(json!({
+            "status": "error",
+            "message": "Knowledge graph not available. Run `index_codebase` first."
+        }).to_string());
+    }
+
+    // Build the nested outline
+    match client.file_outline(file_path).await {
+        Ok(outline) => {
+            let result = json!({
+                "status": "success",
+                "file": file_path,
+                "count": outline.len(),
+                "outline": outline
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Graph file_outline failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Failed to build file outline: {}", e)
+            }).to_string())
+        }
+    }
+}
+
+/// Execute the repo_map tool.
+pub async fn execute_repo_map<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let max_tokens = args
+        .get("max_tokens")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(2000) as usize;
+
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Graph search requires indexing to be enabled."
+        }).to_string());
+    }
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    // Check if graph is available
+    if !client.has_graph().await {
+        return Ok(json!({
+            "status": "error",
+            "message": "Knowledge graph not available. Run `index_codebase` first."
+        }).to_string());
+    }
+
+    match client.repo_map(max_tokens).await {
+        Ok(map) => {
+            let result = json!({
+                "status": "success",
+                "max_tokens": max_tokens,
+                "map": map
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Repo map failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Failed to build repo map: {}", e)
+            }).to_string())
+        }
+    }
+}
+
+/// Execute the graph_find_callers tool.
+pub async fn execute_graph_find_callers<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let symbol_id = args
+        .get("symbol_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: symbol_id"))?;
+
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Graph search requires indexing to be enabled."
+        }).to_string());
+    }
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    // Check if graph is available
+    if !client.has_graph().await {
+        return Ok(json!({
+            "status": "error",
+            "message": "Knowledge graph not available. Run `index_codebase` first."
+        }).to_string());
+    }
+
+    // Find callers
+    match client.find_callers(symbol_id).await {
+        Ok(callers) => {
+            let result = json!({
+                "status": "success",
+                "symbol_id": symbol_id,
+                "count": callers.len(),
+                "callers": callers
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Graph find_callers failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Failed to find callers: {}", e)
+            }).to_string())
+        }
+    }
+}
+
+/// Execute the graph_find_tests tool.
+pub async fn execute_graph_find_tests<W: UiWriter>(
     tool_call: &ToolCall,
     ctx: &mut ToolContext<'_, W>,
 ) -> Result<String> {
     let args = &tool_call.args;
 
-    let name = args
-        .get("name")
+    let symbol_id = args
+        .get("symbol_id")
         .and_then(|v| v.as_str())
-        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?;
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: symbol_id"))?;
 
     // Check if indexing is enabled
     if !ctx.config.index.enabled {
         return Ok(json!({
             "status": "error",
-            "message": "Graph search requires indexing to be enabled. Set `index.enabled = true` in your config."
+            "message": "Graph search requires indexing to be enabled."
         }).to_string());
     }
 
@@ -613,56 +1591,42 @@ pub async fn execute_graph_find_symbol<W: UiWriter>(
     if !client.has_graph().await {
         return Ok(json!({
             "status": "error",
-            "message": "Knowledge graph not available. Run `index_codebase` first to build the graph."
+            "message": "Knowledge graph not available. Run `index_codebase` first."
         }).to_string());
     }
 
-    // Find symbols
-    match client.find_symbols_by_name(name).await {
-        Ok(symbols) => {
-            let formatted: Vec<serde_json::Value> = symbols
-                .iter()
-                .map(|s| {
-                    json!({
-                        "id": s.id,
-                        "name": s.name,
-                        "kind": s.kind,
-                        "file": s.file_id,
-                        "lines": format!("{}-{}", s.line_start, s.line_end),
-                        "signature": s.signature
-                    })
-                })
-                .collect();
-
+    // Find covering tests
+    match client.find_tests(symbol_id).await {
+        Ok(tests) => {
             let result = json!({
                 "status": "success",
-                "name": name,
-                "count": symbols.len(),
-                "symbols": formatted
+                "symbol_id": symbol_id,
+                "count": tests.len(),
+                "tests": tests
             });
             Ok(serde_json::to_string_pretty(&result)?)
         }
         Err(e) => {
-            warn!("Graph find_symbol failed: {}", e);
+            warn!("Graph find_tests failed: {}", e);
             Ok(json!({
                 "status": "error",
-                "message": format!("Failed to find symbols: {}", e)
+                "message": format!("Failed to find tests: {}", e)
             }).to_string())
         }
     }
 }
 
-/// Execute the graph_file_symbols tool.
-pub async fn execute_graph_file_symbols<W: UiWriter>(
+/// Execute the graph_find_references tool.
+pub async fn execute_graph_find_references<W: UiWriter>(
     tool_call: &ToolCall,
     ctx: &mut ToolContext<'_, W>,
 ) -> Result<String> {
     let args = &tool_call.args;
 
-    let file_path = args
-        .get("file_path")
+    let symbol_id = args
+        .get("symbol_id")
         .and_then(|v| v.as_str())
-        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: file_path"))?;
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: symbol_id"))?;
 
     // Check if indexing is enabled
     if !ctx.config.index.enabled {
@@ -683,42 +1647,41 @@ pub async fn execute_graph_file_symbols<W: UiWriter>(
         }).to_string());
     }
 
-    // Get symbols in file
-    match client.get_file_symbols(file_path).await {
-        Ok(symbols) => {
-            let formatted: Vec<serde_json::Value> = symbols
+    // Find references
+    match client.find_references(symbol_id).await {
+        Ok(refs) => {
+            let formatted: Vec<serde_json::Value> = refs
                 .iter()
-                .map(|s| {
+                .map(|r| {
                     json!({
-                        "id": s.id,
-                        "name": s.name,
-                        "kind": s.kind,
-                        "lines": format!("{}-{}", s.line_start, s.line_end),
-                        "signature": s.signature
+                        "source": r.source,
+                        "target": r.target,
+                        "file": r.file,
+                        "line": r.line
                     })
                 })
                 .collect();
 
             let result = json!({
                 "status": "success",
-                "file": file_path,
-                "count": symbols.len(),
-                "symbols": formatted
+                "symbol_id": symbol_id,
+                "count": refs.len(),
+                "references": formatted
             });
             Ok(serde_json::to_string_pretty(&result)?)
         }
         Err(e) => {
-            warn!("Graph file_symbols failed: {}", e);
+            warn!("Graph find_references failed: {}", e);
             Ok(json!({
                 "status": "error",
-                "message": format!("Failed to get file symbols: {}", e)
+                "message": format!("Failed to find references: {}", e)
             }).to_string())
         }
     }
 }
 
-/// Execute the graph_find_callers tool.
-pub async fn execute_graph_find_callers<W: UiWriter>(
+/// Execute the graph_neighbors tool.
+pub async fn execute_graph_neighbors<W: UiWriter>(
     tool_call: &ToolCall,
     ctx: &mut ToolContext<'_, W>,
 ) -> Result<String> {
@@ -748,38 +1711,40 @@ pub async fn execute_graph_find_callers<W: UiWriter>(
         }).to_string());
     }
 
-    // Find callers
-    match client.find_callers(symbol_id).await {
-        Ok(callers) => {
+    // Get the one-hop neighborhood
+    match client.graph_neighbors(symbol_id).await {
+        Ok(neighborhood) => {
             let result = json!({
                 "status": "success",
                 "symbol_id": symbol_id,
-                "count": callers.len(),
-                "callers": callers
+                "callers": neighborhood.callers,
+                "callees": neighborhood.callees,
+                "references": neighborhood.references,
+                "implements": neighborhood.implements
             });
             Ok(serde_json::to_string_pretty(&result)?)
         }
         Err(e) => {
-            warn!("Graph find_callers failed: {}", e);
+            warn!("Graph neighbors failed: {}", e);
             Ok(json!({
                 "status": "error",
-                "message": format!("Failed to find callers: {}", e)
+                "message": format!("Failed to get neighborhood: {}", e)
             }).to_string())
         }
     }
 }
 
-/// Execute the graph_find_references tool.
-pub async fn execute_graph_find_references<W: UiWriter>(
+/// Execute the graph_query tool.
+pub async fn execute_graph_query<W: UiWriter>(
     tool_call: &ToolCall,
     ctx: &mut ToolContext<'_, W>,
 ) -> Result<String> {
     let args = &tool_call.args;
 
-    let symbol_id = args
-        .get("symbol_id")
+    let query = args
+        .get("query")
         .and_then(|v| v.as_str())
-        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: symbol_id"))?;
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: query"))?;
 
     // Check if indexing is enabled
     if !ctx.config.index.enabled {
@@ -800,34 +1765,212 @@ pub async fn execute_graph_find_references<W: UiWriter>(
         }).to_string());
     }
 
-    // Find references
-    match client.find_references(symbol_id).await {
-        Ok(refs) => {
-            let formatted: Vec<serde_json::Value> = refs
+    // Run the query (parse errors come back with a helpful message)
+    match client.graph_query(query).await {
+        Ok(symbols) => {
+            let result = json!({
+                "status": "success",
+                "query": query,
+                "count": symbols.len(),
+                "symbols": symbols
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Graph query failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Graph query failed: {}", e)
+            }).to_string())
+        }
+    }
+}
+
+/// Execute the graph_file_path tool.
+pub async fn execute_graph_file_path<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let from_file = args
+        .get("from_file")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: from_file"))?;
+
+    let to_file = args
+        .get("to_file")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: to_file"))?;
+
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Graph search requires indexing to be enabled."
+        }).to_string());
+    }
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    // Check if graph is available
+    if !client.has_graph().await {
+        return Ok(json!({
+            "status": "error",
+            "message": "Knowledge graph not available. Run `index_codebase` first."
+        }).to_string());
+    }
+
+    // Find the file-level dependency path
+    match client.file_dependency_path(from_file, to_file).await {
+        Ok(Some(path)) => {
+            let result = json!({
+                "status": "success",
+                "from_file": from_file,
+                "to_file": to_file,
+                "connected": true,
+                "hops": path.len().saturating_sub(1),
+                "path": path
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Ok(None) => {
+            let result = json!({
+                "status": "success",
+                "from_file": from_file,
+                "to_file": to_file,
+                "connected": false,
+                "message": "No dependency path found; the files are independent."
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Err(e) => {
+            warn!("Graph file_path failed: {}", e);
+            Ok(json!({
+                "status": "error",
+                "message": format!("Failed to find file dependency path: {}", e)
+            }).to_string())
+        }
+    }
+}
+
+/// Execute the graph_diff tool.
+pub async fn execute_graph_diff<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let save_baseline = args
+        .get("save_baseline")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    // Check if indexing is enabled
+    if !ctx.config.index.enabled {
+        return Ok(json!({
+            "status": "error",
+            "message": "Graph diff requires indexing to be enabled."
+        }).to_string());
+    }
+
+    // Get index client
+    let client = get_or_init_client(ctx).await?;
+
+    // Check if graph is available
+    if !client.has_graph().await {
+        return Ok(json!({
+            "status": "error",
+            "message": "Knowledge graph not available. Run `index_codebase` first."
+        }).to_string());
+    }
+
+    // Save the current graph as the new baseline
+    if save_baseline {
+        return match client.save_graph_baseline().await {
+            Ok(true) => Ok(json!({
+                "status": "success",
+                "message": "Saved current graph as the diff baseline."
+            }).to_string()),
+            Ok(false) => Ok(json!({
+                "status": "error",
+                "message": "Knowledge graph not available. Run `index_codebase` first."
+            }).to_string()),
+            Err(e) => {
+                warn!("Graph baseline save failed: {}", e);
+                Ok(json!({
+                    "status": "error",
+                    "message": format!("Failed to save baseline: {}", e)
+                }).to_string())
+            }
+        };
+    }
+
+    // Diff the current graph against the saved baseline
+    match client.graph_diff().await {
+        Ok(Some(diff)) => {
+            let added: Vec<serde_json::Value> = diff
+                .added
+                .values()
+                .map(|s| {
+                    json!({
+                        "id": s.id,
+                        "name": s.name,
+                        "kind": s.kind.label(),
+                        "file": s.file_id,
+                        "line": s.line_start
+                    })
+                })
+                .collect();
+
+            let removed: Vec<serde_json::Value> = diff
+                .removed
+                .values()
+                .map(|s| {
+                    json!({
+                        "id": s.id,
+                        "name": s.name,
+                        "kind": s.kind.label(),
+                        "file": s.file_id,
+                        "line": s.line_start
+                    })
+                })
+                .collect();
+
+            let signature_changes: Vec<serde_json::Value> = diff
+                .signature_changes
                 .iter()
-                .map(|r| {
+                .map(|(id, change)| {
                     json!({
-                        "source": r.source,
-                        "target": r.target,
-                        "file": r.file,
-                        "line": r.line
+                        "id": id,
+                        "old_signature": change.old_signature,
+                        "new_signature": change.new_signature
                     })
                 })
                 .collect();
 
             let result = json!({
                 "status": "success",
-                "symbol_id": symbol_id,
-                "count": refs.len(),
-                "references": formatted
+                "change_count": diff.change_count(),
+                "added": added,
+                "removed": removed,
+                "signature_changes": signature_changes
+            });
+            Ok(serde_json::to_string_pretty(&result)?)
+        }
+        Ok(None) => {
+            let result = json!({
+                "status": "success",
+                "message": "No baseline saved yet. Call graph_diff with save_baseline=true to record one."
             });
             Ok(serde_json::to_string_pretty(&result)?)
         }
         Err(e) => {
-            warn!("Graph find_references failed: {}", e);
+            warn!("Graph diff failed: {}", e);
             Ok(json!({
                 "status": "error",
-                "message": format!("Failed to find references: {}", e)
+                "message": format!("Failed to diff graph: {}", e)
             }).to_string())
         }
     }
@@ -865,7 +2008,9 @@ pub async fn execute_graph_stats<W: UiWriter>(
                 "status": "success",
                 "graph_enabled": true,
                 "symbol_count": stats.symbol_count,
-                "file_count": stats.file_count
+                "file_count": stats.file_count,
+                "symbols_by_kind": stats.symbols_by_kind,
+                "files_by_language": stats.files_by_language
             });
             Ok(serde_json::to_string_pretty(&result)?)
         }
@@ -1345,3 +2490,207 @@ pub(crate) async fn get_or_init_client<W: UiWriter>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_fallback_finds_keyword_in_fixture() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp.path().join("src")).unwrap();
+        std::fs::write(
+            temp.path().join("src/auth.rs"),
+            "fn validate_token(token: &str) -> bool {\n    token.starts_with(\"g3_\")\n}\n",
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("README.md"), "No secrets here\n").unwrap();
+
+        let results = text_fallback_search(temp.path(), "validate_token", 10, None);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["file"], "src/auth.rs");
+        assert_eq!(results[0]["lines"], "1-1");
+        assert_eq!(results[0]["source"], "text_fallback");
+        assert!(results[0]["content"]
+            .as_str()
+            .unwrap()
+            .contains("validate_token"));
+    }
+
+    #[test]
+    fn test_ast_truncation_ends_at_statement_boundary() {
+        let content = "fn demo() {\n    let a = 1;\n    let b = 2;\n    let c = some_call(a,\n        b);\n}\n";
+        // Budget lands inside the `some_call(` expression
+        let budget = content.find("some_call").unwrap() + 5;
+
+        let truncated = truncate_content_ast(content, budget, "src/demo.rs");
+
+        assert!(truncated.ends_with("let b = 2;..."));
+        assert!(!truncated.contains("some_call"));
+    }
+
+    #[test]
+    fn test_ast_truncation_falls_back_for_non_code() {
+        let content = "word ".repeat(200);
+        let truncated = truncate_content_ast(&content, 100, "notes.md");
+        assert_eq!(truncated, truncate_content(&content, 100));
+    }
+
+    #[test]
+    fn test_apply_content_budget_stays_under_cap() {
+        let contents = vec![
+            "a".repeat(400),
+            "b".repeat(400),
+            "c".repeat(400),
+            "d".repeat(400),
+        ];
+
+        let (kept, omitted) = apply_content_budget(&contents, 1000);
+
+        // First two fit whole, third is truncated, fourth is dropped
+        assert_eq!(kept.len(), 3);
+        assert_eq!(omitted, 1);
+        assert_eq!(kept[2].chars().count(), 203); // 200 chars + "..."
+
+        let total: usize = kept
+            .iter()
+            .map(|c| c.trim_end_matches("...").chars().count())
+            .sum();
+        assert!(total <= 1000);
+    }
+
+    #[test]
+    fn test_apply_content_budget_no_op_when_under_budget() {
+        let contents = vec!["short".to_string(), "also short".to_string()];
+        let (kept, omitted) = apply_content_budget(&contents, 1000);
+        assert_eq!(kept, contents);
+        assert_eq!(omitted, 0);
+    }
+
+    #[test]
+    fn test_text_fallback_respects_limit_and_filter() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(temp.path().join("a.rs"), "needle\nneedle\nneedle\n").unwrap();
+        std::fs::write(temp.path().join("b.py"), "needle\n").unwrap();
+
+        let results = text_fallback_search(temp.path(), "needle", 2, None);
+        assert_eq!(results.len(), 2);
+
+        let results = text_fallback_search(temp.path(), "needle", 10, Some("*.py"));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["file"], "b.py");
+    }
+
+    #[test]
+    fn test_text_fallback_invalid_regex_degrades_to_literal() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(temp.path().join("a.rs"), "weird [pattern here\n").unwrap();
+
+        let results = text_fallback_search(temp.path(), "weird [pattern", 10, None);
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_directory_tree_depth_limit_and_aggregates() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("sub/deep/deeper")).unwrap();
+        std::fs::write(root.join("a.txt"), "aaa").unwrap();
+        std::fs::write(root.join("sub/b.txt"), "bbbb").unwrap();
+        std::fs::write(root.join("sub/deep/c.txt"), "cc").unwrap();
+        std::fs::write(root.join("sub/deep/deeper/d.txt"), "d").unwrap();
+
+        let (entries, total_size, file_count) =
+            build_directory_tree(root, 2, false).unwrap();
+
+        // Aggregates cover the whole subtree, past the listing cutoff
+        assert_eq!(file_count, 4);
+        assert_eq!(total_size, 10);
+
+        // Depth 1: the root file and the sub directory
+        assert_eq!(entries.len(), 2);
+        let sub = entries.iter().find(|e| e["name"] == "sub").unwrap();
+        assert_eq!(sub["file_count"], 3);
+        assert_eq!(sub["total_size"], 7);
+
+        // Depth 2: grandchildren are listed...
+        let sub_entries = sub["entries"].as_array().unwrap();
+        let deep = sub_entries.iter().find(|e| e["name"] == "deep").unwrap();
+        assert_eq!(deep["file_count"], 2);
+
+        // ...but great-grandchildren are not, only their totals
+        assert!(deep.get("entries").is_none());
+    }
+
+    #[test]
+    fn test_preview_window_centers_on_line_with_numbering() {
+        let content: Vec<String> = (1..=20).map(|n| format!("line {}", n)).collect();
+        let lines: Vec<&str> = content.iter().map(String::as_str).collect();
+
+        let (start, end, preview) = preview_window(&lines, 10, 3);
+        assert_eq!(start, 7);
+        assert_eq!(end, 13);
+        let rendered: Vec<&str> = preview.lines().collect();
+        assert_eq!(rendered.len(), 7);
+        assert_eq!(rendered[0], " 7 | line 7");
+        assert_eq!(rendered[3], "10 | line 10");
+        assert_eq!(rendered[6], "13 | line 13");
+
+        // Windows clamp at the file boundaries
+        let (start, end, _) = preview_window(&lines, 2, 5);
+        assert_eq!((start, end), (1, 7));
+        let (start, end, _) = preview_window(&lines, 19, 5);
+        assert_eq!((start, end), (14, 20));
+    }
+
+    #[test]
+    fn test_signatures_only_omits_content_and_includes_signature() {
+        let result = g3_index::SearchResult {
+            id: "chunk-1".to_string(),
+            file_path: "src/auth.rs".to_string(),
+            start_line: 10,
+            end_line: 42,
+            start_col: 8,
+            end_col: 13,
+            content: "pub fn login(user: &str) -> Result<Session> { /* ... */ }".to_string(),
+            kind: "function".to_string(),
+            name: Some("login".to_string()),
+            signature: Some("pub fn login(user: &str) -> Result<Session>".to_string()),
+            scope: None,
+            qualified_name: Some("auth::login".to_string()),
+            symbol_id: None,
+            enclosing_symbol: None,
+            enclosing_signature: None,
+            repo: None,
+            explanation: None,
+            score: 0.91,
+            vector_score: None,
+            bm25_score: None,
+            stale: false,
+        };
+
+        let with_content = format_search_result(&result, Some("snippet"));
+        assert_eq!(with_content["content"], "snippet");
+        assert!(with_content.get("signature").is_none());
+
+        let signatures_only = format_search_result(&result, None);
+        assert!(signatures_only.get("content").is_none());
+        assert_eq!(
+            signatures_only["signature"],
+            "pub fn login(user: &str) -> Result<Session>"
+        );
+        assert_eq!(signatures_only["name"], "login");
+        assert_eq!(signatures_only["file"], "src/auth.rs");
+    }
+
+    #[test]
+    fn test_matches_file_filter() {
+        assert!(matches_file_filter("src/main.rs", "*.rs"));
+        assert!(matches_file_filter("src/main.rs", "src/**/*.rs"));
+        assert!(matches_file_filter("src/deep/nested/mod.rs", "src/**/*.rs"));
+        assert!(!matches_file_filter("src/main.py", "*.rs"));
+        assert!(!matches_file_filter("lib/main.rs", "src/**/*.rs"));
+        assert!(matches_file_filter("src/anything", "src/*"));
+    }
+}
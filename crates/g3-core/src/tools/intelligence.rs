@@ -92,7 +92,7 @@ async fn execute_find_definition<W: UiWriter>(
         Err(e) => return Ok(e),
     };
 
-    match client.find_symbols_by_name(symbol).await {
+    match client.find_symbols_by_name(symbol, None, None).await {
         Ok(results) => {
             let formatted: Vec<_> = results
                 .into_iter()
@@ -185,7 +185,7 @@ async fn execute_find_callers<W: UiWriter>(
         Err(e) => return Ok(e),
     };
 
-    match client.find_symbols_by_name(symbol).await {
+    match client.find_symbols_by_name(symbol, None, None).await {
         Ok(symbols) => {
             let mut all_callers = Vec::new();
 
@@ -244,7 +244,7 @@ async fn execute_find_callees<W: UiWriter>(
         Err(e) => return Ok(e),
     };
 
-    match client.find_symbols_by_name(symbol).await {
+    match client.find_symbols_by_name(symbol, None, None).await {
         Ok(symbols) => {
             let mut all_callees = Vec::new();
 
@@ -302,7 +302,7 @@ async fn execute_find_similar<W: UiWriter>(
         Err(e) => return Ok(e),
     };
 
-    match client.search(query, 10, None).await {
+    match client.search(query, 10, None, false).await {
         Ok(results) => {
             let formatted: Vec<_> = results
                 .into_iter()
@@ -355,7 +355,7 @@ async fn execute_explore_graph<W: UiWriter>(
         Err(e) => return Ok(e),
     };
 
-    match client.find_symbols_by_name(symbol).await {
+    match client.find_symbols_by_name(symbol, None, None).await {
         Ok(symbols) => {
             let mut traversal_results = Vec::new();
 
@@ -424,7 +424,7 @@ async fn execute_graph_query<W: UiWriter>(
         Err(e) => return Ok(e),
     };
 
-    match client.find_symbols_by_name(symbol).await {
+    match client.find_symbols_by_name(symbol, None, None).await {
         Ok(symbols) => {
             let mut callers_list = Vec::new();
             let mut references_list = Vec::new();
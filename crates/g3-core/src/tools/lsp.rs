@@ -8,14 +8,14 @@
 //! - Go to implementation
 //! - Call hierarchy
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Result;
 use g3_lsp::{
-    CallHierarchyIncomingCall, CallHierarchyOutgoingCall, DocumentSymbol,
-    HoverContents, LspClient, LspLocation, LspServerConfig, MarkedString,
-    SymbolInformation, SymbolKind,
+    CallHierarchyIncomingCall, CallHierarchyOutgoingCall, DocumentChanges, DocumentSymbol,
+    HoverContents, LspClient, LspLocation, LspServerConfig, MarkedString, OneOf,
+    SymbolInformation, SymbolKind, TextEdit, WorkspaceEdit,
 };
 use serde_json::json;
 use tokio::sync::RwLock;
@@ -179,6 +179,326 @@ pub async fn execute_goto_definition<W: UiWriter>(
     }
 }
 
+/// Execute the find_definition tool.
+///
+/// Answers from the knowledge graph when one is available - no language
+/// server startup - and falls back to LSP for positions the graph cannot
+/// resolve.
+pub async fn execute_find_definition<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let file_path = args
+        .get("file_path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: file_path"))?
+        .to_string();
+
+    let line = args
+        .get("line")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: line"))?;
+
+    let character = args
+        .get("character")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: character"))?;
+
+    debug!(
+        file = %file_path,
+        line = line,
+        character = character,
+        "Executing find_definition"
+    );
+
+    // Fast path: answer from the knowledge graph without a language server
+    if let Ok(client) = crate::tools::index::get_or_init_client(ctx).await {
+        if client.has_graph().await {
+            if let Ok(Some(symbol)) = client
+                .resolve_definition(&file_path, line as usize, character as usize)
+                .await
+            {
+                return Ok(json!({
+                    "status": "success",
+                    "source": "graph",
+                    "count": 1,
+                    "locations": [{
+                        "file": symbol.file_id,
+                        "line": symbol.line_start,
+                        "column": symbol.column_start,
+                        "name": symbol.name,
+                        "kind": symbol.kind.label(),
+                        "signature": symbol.signature,
+                    }]
+                })
+                .to_string());
+            }
+        }
+    }
+
+    // Fall back to a language server
+    let language = LspManager::detect_language(&file_path)
+        .ok_or_else(|| anyhow::anyhow!("Cannot determine language for file: {}", file_path))?;
+
+    let lsp_manager = get_or_create_lsp_manager(ctx).await?;
+    let client = lsp_manager
+        .get_client(language)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    match client
+        .goto_definition(Path::new(&file_path), line as u32, character as u32)
+        .await
+    {
+        Ok(locations) => {
+            if locations.is_empty() {
+                Ok(json!({
+                    "status": "success",
+                    "source": "lsp",
+                    "message": "No definition found at this location",
+                    "locations": []
+                })
+                .to_string())
+            } else {
+                let formatted = format_locations(&locations);
+                Ok(json!({
+                    "status": "success",
+                    "source": "lsp",
+                    "count": locations.len(),
+                    "locations": formatted
+                })
+                .to_string())
+            }
+        }
+        Err(e) => Ok(json!({
+            "status": "error",
+            "message": format!("Failed to find definition: {}", e)
+        })
+        .to_string()),
+    }
+}
+
+/// Execute the rename_symbol tool.
+///
+/// Asks the language server for the workspace-wide edit set, then applies it
+/// transactionally: originals are kept in memory as backups and restored if
+/// any write fails, so the workspace is never left half-renamed.
+pub async fn execute_rename_symbol<W: UiWriter>(
+    tool_call: &ToolCall,
+    ctx: &mut ToolContext<'_, W>,
+) -> Result<String> {
+    let args = &tool_call.args;
+
+    let file_path = args
+        .get("file_path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: file_path"))?;
+
+    let line = args
+        .get("line")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: line"))? as u32;
+
+    let character = args
+        .get("character")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: character"))? as u32;
+
+    let new_name = args
+        .get("new_name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required parameter: new_name"))?;
+
+    debug!(
+        file = file_path,
+        line = line,
+        character = character,
+        new_name = new_name,
+        "Executing rename_symbol"
+    );
+
+    let language = LspManager::detect_language(file_path)
+        .ok_or_else(|| anyhow::anyhow!("Cannot determine language for file: {}", file_path))?;
+
+    let lsp_manager = get_or_create_lsp_manager(ctx).await?;
+    let client = lsp_manager
+        .get_client(language)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let workspace_edit = match client
+        .rename(Path::new(file_path), line, character, new_name)
+        .await
+    {
+        Ok(edit) => edit,
+        Err(e) => {
+            return Ok(json!({
+                "status": "error",
+                "message": format!("Rename failed: {}", e)
+            })
+            .to_string());
+        }
+    };
+
+    let planned = match plan_workspace_edit(&workspace_edit) {
+        Ok(planned) => planned,
+        Err(e) => {
+            return Ok(json!({
+                "status": "error",
+                "message": format!("Could not plan rename edits (nothing was written): {}", e)
+            })
+            .to_string());
+        }
+    };
+
+    if planned.is_empty() {
+        return Ok(json!({
+            "status": "success",
+            "message": "Language server returned no edits for this rename",
+            "changed_files": []
+        })
+        .to_string());
+    }
+
+    match commit_planned_edits(&planned) {
+        Ok(changed_files) => {
+            let files: Vec<String> = changed_files
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect();
+            Ok(json!({
+                "status": "success",
+                "new_name": new_name,
+                "count": files.len(),
+                "changed_files": files
+            })
+            .to_string())
+        }
+        Err(e) => Ok(json!({
+            "status": "error",
+            "message": format!("Rename rolled back: {}", e)
+        })
+        .to_string()),
+    }
+}
+
+/// One file's planned rename: its content before and after the edits.
+struct PlannedEdit {
+    path: PathBuf,
+    original: String,
+    updated: String,
+}
+
+/// Byte offset of a 0-indexed LSP position within `content`.
+fn position_to_offset(content: &str, line: u32, character: u32) -> usize {
+    let mut offset = 0;
+    for (i, l) in content.split_inclusive('\n').enumerate() {
+        if i as u32 == line {
+            return offset
+                + l.chars()
+                    .take(character as usize)
+                    .map(|c| c.len_utf8())
+                    .sum::<usize>();
+        }
+        offset += l.len();
+    }
+    content.len()
+}
+
+/// Apply LSP text edits to a file's content, bottom-up so earlier ranges
+/// stay valid while later ones are replaced.
+fn apply_text_edits(content: &str, edits: &[TextEdit]) -> String {
+    let mut sorted: Vec<&TextEdit> = edits.iter().collect();
+    sorted.sort_by_key(|e| (e.range.start.line, e.range.start.character));
+
+    let mut result = content.to_string();
+    for edit in sorted.iter().rev() {
+        let start = position_to_offset(&result, edit.range.start.line, edit.range.start.character);
+        let end = position_to_offset(&result, edit.range.end.line, edit.range.end.character);
+        result.replace_range(start..end, &edit.new_text);
+    }
+    result
+}
+
+/// Plan the edits for every file in a `WorkspaceEdit`, reading all originals
+/// up front so nothing is written if any file is unreadable.
+fn plan_workspace_edit(edit: &WorkspaceEdit) -> Result<Vec<PlannedEdit>> {
+    let mut per_file: Vec<(PathBuf, Vec<TextEdit>)> = Vec::new();
+
+    if let Some(changes) = &edit.changes {
+        for (uri, edits) in changes {
+            let path = uri
+                .to_file_path()
+                .map_err(|_| anyhow::anyhow!("Cannot convert URI to a local path: {}", uri))?;
+            per_file.push((path, edits.clone()));
+        }
+    }
+
+    if let Some(document_changes) = &edit.document_changes {
+        match document_changes {
+            DocumentChanges::Edits(doc_edits) => {
+                for doc_edit in doc_edits {
+                    let uri = &doc_edit.text_document.uri;
+                    let path = uri.to_file_path().map_err(|_| {
+                        anyhow::anyhow!("Cannot convert URI to a local path: {}", uri)
+                    })?;
+                    let edits: Vec<TextEdit> = doc_edit
+                        .edits
+                        .iter()
+                        .map(|e| match e {
+                            OneOf::Left(edit) => edit.clone(),
+                            OneOf::Right(annotated) => annotated.text_edit.clone(),
+                        })
+                        .collect();
+                    per_file.push((path, edits));
+                }
+            }
+            DocumentChanges::Operations(_) => {
+                anyhow::bail!("Rename requires file create/rename/delete operations, which are not supported");
+            }
+        }
+    }
+
+    let mut planned = Vec::new();
+    for (path, edits) in per_file {
+        let original = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let updated = apply_text_edits(&original, &edits);
+        planned.push(PlannedEdit {
+            path,
+            original,
+            updated,
+        });
+    }
+    planned.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(planned)
+}
+
+/// Write all planned edits, restoring the in-memory backups if any write
+/// fails. Returns the list of changed files on success.
+fn commit_planned_edits(planned: &[PlannedEdit]) -> Result<Vec<PathBuf>> {
+    let mut written: Vec<&PlannedEdit> = Vec::new();
+
+    for edit in planned {
+        if let Err(e) = std::fs::write(&edit.path, &edit.updated) {
+            for done in &written {
+                let _ = std::fs::write(&done.path, &done.original);
+            }
+            anyhow::bail!(
+                "Failed to write {}: {} ({} already-written file(s) were restored)",
+                edit.path.display(),
+                e,
+                written.len()
+            );
+        }
+        written.push(edit);
+    }
+
+    Ok(written.iter().map(|e| e.path.clone()).collect())
+}
+
 /// Execute the lsp_find_references tool.
 pub async fn execute_find_references<W: UiWriter>(
     tool_call: &ToolCall,
@@ -381,7 +701,12 @@ pub async fn execute_workspace_symbols<W: UiWriter>(
         .and_then(|v| v.as_str())
         .unwrap_or("rust");
 
-    debug!(query = query, language = language, "Executing lsp_workspace_symbols");
+    let limit = args
+        .get("limit")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(50) as usize;
+
+    debug!(query = query, language = language, limit = limit, "Executing lsp_workspace_symbols");
 
     let lsp_manager = get_or_create_lsp_manager(ctx).await?;
     let client = lsp_manager
@@ -389,9 +714,9 @@ pub async fn execute_workspace_symbols<W: UiWriter>(
         .await
         .map_err(|e| anyhow::anyhow!(e))?;
 
-    match client.workspace_symbols(query).await {
-        Ok(symbols) => {
-            if symbols.is_empty() {
+    match client.workspace_symbols(query, limit).await {
+        Ok(result) => {
+            if result.symbols.is_empty() {
                 Ok(json!({
                     "status": "success",
                     "message": format!("No symbols matching '{}' found", query),
@@ -399,11 +724,12 @@ pub async fn execute_workspace_symbols<W: UiWriter>(
                 })
                 .to_string())
             } else {
-                let formatted = format_symbol_information(&symbols);
+                let formatted = format_symbol_information(&result.symbols);
                 Ok(json!({
                     "status": "success",
                     "query": query,
-                    "count": symbols.len(),
+                    "count": result.symbols.len(),
+                    "truncated": result.truncated,
                     "symbols": formatted
                 })
                 .to_string())
@@ -849,3 +1175,117 @@ fn format_outgoing_calls(calls: &[CallHierarchyOutgoingCall]) -> Vec<serde_json:
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use g3_lsp::{Position, Range, Url};
+
+    fn text_edit(
+        start_line: u32,
+        start_char: u32,
+        end_line: u32,
+        end_char: u32,
+        new_text: &str,
+    ) -> TextEdit {
+        TextEdit {
+            range: Range {
+                start: Position::new(start_line, start_char),
+                end: Position::new(end_line, end_char),
+            },
+            new_text: new_text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_text_edits_multiple_on_one_line() {
+        let content = "fn old_name() { old_name() }\n";
+        let edits = vec![
+            text_edit(0, 3, 0, 11, "new_name"),
+            text_edit(0, 16, 0, 24, "new_name"),
+        ];
+
+        let updated = apply_text_edits(content, &edits);
+        assert_eq!(updated, "fn new_name() { new_name() }\n");
+    }
+
+    #[test]
+    fn test_rename_rolls_back_when_a_write_fails() {
+        let temp = tempfile::tempdir().unwrap();
+        let file_a = temp.path().join("a.rs");
+        std::fs::write(&file_a, "fn old() {}\n").unwrap();
+
+        // The second target is a directory, so writing to it must fail
+        let blocked = temp.path().join("blocked");
+        std::fs::create_dir(&blocked).unwrap();
+
+        let planned = vec![
+            PlannedEdit {
+                path: file_a.clone(),
+                original: "fn old() {}\n".to_string(),
+                updated: "fn renamed() {}\n".to_string(),
+            },
+            PlannedEdit {
+                path: blocked,
+                original: String::new(),
+                updated: "anything".to_string(),
+            },
+        ];
+
+        let result = commit_planned_edits(&planned);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("restored"));
+        // The first file was written then rolled back to its original content
+        assert_eq!(std::fs::read_to_string(&file_a).unwrap(), "fn old() {}\n");
+    }
+
+    #[test]
+    fn test_commit_reports_changed_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let file_a = temp.path().join("a.rs");
+        let file_b = temp.path().join("b.rs");
+        std::fs::write(&file_a, "old\n").unwrap();
+        std::fs::write(&file_b, "old\n").unwrap();
+
+        let planned = vec![
+            PlannedEdit {
+                path: file_a.clone(),
+                original: "old\n".to_string(),
+                updated: "new\n".to_string(),
+            },
+            PlannedEdit {
+                path: file_b.clone(),
+                original: "old\n".to_string(),
+                updated: "new\n".to_string(),
+            },
+        ];
+
+        let changed = commit_planned_edits(&planned).unwrap();
+        assert_eq!(changed, vec![file_a.clone(), file_b.clone()]);
+        assert_eq!(std::fs::read_to_string(&file_a).unwrap(), "new\n");
+        assert_eq!(std::fs::read_to_string(&file_b).unwrap(), "new\n");
+    }
+
+    #[test]
+    fn test_plan_workspace_edit_reads_and_rewrites() {
+        let temp = tempfile::tempdir().unwrap();
+        let file_a = temp.path().join("a.rs");
+        std::fs::write(&file_a, "fn old() {}\n").unwrap();
+
+        let uri = Url::from_file_path(&file_a).unwrap();
+        let mut changes = std::collections::HashMap::new();
+        changes.insert(uri, vec![text_edit(0, 3, 0, 6, "renamed")]);
+
+        let edit = WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        };
+
+        let planned = plan_workspace_edit(&edit).unwrap();
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].updated, "fn renamed() {}\n");
+        // Planning must not touch the file
+        assert_eq!(std::fs::read_to_string(&file_a).unwrap(), "fn old() {}\n");
+    }
+}
@@ -157,6 +157,45 @@ pub async fn execute_code_search<W: UiWriter>(
     }
 }
 
+/// Execute the `validate_query` tool.
+///
+/// Compiles a tree-sitter query against a language grammar without
+/// running it, so malformed queries fail fast with a position and hint.
+pub async fn execute_validate_query<W: UiWriter>(
+    tool_call: &ToolCall,
+    _ctx: &ToolContext<'_, W>,
+) -> Result<String> {
+    debug!("Processing validate_query tool call");
+
+    let query = match tool_call.args.get("query").and_then(|v| v.as_str()) {
+        Some(q) => q,
+        None => return Ok("❌ Missing required parameter: query".to_string()),
+    };
+    let language = match tool_call.args.get("language").and_then(|v| v.as_str()) {
+        Some(l) => l,
+        None => return Ok("❌ Missing required parameter: language".to_string()),
+    };
+
+    let searcher = match crate::code_search::TreeSitterSearcher::new() {
+        Ok(s) => s,
+        Err(e) => return Ok(format!("❌ Failed to initialize parsers: {}", e)),
+    };
+
+    match searcher.validate_query(language, query) {
+        Ok(validation) if validation.valid => {
+            Ok(format!("✅ Query is valid for {}", language))
+        }
+        Ok(validation) => Ok(format!(
+            "❌ Invalid query at line {}, column {}: {}\nHint: {}",
+            validation.row.unwrap_or(0),
+            validation.column.unwrap_or(0),
+            validation.error.unwrap_or_default(),
+            validation.hint.unwrap_or_default()
+        )),
+        Err(e) => Ok(format!("❌ {}", e)),
+    }
+}
+
 /// Execute the `switch_mode` tool.
 /// Allows the agent to recommend switching to a different execution mode.
 pub async fn execute_switch_mode<W: UiWriter>(
@@ -191,6 +191,7 @@ pub async fn execute_research<W: UiWriter>(
 
     // Spawn the scout agent in a background task
     tokio::spawn(async move {
+        manager.mark_running(&research_id_clone);
         let result = run_scout_agent(&g3_path, &query_owned, browser).await;
         
         match result {
@@ -343,7 +344,8 @@ pub async fn execute_research_status<W: UiWriter>(
         match ctx.pending_research_manager.get(&id.to_string()) {
             Some(task) => {
                 let status_emoji = match task.status {
-                    crate::pending_research::ResearchStatus::Pending => "🔄",
+                    crate::pending_research::ResearchStatus::Pending => "⏳",
+                    crate::pending_research::ResearchStatus::Running => "🔄",
                     crate::pending_research::ResearchStatus::Complete => "✅",
                     crate::pending_research::ResearchStatus::Failed => "❌",
                 };
@@ -362,7 +364,7 @@ pub async fn execute_research_status<W: UiWriter>(
                 
                 if task.injected {
                     output.push_str("\n_Results have already been injected into the conversation._\n");
-                } else if task.status != crate::pending_research::ResearchStatus::Pending {
+                } else if task.status.is_terminal() {
                     output.push_str("\n_Results will be injected at the next opportunity._\n");
                 }
                 
@@ -382,7 +384,8 @@ pub async fn execute_research_status<W: UiWriter>(
         
         for task in tasks {
             let status_emoji = match task.status {
-                crate::pending_research::ResearchStatus::Pending => "🔄",
+                crate::pending_research::ResearchStatus::Pending => "⏳",
+                crate::pending_research::ResearchStatus::Running => "🔄",
                 crate::pending_research::ResearchStatus::Complete => "✅",
                 crate::pending_research::ResearchStatus::Failed => "❌",
             };
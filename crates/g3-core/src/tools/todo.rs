@@ -178,6 +178,7 @@ mod tests {
                 index_client: None,
                 lsp_manager: None,
                 active_persona: None,
+                file_write_guard: None,
             }
         }
     }
@@ -2,13 +2,14 @@
 
 use anyhow::Result;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use g3_config::Config;
 use g3_index::embeddings::EmbeddingProvider;
 
 use super::descriptor::{DescriptorPair, EmbeddedDescriptors};
+use super::locks::{FileConflict, FileLockRegistry};
 use super::manager::ManagerAgent;
 use super::message;
 use super::topology::TopologyBuilder;
@@ -30,6 +31,7 @@ pub struct DyTopoCoordinator {
     embedder: Arc<dyn EmbeddingProvider>,
     workers: Vec<AgentWorker>,
     manager: ManagerAgent,
+    locks: Arc<Mutex<FileLockRegistry>>,
 }
 
 impl DyTopoCoordinator {
@@ -39,6 +41,7 @@ impl DyTopoCoordinator {
         embedder: Arc<dyn EmbeddingProvider>,
     ) -> Result<Self> {
         println!("  Initializing workers...");
+        let locks = Arc::new(Mutex::new(FileLockRegistry::new()));
         let mut workers = Vec::new();
         for agent_id in &config.agent_ids {
             let prompt = config.agent_prompts.get(agent_id)
@@ -46,7 +49,12 @@ impl DyTopoCoordinator {
                 .unwrap_or_else(|| format!("You are agent '{}'.", agent_id));
             print!("    Creating worker '{}'... ", agent_id);
             std::io::Write::flush(&mut std::io::stdout()).ok();
-            let worker = AgentWorker::new(agent_id.clone(), &prompt, llm_config.clone()).await?;
+            let worker = AgentWorker::new(
+                agent_id.clone(),
+                &prompt,
+                llm_config.clone(),
+                Arc::clone(&locks),
+            ).await?;
             println!("ok");
             workers.push(worker);
         }
@@ -55,7 +63,32 @@ impl DyTopoCoordinator {
         let manager = ManagerAgent::new(llm_config.clone()).await?;
         println!("ok");
 
-        Ok(Self { config, llm_config, embedder, workers, manager })
+        Ok(Self {
+            config,
+            llm_config,
+            embedder,
+            workers,
+            manager,
+            locks,
+        })
+    }
+
+    /// File lock registry guarding concurrent writes across workers.
+    ///
+    /// Shared with every worker's write guard: an agent's write tools
+    /// acquire the file here before touching it, and a second worker
+    /// hitting the same file is queued until the holder's round ends.
+    pub fn lock_registry(&self) -> Arc<Mutex<FileLockRegistry>> {
+        Arc::clone(&self.locks)
+    }
+
+    /// File conflicts recorded across all rounds so far.
+    pub fn lock_conflicts(&self) -> Vec<FileConflict> {
+        self.locks
+            .lock()
+            .expect("file lock registry poisoned")
+            .conflicts()
+            .to_vec()
     }
 
     pub async fn run(&mut self, task: &str) -> Result<String> {
@@ -74,8 +107,24 @@ impl DyTopoCoordinator {
             println!("=== Round {}/{} ===", round, self.config.max_rounds);
             println!("  Goal: {}", current_goal);
 
+            let conflicts_before = self.lock_conflicts().len();
             let round_outputs = self.run_workers(&current_goal, round).await?;
 
+            // Surface file contention recorded while workers ran
+            let all_conflicts = self.lock_conflicts();
+            let new_conflicts = &all_conflicts[conflicts_before..];
+            if !new_conflicts.is_empty() {
+                println!("  File conflicts this round:");
+                for conflict in new_conflicts {
+                    println!(
+                        "    {} blocked on {} (held by {})",
+                        conflict.requester,
+                        conflict.path.display(),
+                        conflict.holder
+                    );
+                }
+            }
+
             // Collect descriptor pairs with debug info
             let descriptor_pairs: Vec<DescriptorPair> = round_outputs.iter().map(|(id, output)| {
                 if output.query.is_empty() && output.key.is_empty() {
@@ -178,6 +227,11 @@ impl DyTopoCoordinator {
             let output = worker.run_round(goal, &[], round).await?;
             let elapsed = start.elapsed();
             println!("done ({:.1}s)", elapsed.as_secs_f64());
+            // The worker's turn is over: free its files so queued agents proceed
+            self.locks
+                .lock()
+                .expect("file lock registry poisoned")
+                .release_all(&agent_id);
             outputs.push((agent_id, output));
         }
         Ok(outputs)
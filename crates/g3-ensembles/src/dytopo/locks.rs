@@ -0,0 +1,257 @@
+//! File-level lock registry for parallel agent runs.
+//!
+//! When several agents run in parallel, two can edit the same file and
+//! clobber each other's work. The registry tracks which agent holds which
+//! file: the first writer gets the lock, later writers are queued behind it
+//! and promoted in FIFO order when the holder releases. Every contention is
+//! recorded so the coordinator can surface it in its status output.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use g3_core::FileWriteGuard;
+
+/// Outcome of a lock request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockRequest {
+    /// The requesting agent now holds the file.
+    Granted,
+    /// Another agent holds the file; the requester is queued behind it.
+    Queued {
+        /// Agent currently holding the lock
+        holder: String,
+        /// 1-based position in the wait queue
+        position: usize,
+    },
+}
+
+/// A recorded contention event between two agents over one file.
+#[derive(Debug, Clone)]
+pub struct FileConflict {
+    /// The contested file
+    pub path: PathBuf,
+    /// Agent that held the lock when the conflict occurred
+    pub holder: String,
+    /// Agent whose write was blocked
+    pub requester: String,
+}
+
+/// Tracks which agent holds which file during a parallel run.
+#[derive(Debug, Default)]
+pub struct FileLockRegistry {
+    holders: HashMap<PathBuf, String>,
+    queues: HashMap<PathBuf, VecDeque<String>>,
+    conflicts: Vec<FileConflict>,
+}
+
+impl FileLockRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request a write lock on `path` for `agent_id`.
+    ///
+    /// Granted when the file is free or already held by the same agent.
+    /// Otherwise the agent is queued (once, no matter how often it retries)
+    /// and the conflict is recorded.
+    pub fn acquire(&mut self, path: impl AsRef<Path>, agent_id: &str) -> LockRequest {
+        let path = path.as_ref().to_path_buf();
+        match self.holders.get(&path) {
+            None => {
+                self.holders.insert(path, agent_id.to_string());
+                LockRequest::Granted
+            }
+            Some(holder) if holder == agent_id => LockRequest::Granted,
+            Some(holder) => {
+                let holder = holder.clone();
+                let queue = self.queues.entry(path.clone()).or_default();
+                let position = match queue.iter().position(|queued| queued == agent_id) {
+                    Some(index) => index + 1,
+                    None => {
+                        queue.push_back(agent_id.to_string());
+                        self.conflicts.push(FileConflict {
+                            path,
+                            holder: holder.clone(),
+                            requester: agent_id.to_string(),
+                        });
+                        queue.len()
+                    }
+                };
+                LockRequest::Queued { holder, position }
+            }
+        }
+    }
+
+    /// Release `path` if held by `agent_id`, promoting the next queued agent.
+    ///
+    /// Returns the new holder, if any. Releasing a file the agent does not
+    /// hold is a no-op returning `None`.
+    pub fn release(&mut self, path: impl AsRef<Path>, agent_id: &str) -> Option<String> {
+        let path = path.as_ref();
+        if self.holders.get(path).map(String::as_str) != Some(agent_id) {
+            return None;
+        }
+        self.holders.remove(path);
+        let next = self.queues.get_mut(path).and_then(|queue| queue.pop_front());
+        if let Some(ref next_holder) = next {
+            self.holders.insert(path.to_path_buf(), next_holder.clone());
+        }
+        if self.queues.get(path).map(|queue| queue.is_empty()) == Some(true) {
+            self.queues.remove(path);
+        }
+        next
+    }
+
+    /// Release every lock held by `agent_id`, promoting queued agents.
+    ///
+    /// Returns the paths that were released.
+    pub fn release_all(&mut self, agent_id: &str) -> Vec<PathBuf> {
+        let held: Vec<PathBuf> = self
+            .holders
+            .iter()
+            .filter(|(_, holder)| holder.as_str() == agent_id)
+            .map(|(path, _)| path.clone())
+            .collect();
+        for path in &held {
+            self.release(path, agent_id);
+        }
+        held
+    }
+
+    /// Current holder of `path`, if locked.
+    pub fn holder(&self, path: impl AsRef<Path>) -> Option<&str> {
+        self.holders.get(path.as_ref()).map(String::as_str)
+    }
+
+    /// All contention events recorded so far.
+    pub fn conflicts(&self) -> &[FileConflict] {
+        &self.conflicts
+    }
+}
+
+/// Bridges one worker's file writes into the shared lock registry.
+///
+/// Installed on the worker's agent at construction, so every `write_file`
+/// and `str_replace` the agent attempts first acquires the file here. A
+/// granted lock is held until the coordinator releases the worker's files
+/// at the end of its round; a queued request vetoes the write and tells
+/// the model who holds the file.
+pub struct WorkerWriteGuard {
+    agent_id: String,
+    registry: Arc<Mutex<FileLockRegistry>>,
+}
+
+impl WorkerWriteGuard {
+    /// Create a guard for `agent_id` over the run's shared registry.
+    pub fn new(agent_id: String, registry: Arc<Mutex<FileLockRegistry>>) -> Self {
+        Self { agent_id, registry }
+    }
+}
+
+impl FileWriteGuard for WorkerWriteGuard {
+    fn check_write(&self, path: &Path) -> Option<String> {
+        let mut registry = self.registry.lock().expect("file lock registry poisoned");
+        match registry.acquire(path, &self.agent_id) {
+            LockRequest::Granted => None,
+            LockRequest::Queued { holder, position } => Some(format!(
+                "Blocked: '{}' is being edited by agent '{}' (queue position {}). \
+                 Work on other files this round; the lock is released when that \
+                 agent's turn ends.",
+                path.display(),
+                holder,
+                position
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_second_writer_blocked_until_first_releases() {
+        let mut registry = FileLockRegistry::new();
+
+        assert_eq!(registry.acquire("src/lib.rs", "alpha"), LockRequest::Granted);
+
+        // Second worker is queued behind the holder
+        match registry.acquire("src/lib.rs", "beta") {
+            LockRequest::Queued { holder, position } => {
+                assert_eq!(holder, "alpha");
+                assert_eq!(position, 1);
+            }
+            other => panic!("expected Queued, got {:?}", other),
+        }
+        assert_eq!(registry.holder("src/lib.rs"), Some("alpha"));
+
+        // Retrying does not duplicate the queue entry or the conflict
+        assert!(matches!(
+            registry.acquire("src/lib.rs", "beta"),
+            LockRequest::Queued { position: 1, .. }
+        ));
+        assert_eq!(registry.conflicts().len(), 1);
+        assert_eq!(registry.conflicts()[0].requester, "beta");
+
+        // Release promotes the queued worker
+        assert_eq!(registry.release("src/lib.rs", "alpha").as_deref(), Some("beta"));
+        assert_eq!(registry.holder("src/lib.rs"), Some("beta"));
+        assert_eq!(registry.acquire("src/lib.rs", "beta"), LockRequest::Granted);
+    }
+
+    #[test]
+    fn test_reacquire_and_foreign_release() {
+        let mut registry = FileLockRegistry::new();
+
+        assert_eq!(registry.acquire("src/main.rs", "alpha"), LockRequest::Granted);
+        // Holder can reacquire its own lock freely
+        assert_eq!(registry.acquire("src/main.rs", "alpha"), LockRequest::Granted);
+
+        // A non-holder cannot release the lock
+        assert_eq!(registry.release("src/main.rs", "beta"), None);
+        assert_eq!(registry.holder("src/main.rs"), Some("alpha"));
+    }
+
+    #[test]
+    fn test_worker_guards_serialize_writes_through_shared_registry() {
+        let registry = Arc::new(Mutex::new(FileLockRegistry::new()));
+        let alpha = WorkerWriteGuard::new("alpha".to_string(), Arc::clone(&registry));
+        let beta = WorkerWriteGuard::new("beta".to_string(), Arc::clone(&registry));
+
+        // Alpha's write goes through; beta's write to the same file is vetoed
+        assert_eq!(alpha.check_write(Path::new("src/lib.rs")), None);
+        let veto = beta
+            .check_write(Path::new("src/lib.rs"))
+            .expect("beta should be blocked");
+        assert!(veto.contains("alpha"));
+
+        // The conflict is recorded for the coordinator's round summary
+        assert_eq!(registry.lock().unwrap().conflicts().len(), 1);
+
+        // Beta is free to write elsewhere while queued
+        assert_eq!(beta.check_write(Path::new("src/other.rs")), None);
+
+        // End of alpha's round: the coordinator releases, beta is promoted
+        registry.lock().unwrap().release_all("alpha");
+        assert_eq!(registry.lock().unwrap().holder("src/lib.rs"), Some("beta"));
+        assert_eq!(beta.check_write(Path::new("src/lib.rs")), None);
+    }
+
+    #[test]
+    fn test_release_all_promotes_queued_agents() {
+        let mut registry = FileLockRegistry::new();
+
+        registry.acquire("src/a.rs", "alpha");
+        registry.acquire("src/b.rs", "alpha");
+        registry.acquire("src/a.rs", "beta");
+
+        let mut released = registry.release_all("alpha");
+        released.sort();
+        assert_eq!(released, vec![PathBuf::from("src/a.rs"), PathBuf::from("src/b.rs")]);
+
+        assert_eq!(registry.holder("src/a.rs"), Some("beta"));
+        assert_eq!(registry.holder("src/b.rs"), None);
+    }
+}
@@ -3,10 +3,12 @@
 pub mod channel_ui_writer;
 pub mod coordinator;
 pub mod descriptor;
+pub mod locks;
 pub mod manager;
 pub mod message;
 pub mod topology;
 pub mod worker;
 
 pub use coordinator::{DyTopoConfig, DyTopoCoordinator};
+pub use locks::{FileConflict, FileLockRegistry, LockRequest, WorkerWriteGuard};
 pub use topology::TopologyGraph;
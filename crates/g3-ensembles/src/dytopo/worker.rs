@@ -1,6 +1,7 @@
 //! Worker agent wrapper for DyTopo rounds.
 
 use anyhow::Result;
+use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 
 use g3_config::Config;
@@ -9,6 +10,7 @@ use g3_core::get_agent_system_prompt;
 
 use super::channel_ui_writer::ChannelUiWriter;
 use super::descriptor::{AgentRoundOutput, DescriptorBlock};
+use super::locks::{FileLockRegistry, WorkerWriteGuard};
 use super::message::Message;
 
 const DESCRIPTOR_PROMPT: &str = r#"
@@ -32,12 +34,20 @@ pub struct AgentWorker {
 }
 
 impl AgentWorker {
-    pub async fn new(agent_id: String, persona_prompt: &str, config: Config) -> Result<Self> {
+    pub async fn new(
+        agent_id: String,
+        persona_prompt: &str,
+        config: Config,
+        locks: Arc<Mutex<FileLockRegistry>>,
+    ) -> Result<Self> {
         let (sender, receiver) = mpsc::unbounded_channel();
         let ui_writer = ChannelUiWriter::new(sender);
         let persona_with_descriptors = format!("{}\n{}", persona_prompt, DESCRIPTOR_PROMPT);
         let system_prompt = get_agent_system_prompt(&persona_with_descriptors, true);
-        let agent = Agent::new_with_custom_prompt(config, ui_writer, system_prompt, None).await?;
+        let mut agent = Agent::new_with_custom_prompt(config, ui_writer, system_prompt, None).await?;
+        // Serialize file writes across workers: every write_file/str_replace
+        // this agent makes must first acquire the file in the shared registry
+        agent.set_file_write_guard(Arc::new(WorkerWriteGuard::new(agent_id.clone(), locks)));
         Ok(Self { agent_id, agent, _receiver: receiver })
     }
 
@@ -3,6 +3,9 @@
 use anyhow::{Context, Result};
 use chrono::Utc;
 use g3_config::Config;
+use g3_index::{CodeGraph, EdgeKind};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use tokio::io::{AsyncBufReadExt, BufReader};
@@ -360,6 +363,7 @@ impl FlockMode {
                 max_turns,
                 last_message: Some("Starting...".to_string()),
                 error_message: None,
+                assigned_modules: Vec::new(),
             };
             
             self.status.update_segment(segment_id, segment_status);
@@ -418,6 +422,7 @@ impl FlockMode {
                             max_turns: self.config.max_turns,
                             last_message: None,
                             error_message: Some(e.to_string()),
+                            assigned_modules: Vec::new(),
                         });
                     segment_status.state = SegmentState::Failed;
                     segment_status.completed_at = Some(Utc::now());
@@ -442,6 +447,7 @@ impl FlockMode {
                             max_turns: self.config.max_turns,
                             last_message: None,
                             error_message: Some(format!("Task panicked: {}", e)),
+                            assigned_modules: Vec::new(),
                         });
                     segment_status.state = SegmentState::Failed;
                     segment_status.completed_at = Some(Utc::now());
@@ -476,6 +482,50 @@ impl FlockMode {
         let status_file = self.get_status_file_path();
         self.status.save_to_file(&status_file)
     }
+
+    /// Partition the workspace by code graph module boundaries
+    ///
+    /// Alternative to the agent-based `partition_requirements`: uses the
+    /// indexed code graph to split modules into roughly balanced,
+    /// low-coupling segments.
+    pub fn partition_modules(&self, graph: &CodeGraph) -> Vec<ModuleSegment> {
+        partition_by_module(graph, self.config.num_segments)
+    }
+
+    /// Record module partition assignments in the segment statuses
+    ///
+    /// Each segment gets a pending `SegmentStatus` listing the modules it
+    /// owns, so workers (and the status file) know their boundaries before
+    /// any agent starts.
+    pub fn assign_module_segments(&mut self, segments: &[ModuleSegment]) -> Result<()> {
+        for segment in segments {
+            let segment_dir = self
+                .config
+                .flock_workspace
+                .join(format!("segment-{}", segment.segment_id));
+            let segment_status = SegmentStatus {
+                segment_id: segment.segment_id,
+                workspace: segment_dir,
+                state: SegmentState::Pending,
+                started_at: Utc::now(),
+                completed_at: None,
+                tokens_used: 0,
+                tool_calls: 0,
+                errors: 0,
+                current_turn: 0,
+                max_turns: self.config.max_turns,
+                last_message: Some(format!(
+                    "Assigned {} modules ({} symbols)",
+                    segment.modules.len(),
+                    segment.weight
+                )),
+                error_message: None,
+                assigned_modules: segment.modules.clone(),
+            };
+            self.status.update_segment(segment.segment_id, segment_status);
+        }
+        self.save_status()
+    }
 }
 
 /// Run a single segment worker
@@ -503,6 +553,7 @@ async fn run_segment(
         max_turns,
         last_message: Some("Starting autonomous mode...".to_string()),
         error_message: None,
+        assigned_modules: Vec::new(),
     };
     
     // Run g3 in autonomous mode with segment-requirements.md
@@ -659,6 +710,120 @@ pub fn route_task_to_agent(task: &str, agents: &[g3_core::persona::AgentFile]) -
     best_agent.map(|s| s.to_string())
 }
 
+/// A set of modules assigned to one flock segment by graph partitioning
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleSegment {
+    /// Segment number (1-based, matching `SegmentStatus`)
+    pub segment_id: usize,
+
+    /// Module path prefixes (parent directories of indexed files)
+    pub modules: Vec<String>,
+
+    /// Total symbol count across the segment's modules
+    pub weight: usize,
+}
+
+/// Partition the code graph's modules into roughly balanced, low-coupling segments.
+///
+/// A module is the parent directory of an indexed file. Modules are weighted
+/// by symbol count and greedily assigned: the heaviest modules seed the
+/// segments, then each remaining module joins the segment it shares the most
+/// dependency edges with (falling back to the lightest segment when it has no
+/// coupling to any). Structural edges (`Defines`, `BelongsTo`, `Contains`)
+/// and intra-module edges are ignored when measuring coupling.
+pub fn partition_by_module(graph: &CodeGraph, num_segments: usize) -> Vec<ModuleSegment> {
+    if num_segments == 0 {
+        return Vec::new();
+    }
+
+    // Group files into modules keyed by parent directory
+    let mut file_module: HashMap<&str, String> = HashMap::new();
+    let mut module_weight: HashMap<String, usize> = HashMap::new();
+    for (file_id, file) in &graph.files {
+        let module = file
+            .path
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .filter(|p| !p.is_empty())
+            .unwrap_or_else(|| ".".to_string());
+        *module_weight.entry(module.clone()).or_insert(0) += file.symbol_count;
+        file_module.insert(file_id.as_str(), module);
+    }
+
+    // Count cross-module dependency edges
+    let module_of = |id: &str| -> Option<&String> {
+        if let Some(symbol) = graph.symbols.get(id) {
+            file_module.get(symbol.file_id.as_str())
+        } else {
+            file_module.get(id)
+        }
+    };
+    let mut coupling: HashMap<(String, String), usize> = HashMap::new();
+    for edge in &graph.edges {
+        if matches!(
+            edge.kind,
+            EdgeKind::Defines | EdgeKind::BelongsTo | EdgeKind::Contains
+        ) {
+            continue;
+        }
+        let (Some(source), Some(target)) = (module_of(&edge.source), module_of(&edge.target))
+        else {
+            continue;
+        };
+        if source == target {
+            continue;
+        }
+        *coupling.entry((source.clone(), target.clone())).or_insert(0) += 1;
+        *coupling.entry((target.clone(), source.clone())).or_insert(0) += 1;
+    }
+
+    // Heaviest modules first, so segments are seeded with the biggest work items
+    let mut modules: Vec<(String, usize)> = module_weight.into_iter().collect();
+    modules.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let mut segments: Vec<ModuleSegment> = (1..=num_segments)
+        .map(|segment_id| ModuleSegment {
+            segment_id,
+            modules: Vec::new(),
+            weight: 0,
+        })
+        .collect();
+
+    for (module, weight) in modules {
+        let target = if let Some(empty) = segments.iter().position(|s| s.modules.is_empty()) {
+            // Seed each empty segment before packing
+            empty
+        } else {
+            // Join the segment this module is most coupled to; when there is
+            // no coupling, balance load by picking the lightest segment
+            let affinity = |segment: &ModuleSegment| -> usize {
+                segment
+                    .modules
+                    .iter()
+                    .filter_map(|m| coupling.get(&(module.clone(), m.clone())))
+                    .sum()
+            };
+            segments
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| {
+                    affinity(a)
+                        .cmp(&affinity(b))
+                        .then_with(|| b.weight.cmp(&a.weight))
+                })
+                .map(|(i, _)| i)
+                .unwrap()
+        };
+        segments[target].modules.push(module);
+        segments[target].weight += weight;
+    }
+
+    for segment in &mut segments {
+        segment.modules.sort();
+    }
+    segments
+}
+
 /// Update the status file with new segment status
 fn update_status_file(
     status_file: &PathBuf,
@@ -17,6 +17,6 @@ mod tests;
 
 /// Re-export main types for convenience
 pub use dytopo::{DyTopoConfig, DyTopoCoordinator};
-pub use flock::{FlockConfig, FlockMode};
+pub use flock::{partition_by_module, FlockConfig, FlockMode, ModuleSegment};
 pub use status::{FlockStatus, SegmentStatus};
 pub use workflow::{Workflow, WorkflowBuilder, WorkflowState};
@@ -43,6 +43,11 @@ pub struct SegmentStatus {
     
     /// Error message (if failed)
     pub error_message: Option<String>,
+
+    /// Module path prefixes assigned to this segment by module partitioning
+    /// (empty when requirements were partitioned by an agent instead)
+    #[serde(default)]
+    pub assigned_modules: Vec<String>,
 }
 
 /// State of a segment worker
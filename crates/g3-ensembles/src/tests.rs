@@ -2,8 +2,10 @@
 
 #[cfg(test)]
 mod tests {
+    use crate::flock::partition_by_module;
     use crate::status::{FlockStatus, SegmentState, SegmentStatus};
     use chrono::Utc;
+    use g3_index::{CodeGraph, Edge, EdgeKind, FileNode, SymbolKind, SymbolNode};
     use std::path::PathBuf;
 
     #[test]
@@ -55,6 +57,7 @@ mod tests {
             max_turns: 10,
             last_message: Some("Done".to_string()),
             error_message: None,
+            assigned_modules: Vec::new(),
         };
 
         status.update_segment(1, segment1);
@@ -87,6 +90,7 @@ mod tests {
             max_turns: 10,
             last_message: Some("Done".to_string()),
             error_message: None,
+            assigned_modules: Vec::new(),
         };
 
         let segment2 = SegmentStatus {
@@ -102,6 +106,7 @@ mod tests {
             max_turns: 10,
             last_message: Some("Error".to_string()),
             error_message: Some("Test error".to_string()),
+            assigned_modules: Vec::new(),
         };
 
         status.update_segment(1, segment1);
@@ -139,6 +144,7 @@ mod tests {
             max_turns: 10,
             last_message: None,
             error_message: None,
+            assigned_modules: Vec::new(),
         };
         status.update_segment(1, segment1);
 
@@ -159,6 +165,7 @@ mod tests {
             max_turns: 10,
             last_message: None,
             error_message: None,
+            assigned_modules: Vec::new(),
         };
         status.update_segment(2, segment2);
 
@@ -179,6 +186,7 @@ mod tests {
             max_turns: 10,
             last_message: None,
             error_message: None,
+            assigned_modules: Vec::new(),
         };
         status.update_segment(2, segment2_done);
 
@@ -208,6 +216,7 @@ mod tests {
             max_turns: 10,
             last_message: None,
             error_message: None,
+            assigned_modules: Vec::new(),
         };
 
         let segment2 = SegmentStatus {
@@ -223,6 +232,7 @@ mod tests {
             max_turns: 10,
             last_message: None,
             error_message: Some("Error".to_string()),
+            assigned_modules: Vec::new(),
         };
 
         let segment3 = SegmentStatus {
@@ -238,6 +248,7 @@ mod tests {
             max_turns: 10,
             last_message: None,
             error_message: None,
+            assigned_modules: Vec::new(),
         };
 
         status.update_segment(1, segment1);
@@ -272,6 +283,7 @@ mod tests {
             max_turns: 10,
             last_message: Some("Done".to_string()),
             error_message: None,
+            assigned_modules: Vec::new(),
         };
 
         status.update_segment(1, segment1);
@@ -312,6 +324,7 @@ mod tests {
             max_turns: 10,
             last_message: Some("Done".to_string()),
             error_message: None,
+            assigned_modules: Vec::new(),
         };
 
         status.update_segment(1, segment1);
@@ -328,4 +341,74 @@ mod tests {
         assert!(report.contains("Total Tool Calls: 50"));
         assert!(report.contains("Total Errors: 2"));
     }
+
+    fn add_fn(graph: &mut CodeGraph, name: &str, file: &str, line: usize) -> String {
+        let symbol = SymbolNode::new(name, SymbolKind::Function, file, line);
+        let id = symbol.id.clone();
+        graph.add_symbol(symbol);
+        id
+    }
+
+    #[test]
+    fn test_partition_by_module_disjoint_connected_segments() {
+        let mut graph = CodeGraph::new();
+        for path in [
+            "src/auth/mod.rs",
+            "src/auth/providers/oauth.rs",
+            "src/net/mod.rs",
+            "src/net/http/client.rs",
+        ] {
+            graph.add_file(FileNode::new(path, "rust"));
+        }
+
+        // Auth cluster: src/auth (3 symbols) calls into src/auth/providers (2)
+        let login = add_fn(&mut graph, "login", "src/auth/mod.rs", 10);
+        add_fn(&mut graph, "logout", "src/auth/mod.rs", 20);
+        add_fn(&mut graph, "session", "src/auth/mod.rs", 30);
+        let oauth = add_fn(&mut graph, "oauth_flow", "src/auth/providers/oauth.rs", 5);
+        add_fn(&mut graph, "refresh", "src/auth/providers/oauth.rs", 15);
+
+        // Net cluster: src/net (3 symbols) calls into src/net/http (2)
+        let connect = add_fn(&mut graph, "connect", "src/net/mod.rs", 10);
+        add_fn(&mut graph, "bind", "src/net/mod.rs", 20);
+        add_fn(&mut graph, "resolve", "src/net/mod.rs", 30);
+        let get = add_fn(&mut graph, "http_get", "src/net/http/client.rs", 5);
+        add_fn(&mut graph, "http_post", "src/net/http/client.rs", 15);
+
+        // Dependency edges stay within each cluster
+        graph.add_edge(Edge::new(&login, &oauth, EdgeKind::Calls));
+        graph.add_edge(Edge::new(&connect, &get, EdgeKind::Calls));
+
+        let segments = partition_by_module(&graph, 2);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].segment_id, 1);
+        assert_eq!(segments[1].segment_id, 2);
+
+        // Segments are disjoint and cover every module
+        let mut all_modules: Vec<&String> = segments
+            .iter()
+            .flat_map(|s| s.modules.iter())
+            .collect();
+        all_modules.sort();
+        let before_dedup = all_modules.len();
+        all_modules.dedup();
+        assert_eq!(all_modules.len(), before_dedup, "modules assigned twice");
+        assert_eq!(all_modules.len(), 4);
+
+        // Coupled modules land in the same segment, keeping clusters intact
+        let auth_segment = segments
+            .iter()
+            .find(|s| s.modules.contains(&"src/auth".to_string()))
+            .unwrap();
+        assert!(auth_segment.modules.contains(&"src/auth/providers".to_string()));
+        let net_segment = segments
+            .iter()
+            .find(|s| s.modules.contains(&"src/net".to_string()))
+            .unwrap();
+        assert!(net_segment.modules.contains(&"src/net/http".to_string()));
+
+        // Both clusters carry the same symbol weight, so the split is balanced
+        assert_eq!(auth_segment.weight, 5);
+        assert_eq!(net_segment.weight, 5);
+    }
 }
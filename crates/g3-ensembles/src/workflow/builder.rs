@@ -1,6 +1,6 @@
 //! Workflow builder - fluent API for constructing workflows.
 
-use super::{AgentRole, Condition, Edge, Node, Workflow};
+use super::{AgentRole, Condition, Edge, Node, ValidationError, Workflow};
 use anyhow::{anyhow, Result};
 use std::collections::HashSet;
 
@@ -97,19 +97,40 @@ impl WorkflowBuilder {
         self
     }
     
-    /// Build and validate the workflow
-    pub fn build(self) -> Result<Workflow> {
-        // Create the workflow
+    /// Build the workflow, running validation automatically.
+    ///
+    /// Returns every `ValidationError` found if the workflow is invalid
+    /// (missing entrypoint, dangling edges, cycles, unreachable nodes),
+    /// so callers can report all problems at once.
+    pub fn build(self) -> std::result::Result<Workflow, Vec<ValidationError>> {
+        let workflow = self.build_unchecked();
+
+        let errors = workflow
+            .validate()
+            .expect("workflow validation is infallible");
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(workflow)
+    }
+
+    /// Build without validating.
+    ///
+    /// Escape hatch for tests that intentionally construct invalid graphs;
+    /// prefer `build()`. A missing entrypoint is left empty and will be
+    /// flagged by `Workflow::validate()`.
+    pub fn build_unchecked(self) -> Workflow {
         let mut workflow = Workflow::new(&self.name);
         workflow.description = self.description;
         workflow.max_iterations = self.max_iterations;
         workflow.checkpoints = self.checkpoints;
-        
+
         // Add nodes
         for node in self.nodes {
             workflow.nodes.insert(node.id.clone(), node);
         }
-        
+
         // Add edges
         for edge_builder in self.edges {
             workflow.edges.push(Edge {
@@ -118,25 +139,21 @@ impl WorkflowBuilder {
                 condition: edge_builder.condition,
             });
         }
-        
-        // Set entrypoint
-        workflow.entrypoint = self.entrypoint
-            .ok_or_else(|| anyhow!("Workflow must have an entrypoint"))?
-            .into();
-        
-        // Validate
-        let errors = workflow.validate()?;
-        if !errors.is_empty() {
-            let error_messages: Vec<_> = errors.iter().map(|e| e.to_string()).collect();
-            return Err(anyhow!("Workflow validation failed:\n{}", error_messages.join("\n  ")));
-        }
-        
-        Ok(workflow)
+
+        workflow.entrypoint = self.entrypoint.unwrap_or_default();
+
+        workflow
     }
 }
 
 /// Convenience methods for common workflow patterns
 impl WorkflowBuilder {
+    /// Convert validation errors into a single anyhow error
+    fn validation_failure(errors: Vec<ValidationError>) -> anyhow::Error {
+        let error_messages: Vec<_> = errors.iter().map(|e| e.to_string()).collect();
+        anyhow!("Workflow validation failed:\n  {}", error_messages.join("\n  "))
+    }
+
     /// Create a simple linear workflow (analyze -> implement -> test)
     pub fn linear_linear(name: impl Into<String>, _task: impl Into<String>) -> Result<Workflow> {
         Self::new(name)
@@ -148,8 +165,9 @@ impl WorkflowBuilder {
             .edge("test", "DONE")
             .entrypoint("analyze")
             .build()
+            .map_err(Self::validation_failure)
     }
-    
+
     /// Create a workflow with review loop
     pub fn with_review(name: impl Into<String>) -> Result<Workflow> {
         Self::new(name)
@@ -165,6 +183,7 @@ impl WorkflowBuilder {
             .entrypoint("analyze")
             .checkpoint("review")
             .build()
+            .map_err(Self::validation_failure)
     }
 }
 
@@ -210,21 +229,39 @@ mod tests {
     
     #[test]
     fn test_missing_entrypoint() {
-        let result = WorkflowBuilder::new("test")
+        let errors = WorkflowBuilder::new("test")
             .node("a", AgentRole::Coder)
-            .build();
-        
-        assert!(result.is_err());
+            .build()
+            .unwrap_err();
+
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::MissingEntrypoint { .. })));
     }
-    
+
     #[test]
-    fn test_invalid_edge() {
-        let result = WorkflowBuilder::new("test")
+    fn test_dangling_edge_returns_errors() {
+        let errors = WorkflowBuilder::new("test")
             .node("a", AgentRole::Coder)
             .edge("a", "nonexistent")
             .entrypoint("a")
-            .build();
-        
-        assert!(result.is_err());
+            .build()
+            .unwrap_err();
+
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::InvalidEdgeTarget { node, .. } if node == "nonexistent")));
+    }
+
+    #[test]
+    fn test_build_unchecked_skips_validation() {
+        let workflow = WorkflowBuilder::new("test")
+            .node("a", AgentRole::Coder)
+            .edge("a", "nonexistent")
+            .entrypoint("a")
+            .build_unchecked();
+
+        assert_eq!(workflow.nodes.len(), 1);
+        assert!(!workflow.validate().unwrap().is_empty());
     }
 }
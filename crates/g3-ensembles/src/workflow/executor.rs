@@ -116,32 +116,57 @@ impl<E: NodeExecutor + std::marker::Sync> WorkflowExecutor<E> {
                 }
             }
             
-            // Execute the node
+            // Execute the node (retrying on failure up to retry_count)
             self.state.start_node(&current_node);
             let start = Instant::now();
-            
-            let input = self.map_inputs(&node)?;
-            let result = self.node_executor.execute(&node, input).await;
-            
+
+            let result = self.execute_with_retries(&node).await;
+
             let duration = start.elapsed();
-            
+
             match result {
                 Ok(node_result) if node_result.success => {
                     self.state.complete_node(true, &node_result.output);
                     self.map_outputs(&node, &node_result)?;
-                    
+
+                    // Enforce the declared state schema so a node writing
+                    // the wrong type fails here, not at a downstream reader
+                    let violations = self.state.validate_schema(&self.workflow.state_schema);
+                    if !violations.is_empty() {
+                        let messages: Vec<String> =
+                            violations.iter().map(|v| v.to_string()).collect();
+                        return Ok(WorkflowOutcome::Failed {
+                            error: format!(
+                                "Node '{}' violated the state schema: {}",
+                                current_node,
+                                messages.join("; ")
+                            ),
+                            state: self.state,
+                        });
+                    }
+
                     // Find next node
                     current_node = self.find_next_node(&current_node)?;
                     self.state.increment_iteration();
-                    
+
                     info!("Node '{}' completed in {:?}", current_node, duration);
                 }
-                Ok(node_result) => {
-                    self.state.complete_node(false, node_result.error.as_deref().unwrap_or("Unknown error"));
-                    
-                    if node.config.critical {
+                failed => {
+                    let error = match failed {
+                        Ok(node_result) => node_result.error.unwrap_or_else(|| "Node failed".to_string()),
+                        Err(e) => e.to_string(),
+                    };
+                    self.state.complete_node(false, &error);
+
+                    if let Some(target) = node.config.on_error.clone() {
+                        // Route to the recovery node instead of terminating
+                        warn!("Node '{}' failed, routing to recovery node '{}'", current_node, target);
+                        self.state.set("last_error", serde_json::json!(&error));
+                        current_node = target;
+                        self.state.increment_iteration();
+                    } else if node.config.critical {
                         return Ok(WorkflowOutcome::Failed {
-                            error: node_result.error.unwrap_or_else(|| "Node failed".to_string()),
+                            error,
                             state: self.state,
                         });
                     } else {
@@ -151,20 +176,6 @@ impl<E: NodeExecutor + std::marker::Sync> WorkflowExecutor<E> {
                         self.state.increment_iteration();
                     }
                 }
-                Err(e) => {
-                    self.state.complete_node(false, &e.to_string());
-                    
-                    if node.config.critical {
-                        return Ok(WorkflowOutcome::Failed {
-                            error: e.to_string(),
-                            state: self.state,
-                        });
-                    } else {
-                        warn!("Non-critical node '{}' errored: {}, continuing", current_node, e);
-                        current_node = self.find_next_node(&current_node)?;
-                        self.state.increment_iteration();
-                    }
-                }
             }
         }
         
@@ -173,6 +184,21 @@ impl<E: NodeExecutor + std::marker::Sync> WorkflowExecutor<E> {
         Ok(WorkflowOutcome::Completed(self.state))
     }
     
+    /// Execute a node, retrying on failure up to its configured retry count
+    async fn execute_with_retries(&self, node: &Node) -> Result<NodeResult> {
+        let mut last = self.node_executor.execute(node, self.map_inputs(node)?).await;
+
+        for attempt in 1..=node.config.retry_count {
+            if matches!(&last, Ok(result) if result.success) {
+                break;
+            }
+            warn!("Node '{}' failed (attempt {}/{}), retrying", node.id, attempt, node.config.retry_count + 1);
+            last = self.node_executor.execute(node, self.map_inputs(node)?).await;
+        }
+
+        last
+    }
+
     /// Map workflow state to node input
     fn map_inputs(&self, node: &Node) -> Result<HashMap<String, serde_json::Value>> {
         let mut input = HashMap::new();
@@ -297,7 +323,96 @@ mod tests {
         
         let executor = WorkflowExecutor::new(workflow, MockExecutor);
         let result = executor.run("Test".to_string()).await.unwrap();
-        
+
+        assert!(result.is_success());
+    }
+
+    struct FailingNodeExecutor {
+        failing_node: String,
+    }
+
+    #[async_trait::async_trait]
+    impl NodeExecutor for FailingNodeExecutor {
+        async fn execute(
+            &self,
+            node: &Node,
+            _input: HashMap<String, serde_json::Value>,
+        ) -> Result<NodeResult> {
+            if node.id == self.failing_node {
+                Ok(NodeResult::failure(&node.id, "boom"))
+            } else {
+                Ok(NodeResult::success(&node.id, format!("Mock output for {}", node.id)))
+            }
+        }
+    }
+
+    struct WrongTypeExecutor;
+
+    #[async_trait::async_trait]
+    impl NodeExecutor for WrongTypeExecutor {
+        async fn execute(
+            &self,
+            node: &Node,
+            _input: HashMap<String, serde_json::Value>,
+        ) -> Result<NodeResult> {
+            // The schema declares this key as an integer
+            Ok(NodeResult::success(&node.id, "done")
+                .with_metadata("test_failures", json!("three")))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schema_violation_fails_at_offending_node() {
+        let mut workflow = WorkflowBuilder::new("test")
+            .node("count", AgentRole::Tester)
+            .node("report", AgentRole::Coder)
+            .edge("count", "report")
+            .edge("report", "DONE")
+            .entrypoint("count")
+            .build()
+            .unwrap();
+        workflow.state_schema.insert(
+            "count.test_failures".to_string(),
+            crate::workflow::StateValueType::Integer,
+        );
+
+        let executor = WorkflowExecutor::new(workflow, WrongTypeExecutor);
+        let result = executor.run("Test".to_string()).await.unwrap();
+
+        match result {
+            WorkflowOutcome::Failed { error, state } => {
+                assert!(error.contains("count"), "error names the node: {}", error);
+                assert!(error.contains("test_failures"), "error names the key: {}", error);
+                assert!(error.contains("integer"), "error names the expected type: {}", error);
+                // The workflow stopped at the offending node
+                assert_eq!(state.history.len(), 1);
+            }
+            other => panic!("expected Failed, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_on_error_routes_to_recovery_node() {
+        let workflow = WorkflowBuilder::new("test")
+            .node_with_config(
+                Node::new("work", AgentRole::Coder)
+                    .with_retry(1)
+                    .with_on_error("recover"),
+            )
+            .node("recover", AgentRole::Researcher)
+            .edge("work", "DONE")
+            .edge("recover", "DONE")
+            .entrypoint("work")
+            .build()
+            .unwrap();
+
+        let executor = WorkflowExecutor::new(workflow, FailingNodeExecutor {
+            failing_node: "work".to_string(),
+        });
+        let result = executor.run("Test".to_string()).await.unwrap();
+
         assert!(result.is_success());
+        assert_eq!(result.state().get("last_error"), Some(&json!("boom")));
+        assert_eq!(result.state().history.last().unwrap().node_id, "recover");
     }
 }
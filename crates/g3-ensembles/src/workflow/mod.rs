@@ -14,6 +14,7 @@ pub mod checkpoint;
 pub mod condition;
 pub mod executor;
 pub mod visualize;
+pub mod molecule;
 pub mod node;
 pub mod state;
 
@@ -27,8 +28,9 @@ pub use approval::{
 pub use executor::{WorkflowExecutor, WorkflowOutcome};
 pub use dag::{Dag, DagExecutor, DagNode, ParallelConfig};
 pub use visualize::{to_mermaid, to_mermaid_with_state, to_ascii, state_summary};
+pub use molecule::{workflow_from_molecule, Molecule, MoleculeStep};
 pub use node::{Node, NodeConfig};
-pub use state::WorkflowState;
+pub use state::{StateTypeError, StateValueType, WorkflowState};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -133,6 +135,12 @@ pub struct Workflow {
     /// Maximum iterations to prevent infinite loops
     #[serde(default = "default_max_iterations")]
     pub max_iterations: usize,
+    /// Optional declared types for state keys. After every node, the
+    /// executor checks written values against this schema so an
+    /// inter-node contract violation fails at the offending node rather
+    /// than surprising a downstream reader.
+    #[serde(default)]
+    pub state_schema: HashMap<String, state::StateValueType>,
 }
 
 fn default_max_iterations() -> usize {
@@ -151,6 +159,7 @@ impl Workflow {
             entrypoint: String::new(),
             checkpoints: HashSet::new(),
             max_iterations: 100,
+            state_schema: HashMap::new(),
         }
     }
     
@@ -181,6 +190,18 @@ impl Workflow {
             }
         }
         
+        // Check on_error targets exist
+        for (node_id, node) in &self.nodes {
+            if let Some(target) = &node.config.on_error {
+                if !self.nodes.contains_key(target) && target != "DONE" {
+                    errors.push(ValidationError::InvalidOnErrorTarget {
+                        node: node_id.clone(),
+                        target: target.clone(),
+                    });
+                }
+            }
+        }
+
         // Check for cycles (simple DFS)
         if let Some(cycle) = self.detect_cycle() {
             errors.push(ValidationError::CycleDetected { cycle });
@@ -262,6 +283,12 @@ impl Workflow {
                         queue.push(edge.to.clone());
                     }
                 }
+                // on_error targets are reachable via error routing
+                if let Some(target) = self.nodes.get(&node_id).and_then(|n| n.config.on_error.clone()) {
+                    if target != "DONE" && !reachable.contains(&target) {
+                        queue.push(target);
+                    }
+                }
             }
         }
         
@@ -277,6 +304,30 @@ impl Workflow {
     pub fn is_checkpoint(&self, node_id: &str) -> bool {
         self.checkpoints.contains(node_id)
     }
+
+    /// Parse a workflow from hand-authored YAML, validating it on load.
+    ///
+    /// Fails on malformed YAML and on structural problems (missing
+    /// entrypoint, dangling edges, cycles, unreachable nodes), so a broken
+    /// workflow checked into a repo is caught at load time rather than
+    /// mid-execution.
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        let workflow: Workflow =
+            serde_yaml::from_str(yaml).map_err(|e| anyhow::anyhow!("Invalid workflow YAML: {}", e))?;
+
+        let errors = workflow.validate()?;
+        if !errors.is_empty() {
+            let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+            anyhow::bail!("Workflow failed validation:\n  {}", messages.join("\n  "));
+        }
+
+        Ok(workflow)
+    }
+
+    /// Serialize the workflow to YAML for human-readable storage.
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(self).map_err(|e| anyhow::anyhow!("Failed to serialize workflow: {}", e))
+    }
 }
 
 /// Validation errors for workflows
@@ -296,6 +347,11 @@ pub enum ValidationError {
         edge: String,
         node: String,
     },
+    /// Node's on_error references a non-existent recovery node
+    InvalidOnErrorTarget {
+        node: String,
+        target: String,
+    },
     /// Cycle detected in workflow
     CycleDetected {
         cycle: Vec<String>,
@@ -318,6 +374,9 @@ impl std::fmt::Display for ValidationError {
             ValidationError::InvalidEdgeTarget { edge, node } => {
                 write!(f, "Edge '{}' references non-existent target node '{}'", edge, node)
             }
+            ValidationError::InvalidOnErrorTarget { node, target } => {
+                write!(f, "Node '{}' routes errors to non-existent node '{}'", node, target)
+            }
             ValidationError::CycleDetected { cycle } => {
                 write!(f, "Cycle detected: {}", cycle.join(" -> "))
             }
@@ -361,6 +420,102 @@ mod tests {
         assert!(has_cycle);
     }
     
+    #[test]
+    fn test_workflow_invalid_on_error_target() {
+        let mut workflow = Workflow::new("on_error_test");
+        workflow.entrypoint = "a".to_string();
+
+        workflow.nodes.insert(
+            "a".to_string(),
+            Node::new("a", AgentRole::Coder).with_on_error("missing"),
+        );
+        workflow.edges.push(Edge::new("a", "DONE"));
+
+        let errors = workflow.validate().unwrap();
+        let has_invalid = errors.iter().any(|e| matches!(e, ValidationError::InvalidOnErrorTarget { .. }));
+        assert!(has_invalid);
+    }
+
+    const REVIEW_WORKFLOW_YAML: &str = r#"
+id: wf-review
+name: review
+description: Research, fix, then gated review
+entrypoint: research
+max_iterations: 25
+checkpoints:
+  - review
+nodes:
+  research:
+    id: research
+    agent_role: Researcher
+  fix:
+    id: fix
+    agent_role: Coder
+  review:
+    id: review
+    agent_role:
+      Custom: security-reviewer
+edges:
+  - from: research
+    to: fix
+  - from: fix
+    to: review
+    condition:
+      IsTrue:
+        key: tests_passed
+  - from: review
+    to: DONE
+"#;
+
+    #[test]
+    fn test_workflow_yaml_round_trip() {
+        let workflow = Workflow::from_yaml(REVIEW_WORKFLOW_YAML).unwrap();
+
+        // Roles, conditions, checkpoints, and limits all survive parsing
+        assert_eq!(workflow.name, "review");
+        assert_eq!(workflow.entrypoint, "research");
+        assert_eq!(workflow.max_iterations, 25);
+        assert!(workflow.is_checkpoint("review"));
+        assert_eq!(
+            workflow.nodes["review"].agent_role,
+            AgentRole::Custom("security-reviewer".to_string())
+        );
+        assert!(matches!(
+            &workflow.edges[1].condition,
+            Some(Condition::IsTrue { key }) if key == "tests_passed"
+        ));
+        assert!(workflow.edges[0].condition.is_none());
+
+        // Re-serialized YAML parses back to an equivalent workflow (compared
+        // as YAML values, since map key order is not guaranteed)
+        let yaml = workflow.to_yaml().unwrap();
+        let reloaded = Workflow::from_yaml(&yaml).unwrap();
+        let original: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        let round_tripped: serde_yaml::Value =
+            serde_yaml::from_str(&reloaded.to_yaml().unwrap()).unwrap();
+        assert_eq!(original, round_tripped);
+    }
+
+    #[test]
+    fn test_workflow_from_yaml_rejects_invalid_structure() {
+        // Entrypoint points at a node that doesn't exist
+        let yaml = r#"
+id: wf-bad
+name: bad
+entrypoint: missing
+nodes:
+  only:
+    id: only
+    agent_role: Coder
+edges: []
+"#;
+        let err = Workflow::from_yaml(yaml).unwrap_err();
+        assert!(err.to_string().contains("does not exist"));
+
+        // Not YAML at all
+        assert!(Workflow::from_yaml(": not workflow yaml").is_err());
+    }
+
     #[test]
     fn test_workflow_unreachable_node() {
         let mut workflow = Workflow::new("unreachable_test");
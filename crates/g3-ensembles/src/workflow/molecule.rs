@@ -0,0 +1,229 @@
+//! Bridge from Beads molecules to executable workflows.
+//!
+//! Multi-step work tracked as a Beads molecule (steps plus dependencies)
+//! can be turned into a [`Workflow`] DAG: one node per step, an edge per
+//! dependency, and a human-approval checkpoint for steps flagged as
+//! needing review. The input mirrors the `bd ... --json` molecule shape,
+//! so output from the beads tools deserializes directly.
+
+use super::{AgentRole, Node, Workflow, WorkflowBuilder};
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// A Beads molecule: a tracked unit of multi-step work.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Molecule {
+    /// Molecule identifier (e.g., "mol-42")
+    pub id: String,
+    /// Human-readable title
+    #[serde(default)]
+    pub title: String,
+    /// The molecule's steps, in declaration order
+    #[serde(default)]
+    pub steps: Vec<MoleculeStep>,
+}
+
+/// One step of a molecule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoleculeStep {
+    /// Step identifier, unique within the molecule
+    pub id: String,
+    /// Human-readable title
+    #[serde(default)]
+    pub title: String,
+    /// Ids of steps that must complete before this one
+    #[serde(default, alias = "dependencies")]
+    pub depends_on: Vec<String>,
+    /// Whether this step requires human review before the workflow
+    /// continues past it
+    #[serde(default)]
+    pub needs_review: bool,
+    /// Agent role executing the step (e.g., "coder", "tester");
+    /// unrecognized names become custom roles, absent defaults to coder
+    #[serde(default)]
+    pub role: Option<String>,
+}
+
+/// Generate an executable [`Workflow`] from a molecule.
+///
+/// Each step becomes a node, each dependency an edge, and steps marked
+/// `needs_review` become checkpoints. The entrypoint is the first step
+/// without dependencies; steps nothing depends on get an edge to `DONE`.
+/// Validation runs on the result, so a molecule with cyclic or dangling
+/// dependencies fails here rather than mid-execution.
+pub fn workflow_from_molecule(molecule: &Molecule) -> Result<Workflow> {
+    if molecule.steps.is_empty() {
+        return Err(anyhow!("Molecule '{}' has no steps", molecule.id));
+    }
+
+    let entrypoint = molecule
+        .steps
+        .iter()
+        .find(|s| s.depends_on.is_empty())
+        .map(|s| s.id.clone())
+        .ok_or_else(|| {
+            anyhow!(
+                "Molecule '{}' has no dependency-free step to start from",
+                molecule.id
+            )
+        })?;
+
+    let name = if molecule.title.is_empty() {
+        molecule.id.clone()
+    } else {
+        molecule.title.clone()
+    };
+    let mut builder = WorkflowBuilder::new(name)
+        .description(format!("Generated from molecule '{}'", molecule.id))
+        .entrypoint(entrypoint);
+
+    let depended_on: HashSet<&str> = molecule
+        .steps
+        .iter()
+        .flat_map(|s| s.depends_on.iter().map(String::as_str))
+        .collect();
+
+    for step in &molecule.steps {
+        let node = Node::new(&step.id, role_from_name(step.role.as_deref()))
+            .with_description(step.title.clone());
+        builder = builder.node_with_config(node);
+
+        for dep in &step.depends_on {
+            builder = builder.edge(dep, &step.id);
+        }
+        if !depended_on.contains(step.id.as_str()) {
+            builder = builder.edge(&step.id, "DONE");
+        }
+        if step.needs_review {
+            builder = builder.checkpoint(&step.id);
+        }
+    }
+
+    builder.build().map_err(|errors| {
+        let messages: Vec<_> = errors.iter().map(|e| e.to_string()).collect();
+        anyhow!(
+            "Molecule '{}' does not form a valid workflow:\n  {}",
+            molecule.id,
+            messages.join("\n  ")
+        )
+    })
+}
+
+/// Map a molecule step's role name onto an agent role.
+fn role_from_name(role: Option<&str>) -> AgentRole {
+    match role {
+        None => AgentRole::Coder,
+        Some("researcher") => AgentRole::Researcher,
+        Some("planner") => AgentRole::Planner,
+        Some("coder") => AgentRole::Coder,
+        Some("tester") => AgentRole::Tester,
+        Some("reviewer") => AgentRole::Reviewer,
+        Some("deployer") => AgentRole::Deployer,
+        Some(other) => AgentRole::Custom(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(id: &str, deps: &[&str]) -> MoleculeStep {
+        MoleculeStep {
+            id: id.to_string(),
+            title: format!("Step {}", id),
+            depends_on: deps.iter().map(|d| d.to_string()).collect(),
+            needs_review: false,
+            role: None,
+        }
+    }
+
+    #[test]
+    fn test_linear_molecule_converts_to_valid_workflow() {
+        let molecule = Molecule {
+            id: "mol-1".to_string(),
+            title: "Ship feature".to_string(),
+            steps: vec![
+                step("design", &[]),
+                step("implement", &["design"]),
+                {
+                    let mut s = step("review", &["implement"]);
+                    s.needs_review = true;
+                    s.role = Some("reviewer".to_string());
+                    s
+                },
+            ],
+        };
+
+        let workflow = workflow_from_molecule(&molecule).unwrap();
+
+        assert_eq!(workflow.nodes.len(), 3);
+        assert_eq!(workflow.entrypoint, "design");
+        assert_eq!(workflow.nodes["review"].agent_role, AgentRole::Reviewer);
+        assert!(workflow.checkpoints.contains("review"));
+
+        // Dependency edges plus the terminal edge to DONE
+        assert!(workflow
+            .edges
+            .iter()
+            .any(|e| e.from == "design" && e.to == "implement"));
+        assert!(workflow
+            .edges
+            .iter()
+            .any(|e| e.from == "implement" && e.to == "review"));
+        assert!(workflow
+            .edges
+            .iter()
+            .any(|e| e.from == "review" && e.to == "DONE"));
+
+        // build() already validated: acyclic and fully reachable
+        assert!(workflow.validate().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cyclic_molecule_is_rejected() {
+        let molecule = Molecule {
+            id: "mol-2".to_string(),
+            title: String::new(),
+            steps: vec![
+                step("start", &[]),
+                step("a", &["start", "b"]),
+                step("b", &["a"]),
+            ],
+        };
+
+        let err = workflow_from_molecule(&molecule).unwrap_err();
+        assert!(err.to_string().contains("does not form a valid workflow"));
+    }
+
+    #[test]
+    fn test_molecule_without_root_step_is_rejected() {
+        let molecule = Molecule {
+            id: "mol-3".to_string(),
+            title: String::new(),
+            steps: vec![step("a", &["b"]), step("b", &["a"])],
+        };
+
+        let err = workflow_from_molecule(&molecule).unwrap_err();
+        assert!(err.to_string().contains("no dependency-free step"));
+    }
+
+    #[test]
+    fn test_molecule_deserializes_from_bd_json() {
+        let molecule: Molecule = serde_json::from_str(
+            r#"{
+                "id": "mol-7",
+                "title": "Fix flaky test",
+                "steps": [
+                    {"id": "repro", "title": "Reproduce", "dependencies": []},
+                    {"id": "fix", "title": "Fix", "dependencies": ["repro"], "needs_review": true}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(molecule.steps.len(), 2);
+        assert_eq!(molecule.steps[1].depends_on, vec!["repro".to_string()]);
+        assert!(molecule.steps[1].needs_review);
+    }
+}
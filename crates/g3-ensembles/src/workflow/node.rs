@@ -30,6 +30,9 @@ pub struct NodeConfig {
     /// Whether to fail the workflow if this node fails
     #[serde(default = "default_critical")]
     pub critical: bool,
+    /// Node to route to if this node fails after exhausting retries
+    #[serde(default)]
+    pub on_error: Option<String>,
 }
 
 fn default_max_turns() -> usize { 10 }
@@ -46,6 +49,7 @@ impl Default for NodeConfig {
             output_mapping: HashMap::new(),
             retry_count: 0,
             critical: default_critical(),
+            on_error: None,
         }
     }
 }
@@ -122,6 +126,12 @@ impl Node {
         self
     }
     
+    /// Route to a recovery node if this node fails after exhausting retries
+    pub fn with_on_error(mut self, target: impl Into<String>) -> Self {
+        self.config.on_error = Some(target.into());
+        self
+    }
+
     /// Mark as non-critical (workflow continues on failure)
     pub fn non_critical(mut self) -> Self {
         self.config.critical = false;
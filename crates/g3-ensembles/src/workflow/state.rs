@@ -23,6 +23,76 @@ pub struct ExecutionStep {
     pub summary: String,
 }
 
+/// Declared type for a schema-checked state key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateValueType {
+    /// JSON string
+    String,
+    /// JSON integer (floats are rejected)
+    Integer,
+    /// Any JSON number
+    Number,
+    /// JSON boolean
+    Boolean,
+    /// JSON array
+    Array,
+    /// JSON object
+    Object,
+}
+
+impl StateValueType {
+    /// Human-readable name for error messages
+    pub fn name(&self) -> &'static str {
+        match self {
+            StateValueType::String => "string",
+            StateValueType::Integer => "integer",
+            StateValueType::Number => "number",
+            StateValueType::Boolean => "boolean",
+            StateValueType::Array => "array",
+            StateValueType::Object => "object",
+        }
+    }
+
+    /// Check whether a JSON value conforms to this type
+    pub fn matches(&self, value: &JsonValue) -> bool {
+        match self {
+            StateValueType::String => value.is_string(),
+            StateValueType::Integer => value.is_i64() || value.is_u64(),
+            StateValueType::Number => value.is_number(),
+            StateValueType::Boolean => value.is_boolean(),
+            StateValueType::Array => value.is_array(),
+            StateValueType::Object => value.is_object(),
+        }
+    }
+}
+
+/// Errors from typed state access and schema validation.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum StateTypeError {
+    #[error("State key '{key}' is not set")]
+    Missing { key: String },
+
+    #[error("State key '{key}' holds a {actual}, expected a {expected} - an upstream node likely wrote the wrong type")]
+    WrongType {
+        key: String,
+        expected: &'static str,
+        actual: &'static str,
+    },
+}
+
+/// JSON type name for error messages
+fn json_type_name(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
 /// Workflow state - passed between nodes during execution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowState {
@@ -80,6 +150,69 @@ impl WorkflowState {
         self.data.get(key).and_then(|v| T::deserialize(v.clone()).ok())
     }
     
+    /// Get an integer value, with a descriptive error when the key is
+    /// missing or an upstream node wrote a different type
+    pub fn get_i64(&self, key: &str) -> Result<i64, StateTypeError> {
+        let value = self.data.get(key).ok_or_else(|| StateTypeError::Missing {
+            key: key.to_string(),
+        })?;
+        value.as_i64().ok_or_else(|| StateTypeError::WrongType {
+            key: key.to_string(),
+            expected: "integer",
+            actual: json_type_name(value),
+        })
+    }
+    
+    /// Get a string value, with a descriptive error on missing key or
+    /// wrong type
+    pub fn get_str(&self, key: &str) -> Result<&str, StateTypeError> {
+        let value = self.data.get(key).ok_or_else(|| StateTypeError::Missing {
+            key: key.to_string(),
+        })?;
+        value.as_str().ok_or_else(|| StateTypeError::WrongType {
+            key: key.to_string(),
+            expected: "string",
+            actual: json_type_name(value),
+        })
+    }
+    
+    /// Get a boolean value, with a descriptive error on missing key or
+    /// wrong type
+    pub fn get_bool(&self, key: &str) -> Result<bool, StateTypeError> {
+        let value = self.data.get(key).ok_or_else(|| StateTypeError::Missing {
+            key: key.to_string(),
+        })?;
+        value.as_bool().ok_or_else(|| StateTypeError::WrongType {
+            key: key.to_string(),
+            expected: "boolean",
+            actual: json_type_name(value),
+        })
+    }
+    
+    /// Check every set key against a declared schema.
+    ///
+    /// Keys absent from the schema are unchecked, and declared keys that
+    /// have not been written yet are fine - only a present value of the
+    /// wrong type is a violation.
+    pub fn validate_schema(
+        &self,
+        schema: &HashMap<String, StateValueType>,
+    ) -> Vec<StateTypeError> {
+        let mut errors = Vec::new();
+        for (key, expected) in schema {
+            if let Some(value) = self.data.get(key) {
+                if !expected.matches(value) {
+                    errors.push(StateTypeError::WrongType {
+                        key: key.clone(),
+                        expected: expected.name(),
+                        actual: json_type_name(value),
+                    });
+                }
+            }
+        }
+        errors
+    }
+    
     /// Set a value in state
     pub fn set(&mut self, key: impl Into<String>, value: JsonValue) {
         self.data.insert(key.into(), value);
@@ -242,6 +375,48 @@ mod tests {
         assert_eq!(data, retrieved);
     }
     
+    #[test]
+    fn test_typed_getters_validate_types() {
+        let mut state = WorkflowState::new("test");
+        state.set("count", json!(3));
+        state.set("label", json!("ready"));
+        state.set("flag", json!(true));
+        
+        assert_eq!(state.get_i64("count").unwrap(), 3);
+        assert_eq!(state.get_str("label").unwrap(), "ready");
+        assert!(state.get_bool("flag").unwrap());
+        
+        // Wrong type names the key, the expected type, and what was found
+        let err = state.get_i64("label").unwrap_err();
+        assert!(matches!(err, StateTypeError::WrongType { .. }));
+        assert!(err.to_string().contains("label"));
+        assert!(err.to_string().contains("integer"));
+        assert!(err.to_string().contains("string"));
+        
+        // Missing key is distinguished from a type mismatch
+        assert!(matches!(
+            state.get_bool("absent").unwrap_err(),
+            StateTypeError::Missing { .. }
+        ));
+    }
+    
+    #[test]
+    fn test_schema_flags_wrong_type_but_not_unset_keys() {
+        let mut schema = HashMap::new();
+        schema.insert("test_failures".to_string(), StateValueType::Integer);
+        schema.insert("not_written_yet".to_string(), StateValueType::String);
+        
+        let mut state = WorkflowState::new("test");
+        state.set("test_failures", json!("three"));
+        
+        let errors = state.validate_schema(&schema);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("test_failures"));
+        
+        state.set("test_failures", json!(3));
+        assert!(state.validate_schema(&schema).is_empty());
+    }
+    
     #[test]
     fn test_state_node_tracking() {
         let mut state = WorkflowState::new("test");
@@ -0,0 +1,132 @@
+//! Diagnostic report over stored chunk sizes.
+//!
+//! A single oversized chunk (e.g. a huge match statement) can dominate
+//! embeddings and crowd out other results. This module ranks stored
+//! chunks by size so such pathologies are easy to spot and
+//! `max_chunk_tokens` can be tuned accordingly.
+
+use serde::{Deserialize, Serialize};
+
+use crate::qdrant::PointPayload;
+
+/// How chunks are ranked in the report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChunkSortKey {
+    /// Rank by byte size of the chunk content
+    #[default]
+    Bytes,
+    /// Rank by number of source lines spanned
+    Lines,
+}
+
+impl ChunkSortKey {
+    /// Parse a sort key from a user-facing string ("bytes" or "lines").
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "bytes" => Some(ChunkSortKey::Bytes),
+            "lines" => Some(ChunkSortKey::Lines),
+            _ => None,
+        }
+    }
+}
+
+/// A single entry in the chunk size report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkReportEntry {
+    /// File the chunk came from
+    pub file_path: String,
+    /// Symbol name (function, struct, etc.)
+    pub name: String,
+    /// Kind of chunk ("function", "struct", "docstring", ...)
+    pub chunk_type: String,
+    /// Start line in the source file
+    pub line_start: usize,
+    /// End line in the source file
+    pub line_end: usize,
+    /// Number of source lines spanned
+    pub line_count: usize,
+    /// Byte size of the chunk content
+    pub byte_size: usize,
+}
+
+impl From<&PointPayload> for ChunkReportEntry {
+    fn from(payload: &PointPayload) -> Self {
+        Self {
+            file_path: payload.file_path.clone(),
+            name: payload.name.clone(),
+            chunk_type: payload.chunk_type.clone(),
+            line_start: payload.line_start,
+            line_end: payload.line_end,
+            line_count: payload.line_end.saturating_sub(payload.line_start) + 1,
+            byte_size: payload.code.len(),
+        }
+    }
+}
+
+/// Return the `n` largest chunks, ranked by the given sort key.
+pub fn top_chunks(
+    mut entries: Vec<ChunkReportEntry>,
+    n: usize,
+    sort: ChunkSortKey,
+) -> Vec<ChunkReportEntry> {
+    match sort {
+        ChunkSortKey::Bytes => entries.sort_by(|a, b| b.byte_size.cmp(&a.byte_size)),
+        ChunkSortKey::Lines => entries.sort_by(|a, b| b.line_count.cmp(&a.line_count)),
+    }
+    entries.truncate(n);
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_entry(name: &str, line_start: usize, line_end: usize, bytes: usize) -> ChunkReportEntry {
+        ChunkReportEntry {
+            file_path: format!("src/{}.rs", name),
+            name: name.to_string(),
+            chunk_type: "function".to_string(),
+            line_start,
+            line_end,
+            line_count: line_end - line_start + 1,
+            byte_size: bytes,
+        }
+    }
+
+    #[test]
+    fn test_oversized_chunk_tops_report() {
+        let entries = vec![
+            make_entry("small", 1, 10, 200),
+            make_entry("giant_match", 1, 900, 40_000),
+            make_entry("medium", 1, 50, 1_500),
+        ];
+
+        let report = top_chunks(entries, 2, ChunkSortKey::Bytes);
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].name, "giant_match");
+        assert_eq!(report[1].name, "medium");
+    }
+
+    #[test]
+    fn test_sort_by_lines_differs_from_bytes() {
+        // Long but sparse vs short but dense
+        let entries = vec![
+            make_entry("sparse", 1, 500, 1_000),
+            make_entry("dense", 1, 20, 5_000),
+        ];
+
+        let by_lines = top_chunks(entries.clone(), 1, ChunkSortKey::Lines);
+        assert_eq!(by_lines[0].name, "sparse");
+
+        let by_bytes = top_chunks(entries, 1, ChunkSortKey::Bytes);
+        assert_eq!(by_bytes[0].name, "dense");
+    }
+
+    #[test]
+    fn test_sort_key_parse() {
+        assert_eq!(ChunkSortKey::parse("bytes"), Some(ChunkSortKey::Bytes));
+        assert_eq!(ChunkSortKey::parse("LINES"), Some(ChunkSortKey::Lines));
+        assert_eq!(ChunkSortKey::parse("tokens"), None);
+    }
+}
@@ -41,6 +41,9 @@ pub enum ChunkType {
     Module,
     Class,     // Python/JS
     Interface, // TypeScript
+    /// A symbol's doc comment extracted as its own chunk, so natural
+    /// language queries can match the prose directly
+    Docstring,
 }
 
 impl ChunkType {
@@ -56,6 +59,32 @@ impl ChunkType {
             Self::Module => "module",
             Self::Class => "class",
             Self::Interface => "interface",
+            Self::Docstring => "docstring",
+        }
+    }
+}
+
+/// Symbol visibility extracted from the AST, so searches can distinguish a
+/// codebase's public API surface from its internals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// `pub` in Rust, `export`ed in JS/TS, capitalized in Go, or no leading
+    /// underscore in Python
+    Public,
+    /// `pub(crate)` / `pub(super)` / `pub(in ...)` - visible within the crate only
+    Crate,
+    /// No visibility modifier (or a leading underscore in Python)
+    #[default]
+    Private,
+}
+
+impl Visibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Crate => "crate",
+            Self::Private => "private",
         }
     }
 }
@@ -78,12 +107,32 @@ pub struct ChunkMetadata {
     /// End line (1-indexed)
     pub line_end: usize,
 
+    /// Start column of the symbol's name identifier (1-indexed), for
+    /// column-precise navigation and LSP interop. Falls back to the
+    /// definition node's span when the identifier can't be located, and
+    /// to 1 for chunks indexed before columns were captured.
+    #[serde(default = "default_column")]
+    pub column_start: usize,
+
+    /// End column of the symbol's name identifier (1-indexed, inclusive)
+    #[serde(default = "default_column")]
+    pub column_end: usize,
+
     /// Module path (if available)
     pub module: Option<String>,
 
     /// Scope context (e.g., "impl Foo" for methods)
     pub scope: Option<String>,
 
+    /// Symbol visibility (public API vs internal). Defaults to private for
+    /// chunks indexed before visibility was captured.
+    #[serde(default)]
+    pub visibility: Visibility,
+
+    /// Fully-qualified symbol path built from the AST ancestor chain,
+    /// e.g. "auth::Foo::validate". File-local: does not include the crate root.
+    pub qualified_name: String,
+
     /// SHA256 hash of the chunk content
     pub content_hash: String,
 
@@ -91,6 +140,11 @@ pub struct ChunkMetadata {
     pub language: String,
 }
 
+/// Column fallback for data serialized before columns were captured.
+fn default_column() -> usize {
+    1
+}
+
 /// A chunk of code extracted from a source file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Chunk {
@@ -107,10 +161,56 @@ pub struct Chunk {
     pub metadata: ChunkMetadata,
 }
 
+/// Version of the chunk id derivation scheme.
+///
+/// Bump this whenever [`Chunk::stable_id`] changes how ids are derived; the
+/// manifest records the scheme it was written with, so a mismatch triggers a
+/// re-index instead of silently orphaning stored points.
+pub const CHUNK_ID_SCHEME_VERSION: u32 = 1;
+
+impl Chunk {
+    /// Deterministic chunk id, stable across runs and releases.
+    ///
+    /// Derived as SHA256 over the scheme version, the normalized file path
+    /// (forward slashes), the qualified symbol name, and the chunk's content
+    /// hash, formatted as a UUID from the first 16 digest bytes so Qdrant
+    /// accepts it as a point id. Line numbers are deliberately excluded:
+    /// moving an unchanged definition within a file keeps its id, so
+    /// incremental updates overwrite instead of duplicating.
+    pub fn stable_id(&self) -> String {
+        use sha2::{Digest, Sha256};
+
+        let normalized_path = self.file_path.replace('\\', "/");
+
+        let mut hasher = Sha256::new();
+        hasher.update(CHUNK_ID_SCHEME_VERSION.to_string().as_bytes());
+        hasher.update(b"\n");
+        hasher.update(normalized_path.as_bytes());
+        hasher.update(b"\n");
+        hasher.update(self.metadata.qualified_name.as_bytes());
+        hasher.update(b"\n");
+        hasher.update(self.metadata.content_hash.as_bytes());
+        let digest = hasher.finalize();
+
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        uuid::Uuid::from_bytes(bytes).to_string()
+    }
+}
+
+/// Maximum number of import statements prepended to embedded text.
+///
+/// Caps the context so an import-heavy file cannot drown the actual code
+/// in every one of its chunks.
+pub const MAX_IMPORT_CONTEXT_STATEMENTS: usize = 20;
+
 /// Code chunker that uses tree-sitter for AST-aware chunking.
 pub struct CodeChunker {
     parsers: HashMap<String, Parser>,
     include_context: bool,
+    include_imports: bool,
+    extract_docstrings: bool,
+    attach_leading_comments: bool,
 }
 
 impl CodeChunker {
@@ -165,6 +265,9 @@ impl CodeChunker {
         Ok(Self {
             parsers,
             include_context,
+            include_imports: false,
+            extract_docstrings: false,
+            attach_leading_comments: false,
         })
     }
 
@@ -173,6 +276,42 @@ impl CodeChunker {
         Self::new(4000, true)
     }
 
+    /// Prepend the file's `use`/`import` statements to each chunk's
+    /// embedded text (capped at [`MAX_IMPORT_CONTEXT_STATEMENTS`]).
+    ///
+    /// A function embedded in isolation loses the imports that give its
+    /// type names meaning; carrying them in `enriched_content` helps the
+    /// embedding model while `content` stays the pure definition.
+    /// Only takes effect when context enrichment is enabled.
+    pub fn with_import_context(mut self, enabled: bool) -> Self {
+        self.include_imports = enabled;
+        self
+    }
+
+    /// Also emit each symbol's doc comment as a separate `Docstring` chunk.
+    ///
+    /// The code chunk is kept as-is; the docstring chunk shares the symbol's
+    /// name and qualified name so the two stay linked. Embedding the prose
+    /// on its own lets natural-language queries match the documentation
+    /// rather than the code.
+    pub fn with_docstring_chunks(mut self, enabled: bool) -> Self {
+        self.extract_docstrings = enabled;
+        self
+    }
+
+    /// Attach leading doc comments and attributes/decorators to the
+    /// following symbol's chunk, extending `line_start` upward.
+    ///
+    /// Without this a symbol's `///` block or `#[...]` attributes sit
+    /// outside its chunk, so the documentation's intent is separated from
+    /// the implementation it describes when the chunk is embedded. Python
+    /// docstrings already live inside the body and are unaffected;
+    /// decorators above a `def` are attached.
+    pub fn with_attached_comments(mut self, enabled: bool) -> Self {
+        self.attach_leading_comments = enabled;
+        self
+    }
+
     /// Detect language from file extension.
     pub fn detect_language(path: &Path) -> Option<String> {
         match path.extension()?.to_str()? {
@@ -196,6 +335,21 @@ impl CodeChunker {
         self.chunk_source(&source, &file_path, &language)
     }
 
+    /// Chunk an in-memory buffer that has no file on disk (yet).
+    ///
+    /// `virtual_path` is recorded as the chunks' `file_path` and feeds into
+    /// their stable IDs, so chunking an unsaved buffer under the path it
+    /// will be written to yields the same chunks as chunking the saved
+    /// file. Useful for indexing pending edits before they are written.
+    pub fn chunk_str(
+        &mut self,
+        content: &str,
+        language: &str,
+        virtual_path: &str,
+    ) -> Result<Vec<Chunk>> {
+        self.chunk_source(content, virtual_path, language)
+    }
+
     /// Chunk source code string into semantic blocks.
     pub fn chunk_source(
         &mut self,
@@ -224,8 +378,13 @@ impl CodeChunker {
 
         // Enrich chunks with context
         if self.include_context {
+            let imports = if self.include_imports {
+                Self::extract_imports(tree.root_node(), source, language)
+            } else {
+                Vec::new()
+            };
             for chunk in &mut chunks {
-                chunk.enriched_content = self.enrich_chunk(chunk, file_path);
+                chunk.enriched_content = self.enrich_chunk(chunk, file_path, &imports);
             }
         }
 
@@ -272,6 +431,11 @@ impl CodeChunker {
                     );
                 }
             }
+            if self.extract_docstrings {
+                if let Some(doc_chunk) = self.docstring_chunk(&chunk, node, source) {
+                    chunks.push(doc_chunk);
+                }
+            }
             chunks.push(chunk);
         } else {
             // Recurse into children
@@ -281,6 +445,40 @@ impl CodeChunker {
         }
     }
 
+    /// A single chunk covering the entire file.
+    ///
+    /// Coarse fallback for pathological files whose fine-grained chunk
+    /// count would overwhelm the index (see
+    /// `IndexerConfig::max_chunks_per_file`): the file is indexed as one
+    /// module-level chunk named after its stem.
+    pub fn whole_file_chunk(&self, source: &str, file_path: &str, language: &str) -> Chunk {
+        let name = Path::new(file_path)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| file_path.to_string());
+
+        Chunk {
+            file_path: file_path.to_string(),
+            content: source.to_string(),
+            enriched_content: source.to_string(),
+            metadata: ChunkMetadata {
+                chunk_type: ChunkType::Module,
+                name: name.clone(),
+                signature: None,
+                line_start: 1,
+                line_end: source.lines().count().max(1),
+                column_start: 1,
+                column_end: 1,
+                module: None,
+                scope: None,
+                visibility: Visibility::Private,
+                qualified_name: name,
+                content_hash: Self::compute_hash(source),
+                language: language.to_string(),
+            },
+        }
+    }
+
     fn node_to_chunk(
         &self,
         node: Node,
@@ -353,9 +551,19 @@ impl CodeChunker {
             _ => return None,
         };
 
-        let content = source[node.byte_range()].to_string();
+        let (start_byte, line_start) = if self.attach_leading_comments {
+            self.attachment_start(node, source, language)
+        } else {
+            (node.start_byte(), node.start_position().row + 1)
+        };
+        let content = source[start_byte..node.end_byte()].to_string();
         let signature = self.extract_signature(node, source, &chunk_type);
+        let visibility = self.extract_visibility(node, source, language, &name);
+        let (column_start, column_end) = self
+            .name_identifier_span(node, source, &name)
+            .unwrap_or((node.start_position().column + 1, node.end_position().column));
         let content_hash = Self::compute_hash(&content);
+        let qualified_name = self.qualified_name(node, source, &name);
 
         Some(Chunk {
             file_path: file_path.to_string(),
@@ -365,16 +573,226 @@ impl CodeChunker {
                 chunk_type,
                 name,
                 signature,
-                line_start: node.start_position().row + 1,
+                line_start,
                 line_end: node.end_position().row + 1,
+                column_start,
+                column_end,
                 module: None,
                 scope: scope.map(String::from),
+                visibility,
+                qualified_name,
                 content_hash,
                 language: language.to_string(),
             },
         })
     }
 
+    /// Where a symbol's chunk should start once leading doc comments and
+    /// attributes/decorators are attached.
+    ///
+    /// Walks backwards over preceding siblings, absorbing attribute and
+    /// decorator nodes plus comments that qualify as documentation for the
+    /// language, and returns the byte offset and 1-indexed line of the
+    /// earliest absorbed node (or of the symbol itself when there is
+    /// nothing to attach).
+    fn attachment_start(&self, node: Node, source: &str, language: &str) -> (usize, usize) {
+        let mut start_byte = node.start_byte();
+        let mut line_start = node.start_position().row + 1;
+
+        let mut current = node.prev_sibling();
+        while let Some(sib) = current {
+            let attachable = match sib.kind() {
+                "attribute_item" | "decorator" => true,
+                "line_comment" | "block_comment" | "comment" => {
+                    Self::is_doc_comment(language, &source[sib.byte_range()])
+                }
+                _ => false,
+            };
+            if !attachable {
+                break;
+            }
+            start_byte = sib.start_byte();
+            line_start = sib.start_position().row + 1;
+            current = sib.prev_sibling();
+        }
+
+        (start_byte, line_start)
+    }
+
+    /// Extract a symbol's doc comment into its own `Docstring` chunk.
+    ///
+    /// Python docstrings live inside the body; everything else uses
+    /// doc-style comments immediately preceding the symbol.
+    fn docstring_chunk(&self, code_chunk: &Chunk, node: Node, source: &str) -> Option<Chunk> {
+        let language = code_chunk.metadata.language.as_str();
+        let (text, line_start, line_end) = if language == "python" {
+            self.python_docstring(node, source)?
+        } else {
+            self.leading_doc_comment(node, source, language)?
+        };
+
+        let content_hash = Self::compute_hash(&text);
+        Some(Chunk {
+            file_path: code_chunk.file_path.clone(),
+            content: text.clone(),
+            enriched_content: text,
+            metadata: ChunkMetadata {
+                chunk_type: ChunkType::Docstring,
+                name: code_chunk.metadata.name.clone(),
+                signature: code_chunk.metadata.signature.clone(),
+                line_start,
+                line_end,
+                column_start: 1,
+                column_end: 1,
+                module: None,
+                scope: code_chunk.metadata.scope.clone(),
+                visibility: code_chunk.metadata.visibility,
+                qualified_name: code_chunk.metadata.qualified_name.clone(),
+                content_hash,
+                language: code_chunk.metadata.language.clone(),
+            },
+        })
+    }
+
+    /// Python docstring: a string expression as the first statement of the body.
+    fn python_docstring(&self, node: Node, source: &str) -> Option<(String, usize, usize)> {
+        let body = node.child_by_field_name("body")?;
+        let first = body.named_child(0)?;
+        if first.kind() != "expression_statement" {
+            return None;
+        }
+        let string = first.named_child(0)?;
+        if string.kind() != "string" {
+            return None;
+        }
+        let raw = &source[string.byte_range()];
+        // Strip string prefixes (r"", f"") before the quotes, then the quotes
+        let text = raw
+            .trim_start_matches(['r', 'f', 'b', 'u'])
+            .trim_matches(|c| c == '"' || c == '\'')
+            .trim()
+            .to_string();
+        if text.is_empty() {
+            return None;
+        }
+        Some((
+            text,
+            string.start_position().row + 1,
+            string.end_position().row + 1,
+        ))
+    }
+
+    /// Doc comment lines immediately preceding a symbol, cleaned of markers.
+    fn leading_doc_comment(
+        &self,
+        node: Node,
+        source: &str,
+        language: &str,
+    ) -> Option<(String, usize, usize)> {
+        let mut lines_rev: Vec<String> = Vec::new();
+        let mut line_start = 0;
+        let mut line_end = 0;
+
+        let mut current = node.prev_sibling();
+        while let Some(sib) = current {
+            if !matches!(sib.kind(), "line_comment" | "block_comment" | "comment") {
+                break;
+            }
+            let raw = &source[sib.byte_range()];
+            if !Self::is_doc_comment(language, raw) {
+                break;
+            }
+            if line_end == 0 {
+                line_end = sib.end_position().row + 1;
+            }
+            line_start = sib.start_position().row + 1;
+            lines_rev.push(Self::clean_doc_text(raw));
+            current = sib.prev_sibling();
+        }
+
+        if lines_rev.is_empty() {
+            return None;
+        }
+        lines_rev.reverse();
+        let text = lines_rev.join("\n").trim().to_string();
+        if text.is_empty() {
+            return None;
+        }
+        Some((text, line_start, line_end))
+    }
+
+    /// Whether a comment counts as documentation in the given language.
+    fn is_doc_comment(language: &str, text: &str) -> bool {
+        match language {
+            // Plain `//` comments are not doc comments in Rust
+            "rust" => text.starts_with("///") || text.starts_with("/**"),
+            // JSDoc blocks only
+            "javascript" | "typescript" => text.starts_with("/**"),
+            // godoc convention: any comment block directly above the symbol
+            "go" => text.starts_with("//") || text.starts_with("/*"),
+            _ => false,
+        }
+    }
+
+    /// Strip comment markers so only the prose is embedded.
+    fn clean_doc_text(raw: &str) -> String {
+        raw.lines()
+            .map(|line| {
+                line.trim()
+                    .trim_start_matches("///")
+                    .trim_start_matches("//")
+                    .trim_start_matches("/**")
+                    .trim_start_matches("/*")
+                    .trim_end_matches("*/")
+                    .trim_start_matches('*')
+                    .trim()
+            })
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Build the fully-qualified path for a symbol by walking its AST
+    /// ancestor chain (module -> impl/class -> method).
+    fn qualified_name(&self, node: Node, source: &str, name: &str) -> String {
+        let mut segments = vec![name.to_string()];
+
+        // Go methods hang off a receiver type rather than an enclosing node
+        if node.kind() == "method_declaration" {
+            if let Some(receiver) = self.get_go_receiver_type(node, source) {
+                segments.push(receiver);
+            }
+        }
+
+        let mut current = node.parent();
+        while let Some(ancestor) = current {
+            if let Some(segment) = self.ancestor_name(ancestor, source) {
+                segments.push(segment);
+            }
+            current = ancestor.parent();
+        }
+
+        segments.reverse();
+        segments.join("::")
+    }
+
+    /// Name contributed by an enclosing AST node, if it names a scope.
+    fn ancestor_name(&self, node: Node, source: &str) -> Option<String> {
+        match node.kind() {
+            // Rust
+            "mod_item" => self.get_child_text(node, "identifier", source),
+            "impl_item" => self.get_impl_name(node, source),
+            "trait_item" => self.get_child_text(node, "type_identifier", source),
+            // Python
+            "class_definition" => self.get_child_text(node, "identifier", source),
+            // JavaScript uses "identifier" for class names, TypeScript "type_identifier"
+            "class_declaration" => self
+                .get_child_text(node, "identifier", source)
+                .or_else(|| self.get_child_text(node, "type_identifier", source)),
+            _ => None,
+        }
+    }
+
     fn get_rust_fn_name(&self, node: Node, source: &str) -> Option<String> {
         self.get_child_text(node, "identifier", source)
     }
@@ -393,6 +811,27 @@ impl CodeChunker {
         self.get_child_text(node, "field_identifier", source)
     }
 
+    fn get_go_receiver_type(&self, node: Node, source: &str) -> Option<String> {
+        // The receiver is the first parameter_list; the type may sit under a
+        // pointer_type, so search descendants for the first type identifier
+        let receiver = node
+            .children(&mut node.walk())
+            .find(|c| c.kind() == "parameter_list")?;
+        Self::find_descendant_text(receiver, "type_identifier", source)
+    }
+
+    fn find_descendant_text(node: Node, kind: &str, source: &str) -> Option<String> {
+        if node.kind() == kind {
+            return Some(source[node.byte_range()].to_string());
+        }
+        for child in node.children(&mut node.walk()) {
+            if let Some(text) = Self::find_descendant_text(child, kind, source) {
+                return Some(text);
+            }
+        }
+        None
+    }
+
     fn get_go_type_name(&self, node: Node, source: &str) -> Option<String> {
         for child in node.children(&mut node.walk()) {
             if child.kind() == "type_spec" {
@@ -435,7 +874,87 @@ impl CodeChunker {
         }
     }
 
-    fn enrich_chunk(&self, chunk: &Chunk, file_path: &str) -> String {
+    /// Determine a symbol's visibility from the AST, per language convention.
+    ///
+    /// Rust reads the `visibility_modifier` node; JS/TS check for an enclosing
+    /// `export_statement`; Python uses the leading-underscore convention; Go
+    /// uses identifier capitalization. Methods inherit visibility rules from
+    /// their own declaration, not the enclosing type.
+    fn extract_visibility(
+        &self,
+        node: Node,
+        source: &str,
+        language: &str,
+        name: &str,
+    ) -> Visibility {
+        match language {
+            "rust" => match self.get_child_text(node, "visibility_modifier", source) {
+                Some(vis) if vis == "pub" => Visibility::Public,
+                Some(_) => Visibility::Crate, // pub(crate), pub(super), pub(in ...)
+                None => Visibility::Private,
+            },
+            "javascript" | "typescript" => {
+                // Exported symbols are wrapped in an export_statement node
+                if node
+                    .parent()
+                    .is_some_and(|p| p.kind() == "export_statement")
+                {
+                    Visibility::Public
+                } else {
+                    Visibility::Private
+                }
+            }
+            "python" => {
+                if name.starts_with('_') {
+                    Visibility::Private
+                } else {
+                    Visibility::Public
+                }
+            }
+            "go" => {
+                if name.chars().next().is_some_and(|c| c.is_uppercase()) {
+                    Visibility::Public
+                } else {
+                    Visibility::Private
+                }
+            }
+            _ => Visibility::Public,
+        }
+    }
+
+    /// Column span (1-indexed, inclusive) of the declaration's name
+    /// identifier, so results can point at the symbol itself rather than
+    /// the start of the definition.
+    ///
+    /// Searches the node's children and grandchildren for an identifier
+    /// matching the extracted name; that covers every declaration form the
+    /// chunker recognizes (Go method names and TS interface names sit one
+    /// level down).
+    fn name_identifier_span(&self, node: Node, source: &str, name: &str) -> Option<(usize, usize)> {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.kind().ends_with("identifier") && &source[child.byte_range()] == name {
+                return Some((
+                    child.start_position().column + 1,
+                    child.end_position().column,
+                ));
+            }
+            let mut inner = child.walk();
+            for grandchild in child.children(&mut inner) {
+                if grandchild.kind().ends_with("identifier")
+                    && &source[grandchild.byte_range()] == name
+                {
+                    return Some((
+                        grandchild.start_position().column + 1,
+                        grandchild.end_position().column,
+                    ));
+                }
+            }
+        }
+        None
+    }
+
+    fn enrich_chunk(&self, chunk: &Chunk, file_path: &str, imports: &[String]) -> String {
         let mut enriched = String::new();
 
         // Add file context
@@ -451,12 +970,46 @@ impl CodeChunker {
             enriched.push_str(&format!("# Module: {}\n", module));
         }
 
+        // Add the file's imports so the chunk's type names resolve for the
+        // embedding model; the displayed content stays the pure definition
+        if !imports.is_empty() {
+            enriched.push_str("# Imports:\n");
+            for import in imports {
+                enriched.push_str(import);
+                enriched.push('\n');
+            }
+        }
+
         enriched.push('\n');
         enriched.push_str(&chunk.content);
 
         enriched
     }
 
+    /// Collect the file's top-level import statements, capped at
+    /// [`MAX_IMPORT_CONTEXT_STATEMENTS`].
+    fn extract_imports(root: Node, source: &str, language: &str) -> Vec<String> {
+        let kinds: &[&str] = match language {
+            "rust" => &["use_declaration"],
+            "python" => &["import_statement", "import_from_statement"],
+            "javascript" | "typescript" => &["import_statement"],
+            "go" => &["import_declaration"],
+            _ => &[],
+        };
+
+        let mut imports = Vec::new();
+        let mut cursor = root.walk();
+        for child in root.children(&mut cursor) {
+            if kinds.contains(&child.kind()) {
+                imports.push(source[child.byte_range()].to_string());
+                if imports.len() >= MAX_IMPORT_CONTEXT_STATEMENTS {
+                    break;
+                }
+            }
+        }
+        imports
+    }
+
     /// Compute SHA256 hash of content.
     fn compute_hash(content: &str) -> String {
         use sha2::{Digest, Sha256};
@@ -862,6 +1415,33 @@ func (p *Point) Distance(other *Point) float64 {
         assert_eq!(chunks[0].metadata.chunk_type, ChunkType::Function);
     }
 
+    #[test]
+    fn test_chunk_str_matches_equivalent_file() {
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let source = "/// Adds two numbers.\npub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n\nstruct Pair {\n    left: i32,\n    right: i32,\n}\n";
+
+        let mut file = NamedTempFile::with_suffix(".rs").unwrap();
+        write!(file, "{}", source).unwrap();
+        file.flush().unwrap();
+
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let from_file = chunker.chunk_file(file.path()).unwrap();
+        let virtual_path = file.path().to_string_lossy().to_string();
+        let from_str = chunker.chunk_str(source, "rust", &virtual_path).unwrap();
+
+        assert!(!from_file.is_empty());
+        assert_eq!(from_file.len(), from_str.len());
+        for (a, b) in from_file.iter().zip(from_str.iter()) {
+            assert_eq!(a.stable_id(), b.stable_id());
+            assert_eq!(a.file_path, b.file_path);
+            assert_eq!(a.content, b.content);
+            assert_eq!(a.metadata.line_start, b.metadata.line_start);
+            assert_eq!(a.metadata.line_end, b.metadata.line_end);
+        }
+    }
+
     #[test]
     fn test_chunk_type_as_str() {
         assert_eq!(ChunkType::Function.as_str(), "function");
@@ -914,6 +1494,60 @@ pub fn calculate(x: i32, y: i32) -> i32 {
         );
     }
 
+    #[test]
+    fn test_stable_id_deterministic() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = "fn foo() { }";
+        let chunks1 = chunker.chunk_source(source, "src/lib.rs", "rust").unwrap();
+        let chunks2 = chunker.chunk_source(source, "src/lib.rs", "rust").unwrap();
+
+        // Same chunk yields the same id across runs
+        assert_eq!(chunks1[0].stable_id(), chunks2[0].stable_id());
+
+        // Ids are UUID-formatted so Qdrant accepts them as point ids
+        assert_eq!(chunks1[0].stable_id().len(), 36);
+
+        // A different file or different content yields a different id
+        let other_file = chunker.chunk_source(source, "src/other.rs", "rust").unwrap();
+        assert_ne!(chunks1[0].stable_id(), other_file[0].stable_id());
+        let other_body = chunker.chunk_source("fn foo() { 1; }", "src/lib.rs", "rust").unwrap();
+        assert_ne!(chunks1[0].stable_id(), other_body[0].stable_id());
+    }
+
+    #[test]
+    fn test_stable_id_survives_moving_function_in_file() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let before = r#"
+fn moved() {
+    do_work();
+}
+
+fn anchor() { }
+"#;
+        let after = r#"
+fn anchor() { }
+
+fn moved() {
+    do_work();
+}
+"#;
+        let chunks_before = chunker.chunk_source(before, "src/lib.rs", "rust").unwrap();
+        let chunks_after = chunker.chunk_source(after, "src/lib.rs", "rust").unwrap();
+
+        let find = |chunks: &[Chunk], name: &str| {
+            chunks
+                .iter()
+                .find(|c| c.metadata.name == name)
+                .map(|c| c.stable_id())
+                .unwrap()
+        };
+
+        // The function moved but its content did not change: id is stable,
+        // so an incremental update overwrites instead of duplicating
+        assert_eq!(find(&chunks_before, "moved"), find(&chunks_after, "moved"));
+        assert_eq!(find(&chunks_before, "anchor"), find(&chunks_after, "anchor"));
+    }
+
     #[test]
     fn test_chunk_line_numbers() {
         let mut chunker = CodeChunker::new(4000, false).unwrap();
@@ -940,6 +1574,55 @@ fn second() {
         assert!(second.metadata.line_end >= 7);
     }
 
+    #[test]
+    fn test_import_context_embedded_but_not_displayed() {
+        let source = r#"use std::collections::HashMap;
+use crate::session::Token;
+
+fn validate(tokens: &HashMap<String, Token>) -> bool {
+    !tokens.is_empty()
+}
+"#;
+
+        let mut chunker = CodeChunker::new(4000, true)
+            .unwrap()
+            .with_import_context(true);
+        let chunks = chunker.chunk_source(source, "src/auth.rs", "rust").unwrap();
+        let chunk = chunks.iter().find(|c| c.metadata.name == "validate").unwrap();
+
+        // Embedded text carries the imports that give the types meaning
+        assert!(chunk.enriched_content.contains("use std::collections::HashMap;"));
+        assert!(chunk.enriched_content.contains("use crate::session::Token;"));
+        // Displayed content stays the pure definition
+        assert!(!chunk.content.contains("use std::collections::HashMap;"));
+        assert!(chunk.content.starts_with("fn validate"));
+
+        // Off by default
+        let mut plain = CodeChunker::new(4000, true).unwrap();
+        let chunks = plain.chunk_source(source, "src/auth.rs", "rust").unwrap();
+        let chunk = chunks.iter().find(|c| c.metadata.name == "validate").unwrap();
+        assert!(!chunk.enriched_content.contains("use std::collections::HashMap;"));
+    }
+
+    #[test]
+    fn test_chunk_columns_point_at_name_identifier() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = "fn compute() -> u32 {\n    42\n}\n";
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+
+        assert_eq!(chunks.len(), 1);
+        // "compute" occupies columns 4-10 (1-indexed, inclusive)
+        assert_eq!(chunks[0].metadata.column_start, 4);
+        assert_eq!(chunks[0].metadata.column_end, 10);
+
+        // Indented methods report columns relative to the line, not the file
+        let source = "struct Foo;\n\nimpl Foo {\n    pub fn bar(&self) {}\n}\n";
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+        let method = chunks.iter().find(|c| c.metadata.name == "bar").unwrap();
+        assert_eq!(method.metadata.column_start, 12);
+        assert_eq!(method.metadata.column_end, 14);
+    }
+
     #[test]
     fn test_unsupported_language_error() {
         let mut chunker = CodeChunker::new(4000, false).unwrap();
@@ -967,9 +1650,360 @@ impl Foo {
         }
     }
 
+    #[test]
+    fn test_qualified_name_rust_nested_method() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = r#"
+mod auth {
+    pub struct Foo;
+
+    impl Foo {
+        pub fn validate(&self) -> bool {
+            true
+        }
+    }
+}
+"#;
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+
+        let method = chunks.iter().find(|c| c.metadata.name == "validate").unwrap();
+        assert_eq!(method.metadata.qualified_name, "auth::Foo::validate");
+
+        let strukt = chunks.iter().find(|c| c.metadata.name == "Foo").unwrap();
+        assert_eq!(strukt.metadata.qualified_name, "auth::Foo");
+    }
+
+    #[test]
+    fn test_qualified_name_python_method() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = r#"
+class MyClass:
+    def get_x(self):
+        return self.x
+"#;
+        let chunks = chunker.chunk_source(source, "test.py", "python").unwrap();
+
+        let method = chunks.iter().find(|c| c.metadata.name == "get_x").unwrap();
+        assert_eq!(method.metadata.qualified_name, "MyClass::get_x");
+    }
+
+    #[test]
+    fn test_qualified_name_javascript_method() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = r#"
+class Animal {
+    speak() {
+        console.log("sound");
+    }
+}
+"#;
+        let chunks = chunker.chunk_source(source, "test.js", "javascript").unwrap();
+
+        let method = chunks.iter().find(|c| c.metadata.name == "speak").unwrap();
+        assert_eq!(method.metadata.qualified_name, "Animal::speak");
+    }
+
+    #[test]
+    fn test_qualified_name_typescript_method() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = r#"
+class User {
+    validate(): boolean {
+        return true;
+    }
+}
+"#;
+        let chunks = chunker.chunk_source(source, "test.ts", "typescript").unwrap();
+
+        let method = chunks.iter().find(|c| c.metadata.name == "validate").unwrap();
+        assert_eq!(method.metadata.qualified_name, "User::validate");
+    }
+
+    #[test]
+    fn test_qualified_name_go_method_uses_receiver() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = r#"
+package main
+
+type Point struct {
+    X float64
+}
+
+func (p *Point) Distance(other *Point) float64 {
+    return 0
+}
+"#;
+        let chunks = chunker.chunk_source(source, "main.go", "go").unwrap();
+
+        let method = chunks.iter().find(|c| c.metadata.name == "Distance").unwrap();
+        assert_eq!(method.metadata.qualified_name, "Point::Distance");
+    }
+
+    #[test]
+    fn test_qualified_name_top_level_is_plain_name() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let chunks = chunker
+            .chunk_source("fn standalone() {}", "test.rs", "rust")
+            .unwrap();
+
+        assert_eq!(chunks[0].metadata.qualified_name, "standalone");
+    }
+
     #[test]
     fn test_with_defaults() {
         let chunker = CodeChunker::with_defaults();
         assert!(chunker.is_ok());
     }
+
+    #[test]
+    fn test_docstring_chunk_rust() {
+        let mut chunker = CodeChunker::new(4000, false)
+            .unwrap()
+            .with_docstring_chunks(true);
+        let source = r#"
+/// Validates the user token against the session store.
+///
+/// Expired tokens are rejected.
+pub fn validate() -> bool {
+    true
+}
+"#;
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+
+        // Both the docstring and the code chunk are emitted
+        let doc = chunks
+            .iter()
+            .find(|c| c.metadata.chunk_type == ChunkType::Docstring)
+            .unwrap();
+        assert_eq!(doc.metadata.name, "validate");
+        assert_eq!(doc.metadata.qualified_name, "validate");
+        assert!(doc.content.contains("session store"));
+        assert!(!doc.content.contains("///"));
+
+        let code = chunks
+            .iter()
+            .find(|c| c.metadata.chunk_type == ChunkType::Function)
+            .unwrap();
+        assert_eq!(code.metadata.name, "validate");
+    }
+
+    #[test]
+    fn test_docstring_chunk_python() {
+        let mut chunker = CodeChunker::new(4000, false)
+            .unwrap()
+            .with_docstring_chunks(true);
+        let source = r#"
+def fetch(url):
+    """Downloads the resource with exponential backoff."""
+    return get(url)
+"#;
+        let chunks = chunker.chunk_source(source, "test.py", "python").unwrap();
+
+        let doc = chunks
+            .iter()
+            .find(|c| c.metadata.chunk_type == ChunkType::Docstring)
+            .unwrap();
+        assert_eq!(doc.metadata.name, "fetch");
+        assert!(doc.content.contains("exponential backoff"));
+        assert!(!doc.content.contains('"'));
+    }
+
+    #[test]
+    fn test_docstrings_off_by_default() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = "/// Documented.\npub fn documented() {}\n";
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+        assert!(chunks
+            .iter()
+            .all(|c| c.metadata.chunk_type != ChunkType::Docstring));
+    }
+
+    #[test]
+    fn test_attached_comments_rust_doc_and_attributes() {
+        let mut chunker = CodeChunker::new(4000, false)
+            .unwrap()
+            .with_attached_comments(true);
+        let source = r#"
+/// Validates the user token.
+#[inline]
+pub fn validate() -> bool {
+    true
+}
+"#;
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+        let code = chunks
+            .iter()
+            .find(|c| c.metadata.chunk_type == ChunkType::Function)
+            .unwrap();
+
+        // Doc comment and attribute are part of the symbol's chunk
+        assert!(code.content.starts_with("/// Validates the user token."));
+        assert!(code.content.contains("#[inline]"));
+        assert_eq!(code.metadata.line_start, 2);
+    }
+
+    #[test]
+    fn test_attached_comments_python_decorator_and_docstring() {
+        let mut chunker = CodeChunker::new(4000, false)
+            .unwrap()
+            .with_attached_comments(true);
+        let source = "@retry(times=3)\ndef fetch():\n    \"\"\"Fetches with exponential backoff.\"\"\"\n    return None\n";
+        let chunks = chunker.chunk_source(source, "test.py", "python").unwrap();
+        let code = chunks
+            .iter()
+            .find(|c| c.metadata.chunk_type == ChunkType::Function)
+            .unwrap();
+
+        // The decorator attaches; the docstring is already inside the body
+        assert!(code.content.starts_with("@retry(times=3)"));
+        assert!(code.content.contains("exponential backoff"));
+        assert_eq!(code.metadata.line_start, 1);
+    }
+
+    #[test]
+    fn test_attached_comments_javascript_jsdoc() {
+        let mut chunker = CodeChunker::new(4000, false)
+            .unwrap()
+            .with_attached_comments(true);
+        let source = "/** Formats a price for display. */\nfunction formatPrice(cents) {\n    return cents / 100;\n}\n";
+        let chunks = chunker
+            .chunk_source(source, "test.js", "javascript")
+            .unwrap();
+        let code = chunks
+            .iter()
+            .find(|c| c.metadata.chunk_type == ChunkType::Function)
+            .unwrap();
+
+        assert!(code.content.starts_with("/** Formats a price"));
+        assert_eq!(code.metadata.line_start, 1);
+
+        // Plain `//` comments are not documentation and stay detached
+        let source = "// TODO: refactor\nfunction helper() {}\n";
+        let chunks = chunker
+            .chunk_source(source, "test.js", "javascript")
+            .unwrap();
+        assert!(chunks[0].content.starts_with("function helper"));
+    }
+
+    #[test]
+    fn test_attached_comments_off_by_default() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = "/// Documented.\npub fn documented() {}\n";
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+        assert!(chunks[0].content.starts_with("pub fn documented"));
+        assert_eq!(chunks[0].metadata.line_start, 2);
+    }
+
+    #[test]
+    fn test_docstring_matches_prose_only_query() {
+        // A query matching only the doc text surfaces the docstring chunk,
+        // not the code chunk
+        let mut chunker = CodeChunker::new(4000, false)
+            .unwrap()
+            .with_docstring_chunks(true);
+        let source = r#"
+/// Reconciles the ledger balance after a failed settlement.
+pub fn process(batch: &[u8]) -> usize {
+    batch.len()
+}
+"#;
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+
+        let mut bm25 = crate::search::BM25Index::new();
+        for (i, chunk) in chunks.iter().enumerate() {
+            bm25.add_document(format!("chunk-{}", i), chunk.content.clone());
+        }
+
+        let hits = bm25.search("ledger settlement", 10);
+        assert!(!hits.is_empty());
+        let top_idx: usize = hits[0].0.trim_start_matches("chunk-").parse().unwrap();
+        assert_eq!(chunks[top_idx].metadata.chunk_type, ChunkType::Docstring);
+    }
+
+    #[test]
+    fn test_visibility_rust() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = r#"
+pub fn exposed() {}
+
+pub(crate) fn internal() {}
+
+fn hidden() {}
+"#;
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+
+        let vis = |name: &str| {
+            chunks
+                .iter()
+                .find(|c| c.metadata.name == name)
+                .unwrap()
+                .metadata
+                .visibility
+        };
+        assert_eq!(vis("exposed"), Visibility::Public);
+        assert_eq!(vis("internal"), Visibility::Crate);
+        assert_eq!(vis("hidden"), Visibility::Private);
+    }
+
+    #[test]
+    fn test_visibility_typescript_exports() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+        let source = r#"
+export function publicApi(): void {}
+
+function helper(): void {}
+"#;
+        let chunks = chunker.chunk_source(source, "test.ts", "typescript").unwrap();
+
+        let exported = chunks.iter().find(|c| c.metadata.name == "publicApi").unwrap();
+        assert_eq!(exported.metadata.visibility, Visibility::Public);
+
+        let local = chunks.iter().find(|c| c.metadata.name == "helper").unwrap();
+        assert_eq!(local.metadata.visibility, Visibility::Private);
+    }
+
+    #[test]
+    fn test_visibility_python_and_go_conventions() {
+        let mut chunker = CodeChunker::new(4000, false).unwrap();
+
+        let py = chunker
+            .chunk_source("def fetch():\n    pass\n\ndef _helper():\n    pass\n", "test.py", "python")
+            .unwrap();
+        let vis = |name: &str| {
+            py.iter()
+                .find(|c| c.metadata.name == name)
+                .unwrap()
+                .metadata
+                .visibility
+        };
+        assert_eq!(vis("fetch"), Visibility::Public);
+        assert_eq!(vis("_helper"), Visibility::Private);
+
+        let go = chunker
+            .chunk_source(
+                "package main\n\nfunc Exported() {}\n\nfunc unexported() {}\n",
+                "main.go",
+                "go",
+            )
+            .unwrap();
+        let exported = go.iter().find(|c| c.metadata.name == "Exported").unwrap();
+        assert_eq!(exported.metadata.visibility, Visibility::Public);
+        let unexported = go.iter().find(|c| c.metadata.name == "unexported").unwrap();
+        assert_eq!(unexported.metadata.visibility, Visibility::Private);
+    }
+
+    #[test]
+    fn test_docstring_chunk_inherits_visibility() {
+        let mut chunker = CodeChunker::new(4000, false)
+            .unwrap()
+            .with_docstring_chunks(true);
+        let source = "/// Documented.\npub fn documented() {}\n";
+        let chunks = chunker.chunk_source(source, "test.rs", "rust").unwrap();
+
+        let doc = chunks
+            .iter()
+            .find(|c| c.metadata.chunk_type == ChunkType::Docstring)
+            .unwrap();
+        assert_eq!(doc.metadata.visibility, Visibility::Public);
+    }
 }
@@ -3,10 +3,11 @@
 //! This module defines the interface for generating embeddings
 //! and provides implementations for various embedding APIs.
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 use tracing::{debug, warn};
 
@@ -47,6 +48,33 @@ pub trait EmbeddingProvider: Send + Sync {
     fn max_batch_size(&self) -> usize {
         32
     }
+
+    /// Verify the provider is usable before committing to a long run.
+    ///
+    /// The default embeds a tiny probe string and checks the returned
+    /// vector against [`dimensions`](Self::dimensions), which catches bad
+    /// API keys, missing models, and misconfigured dimensions up front
+    /// instead of after thousands of files have been chunked.
+    async fn health_check(&self) -> Result<()> {
+        let probe = self
+            .embed("g3 health check")
+            .await
+            .with_context(|| {
+                format!(
+                    "Embedding provider health check failed for model '{}' - verify the API key and model name",
+                    self.model_name()
+                )
+            })?;
+        if probe.len() != self.dimensions() {
+            bail!(
+                "Embedding provider health check failed: model '{}' returned {} dimensions but {} were configured - fix the dimensions setting or re-index with the new model",
+                self.model_name(),
+                probe.len(),
+                self.dimensions()
+            );
+        }
+        Ok(())
+    }
 }
 
 /// Request body for embedding API
@@ -56,6 +84,13 @@ struct EmbeddingRequest {
     input: Vec<String>,
 }
 
+/// Request body for providers with a dedicated single-input endpoint
+#[derive(Serialize)]
+struct SingleEmbeddingRequest {
+    model: String,
+    input: String,
+}
+
 /// Response from embedding API
 #[derive(Deserialize)]
 struct EmbeddingResponse {
@@ -69,6 +104,151 @@ struct EmbeddingData {
     index: usize,
 }
 
+/// Retry and backoff configuration for embedding requests.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Initial backoff in seconds
+    pub base_backoff_secs: u64,
+    /// Upper bound for the backoff in seconds
+    pub max_backoff_secs: u64,
+    /// Maximum number of retries on rate limiting
+    pub max_retries: u32,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            base_backoff_secs: 1,
+            max_backoff_secs: 60,
+            max_retries: 3,
+        }
+    }
+}
+
+/// Tunables for the adaptive batch sizer.
+#[derive(Debug, Clone)]
+pub struct AdaptiveBatchConfig {
+    /// Lower bound the batch size never shrinks below
+    pub min_batch_size: usize,
+    /// Starting batch size
+    pub base_batch_size: usize,
+    /// Upper bound the batch size never grows above
+    pub max_batch_size: usize,
+    /// Responses at or under this latency count as "fast"
+    pub fast_threshold: Duration,
+    /// Consecutive fast responses required before the size doubles
+    pub growth_streak: u32,
+}
+
+impl Default for AdaptiveBatchConfig {
+    fn default() -> Self {
+        Self {
+            min_batch_size: 4,
+            base_batch_size: 32,
+            max_batch_size: 128,
+            fast_threshold: Duration::from_secs(2),
+            growth_streak: 3,
+        }
+    }
+}
+
+/// Adapts the embedding batch size to observed API behavior.
+///
+/// Starts at the base size, doubles after a streak of consistently fast
+/// responses, and halves on every rate limit, bounded by `[min, max]`.
+/// A slow-but-successful response just resets the streak. Signals are
+/// fed explicitly (`record_success` / `record_rate_limited`), so tests
+/// can drive the sizer deterministically without a live API.
+pub struct AdaptiveBatchSizer {
+    config: AdaptiveBatchConfig,
+    current: std::sync::atomic::AtomicUsize,
+    fast_streak: std::sync::atomic::AtomicU32,
+}
+
+impl AdaptiveBatchSizer {
+    /// Create a sizer starting at the base size, clamped to `[min, max]`.
+    pub fn new(config: AdaptiveBatchConfig) -> Self {
+        let start = config
+            .base_batch_size
+            .clamp(config.min_batch_size, config.max_batch_size);
+        Self {
+            config,
+            current: std::sync::atomic::AtomicUsize::new(start),
+            fast_streak: std::sync::atomic::AtomicU32::new(0),
+        }
+    }
+
+    /// The batch size to use for the next request.
+    pub fn current(&self) -> usize {
+        self.current.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Record a successful response and its latency.
+    ///
+    /// Fast responses extend the streak and double the size once the
+    /// streak is long enough; slow ones reset the streak without
+    /// shrinking, since slowness alone is not throttling.
+    pub fn record_success(&self, latency: Duration) {
+        use std::sync::atomic::Ordering;
+
+        if latency > self.config.fast_threshold {
+            self.fast_streak.store(0, Ordering::SeqCst);
+            return;
+        }
+
+        let streak = self.fast_streak.fetch_add(1, Ordering::SeqCst) + 1;
+        if streak >= self.config.growth_streak {
+            self.fast_streak.store(0, Ordering::SeqCst);
+            let current = self.current.load(Ordering::SeqCst);
+            let grown = (current * 2).min(self.config.max_batch_size);
+            if grown != current {
+                debug!("Adaptive batch size grown {} -> {}", current, grown);
+                self.current.store(grown, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Record a rate limit (429), halving the size down to the minimum.
+    pub fn record_rate_limited(&self) {
+        use std::sync::atomic::Ordering;
+
+        self.fast_streak.store(0, Ordering::SeqCst);
+        let current = self.current.load(Ordering::SeqCst);
+        let shrunk = (current / 2).max(self.config.min_batch_size);
+        if shrunk != current {
+            warn!("Adaptive batch size halved {} -> {}", current, shrunk);
+            self.current.store(shrunk, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Default total request timeout in seconds for embedding requests.
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 120;
+
+/// Default connect timeout in seconds. Short, so a dead host fails fast
+/// while a slow-but-alive response can still use the full request timeout.
+pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Full jitter: a pseudo-random sleep in `[0, backoff]`.
+///
+/// Seeded from the system clock so concurrent workers that hit a 429 at
+/// the same time spread out instead of retrying in lockstep.
+fn default_jitter(backoff_secs: u64) -> u64 {
+    if backoff_secs == 0 {
+        return 0;
+    }
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u64)
+        .unwrap_or(0);
+    // splitmix64-style scrambling for a uniform-ish spread
+    let mut x = nanos.wrapping_add(0x9E3779B97F4A7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+    x ^= x >> 31;
+    x % (backoff_secs + 1)
+}
+
 /// OpenRouter embedding provider using Qwen3-Embedding-8B.
 pub struct OpenRouterEmbeddings {
     api_key: String,
@@ -76,6 +256,23 @@ pub struct OpenRouterEmbeddings {
     dimensions: usize,
     client: Client,
     base_url: String,
+    /// Dedicated single-input endpoint; when set, `embed` skips the
+    /// batch round-trip and posts the text directly
+    single_input_url: Option<String>,
+    retry: RetryConfig,
+    /// Instruction prefix prepended to query inputs (`embed`)
+    query_prefix: String,
+    /// Instruction prefix prepended to document inputs (`embed_batch`)
+    document_prefix: String,
+    /// Total request timeout applied to the HTTP client
+    request_timeout: Duration,
+    /// Connect timeout applied to the HTTP client
+    connect_timeout: Duration,
+    /// Maps the current backoff to the actual sleep (injectable for tests)
+    jitter_source: Box<dyn Fn(u64) -> u64 + Send + Sync>,
+    /// When set, batch size tracks observed latency and rate limits
+    /// instead of the fixed default
+    adaptive_batch: Option<AdaptiveBatchSizer>,
 }
 
 impl OpenRouterEmbeddings {
@@ -83,45 +280,176 @@ impl OpenRouterEmbeddings {
     ///
     /// Uses Qwen3-Embedding-8B with 4096 dimensions by default.
     pub fn new(api_key: String, model: Option<String>, dimensions: Option<usize>) -> Self {
+        let request_timeout = Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS);
+        let connect_timeout = Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS);
         Self {
             api_key,
             model: model.unwrap_or_else(|| "qwen/qwen3-embedding-8b".to_string()),
             dimensions: dimensions.unwrap_or(4096),
-            client: Client::new(),
+            client: Self::build_client(request_timeout, connect_timeout),
             base_url: "https://openrouter.ai/api/v1/embeddings".to_string(),
+            single_input_url: None,
+            retry: RetryConfig::default(),
+            query_prefix: String::new(),
+            document_prefix: String::new(),
+            request_timeout,
+            connect_timeout,
+            jitter_source: Box::new(default_jitter),
+            adaptive_batch: None,
         }
     }
 
+    /// Build the HTTP client with the given timeouts.
+    ///
+    /// Falls back to a default client if the builder fails, which only
+    /// happens when the TLS backend cannot initialize.
+    fn build_client(request_timeout: Duration, connect_timeout: Duration) -> Client {
+        Client::builder()
+            .timeout(request_timeout)
+            .connect_timeout(connect_timeout)
+            .build()
+            .unwrap_or_default()
+    }
+
     /// Set a custom base URL (useful for testing or proxies).
     pub fn with_base_url(mut self, url: String) -> Self {
         self.base_url = url;
         self
     }
 
-    /// Send embedding request with retry logic for rate limits.
+    /// Set a dedicated single-input endpoint for query embeddings.
+    ///
+    /// Some providers expose a cheaper single-embed endpoint; when set,
+    /// `embed` posts the text there instead of wrapping it in a
+    /// one-element batch. `embed_batch` keeps using the base URL.
+    pub fn with_single_input_url(mut self, url: String) -> Self {
+        self.single_input_url = Some(url);
+        self
+    }
+
+    /// Override the request and connect timeouts, rebuilding the HTTP client.
+    pub fn with_timeouts(mut self, request_timeout: Duration, connect_timeout: Duration) -> Self {
+        self.client = Self::build_client(request_timeout, connect_timeout);
+        self.request_timeout = request_timeout;
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Set the retry/backoff configuration.
+    pub fn with_retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Set the instruction prefixes for instruction-tuned embedding models.
+    ///
+    /// `query_prefix` is prepended to single-query inputs (`embed`) and
+    /// `document_prefix` to batch document inputs (`embed_batch`), e.g.
+    /// "Represent this code for retrieval: ". Both default to empty,
+    /// which preserves the raw-input behavior.
+    pub fn with_prefixes(
+        mut self,
+        query_prefix: impl Into<String>,
+        document_prefix: impl Into<String>,
+    ) -> Self {
+        self.query_prefix = query_prefix.into();
+        self.document_prefix = document_prefix.into();
+        self
+    }
+
+    /// Apply the query prefix to a single query input.
+    fn query_input(&self, text: &str) -> String {
+        format!("{}{}", self.query_prefix, text)
+    }
+
+    /// Apply the document prefix to a batch of document inputs.
+    fn document_inputs(&self, texts: &[String]) -> Vec<String> {
+        if self.document_prefix.is_empty() {
+            return texts.to_vec();
+        }
+        texts
+            .iter()
+            .map(|t| format!("{}{}", self.document_prefix, t))
+            .collect()
+    }
+
+    /// Inject a custom jitter source (useful for deterministic tests).
+    ///
+    /// The function receives the current backoff in seconds and returns
+    /// the sleep duration; results are clamped to `[0, backoff]`.
+    pub fn with_jitter_source(
+        mut self,
+        source: impl Fn(u64) -> u64 + Send + Sync + 'static,
+    ) -> Self {
+        self.jitter_source = Box::new(source);
+        self
+    }
+
+    /// Enable adaptive batch sizing with the given tunables.
+    ///
+    /// `max_batch_size` then reflects the current adaptive size instead
+    /// of the fixed default, so callers that batch by it automatically
+    /// pick up adjustments between requests.
+    pub fn with_adaptive_batching(mut self, config: AdaptiveBatchConfig) -> Self {
+        self.adaptive_batch = Some(AdaptiveBatchSizer::new(config));
+        self
+    }
+
+    /// Compute the sleep before the next retry.
+    ///
+    /// A server-provided `Retry-After` value is authoritative; otherwise
+    /// full jitter is applied so retries stay within `[0, backoff]`.
+    fn backoff_sleep_secs(&self, retry_after: Option<u64>, backoff_secs: u64) -> u64 {
+        match retry_after {
+            Some(secs) => secs,
+            None => (self.jitter_source)(backoff_secs).min(backoff_secs),
+        }
+    }
+
+    /// Send a batch embedding request with retry logic for rate limits.
     async fn send_request(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        let input_count = texts.len();
         let request_body = EmbeddingRequest {
             model: self.model.clone(),
-            input: texts.clone(),
+            input: texts,
+        };
+        self.post_embedding_request(&self.base_url, &request_body, input_count)
+            .await
+    }
+
+    /// Send a single-input embedding request to the dedicated endpoint.
+    async fn send_single_request(&self, url: &str, text: String) -> Result<Vec<Vec<f32>>> {
+        let request_body = SingleEmbeddingRequest {
+            model: self.model.clone(),
+            input: text,
         };
+        self.post_embedding_request(url, &request_body, 1).await
+    }
 
+    /// POST an embedding request body with retry logic for rate limits.
+    async fn post_embedding_request<B: Serialize>(
+        &self,
+        url: &str,
+        request_body: &B,
+        input_count: usize,
+    ) -> Result<Vec<Vec<f32>>> {
         let mut retry_count = 0;
-        let max_retries = 3;
-        let mut backoff_secs = 1u64;
+        let max_retries = self.retry.max_retries;
+        let mut backoff_secs = self.retry.base_backoff_secs;
 
         loop {
             debug!(
                 "Sending embedding request for {} texts to {}",
-                texts.len(),
-                self.base_url
+                input_count, url
             );
 
+            let started = std::time::Instant::now();
             let response = self
                 .client
-                .post(&self.base_url)
+                .post(url)
                 .header("Authorization", format!("Bearer {}", self.api_key))
                 .header("Content-Type", "application/json")
-                .json(&request_body)
+                .json(request_body)
                 .send()
                 .await
                 .map_err(|e| anyhow::anyhow!("Network error: {}", e))?;
@@ -129,24 +457,22 @@ impl OpenRouterEmbeddings {
             let status = response.status();
 
             if status.is_success() {
+                if let Some(sizer) = &self.adaptive_batch {
+                    sizer.record_success(started.elapsed());
+                }
                 let embedding_response: EmbeddingResponse = response
                     .json()
                     .await
                     .map_err(|e| anyhow::anyhow!("Failed to parse response: {}", e))?;
 
-                // Sort by index to ensure correct order
-                let mut embeddings: Vec<(usize, Vec<f32>)> = embedding_response
-                    .data
-                    .into_iter()
-                    .map(|d| (d.index, d.embedding))
-                    .collect();
-                embeddings.sort_by_key(|(idx, _)| *idx);
-
-                return Ok(embeddings.into_iter().map(|(_, emb)| emb).collect());
+                return Ok(sorted_embeddings(embedding_response.data));
             }
 
             if status.as_u16() == 429 {
                 // Rate limited
+                if let Some(sizer) = &self.adaptive_batch {
+                    sizer.record_rate_limited();
+                }
                 retry_count += 1;
                 if retry_count > max_retries {
                     return Err(anyhow::anyhow!(
@@ -160,16 +486,18 @@ impl OpenRouterEmbeddings {
                     .headers()
                     .get("retry-after")
                     .and_then(|v| v.to_str().ok())
-                    .and_then(|s| s.parse::<u64>().ok())
-                    .unwrap_or(backoff_secs);
+                    .and_then(|s| s.parse::<u64>().ok());
+
+                let sleep_secs = self.backoff_sleep_secs(retry_after, backoff_secs);
 
                 warn!(
                     "Rate limited, retrying after {} seconds (attempt {}/{})",
-                    retry_after, retry_count, max_retries
+                    sleep_secs, retry_count, max_retries
                 );
 
-                tokio::time::sleep(tokio::time::Duration::from_secs(retry_after)).await;
-                backoff_secs *= 2; // Exponential backoff
+                tokio::time::sleep(tokio::time::Duration::from_secs(sleep_secs)).await;
+                // Exponential backoff, capped at the configured maximum
+                backoff_secs = (backoff_secs * 2).min(self.retry.max_backoff_secs);
                 continue;
             }
 
@@ -187,14 +515,37 @@ impl OpenRouterEmbeddings {
     }
 }
 
+/// Restore request order by sorting response entries by their index.
+///
+/// APIs may return embeddings out of order; matching them back to the
+/// inputs positionally without this would silently swap vectors.
+fn sorted_embeddings(data: Vec<EmbeddingData>) -> Vec<Vec<f32>> {
+    let mut embeddings: Vec<(usize, Vec<f32>)> =
+        data.into_iter().map(|d| (d.index, d.embedding)).collect();
+    embeddings.sort_by_key(|(idx, _)| *idx);
+    embeddings.into_iter().map(|(_, emb)| emb).collect()
+}
+
+/// Unwrap a single-text response, rejecting anything but exactly one vector.
+fn expect_single(mut embeddings: Vec<Vec<f32>>) -> Result<Vec<f32>> {
+    if embeddings.len() != 1 {
+        return Err(anyhow::anyhow!(
+            "Expected exactly 1 embedding, got {}",
+            embeddings.len()
+        ));
+    }
+    Ok(embeddings.remove(0))
+}
+
 #[async_trait]
 impl EmbeddingProvider for OpenRouterEmbeddings {
     async fn embed(&self, text: &str) -> Result<Vec<f32>> {
-        let embeddings = self.embed_batch(&[text.to_string()]).await?;
-        embeddings
-            .into_iter()
-            .next()
-            .ok_or_else(|| anyhow::anyhow!("No embedding returned"))
+        let input = self.query_input(text);
+        let embeddings = match &self.single_input_url {
+            Some(url) => self.send_single_request(url, input).await?,
+            None => self.send_request(vec![input]).await?,
+        };
+        expect_single(embeddings)
     }
 
     async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
@@ -203,7 +554,7 @@ impl EmbeddingProvider for OpenRouterEmbeddings {
         }
 
         debug!("Embedding batch of {} texts", texts.len());
-        self.send_request(texts.to_vec()).await
+        self.send_request(self.document_inputs(texts)).await
     }
 
     fn dimensions(&self) -> usize {
@@ -215,7 +566,10 @@ impl EmbeddingProvider for OpenRouterEmbeddings {
     }
 
     fn max_batch_size(&self) -> usize {
-        32
+        match &self.adaptive_batch {
+            Some(sizer) => sizer.current(),
+            None => 32,
+        }
     }
 }
 
@@ -223,6 +577,63 @@ impl EmbeddingProvider for OpenRouterEmbeddings {
 mod tests {
     use super::*;
 
+    /// Mock provider whose vectors disagree with its configured dimensions
+    struct WrongDimensionProvider;
+
+    #[async_trait]
+    impl EmbeddingProvider for WrongDimensionProvider {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![0.0; 8])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| vec![0.0; 8]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            4096
+        }
+
+        fn model_name(&self) -> &str {
+            "wrong-dims-mock"
+        }
+    }
+
+    /// Mock provider that behaves consistently
+    struct HealthyProvider;
+
+    #[async_trait]
+    impl EmbeddingProvider for HealthyProvider {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![0.0; 4])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| vec![0.0; 4]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            4
+        }
+
+        fn model_name(&self) -> &str {
+            "healthy-mock"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_health_check_fails_on_dimension_mismatch() {
+        let err = WrongDimensionProvider.health_check().await.unwrap_err();
+        let message = format!("{}", err);
+        assert!(message.contains("8 dimensions"));
+        assert!(message.contains("4096"));
+    }
+
+    #[tokio::test]
+    async fn test_health_check_passes_for_consistent_provider() {
+        assert!(HealthyProvider.health_check().await.is_ok());
+    }
+
     #[test]
     fn test_provider_creation() {
         let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None);
@@ -248,4 +659,230 @@ mod tests {
             .with_base_url("http://localhost:8080/embeddings".to_string());
         assert_eq!(provider.base_url, "http://localhost:8080/embeddings");
     }
+
+    #[test]
+    fn test_prefixes_default_to_raw_inputs() {
+        let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None);
+        assert_eq!(provider.query_input("find the parser"), "find the parser");
+        assert_eq!(
+            provider.document_inputs(&["fn parse() {}".to_string()]),
+            vec!["fn parse() {}".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_prefixes_applied_to_request_payload() {
+        let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None)
+            .with_prefixes(
+                "Represent this query for retrieval: ",
+                "Represent this code for retrieval: ",
+            );
+
+        assert_eq!(
+            provider.query_input("find the parser"),
+            "Represent this query for retrieval: find the parser"
+        );
+
+        let inputs = provider.document_inputs(&["fn parse() {}".to_string()]);
+        let request = EmbeddingRequest {
+            model: provider.model_name().to_string(),
+            input: inputs,
+        };
+        let payload = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            payload["input"][0],
+            "Represent this code for retrieval: fn parse() {}"
+        );
+    }
+
+    #[test]
+    fn test_single_input_url_builder() {
+        let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None);
+        assert!(provider.single_input_url.is_none());
+
+        let provider =
+            provider.with_single_input_url("http://localhost:8080/embed".to_string());
+        assert_eq!(
+            provider.single_input_url.as_deref(),
+            Some("http://localhost:8080/embed")
+        );
+    }
+
+    #[test]
+    fn test_sorted_embeddings_restores_request_order() {
+        let data = vec![
+            EmbeddingData {
+                embedding: vec![2.0, 2.0],
+                index: 1,
+            },
+            EmbeddingData {
+                embedding: vec![1.0, 1.0],
+                index: 0,
+            },
+        ];
+
+        let embeddings = sorted_embeddings(data);
+        assert_eq!(embeddings[0], vec![1.0, 1.0]);
+        assert_eq!(embeddings[1], vec![2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_single_embed_returns_one_vector_of_right_dimension() {
+        let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None);
+
+        // Response path for a single-text embed: one entry, full dimensions
+        let data = vec![EmbeddingData {
+            embedding: vec![0.0; 4096],
+            index: 0,
+        }];
+        let embedding = expect_single(sorted_embeddings(data)).unwrap();
+        assert_eq!(embedding.len(), provider.dimensions());
+
+        // Anything but exactly one vector is an error, never a wrong pick
+        assert!(expect_single(vec![]).is_err());
+        assert!(expect_single(vec![vec![0.0; 4], vec![1.0; 4]]).is_err());
+    }
+
+    #[test]
+    fn test_provider_default_timeouts() {
+        let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None);
+        assert_eq!(
+            provider.request_timeout,
+            Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS)
+        );
+        assert_eq!(
+            provider.connect_timeout,
+            Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS)
+        );
+    }
+
+    #[test]
+    fn test_provider_custom_timeouts() {
+        let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None)
+            .with_timeouts(Duration::from_secs(30), Duration::from_secs(3));
+        assert_eq!(provider.request_timeout, Duration::from_secs(30));
+        assert_eq!(provider.connect_timeout, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_retry_config_default() {
+        let retry = RetryConfig::default();
+        assert_eq!(retry.base_backoff_secs, 1);
+        assert_eq!(retry.max_backoff_secs, 60);
+        assert_eq!(retry.max_retries, 3);
+    }
+
+    #[test]
+    fn test_default_jitter_stays_within_backoff() {
+        for backoff in [0u64, 1, 2, 8, 60] {
+            for _ in 0..100 {
+                let sleep = default_jitter(backoff);
+                assert!(sleep <= backoff, "sleep {} exceeds backoff {}", sleep, backoff);
+            }
+        }
+    }
+
+    #[test]
+    fn test_backoff_sleep_with_injected_rng() {
+        // Injected RNG cycling through values, including ones above the backoff
+        let values = [0u64, 3, 7, 1000];
+        let counter = std::sync::atomic::AtomicUsize::new(0);
+        let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None)
+            .with_jitter_source(move |_| {
+                let i = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                values[i % values.len()]
+            });
+
+        let backoff = 8;
+        for _ in 0..values.len() {
+            let sleep = provider.backoff_sleep_secs(None, backoff);
+            assert!(sleep <= backoff, "sleep {} exceeds backoff {}", sleep, backoff);
+        }
+    }
+
+    fn fast_sizer() -> AdaptiveBatchSizer {
+        AdaptiveBatchSizer::new(AdaptiveBatchConfig {
+            min_batch_size: 4,
+            base_batch_size: 32,
+            max_batch_size: 128,
+            fast_threshold: Duration::from_millis(500),
+            growth_streak: 3,
+        })
+    }
+
+    #[test]
+    fn test_adaptive_sizer_grows_on_fast_streak() {
+        let sizer = fast_sizer();
+        assert_eq!(sizer.current(), 32);
+
+        // Two fast responses are not enough to grow
+        sizer.record_success(Duration::from_millis(100));
+        sizer.record_success(Duration::from_millis(100));
+        assert_eq!(sizer.current(), 32);
+
+        // The third completes the streak
+        sizer.record_success(Duration::from_millis(100));
+        assert_eq!(sizer.current(), 64);
+
+        // Growth is capped at the maximum
+        for _ in 0..9 {
+            sizer.record_success(Duration::from_millis(100));
+        }
+        assert_eq!(sizer.current(), 128);
+    }
+
+    #[test]
+    fn test_adaptive_sizer_slow_response_resets_streak() {
+        let sizer = fast_sizer();
+        sizer.record_success(Duration::from_millis(100));
+        sizer.record_success(Duration::from_millis(100));
+
+        // Slow-but-successful: hold the size, restart the streak
+        sizer.record_success(Duration::from_secs(5));
+        assert_eq!(sizer.current(), 32);
+        sizer.record_success(Duration::from_millis(100));
+        sizer.record_success(Duration::from_millis(100));
+        assert_eq!(sizer.current(), 32);
+        sizer.record_success(Duration::from_millis(100));
+        assert_eq!(sizer.current(), 64);
+    }
+
+    #[test]
+    fn test_adaptive_sizer_halves_on_rate_limit() {
+        let sizer = fast_sizer();
+        sizer.record_rate_limited();
+        assert_eq!(sizer.current(), 16);
+
+        // Shrinking floors at the minimum
+        sizer.record_rate_limited();
+        sizer.record_rate_limited();
+        sizer.record_rate_limited();
+        assert_eq!(sizer.current(), 4);
+
+        // A 429 also discards any in-progress fast streak
+        sizer.record_success(Duration::from_millis(100));
+        sizer.record_success(Duration::from_millis(100));
+        sizer.record_rate_limited();
+        sizer.record_success(Duration::from_millis(100));
+        assert_eq!(sizer.current(), 4);
+    }
+
+    #[test]
+    fn test_provider_adaptive_batch_size_tracks_sizer() {
+        let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None)
+            .with_adaptive_batching(AdaptiveBatchConfig::default());
+        assert_eq!(provider.max_batch_size(), 32);
+
+        provider.adaptive_batch.as_ref().unwrap().record_rate_limited();
+        assert_eq!(provider.max_batch_size(), 16);
+    }
+
+    #[test]
+    fn test_retry_after_is_authoritative() {
+        let provider = OpenRouterEmbeddings::new("test-key".to_string(), None, None)
+            .with_jitter_source(|_| 0);
+
+        // Retry-After wins even when jitter would sleep less
+        assert_eq!(provider.backoff_sleep_secs(Some(42), 8), 42);
+    }
 }
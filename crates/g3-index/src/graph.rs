@@ -29,7 +29,7 @@
 //! graph.add_reference("src/main.rs", "my_function", EdgeKind::Calls, 5);
 //! ```
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
@@ -83,6 +83,31 @@ impl SymbolKind {
             SymbolKind::Other => "symbol",
         }
     }
+
+    /// Parse a symbol kind from a user-facing string.
+    ///
+    /// Accepts both the long form ("function", "struct") and the display
+    /// label ("fn", "const"), case-insensitively.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "function" | "fn" => Some(SymbolKind::Function),
+            "method" => Some(SymbolKind::Method),
+            "struct" => Some(SymbolKind::Struct),
+            "enum" => Some(SymbolKind::Enum),
+            "trait" => Some(SymbolKind::Trait),
+            "interface" => Some(SymbolKind::Interface),
+            "typealias" | "type" => Some(SymbolKind::TypeAlias),
+            "constant" | "const" => Some(SymbolKind::Constant),
+            "static" => Some(SymbolKind::Static),
+            "module" | "mod" => Some(SymbolKind::Module),
+            "variable" | "var" => Some(SymbolKind::Variable),
+            "parameter" | "param" => Some(SymbolKind::Parameter),
+            "generic" => Some(SymbolKind::Generic),
+            "macro" => Some(SymbolKind::Macro),
+            "other" | "symbol" => Some(SymbolKind::Other),
+            _ => None,
+        }
+    }
 }
 
 /// Edge type representing relationships between symbols and files.
@@ -113,6 +138,8 @@ pub enum EdgeKind {
     AliasOf,
     /// Generic type parameter (symbol -> symbol)
     TypeParam,
+    /// Covered by a test (tested symbol -> test function)
+    TestedBy,
 }
 
 /// Represents a code symbol node in graph.
@@ -259,6 +286,16 @@ impl SymbolNode {
         self.metadata = Some(metadata);
         self
     }
+
+    /// Whether this symbol was marked as a test when the graph was built
+    /// (an `is_test` flag in the metadata).
+    pub fn is_test(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.get("is_test"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
 }
 
 /// Represents a file node in graph.
@@ -340,6 +377,16 @@ pub struct Edge {
     pub location_file: Option<FileId>,
     /// Line number where edge occurs (for references)
     pub location_line: Option<usize>,
+    /// Number of occurrences this edge represents - e.g. how many times
+    /// the caller invokes the target within its body. 1 for edges
+    /// recorded before weights were captured.
+    #[serde(default = "default_edge_weight")]
+    pub weight: u32,
+}
+
+/// Weight fallback for edges serialized before weights were captured.
+fn default_edge_weight() -> u32 {
+    1
 }
 
 impl Edge {
@@ -355,6 +402,7 @@ impl Edge {
             kind,
             location_file: None,
             location_line: None,
+            weight: 1,
         }
     }
 
@@ -366,6 +414,63 @@ impl Edge {
     }
 }
 
+/// Aggregate statistics over a [`CodeGraph`].
+///
+/// The breakdown maps use `BTreeMap` so serialized output is deterministic.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphStats {
+    /// Total number of symbols
+    pub symbol_count: usize,
+    /// Total number of files
+    pub file_count: usize,
+    /// Total number of edges
+    pub edge_count: usize,
+    /// Symbol counts keyed by kind label ("fn", "struct", ...)
+    pub symbols_by_kind: BTreeMap<String, usize>,
+    /// File counts keyed by language ("rust", "python", ...)
+    pub files_by_language: BTreeMap<String, usize>,
+}
+
+/// A node in a file's symbol outline tree (see [`CodeGraph::file_outline`]).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutlineNode {
+    /// Symbol name
+    pub name: String,
+    /// Symbol kind
+    pub kind: SymbolKind,
+    /// Start line (1-indexed)
+    pub line_start: usize,
+    /// End line (1-indexed)
+    pub line_end: usize,
+    /// Symbols nested inside this one, ordered by start line
+    pub children: Vec<OutlineNode>,
+}
+
+/// Why a symbol was classified as an entrypoint (see
+/// [`CodeGraph::find_entrypoints`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntrypointKind {
+    /// A `main` function
+    Main,
+    /// A test function (`#[test]`, `#[tokio::test]`, or in a tests module)
+    Test,
+    /// A public function or method with no inbound call edges - likely an
+    /// HTTP handler, CLI subcommand, or other externally-invoked surface
+    PublicRoot,
+}
+
+impl EntrypointKind {
+    /// Display label for the entrypoint kind.
+    pub fn label(&self) -> &'static str {
+        match self {
+            EntrypointKind::Main => "main",
+            EntrypointKind::Test => "test",
+            EntrypointKind::PublicRoot => "public_root",
+        }
+    }
+}
+
 /// Directed graph representing codebase structure.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CodeGraph {
@@ -404,6 +509,29 @@ impl CodeGraph {
         self.edges.len()
     }
 
+    /// Compute aggregate statistics, including per-kind and per-language breakdowns.
+    pub fn stats(&self) -> GraphStats {
+        let mut symbols_by_kind: BTreeMap<String, usize> = BTreeMap::new();
+        for symbol in self.symbols.values() {
+            *symbols_by_kind
+                .entry(symbol.kind.label().to_string())
+                .or_default() += 1;
+        }
+
+        let mut files_by_language: BTreeMap<String, usize> = BTreeMap::new();
+        for file in self.files.values() {
+            *files_by_language.entry(file.language.clone()).or_default() += 1;
+        }
+
+        GraphStats {
+            symbol_count: self.symbols.len(),
+            file_count: self.files.len(),
+            edge_count: self.edges.len(),
+            symbols_by_kind,
+            files_by_language,
+        }
+    }
+
     /// Add a symbol to graph.
     pub fn add_symbol(&mut self, symbol: SymbolNode) {
         // Update name index
@@ -447,14 +575,42 @@ impl CodeGraph {
     /// Add an edge to graph.
     pub fn add_edge(&mut self, edge: Edge) {
         // Add to reverse index
+        let mut reverse = Edge::new(&edge.source, &edge.target, edge.kind);
+        reverse.weight = edge.weight;
         self.reverse_edges
             .entry(edge.target.clone())
             .or_default()
-            .push(Edge::new(&edge.source, &edge.target, edge.kind));
+            .push(reverse);
 
         self.edges.push(edge);
     }
 
+    /// Bump the weight of an existing edge by one occurrence.
+    ///
+    /// Returns false (and records nothing) when no matching edge exists;
+    /// callers create the edge first via [`add_edge`](Self::add_edge).
+    pub fn increment_edge_weight(&mut self, source: &str, target: &str, kind: EdgeKind) -> bool {
+        let Some(edge) = self
+            .edges
+            .iter_mut()
+            .find(|e| e.kind == kind && e.source == source && e.target == target)
+        else {
+            return false;
+        };
+        edge.weight += 1;
+
+        // Keep the reverse index copy in sync
+        if let Some(edges) = self.reverse_edges.get_mut(target) {
+            if let Some(edge) = edges
+                .iter_mut()
+                .find(|e| e.kind == kind && e.source == source)
+            {
+                edge.weight += 1;
+            }
+        }
+        true
+    }
+
     /// Add a reference from a location to a symbol.
     pub fn add_reference(
         &mut self,
@@ -568,6 +724,122 @@ impl CodeGraph {
             .unwrap_or_default()
     }
 
+    /// Find symbols by name, optionally restricted to a kind and/or a
+    /// file-path prefix. Both filters default to matching everything,
+    /// which keeps this equivalent to `find_symbols_by_name`.
+    pub fn find_symbols_filtered(
+        &self,
+        name: &str,
+        kind: Option<SymbolKind>,
+        file_prefix: Option<&str>,
+    ) -> Vec<&SymbolNode> {
+        self.find_symbols_by_name(name)
+            .into_iter()
+            .filter(|s| kind.map_or(true, |k| s.kind == k))
+            .filter(|s| file_prefix.map_or(true, |p| s.file_id.starts_with(p)))
+            .collect()
+    }
+
+    /// Find symbols matching a name (case-insensitively), ranked by how
+    /// likely each is to be the intended definition.
+    ///
+    /// Ranking, in order: exact-case name matches before case-insensitive
+    /// ones; multi-line spans (definitions with bodies) before single-line
+    /// declarations; symbols with signatures before those without; higher
+    /// centrality (fan-in, excluding `Defines` edges) first; then file path
+    /// and line for a stable order.
+    pub fn find_symbols_ranked(
+        &self,
+        name: &str,
+        kind: Option<SymbolKind>,
+        file_prefix: Option<&str>,
+    ) -> Vec<&SymbolNode> {
+        let mut candidates: Vec<&SymbolNode> = self
+            .symbol_name_index
+            .iter()
+            .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+            .flat_map(|(_, ids)| ids.iter().filter_map(|id| self.symbols.get(id)))
+            .filter(|s| kind.map_or(true, |k| s.kind == k))
+            .filter(|s| file_prefix.map_or(true, |p| s.file_id.starts_with(p)))
+            .collect();
+
+        let fan_in = |id: &str| {
+            self.reverse_edges
+                .get(id)
+                .map(|edges| edges.iter().filter(|e| e.kind != EdgeKind::Defines).count())
+                .unwrap_or(0)
+        };
+
+        candidates.sort_by(|a, b| {
+            let key = |s: &SymbolNode| {
+                (
+                    if s.name == name { 0u8 } else { 1 },
+                    if s.line_end > s.line_start { 0u8 } else { 1 },
+                    if s.signature.is_some() { 0u8 } else { 1 },
+                    std::cmp::Reverse(fan_in(&s.id)),
+                )
+            };
+            key(a)
+                .cmp(&key(b))
+                .then_with(|| a.file_id.cmp(&b.file_id))
+                .then_with(|| a.line_start.cmp(&b.line_start))
+        });
+
+        candidates
+    }
+
+    /// Find the program's entrypoints: symbols where execution starts.
+    ///
+    /// Heuristics, in classification order: functions named `main`; test
+    /// functions (flagged during graph building); public functions and
+    /// methods with no inbound `Calls` edges, which catches HTTP handlers,
+    /// CLI subcommands, and other surfaces invoked from outside the
+    /// analyzed code. Seeds reachability and dead-code analysis.
+    ///
+    /// Results are ordered main-first, then tests, then public roots,
+    /// each group sorted by file and line for a stable output.
+    pub fn find_entrypoints(&self) -> Vec<(&SymbolNode, EntrypointKind)> {
+        let inbound_calls = |id: &str| {
+            self.reverse_edges
+                .get(id)
+                .map(|edges| edges.iter().filter(|e| e.kind == EdgeKind::Calls).count())
+                .unwrap_or(0)
+        };
+
+        let mut entrypoints: Vec<(&SymbolNode, EntrypointKind)> = self
+            .symbols
+            .values()
+            .filter(|s| matches!(s.kind, SymbolKind::Function | SymbolKind::Method))
+            .filter_map(|symbol| {
+                if symbol.kind == SymbolKind::Function && symbol.name == "main" {
+                    Some((symbol, EntrypointKind::Main))
+                } else if symbol.is_test() {
+                    Some((symbol, EntrypointKind::Test))
+                } else if symbol.visibility.as_deref() == Some("public")
+                    && inbound_calls(&symbol.id) == 0
+                {
+                    Some((symbol, EntrypointKind::PublicRoot))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        entrypoints.sort_by(|a, b| {
+            let group = |kind: EntrypointKind| match kind {
+                EntrypointKind::Main => 0u8,
+                EntrypointKind::Test => 1,
+                EntrypointKind::PublicRoot => 2,
+            };
+            group(a.1)
+                .cmp(&group(b.1))
+                .then_with(|| a.0.file_id.cmp(&b.0.file_id))
+                .then_with(|| a.0.line_start.cmp(&b.0.line_start))
+        });
+
+        entrypoints
+    }
+
     /// Get all incoming edges to a node.
     pub fn incoming_edges(&self, target: &str) -> Vec<Edge> {
         self.reverse_edges.get(target).cloned().unwrap_or_default()
@@ -593,6 +865,25 @@ impl CodeGraph {
             .collect()
     }
 
+    /// Callers of a symbol with their call-frequency weights, heaviest
+    /// first.
+    ///
+    /// Same callers as [`find_callers`](Self::find_callers), but paired
+    /// with each edge's weight (how often the caller invokes the symbol)
+    /// and sorted so the most coupled caller comes first. Ties break on
+    /// symbol id for deterministic output.
+    pub fn find_callers_weighted(&self, symbol_id: &SymbolId) -> Vec<(SymbolId, u32)> {
+        let mut callers: Vec<(SymbolId, u32)> = self
+            .incoming_edges(symbol_id)
+            .into_iter()
+            .filter(|e| e.kind == EdgeKind::Calls)
+            .filter(|e| self.symbols.contains_key(&e.source))
+            .map(|e| (e.source, e.weight))
+            .collect();
+        callers.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        callers
+    }
+
     /// Find all symbols called by this symbol (outgoing "calls" edges).
     pub fn find_callees(&self, symbol_id: &SymbolId) -> Vec<SymbolId> {
         self.outgoing_edges(symbol_id)
@@ -603,6 +894,45 @@ impl CodeGraph {
             .collect()
     }
 
+    /// Outgoing `Imports` edges of a file (what the file imports).
+    ///
+    /// Targets are symbol ids when the import resolved against the symbol
+    /// table, or raw module paths (e.g. `std::collections::HashMap`) for
+    /// external dependencies with no node in the graph.
+    pub fn file_imports(&self, file_id: &str) -> Vec<Edge> {
+        self.outgoing_edges(file_id)
+            .into_iter()
+            .filter(|e| e.kind == EdgeKind::Imports)
+            .collect()
+    }
+
+    /// `Imports` edges from other files into this one (who imports it).
+    ///
+    /// Collects incoming `Imports` edges targeting the file's symbols;
+    /// unresolved raw-path imports cannot be attributed to a file and are
+    /// never returned here.
+    pub fn file_importers(&self, file_id: &str) -> Vec<Edge> {
+        self.symbols
+            .values()
+            .filter(|s| s.file_id == file_id)
+            .flat_map(|s| self.incoming_edges(&s.id))
+            .filter(|e| e.kind == EdgeKind::Imports)
+            .collect()
+    }
+
+    /// Find tests covering a symbol (outgoing "testedby" edges).
+    ///
+    /// Coverage here is approximated from static calls: a test function
+    /// that calls the symbol counts as covering it.
+    pub fn find_tests(&self, symbol_id: &SymbolId) -> Vec<SymbolId> {
+        self.outgoing_edges(symbol_id)
+            .into_iter()
+            .filter(|e| e.kind == EdgeKind::TestedBy)
+            .map(|e| e.target.clone())
+            .filter(|id| self.symbols.contains_key(id))
+            .collect()
+    }
+
     /// Find all references to a symbol (all incoming edges except Defines/BelongsTo).
     pub fn find_references(&self, symbol_id: &SymbolId) -> Vec<Edge> {
         self.incoming_edges(symbol_id)
@@ -611,6 +941,41 @@ impl CodeGraph {
             .collect()
     }
 
+    /// Get the one-hop neighborhood of a symbol, grouped by edge kind.
+    ///
+    /// Callers and callees split the `Calls` edges by direction, `implements`
+    /// collects `Implements` edges in both directions (implementors of a
+    /// trait and traits a type implements), and `references` covers the
+    /// remaining incoming non-structural edges.
+    pub fn neighbors(&self, symbol_id: &SymbolId) -> Neighborhood {
+        let mut neighborhood = Neighborhood::default();
+
+        for edge in self.incoming_edges(symbol_id) {
+            if !self.symbols.contains_key(&edge.source) {
+                continue;
+            }
+            match edge.kind {
+                EdgeKind::Calls => neighborhood.callers.push(edge.source),
+                EdgeKind::Implements => neighborhood.implements.push(edge.source),
+                EdgeKind::Defines | EdgeKind::BelongsTo => {}
+                _ => neighborhood.references.push(edge.source),
+            }
+        }
+
+        for edge in self.outgoing_edges(symbol_id) {
+            if !self.symbols.contains_key(&edge.target) {
+                continue;
+            }
+            match edge.kind {
+                EdgeKind::Calls => neighborhood.callees.push(edge.target),
+                EdgeKind::Implements => neighborhood.implements.push(edge.target),
+                _ => {}
+            }
+        }
+
+        neighborhood
+    }
+
     /// Get all symbols in a file.
     pub fn symbols_in_file(&self, file_id: &FileId) -> Vec<&SymbolNode> {
         self.symbols
@@ -619,6 +984,263 @@ impl CodeGraph {
             .collect()
     }
 
+    /// Find the symbol whose line range covers the given range in a file.
+    ///
+    /// When several symbols cover the range (e.g., a method inside an impl
+    /// block), the narrowest one wins.
+    pub fn symbol_covering(
+        &self,
+        file_id: &str,
+        line_start: usize,
+        line_end: usize,
+    ) -> Option<&SymbolNode> {
+        self.symbols
+            .values()
+            .filter(|s| {
+                s.file_id == file_id && s.line_start <= line_start && s.line_end >= line_end
+            })
+            .min_by_key(|s| s.line_end - s.line_start)
+    }
+
+    /// Resolve the definition behind a reference position, like an LSP
+    /// `goto_definition` answered from the graph alone.
+    ///
+    /// When the position sits on a definition's own name identifier, that
+    /// symbol is returned directly. Otherwise reference edges recorded at
+    /// the line are followed to their target symbols. Edges don't record
+    /// columns, so when several distinct symbols are referenced on one
+    /// line the call-like edge kinds win and remaining ties break by
+    /// symbol id for determinism.
+    pub fn resolve_definition(&self, file: &str, line: usize, col: usize) -> Option<&SymbolNode> {
+        // On the definition itself: the name identifier covers the position
+        if let Some(symbol) = self.symbols.values().find(|s| {
+            s.file_id == file
+                && s.line_start == line
+                && s.column_start <= col
+                && col <= s.column_end
+        }) {
+            return Some(symbol);
+        }
+
+        // Follow reference edges recorded at this position
+        let mut candidates: Vec<(&Edge, &SymbolNode)> = self
+            .edges
+            .iter()
+            .filter(|e| {
+                e.kind != EdgeKind::Defines
+                    && e.location_file.as_deref() == Some(file)
+                    && e.location_line == Some(line)
+            })
+            .filter_map(|e| self.symbols.get(&e.target).map(|s| (e, s)))
+            .collect();
+
+        // Call-like edges first, then by id so repeated queries agree
+        let rank = |kind: EdgeKind| match kind {
+            EdgeKind::Calls => 0,
+            EdgeKind::References => 1,
+            EdgeKind::Uses => 2,
+            _ => 3,
+        };
+        candidates.sort_by(|a, b| rank(a.0.kind).cmp(&rank(b.0.kind)).then(a.1.id.cmp(&b.1.id)));
+        candidates.first().map(|(_, symbol)| *symbol)
+    }
+
+    /// Reconstruct a file's symbol containment hierarchy (modules → impls
+    /// → methods), like an editor's outline view.
+    ///
+    /// Nesting is derived from line ranges: a symbol becomes a child of
+    /// the narrowest symbol whose range encloses it. Top-level symbols and
+    /// siblings are ordered by start line.
+    pub fn file_outline(&self, file_path: &str) -> Vec<OutlineNode> {
+        let file_id = file_path.to_string();
+        let mut symbols = self.symbols_in_file(&file_id);
+        // Start ascending, end descending, so an enclosing symbol always
+        // precedes the symbols it contains
+        symbols.sort_by(|a, b| {
+            a.line_start
+                .cmp(&b.line_start)
+                .then(b.line_end.cmp(&a.line_end))
+        });
+
+        let mut roots: Vec<OutlineNode> = Vec::new();
+        let mut stack: Vec<OutlineNode> = Vec::new();
+
+        for symbol in symbols {
+            // Close any open scopes that don't enclose this symbol
+            while stack
+                .last()
+                .is_some_and(|open| open.line_end < symbol.line_end)
+            {
+                let closed = stack.pop().unwrap();
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(closed),
+                    None => roots.push(closed),
+                }
+            }
+            stack.push(OutlineNode {
+                name: symbol.name.clone(),
+                kind: symbol.kind,
+                line_start: symbol.line_start,
+                line_end: symbol.line_end,
+                children: Vec::new(),
+            });
+        }
+
+        while let Some(closed) = stack.pop() {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(closed),
+                None => roots.push(closed),
+            }
+        }
+
+        roots
+    }
+
+    /// Render a compact, token-budgeted textual map of the repository:
+    /// directories ranked by how central their code is, each listing its
+    /// files and their most-referenced non-private symbols. Intended for
+    /// priming an LLM's context with a structural overview.
+    ///
+    /// A symbol's centrality is its fan-in (incoming edges other than
+    /// `Defines`), shown in parentheses when non-zero. The budget uses
+    /// the ~4 chars/token heuristic used elsewhere; output is truncated
+    /// at line granularity so the map never exceeds it.
+    pub fn repo_map(&self, max_tokens: usize) -> String {
+        // Fan-in per symbol, in one pass over the reverse index
+        let fan_in = |id: &str| {
+            self.reverse_edges
+                .get(id)
+                .map(|edges| edges.iter().filter(|e| e.kind != EdgeKind::Defines).count())
+                .unwrap_or(0)
+        };
+
+        // Collect each file's non-private symbols with their centrality
+        let mut by_file: HashMap<&str, Vec<(&SymbolNode, usize)>> = HashMap::new();
+        for symbol in self.symbols.values() {
+            // Private symbols are implementation detail, not map material
+            if symbol.visibility.as_deref() == Some("private") {
+                continue;
+            }
+            by_file
+                .entry(symbol.file_id.as_str())
+                .or_default()
+                .push((symbol, fan_in(&symbol.id)));
+        }
+
+        // Rank symbols within each file and score the file by the
+        // centrality of what it exposes
+        struct FileEntry<'a> {
+            file: &'a FileNode,
+            score: usize,
+            symbols: Vec<(&'a SymbolNode, usize)>,
+        }
+
+        let mut dirs: HashMap<String, Vec<FileEntry>> = HashMap::new();
+        for file in self.files.values() {
+            let mut symbols = by_file.remove(file.id.as_str()).unwrap_or_default();
+            symbols.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+            symbols.truncate(REPO_MAP_SYMBOLS_PER_FILE);
+            let score = symbols.iter().map(|(_, s)| *s).sum();
+
+            let dir = file
+                .path
+                .parent()
+                .map(|p| p.to_string_lossy().to_string())
+                .filter(|d| !d.is_empty())
+                .unwrap_or_else(|| ".".to_string());
+            dirs.entry(dir)
+                .or_default()
+                .push(FileEntry { file, score, symbols });
+        }
+
+        // Rank directories by aggregate centrality, then name for stability
+        let mut dirs: Vec<(String, usize, Vec<FileEntry>)> = dirs
+            .into_iter()
+            .map(|(dir, mut files)| {
+                files.sort_by(|a, b| {
+                    b.score.cmp(&a.score).then_with(|| a.file.path.cmp(&b.file.path))
+                });
+                let score = files.iter().map(|f| f.score).sum();
+                (dir, score, files)
+            })
+            .collect();
+        dirs.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        // Emit whole lines until the budget runs out, most central first
+        let budget = max_tokens.saturating_mul(4);
+        let mut out = String::new();
+        let header = format!(
+            "# Repo map: {} files, {} symbols\n",
+            self.files.len(),
+            self.symbols.len()
+        );
+        if header.len() > budget {
+            return out;
+        }
+        out.push_str(&header);
+
+        'emit: for (dir, _, files) in &dirs {
+            let dir_line = format!("\n## {}\n", dir);
+            if out.len() + dir_line.len() > budget {
+                break;
+            }
+            out.push_str(&dir_line);
+
+            for entry in files {
+                let names: Vec<String> = entry
+                    .symbols
+                    .iter()
+                    .map(|(symbol, score)| {
+                        if *score > 0 {
+                            format!("{} {} ({})", symbol.kind.label(), symbol.name, score)
+                        } else {
+                            format!("{} {}", symbol.kind.label(), symbol.name)
+                        }
+                    })
+                    .collect();
+                let file_name = entry
+                    .file
+                    .path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| entry.file.id.clone());
+                let line = if names.is_empty() {
+                    format!("- {}\n", file_name)
+                } else {
+                    format!("- {}: {}\n", file_name, names.join(", "))
+                };
+                if out.len() + line.len() > budget {
+                    break 'emit;
+                }
+                out.push_str(&line);
+            }
+        }
+
+        out
+    }
+
+    /// Build a symbol's qualified name by walking its parent chain
+    /// (e.g. `"Session::login"` for a method inside a struct).
+    ///
+    /// Returns `None` for unknown symbol IDs. Falls back to the bare name
+    /// when the symbol has no recorded parents.
+    pub fn qualified_symbol_name(&self, symbol_id: &SymbolId) -> Option<String> {
+        let symbol = self.symbols.get(symbol_id)?;
+        let mut parts = vec![symbol.name.clone()];
+        let mut current = symbol.parent_id.clone();
+        while let Some(parent_id) = current {
+            match self.symbols.get(&parent_id) {
+                Some(parent) => {
+                    parts.push(parent.name.clone());
+                    current = parent.parent_id.clone();
+                }
+                None => break,
+            }
+        }
+        parts.reverse();
+        Some(parts.join("::"))
+    }
+
     /// Get all files of a language.
     pub fn files_by_language(&self, language: &str) -> Vec<&FileNode> {
         self.file_language_index
@@ -627,6 +1249,236 @@ impl CodeGraph {
             .unwrap_or_default()
     }
 
+    /// Resolve the file that a node belongs to.
+    ///
+    /// File nodes resolve to themselves; symbol nodes resolve to their
+    /// defining file. Returns `None` for unknown node IDs.
+    fn file_of_node(&self, node_id: &str) -> Option<FileId> {
+        if self.files.contains_key(node_id) {
+            Some(node_id.to_string())
+        } else {
+            self.symbols.get(node_id).map(|s| s.file_id.clone())
+        }
+    }
+
+    /// Find the shortest file-level dependency path between two files.
+    ///
+    /// Derives a file-level dependency graph from cross-file edges (any edge
+    /// whose source and target resolve to different files, excluding the
+    /// structural Defines/BelongsTo edges) and runs BFS over it.
+    ///
+    /// # Returns
+    /// The chain of files from `from_file` to `to_file` (inclusive), or
+    /// `None` if the files are independent.
+    pub fn file_dependency_path(&self, from_file: &str, to_file: &str) -> Option<Vec<String>> {
+        if !self.files.contains_key(from_file) || !self.files.contains_key(to_file) {
+            return None;
+        }
+
+        if from_file == to_file {
+            return Some(vec![from_file.to_string()]);
+        }
+
+        // Build file-level adjacency from cross-file edges
+        let mut adjacency: HashMap<FileId, Vec<FileId>> = HashMap::new();
+        for edge in &self.edges {
+            if matches!(edge.kind, EdgeKind::Defines | EdgeKind::BelongsTo) {
+                continue;
+            }
+            let (Some(source_file), Some(target_file)) =
+                (self.file_of_node(&edge.source), self.file_of_node(&edge.target))
+            else {
+                continue;
+            };
+            if source_file != target_file {
+                let neighbors = adjacency.entry(source_file).or_default();
+                if !neighbors.contains(&target_file) {
+                    neighbors.push(target_file);
+                }
+            }
+        }
+
+        // BFS for the shortest path
+        let mut visited: std::collections::HashSet<FileId> = std::collections::HashSet::new();
+        let mut queue: std::collections::VecDeque<Vec<String>> = std::collections::VecDeque::new();
+        visited.insert(from_file.to_string());
+        queue.push_back(vec![from_file.to_string()]);
+
+        while let Some(path) = queue.pop_front() {
+            let current = path.last().expect("path is never empty");
+            if let Some(neighbors) = adjacency.get(current) {
+                for neighbor in neighbors {
+                    if neighbor == to_file {
+                        let mut full_path = path.clone();
+                        full_path.push(neighbor.clone());
+                        return Some(full_path);
+                    }
+                    if visited.insert(neighbor.clone()) {
+                        let mut new_path = path.clone();
+                        new_path.push(neighbor.clone());
+                        queue.push_back(new_path);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Export the graph in the stable interchange JSON schema.
+    ///
+    /// The schema is versioned and intended for external consumers (e.g.
+    /// visualization services), decoupled from the on-disk persistence
+    /// format. Top level:
+    ///
+    /// ```json
+    /// {
+    ///   "version": 1,
+    ///   "files": [{"id", "language"}],
+    ///   "nodes": [{"id", "name", "kind", "file", "line_start", "line_end", "signature"}],
+    ///   "edges": [{"from", "to", "kind"}]
+    /// }
+    /// ```
+    ///
+    /// Arrays are sorted so repeated exports of the same graph are
+    /// byte-identical. Structural Defines/BelongsTo edges are omitted;
+    /// they are implied by node `file` fields and recreated on import.
+    pub fn export_json(&self) -> String {
+        let mut files: Vec<InterchangeFile> = self
+            .files
+            .values()
+            .map(|f| InterchangeFile {
+                id: f.id.clone(),
+                language: f.language.clone(),
+            })
+            .collect();
+        files.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut nodes: Vec<InterchangeNode> = self
+            .symbols
+            .values()
+            .map(|s| InterchangeNode {
+                id: s.id.clone(),
+                name: s.name.clone(),
+                kind: s.kind,
+                file: s.file_id.clone(),
+                line_start: s.line_start,
+                line_end: s.line_end,
+                signature: s.signature.clone(),
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut edges: Vec<InterchangeEdge> = self
+            .edges
+            .iter()
+            .filter(|e| !matches!(e.kind, EdgeKind::Defines | EdgeKind::BelongsTo))
+            .map(|e| InterchangeEdge {
+                from: e.source.clone(),
+                to: e.target.clone(),
+                kind: e.kind,
+            })
+            .collect();
+        edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+
+        let interchange = InterchangeGraph {
+            version: INTERCHANGE_SCHEMA_VERSION,
+            files,
+            nodes,
+            edges,
+        };
+
+        serde_json::to_string_pretty(&interchange)
+            .expect("interchange graph serializes to JSON")
+    }
+
+    /// Import a graph from the interchange JSON schema (see [`Self::export_json`]).
+    ///
+    /// Node ids from the document are preserved verbatim, and the
+    /// structural Defines/BelongsTo edges are rebuilt from node `file`
+    /// fields, so an export/import round trip yields an equal graph.
+    pub fn import_json(json: &str) -> Result<Self, GraphError> {
+        let interchange: InterchangeGraph = serde_json::from_str(json)
+            .map_err(|e| GraphError::InvalidInterchange(e.to_string()))?;
+
+        if interchange.version != INTERCHANGE_SCHEMA_VERSION {
+            return Err(GraphError::InvalidInterchange(format!(
+                "unsupported schema version {} (expected {})",
+                interchange.version, INTERCHANGE_SCHEMA_VERSION
+            )));
+        }
+
+        let mut graph = CodeGraph::new();
+
+        for file in interchange.files {
+            graph.add_file(FileNode::new(&file.id, file.language));
+        }
+
+        for node in interchange.nodes {
+            let mut symbol = SymbolNode::new(node.name, node.kind, node.file, node.line_start)
+                .with_range(node.line_start, node.line_end);
+            if let Some(sig) = node.signature {
+                symbol = symbol.with_signature(sig);
+            }
+            // Preserve the document's id rather than regenerating it
+            symbol.id = node.id;
+            graph.add_symbol(symbol);
+        }
+
+        for edge in interchange.edges {
+            graph.add_edge(Edge::new(&edge.from, &edge.to, edge.kind));
+        }
+
+        Ok(graph)
+    }
+
+    /// Compute the difference between this graph (before) and `other` (after).
+    ///
+    /// Symbols are matched across the two graphs by (file, name, kind) rather
+    /// than by id, since ids embed the defining line and would report every
+    /// line shift as churn. A symbol present only in `other` is added, one
+    /// present only in `self` is removed, and a matched symbol whose
+    /// signature differs is recorded as a signature change keyed by its id
+    /// in `other`.
+    pub fn diff(&self, other: &CodeGraph) -> GraphDiff {
+        fn match_key(symbol: &SymbolNode) -> (&str, &str, SymbolKind) {
+            (&symbol.file_id, &symbol.name, symbol.kind)
+        }
+
+        let old_by_key: HashMap<_, &SymbolNode> =
+            self.symbols.values().map(|s| (match_key(s), s)).collect();
+        let new_by_key: HashMap<_, &SymbolNode> =
+            other.symbols.values().map(|s| (match_key(s), s)).collect();
+
+        let mut diff = GraphDiff::default();
+
+        for symbol in other.symbols.values() {
+            match old_by_key.get(&match_key(symbol)) {
+                None => {
+                    diff.added.insert(symbol.id.clone(), symbol.clone());
+                }
+                Some(old) if old.signature != symbol.signature => {
+                    diff.signature_changes.insert(
+                        symbol.id.clone(),
+                        SignatureChange {
+                            old_signature: old.signature.clone(),
+                            new_signature: symbol.signature.clone(),
+                        },
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+
+        for symbol in self.symbols.values() {
+            if !new_by_key.contains_key(&match_key(symbol)) {
+                diff.removed.insert(symbol.id.clone(), symbol.clone());
+            }
+        }
+
+        diff
+    }
+
     /// Clear all data from graph.
     pub fn clear(&mut self) {
         self.symbols.clear();
@@ -669,6 +1521,102 @@ impl CodeGraph {
     }
 }
 
+/// Version of the interchange schema produced by [`CodeGraph::export_json`].
+pub const INTERCHANGE_SCHEMA_VERSION: u32 = 1;
+
+/// Most symbols listed per file in [`CodeGraph::repo_map`].
+const REPO_MAP_SYMBOLS_PER_FILE: usize = 8;
+
+/// Top-level interchange document.
+#[derive(Serialize, Deserialize)]
+struct InterchangeGraph {
+    version: u32,
+    files: Vec<InterchangeFile>,
+    nodes: Vec<InterchangeNode>,
+    edges: Vec<InterchangeEdge>,
+}
+
+/// A file entry in the interchange schema.
+#[derive(Serialize, Deserialize)]
+struct InterchangeFile {
+    id: String,
+    language: String,
+}
+
+/// A symbol node in the interchange schema.
+#[derive(Serialize, Deserialize)]
+struct InterchangeNode {
+    id: String,
+    name: String,
+    kind: SymbolKind,
+    file: String,
+    line_start: usize,
+    line_end: usize,
+    signature: Option<String>,
+}
+
+/// A relationship edge in the interchange schema.
+#[derive(Serialize, Deserialize)]
+struct InterchangeEdge {
+    from: String,
+    to: String,
+    kind: EdgeKind,
+}
+
+/// One-hop neighborhood of a symbol, as computed by [`CodeGraph::neighbors`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Neighborhood {
+    /// Symbols that call this symbol.
+    pub callers: Vec<SymbolId>,
+    /// Symbols this symbol calls.
+    pub callees: Vec<SymbolId>,
+    /// Symbols referencing this one via non-call edges (uses, imports, etc).
+    pub references: Vec<SymbolId>,
+    /// Implements edges in either direction: implementors of this trait,
+    /// or traits this type implements.
+    pub implements: Vec<SymbolId>,
+}
+
+impl Neighborhood {
+    /// Check if the symbol has no neighbors at all.
+    pub fn is_empty(&self) -> bool {
+        self.callers.is_empty()
+            && self.callees.is_empty()
+            && self.references.is_empty()
+            && self.implements.is_empty()
+    }
+}
+
+/// A signature change detected between two graphs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureChange {
+    pub old_signature: Option<String>,
+    pub new_signature: Option<String>,
+}
+
+/// Difference between two knowledge graphs, as computed by [`CodeGraph::diff`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphDiff {
+    /// Symbols present only in the new graph, keyed by symbol id.
+    pub added: HashMap<SymbolId, SymbolNode>,
+    /// Symbols present only in the old graph, keyed by symbol id.
+    pub removed: HashMap<SymbolId, SymbolNode>,
+    /// Matched symbols whose signatures differ, keyed by the new symbol id.
+    pub signature_changes: HashMap<SymbolId, SignatureChange>,
+}
+
+impl GraphDiff {
+    /// Check if the two graphs were identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.signature_changes.is_empty()
+    }
+
+    /// Total number of changed symbols.
+    pub fn change_count(&self) -> usize {
+        self.added.len() + self.removed.len() + self.signature_changes.len()
+    }
+}
+
 /// Graph operation errors.
 #[derive(Debug, Error)]
 pub enum GraphError {
@@ -683,6 +1631,9 @@ pub enum GraphError {
 
     #[error("Graph cycle detected: {0}")]
     CycleDetected(String),
+
+    #[error("Invalid interchange JSON: {0}")]
+    InvalidInterchange(String),
 }
 
 #[cfg(test)]
@@ -751,6 +1702,303 @@ mod tests {
         assert_eq!(results[0].name, "func1");
     }
 
+    #[test]
+    fn test_find_symbols_filtered_by_kind() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/lib.rs", "rust"));
+        graph.add_file(FileNode::new("src/main.rs", "rust"));
+
+        // Same name, different kinds
+        graph.add_symbol(SymbolNode::new("Config", SymbolKind::Struct, "src/lib.rs", 10));
+        graph.add_symbol(SymbolNode::new("Config", SymbolKind::Function, "src/main.rs", 20));
+
+        let structs = graph.find_symbols_filtered("Config", Some(SymbolKind::Struct), None);
+        assert_eq!(structs.len(), 1);
+        assert_eq!(structs[0].kind, SymbolKind::Struct);
+
+        // No filters behaves like find_symbols_by_name
+        let all = graph.find_symbols_filtered("Config", None, None);
+        assert_eq!(all.len(), 2);
+    }
+
+    #[test]
+    fn test_find_symbols_ranked_by_definition_quality() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/a.rs", "rust"));
+        graph.add_file(FileNode::new("src/b.rs", "rust"));
+        graph.add_file(FileNode::new("src/c.rs", "rust"));
+        graph.add_file(FileNode::new("src/d.rs", "rust"));
+
+        // Case-insensitive match only - must come last
+        graph.add_symbol(
+            SymbolNode::new("Parse", SymbolKind::Function, "src/d.rs", 5)
+                .with_range(5, 20)
+                .with_signature("fn Parse()"),
+        );
+        // Exact case, single-line span (declaration-like), no signature
+        graph.add_symbol(SymbolNode::new("parse", SymbolKind::Function, "src/c.rs", 3));
+        // Exact case, multi-line body, no signature
+        graph.add_symbol(
+            SymbolNode::new("parse", SymbolKind::Function, "src/b.rs", 10).with_range(10, 30),
+        );
+        // Exact case, multi-line body, with signature - the best candidate
+        graph.add_symbol(
+            SymbolNode::new("parse", SymbolKind::Function, "src/a.rs", 1)
+                .with_range(1, 25)
+                .with_signature("fn parse(input: &str) -> Ast"),
+        );
+
+        let ranked = graph.find_symbols_ranked("parse", None, None);
+        let files: Vec<&str> = ranked.iter().map(|s| s.file_id.as_str()).collect();
+        assert_eq!(files, vec!["src/a.rs", "src/b.rs", "src/c.rs", "src/d.rs"]);
+    }
+
+    #[test]
+    fn test_find_symbols_ranked_prefers_central_symbols() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/a.rs", "rust"));
+        graph.add_file(FileNode::new("src/b.rs", "rust"));
+        graph.add_file(FileNode::new("src/main.rs", "rust"));
+
+        // Two equally-shaped definitions; the one in b.rs is called twice
+        graph.add_symbol(
+            SymbolNode::new("run", SymbolKind::Function, "src/a.rs", 1)
+                .with_range(1, 10)
+                .with_signature("fn run()"),
+        );
+        let popular = SymbolNode::new("run", SymbolKind::Function, "src/b.rs", 1)
+            .with_range(1, 10)
+            .with_signature("fn run()");
+        let popular_id = popular.id.clone();
+        graph.add_symbol(popular);
+        graph.add_symbol(
+            SymbolNode::new("main", SymbolKind::Function, "src/main.rs", 1).with_range(1, 5),
+        );
+
+        graph.add_edge(Edge::new("src/main.rs::main@1", popular_id.clone(), EdgeKind::Calls));
+        graph.add_edge(Edge::new("src/main.rs::main@1", popular_id, EdgeKind::Calls));
+
+        let ranked = graph.find_symbols_ranked("run", None, None);
+        assert_eq!(ranked[0].file_id, "src/b.rs");
+        assert_eq!(ranked[1].file_id, "src/a.rs");
+    }
+
+    #[test]
+    fn test_find_entrypoints_classifies_main_tests_and_public_roots() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/main.rs", "rust"));
+        graph.add_file(FileNode::new("src/lib.rs", "rust"));
+        graph.add_file(FileNode::new("tests/api.rs", "rust").test_file());
+
+        let main = SymbolNode::new("main", SymbolKind::Function, "src/main.rs", 1).with_range(1, 10);
+        let main_id = main.id.clone();
+        graph.add_symbol(main);
+
+        // Library function called from main: reachable, not an entrypoint
+        let helper = SymbolNode::new("process", SymbolKind::Function, "src/lib.rs", 5)
+            .with_range(5, 20)
+            .with_visibility("public");
+        let helper_id = helper.id.clone();
+        graph.add_symbol(helper);
+        graph.add_edge(Edge::new(main_id, helper_id, EdgeKind::Calls));
+
+        // Public handler with no static callers: a public root
+        graph.add_symbol(
+            SymbolNode::new("handle_login", SymbolKind::Function, "src/lib.rs", 30)
+                .with_range(30, 45)
+                .with_visibility("public"),
+        );
+
+        // Private helper with no callers: not an entrypoint
+        graph.add_symbol(
+            SymbolNode::new("internal", SymbolKind::Function, "src/lib.rs", 50)
+                .with_range(50, 55)
+                .with_visibility("private"),
+        );
+
+        // Test function, flagged the way the graph builder does
+        graph.add_symbol(
+            SymbolNode::new("test_process", SymbolKind::Function, "tests/api.rs", 3)
+                .with_range(3, 12)
+                .with_metadata(serde_json::json!({ "is_test": true })),
+        );
+
+        let entrypoints = graph.find_entrypoints();
+        let labeled: Vec<(&str, &str)> = entrypoints
+            .iter()
+            .map(|(s, k)| (s.name.as_str(), k.label()))
+            .collect();
+
+        assert_eq!(
+            labeled,
+            vec![
+                ("main", "main"),
+                ("test_process", "test"),
+                ("handle_login", "public_root"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stats_breakdown_by_kind_and_language() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/lib.rs", "rust"));
+        graph.add_file(FileNode::new("src/main.rs", "rust"));
+        graph.add_file(FileNode::new("scripts/run.py", "python"));
+
+        graph.add_symbol(SymbolNode::new("parse", SymbolKind::Function, "src/lib.rs", 10));
+        graph.add_symbol(SymbolNode::new("render", SymbolKind::Function, "src/main.rs", 20));
+        graph.add_symbol(SymbolNode::new("Config", SymbolKind::Struct, "src/lib.rs", 30));
+        graph.add_symbol(SymbolNode::new("validate", SymbolKind::Method, "src/lib.rs", 40));
+
+        let stats = graph.stats();
+        assert_eq!(stats.symbol_count, 4);
+        assert_eq!(stats.file_count, 3);
+        assert_eq!(stats.symbols_by_kind.get("fn"), Some(&2));
+        assert_eq!(stats.symbols_by_kind.get("struct"), Some(&1));
+        assert_eq!(stats.symbols_by_kind.get("method"), Some(&1));
+        assert_eq!(stats.symbols_by_kind.get("trait"), None);
+        assert_eq!(stats.files_by_language.get("rust"), Some(&2));
+        assert_eq!(stats.files_by_language.get("python"), Some(&1));
+    }
+
+    #[test]
+    fn test_find_symbols_filtered_by_file_prefix() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/auth/mod.rs", "rust"));
+        graph.add_file(FileNode::new("src/net/mod.rs", "rust"));
+
+        graph.add_symbol(SymbolNode::new("validate", SymbolKind::Function, "src/auth/mod.rs", 5));
+        graph.add_symbol(SymbolNode::new("validate", SymbolKind::Function, "src/net/mod.rs", 7));
+
+        let scoped = graph.find_symbols_filtered("validate", None, Some("src/auth"));
+        assert_eq!(scoped.len(), 1);
+        assert_eq!(scoped[0].file_id, "src/auth/mod.rs");
+    }
+
+    #[test]
+    fn test_symbol_covering_prefers_narrowest() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/lib.rs", "rust"));
+
+        // An impl block enclosing a method
+        graph.add_symbol(
+            SymbolNode::new("Foo", SymbolKind::Struct, "src/lib.rs", 10).with_range(10, 50),
+        );
+        graph.add_symbol(
+            SymbolNode::new("validate", SymbolKind::Method, "src/lib.rs", 20).with_range(20, 30),
+        );
+
+        // Chunk inside the method resolves to the method, not the impl
+        let covering = graph.symbol_covering("src/lib.rs", 22, 28).unwrap();
+        assert_eq!(covering.name, "validate");
+
+        // Chunk spanning beyond the method falls back to the struct
+        let covering = graph.symbol_covering("src/lib.rs", 15, 40).unwrap();
+        assert_eq!(covering.name, "Foo");
+
+        // No symbol covers this range
+        assert!(graph.symbol_covering("src/lib.rs", 60, 70).is_none());
+        assert!(graph.symbol_covering("src/other.rs", 22, 28).is_none());
+    }
+
+    #[test]
+    fn test_resolve_definition_follows_call_site_to_definition() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/auth.rs", "rust"));
+        graph.add_file(FileNode::new("src/session.rs", "rust"));
+
+        let validate = SymbolNode::new("validate", SymbolKind::Function, "src/auth.rs", 10)
+            .with_range(10, 20)
+            .with_columns(8, 15);
+        let validate_id = validate.id.clone();
+        graph.add_symbol(validate);
+
+        let login = SymbolNode::new("login", SymbolKind::Function, "src/session.rs", 30)
+            .with_range(30, 45);
+        let login_id = login.id.clone();
+        graph.add_symbol(login);
+
+        // login() calls validate() at src/session.rs:35
+        graph.add_edge(
+            Edge::new(&login_id, &validate_id, EdgeKind::Calls)
+                .with_location("src/session.rs".to_string(), 35),
+        );
+
+        // The call site resolves to the definition
+        let definition = graph.resolve_definition("src/session.rs", 35, 12).unwrap();
+        assert_eq!(definition.name, "validate");
+        assert_eq!(definition.file_id, "src/auth.rs");
+        assert_eq!(definition.line_start, 10);
+
+        // A position on the definition's own name identifier returns it
+        let definition = graph.resolve_definition("src/auth.rs", 10, 9).unwrap();
+        assert_eq!(definition.id, validate_id);
+
+        // Nothing recorded at this position
+        assert!(graph.resolve_definition("src/session.rs", 40, 1).is_none());
+    }
+
+    #[test]
+    fn test_file_outline_nests_methods_under_impls() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/lib.rs", "rust"));
+
+        // Two impl blocks with methods, and a free function between them
+        graph.add_symbol(
+            SymbolNode::new("Session", SymbolKind::Other, "src/lib.rs", 10).with_range(10, 40),
+        );
+        graph.add_symbol(
+            SymbolNode::new("login", SymbolKind::Method, "src/lib.rs", 12).with_range(12, 20),
+        );
+        graph.add_symbol(
+            SymbolNode::new("logout", SymbolKind::Method, "src/lib.rs", 25).with_range(25, 38),
+        );
+        graph.add_symbol(
+            SymbolNode::new("helper", SymbolKind::Function, "src/lib.rs", 45).with_range(45, 50),
+        );
+        graph.add_symbol(
+            SymbolNode::new("Token", SymbolKind::Other, "src/lib.rs", 55).with_range(55, 70),
+        );
+        graph.add_symbol(
+            SymbolNode::new("refresh", SymbolKind::Method, "src/lib.rs", 57).with_range(57, 68),
+        );
+
+        let outline = graph.file_outline("src/lib.rs");
+
+        // Top level: Session impl, free function, Token impl - in line order
+        assert_eq!(outline.len(), 3);
+        assert_eq!(outline[0].name, "Session");
+        assert_eq!(outline[1].name, "helper");
+        assert_eq!(outline[2].name, "Token");
+
+        // Methods nest under their impl blocks, in line order
+        let session_children: Vec<&str> =
+            outline[0].children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(session_children, vec!["login", "logout"]);
+        assert!(outline[1].children.is_empty());
+        let token_children: Vec<&str> =
+            outline[2].children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(token_children, vec!["refresh"]);
+
+        // Line ranges carry through
+        assert_eq!(outline[0].children[0].line_start, 12);
+        assert_eq!(outline[0].children[0].line_end, 20);
+        assert_eq!(outline[0].children[0].kind, SymbolKind::Method);
+
+        // Unknown files produce an empty outline
+        assert!(graph.file_outline("src/other.rs").is_empty());
+    }
+
+    #[test]
+    fn test_symbol_kind_parse() {
+        assert_eq!(SymbolKind::parse("struct"), Some(SymbolKind::Struct));
+        assert_eq!(SymbolKind::parse("function"), Some(SymbolKind::Function));
+        assert_eq!(SymbolKind::parse("fn"), Some(SymbolKind::Function));
+        assert_eq!(SymbolKind::parse("Trait"), Some(SymbolKind::Trait));
+        assert_eq!(SymbolKind::parse("bogus"), None);
+    }
+
     #[test]
     fn test_code_graph_add_reference() {
         let mut graph = CodeGraph::new();
@@ -805,6 +2053,223 @@ mod tests {
         assert_eq!(callers[0], caller_id);
     }
 
+    #[test]
+    fn test_code_graph_neighbors() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/lib.rs", "rust"));
+
+        // Fixture: caller -> process -> helper, with a user referencing
+        // process and an implementor implementing it (as if a trait method).
+        let process = SymbolNode::new("process", SymbolKind::Function, "src/lib.rs", 10);
+        let caller = SymbolNode::new("caller", SymbolKind::Function, "src/lib.rs", 20);
+        let helper = SymbolNode::new("helper", SymbolKind::Function, "src/lib.rs", 30);
+        let user = SymbolNode::new("user", SymbolKind::Function, "src/lib.rs", 40);
+        let implementor = SymbolNode::new("Implementor", SymbolKind::Struct, "src/lib.rs", 50);
+
+        let process_id = process.id.clone();
+        let caller_id = caller.id.clone();
+        let helper_id = helper.id.clone();
+        let user_id = user.id.clone();
+        let implementor_id = implementor.id.clone();
+
+        for symbol in [process, caller, helper, user, implementor] {
+            graph.add_symbol(symbol);
+        }
+
+        graph.add_edge(Edge::new(&caller_id, &process_id, EdgeKind::Calls));
+        graph.add_edge(Edge::new(&process_id, &helper_id, EdgeKind::Calls));
+        graph.add_edge(Edge::new(&user_id, &process_id, EdgeKind::Uses));
+        graph.add_edge(Edge::new(&implementor_id, &process_id, EdgeKind::Implements));
+
+        let neighborhood = graph.neighbors(&process_id);
+        assert_eq!(neighborhood.callers, vec![caller_id]);
+        assert_eq!(neighborhood.callees, vec![helper_id.clone()]);
+        assert_eq!(neighborhood.references, vec![user_id.clone()]);
+        assert_eq!(neighborhood.implements, vec![implementor_id]);
+        assert!(!neighborhood.is_empty());
+
+        // helper is only ever called by process.
+        let helper_neighborhood = graph.neighbors(&helper_id);
+        assert_eq!(helper_neighborhood.callers, vec![process_id]);
+        assert!(helper_neighborhood.callees.is_empty());
+
+        // user only has an outgoing Uses edge, which is not part of its
+        // own neighborhood.
+        assert!(graph.neighbors(&user_id).is_empty());
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/lib.rs", "rust"));
+
+        let target = SymbolNode::new("target_func", SymbolKind::Function, "src/lib.rs", 10)
+            .with_range(10, 18)
+            .with_signature("fn target_func() -> i32");
+        let caller = SymbolNode::new("caller_func", SymbolKind::Function, "src/lib.rs", 20);
+        let target_id = target.id.clone();
+        let caller_id = caller.id.clone();
+        graph.add_symbol(target);
+        graph.add_symbol(caller);
+        graph.add_edge(Edge::new(&caller_id, &target_id, EdgeKind::Calls));
+
+        let exported = graph.export_json();
+        let imported = CodeGraph::import_json(&exported).unwrap();
+
+        assert_eq!(imported.node_count(), graph.node_count());
+        assert_eq!(imported.edge_count(), graph.edge_count());
+
+        let target_after = imported.get_symbol(&target_id).unwrap();
+        assert_eq!(target_after.name, "target_func");
+        assert_eq!(target_after.kind, SymbolKind::Function);
+        assert_eq!(target_after.line_start, 10);
+        assert_eq!(target_after.line_end, 18);
+        assert_eq!(
+            target_after.signature.as_deref(),
+            Some("fn target_func() -> i32")
+        );
+        assert_eq!(imported.find_callers(&target_id), vec![caller_id]);
+
+        // Re-exporting the imported graph is byte-identical (stable schema)
+        assert_eq!(imported.export_json(), exported);
+    }
+
+    #[test]
+    fn test_export_json_schema_shape() {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/lib.rs", "rust"));
+        let a = SymbolNode::new("a", SymbolKind::Function, "src/lib.rs", 1);
+        let b = SymbolNode::new("b", SymbolKind::Function, "src/lib.rs", 5);
+        let (a_id, b_id) = (a.id.clone(), b.id.clone());
+        graph.add_symbol(a);
+        graph.add_symbol(b);
+        graph.add_edge(Edge::new(&a_id, &b_id, EdgeKind::Calls));
+
+        let doc: serde_json::Value = serde_json::from_str(&graph.export_json()).unwrap();
+        assert_eq!(doc["version"], INTERCHANGE_SCHEMA_VERSION);
+        assert_eq!(doc["files"][0]["id"], "src/lib.rs");
+        assert_eq!(doc["files"][0]["language"], "rust");
+
+        let node = &doc["nodes"][0];
+        for key in ["id", "name", "kind", "file", "line_start", "line_end", "signature"] {
+            assert!(node.get(key).is_some(), "node is missing key '{}'", key);
+        }
+        assert_eq!(node["kind"], "function");
+
+        // Structural edges are omitted; only the Calls edge remains
+        assert_eq!(doc["edges"].as_array().unwrap().len(), 1);
+        assert_eq!(doc["edges"][0]["from"], a_id);
+        assert_eq!(doc["edges"][0]["to"], b_id);
+        assert_eq!(doc["edges"][0]["kind"], "calls");
+    }
+
+    #[test]
+    fn test_import_json_rejects_unknown_version() {
+        let doc = r#"{"version": 99, "files": [], "nodes": [], "edges": []}"#;
+        assert!(matches!(
+            CodeGraph::import_json(doc),
+            Err(GraphError::InvalidInterchange(_))
+        ));
+    }
+
+    #[test]
+    fn test_file_dependency_path_three_file_chain() {
+        let mut graph = CodeGraph::new();
+
+        // Fixture: ui.rs -> service.rs -> db.rs dependency chain
+        graph.add_file(FileNode::new("src/ui.rs", "rust"));
+        graph.add_file(FileNode::new("src/service.rs", "rust"));
+        graph.add_file(FileNode::new("src/db.rs", "rust"));
+        graph.add_file(FileNode::new("src/unrelated.rs", "rust"));
+
+        let ui_fn = SymbolNode::new("render", SymbolKind::Function, "src/ui.rs", 10);
+        let service_fn = SymbolNode::new("fetch_data", SymbolKind::Function, "src/service.rs", 20);
+        let db_fn = SymbolNode::new("query", SymbolKind::Function, "src/db.rs", 30);
+        let ui_id = ui_fn.id.clone();
+        let service_id = service_fn.id.clone();
+        let db_id = db_fn.id.clone();
+
+        graph.add_symbol(ui_fn);
+        graph.add_symbol(service_fn);
+        graph.add_symbol(db_fn);
+
+        graph.add_edge(Edge::new(&ui_id, &service_id, EdgeKind::Calls));
+        graph.add_edge(Edge::new(&service_id, &db_id, EdgeKind::Calls));
+
+        // UI reaches the database layer through the service layer
+        let path = graph.file_dependency_path("src/ui.rs", "src/db.rs");
+        assert_eq!(
+            path,
+            Some(vec![
+                "src/ui.rs".to_string(),
+                "src/service.rs".to_string(),
+                "src/db.rs".to_string()
+            ])
+        );
+
+        // No reverse dependency
+        assert!(graph.file_dependency_path("src/db.rs", "src/ui.rs").is_none());
+
+        // Independent file
+        assert!(graph.file_dependency_path("src/ui.rs", "src/unrelated.rs").is_none());
+
+        // Same file is a trivial path
+        assert_eq!(
+            graph.file_dependency_path("src/ui.rs", "src/ui.rs"),
+            Some(vec!["src/ui.rs".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_graph_diff_rename_and_signature_change() {
+        let mut before = CodeGraph::new();
+        before.add_file(FileNode::new("src/lib.rs", "rust"));
+        before.add_symbol(
+            SymbolNode::new("old_name", SymbolKind::Function, "src/lib.rs", 10)
+                .with_signature("fn old_name()"),
+        );
+        before.add_symbol(
+            SymbolNode::new("process", SymbolKind::Function, "src/lib.rs", 20)
+                .with_signature("fn process(input: &str)"),
+        );
+
+        let mut after = CodeGraph::new();
+        after.add_file(FileNode::new("src/lib.rs", "rust"));
+        // old_name renamed to new_name
+        let renamed = SymbolNode::new("new_name", SymbolKind::Function, "src/lib.rs", 10)
+            .with_signature("fn new_name()");
+        let renamed_id = renamed.id.clone();
+        after.add_symbol(renamed);
+        // process gained a parameter and shifted down a few lines
+        let changed = SymbolNode::new("process", SymbolKind::Function, "src/lib.rs", 23)
+            .with_signature("fn process(input: &str, limit: usize)");
+        let changed_id = changed.id.clone();
+        after.add_symbol(changed);
+
+        let diff = before.diff(&after);
+
+        assert!(!diff.is_empty());
+        assert_eq!(diff.change_count(), 3);
+
+        // The rename shows up as one removed and one added symbol
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[&renamed_id].name, "new_name");
+        assert_eq!(diff.removed.len(), 1);
+        assert!(diff.removed.values().any(|s| s.name == "old_name"));
+
+        // The line shift alone is not churn; only the signature change is reported
+        assert_eq!(diff.signature_changes.len(), 1);
+        let change = &diff.signature_changes[&changed_id];
+        assert_eq!(change.old_signature.as_deref(), Some("fn process(input: &str)"));
+        assert_eq!(
+            change.new_signature.as_deref(),
+            Some("fn process(input: &str, limit: usize)")
+        );
+
+        // Identical graphs produce an empty diff
+        assert!(after.diff(&after).is_empty());
+    }
+
     #[test]
     fn test_symbol_kind_labels() {
         assert_eq!(SymbolKind::Function.label(), "fn");
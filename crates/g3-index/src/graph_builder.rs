@@ -9,16 +9,60 @@ use anyhow::{Context, Result};
 use tracing::{debug, info};
 
 use crate::chunker::{Chunk, ChunkType};
-use crate::graph::{CodeGraph, FileNode, SymbolKind, SymbolNode};
+use crate::graph::{CodeGraph, Edge, EdgeKind, FileNode, SymbolKind, SymbolNode};
 use crate::storage::{GraphStorage, DEFAULT_GRAPH_DIR};
 
 /// Orchestrates graph building from indexed chunks.
 ///
 /// GraphBuilder maintains a CodeGraph and GraphStorage, providing methods
 /// to add files and symbols during indexing, and persist the graph to disk.
+/// Default number of files between automatic graph checkpoints.
+pub const DEFAULT_GRAPH_CHECKPOINT_INTERVAL: usize = 200;
+
 pub struct GraphBuilder {
     storage: GraphStorage,
     root_path: PathBuf,
+    /// Flush the partial graph after this many added files (0 = disabled)
+    checkpoint_interval: usize,
+    /// Files added since the last flush
+    files_since_checkpoint: usize,
+    /// Call sites collected during `add_file`, resolved against the global
+    /// symbol table by `link_references` once all files are in
+    pending_references: Vec<PendingReference>,
+    /// Import statements collected during `add_file`, resolved alongside
+    /// the call sites so imported symbols can live in not-yet-added files
+    pending_imports: Vec<PendingImport>,
+}
+
+/// A call site waiting for the second linking pass.
+///
+/// References are buffered rather than resolved eagerly because the callee
+/// may live in a file (or another workspace crate) that has not been added
+/// yet; resolving after all files are in makes cross-crate callers visible.
+#[derive(Debug, Clone)]
+struct PendingReference {
+    /// Symbol containing the call site
+    caller_id: String,
+    /// Referenced name, resolved by the second pass
+    callee_name: String,
+    /// File containing the call site
+    file_id: String,
+    /// Line of the call site (1-indexed)
+    line: usize,
+    /// Whether the caller is a test function; calls from tests to
+    /// non-test symbols additionally produce `TestedBy` edges
+    caller_is_test: bool,
+}
+
+/// An import statement waiting for the second linking pass.
+#[derive(Debug, Clone)]
+struct PendingImport {
+    /// File containing the import
+    file_id: String,
+    /// Imported module path as written (e.g. `crate::chunker::Chunk`)
+    target: String,
+    /// Line of the import statement (1-indexed)
+    line: usize,
 }
 
 impl GraphBuilder {
@@ -39,9 +83,21 @@ impl GraphBuilder {
         Ok(Self {
             storage,
             root_path: root_path.to_path_buf(),
+            checkpoint_interval: DEFAULT_GRAPH_CHECKPOINT_INTERVAL,
+            files_since_checkpoint: 0,
+            pending_references: Vec::new(),
+            pending_imports: Vec::new(),
         })
     }
 
+    /// Set how many files may be added before the partial graph is flushed
+    /// to disk. A crash then loses at most this many files of graph work;
+    /// 0 disables checkpointing and only persists on `save`.
+    pub fn with_checkpoint_interval(mut self, files: usize) -> Self {
+        self.checkpoint_interval = files;
+        self
+    }
+
     /// Add a file and its chunks to the graph.
     ///
     /// Converts chunks into symbols and adds appropriate edges.
@@ -68,15 +124,55 @@ impl GraphBuilder {
         if self.storage.graph().files.contains_key(&file_id) {
             let _ = self.storage.graph_mut().remove_file(&file_id);
         }
+        self.pending_references.retain(|r| r.file_id != file_id);
+        self.pending_imports.retain(|i| i.file_id != file_id);
 
         // Add file node
         let file_node = FileNode::new(relative_path, language).with_loc(loc);
         self.storage.graph_mut().add_file(file_node);
 
-        // Add symbols from chunks
+        // Add symbols from chunks. Docstring chunks mirror their symbol's
+        // code chunk, so adding them too would duplicate graph nodes.
         for chunk in chunks {
-            let symbol = chunk_to_symbol(chunk, &file_id);
+            if chunk.metadata.chunk_type == ChunkType::Docstring {
+                continue;
+            }
+            let mut symbol = chunk_to_symbol(chunk, &file_id);
+            let is_test = is_test_chunk(chunk);
+            if is_test {
+                // Marked so the linking pass can tell test callers from
+                // production code when creating TestedBy edges
+                symbol.metadata = Some(serde_json::json!({ "is_test": true }));
+            }
+            let caller_id = symbol.id.clone();
             self.storage.graph_mut().add_symbol(symbol);
+
+            // First pass: buffer call sites for later global resolution
+            for (callee_name, line_offset) in extract_call_names(&chunk.content) {
+                if callee_name == chunk.metadata.name {
+                    continue;
+                }
+                self.pending_references.push(PendingReference {
+                    caller_id: caller_id.clone(),
+                    callee_name,
+                    file_id: file_id.clone(),
+                    line: chunk.metadata.line_start + line_offset,
+                    caller_is_test: is_test,
+                });
+            }
+        }
+
+        // Import statements live at file top level, outside any chunk, so
+        // they are read from the source itself. Best-effort: a file that is
+        // no longer on disk simply contributes no import edges.
+        if let Ok(source) = std::fs::read_to_string(file_path) {
+            for (target, line) in extract_import_targets(&source, language) {
+                self.pending_imports.push(PendingImport {
+                    file_id: file_id.clone(),
+                    target,
+                    line,
+                });
+            }
         }
 
         debug!(
@@ -84,6 +180,24 @@ impl GraphBuilder {
             file_id,
             chunks.len()
         );
+
+        self.files_since_checkpoint += 1;
+        if self.checkpoint_interval > 0 && self.files_since_checkpoint >= self.checkpoint_interval {
+            self.checkpoint()?;
+        }
+
+        Ok(())
+    }
+
+    /// Flush the partial graph to disk mid-run.
+    ///
+    /// Called automatically every `checkpoint_interval` files so a crashed
+    /// index run can resume from the last checkpoint instead of starting
+    /// over; already-processed files are skipped via the manifest.
+    pub fn checkpoint(&mut self) -> Result<()> {
+        self.storage.save()?;
+        self.files_since_checkpoint = 0;
+        debug!("Graph checkpoint written ({} files)", self.file_count());
         Ok(())
     }
 
@@ -95,13 +209,204 @@ impl GraphBuilder {
         let file_id = relative_path.to_string_lossy().to_string();
 
         self.storage.graph_mut().remove_file(&file_id)?;
+        self.pending_references.retain(|r| r.file_id != file_id);
+        self.pending_imports.retain(|i| i.file_id != file_id);
         debug!("Removed file from graph: {}", file_id);
         Ok(())
     }
 
+    /// Second pass: resolve buffered call sites against the global symbol
+    /// table and add `Calls` edges, plus `TestedBy` edges for calls made
+    /// from test functions into production code, plus `Imports` edges from
+    /// file nodes to what they import.
+    ///
+    /// Because every indexed file has already contributed its symbols by the
+    /// time this runs, references resolve across file and crate boundaries —
+    /// a function called from another workspace crate shows up as a caller.
+    /// Returns the number of edges added.
+    pub fn link_references(&mut self) -> usize {
+        let pending = std::mem::take(&mut self.pending_references);
+        let pending_imports = std::mem::take(&mut self.pending_imports);
+        if pending.is_empty() && pending_imports.is_empty() {
+            return 0;
+        }
+
+        // Don't duplicate edges from a previous linking pass
+        let mut seen: std::collections::HashSet<(String, String)> = self
+            .storage
+            .graph()
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Calls)
+            .map(|e| (e.source.clone(), e.target.clone()))
+            .collect();
+        let mut seen_tested: std::collections::HashSet<(String, String)> = self
+            .storage
+            .graph()
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::TestedBy)
+            .map(|e| (e.source.clone(), e.target.clone()))
+            .collect();
+
+        let mut added = 0;
+        for reference in &pending {
+            let targets: Vec<(String, bool)> = self
+                .storage
+                .graph()
+                .find_symbols_by_name(&reference.callee_name)
+                .iter()
+                .map(|s| (s.id.clone(), symbol_is_test(s)))
+                .collect();
+
+            for (target_id, target_is_test) in targets {
+                if target_id == reference.caller_id {
+                    continue;
+                }
+
+                // A test calling production code approximates coverage:
+                // edge from the tested symbol to the test
+                if reference.caller_is_test
+                    && !target_is_test
+                    && seen_tested.insert((target_id.clone(), reference.caller_id.clone()))
+                {
+                    self.storage.graph_mut().add_edge(
+                        Edge::new(&target_id, &reference.caller_id, EdgeKind::TestedBy)
+                            .with_location(reference.file_id.clone(), reference.line),
+                    );
+                    added += 1;
+                }
+
+                if !seen.insert((reference.caller_id.clone(), target_id.clone())) {
+                    // Repeated call from the same caller: count the
+                    // occurrence as edge weight instead of a duplicate edge
+                    self.storage.graph_mut().increment_edge_weight(
+                        &reference.caller_id,
+                        &target_id,
+                        EdgeKind::Calls,
+                    );
+                    continue;
+                }
+                self.storage.graph_mut().add_edge(
+                    Edge::new(&reference.caller_id, &target_id, EdgeKind::Calls)
+                        .with_location(reference.file_id.clone(), reference.line),
+                );
+                added += 1;
+            }
+        }
+
+        // Imports resolve against the symbol table by the path's last
+        // segment; an import of something not in the graph (std, external
+        // crates, npm packages) keeps its raw module path as the target so
+        // the dependency is still visible.
+        let mut seen_imports: std::collections::HashSet<(String, String)> = self
+            .storage
+            .graph()
+            .edges
+            .iter()
+            .filter(|e| e.kind == EdgeKind::Imports)
+            .map(|e| (e.source.clone(), e.target.clone()))
+            .collect();
+
+        for import in &pending_imports {
+            let short_name = import
+                .target
+                .rsplit("::")
+                .next()
+                .and_then(|s| s.rsplit(['.', '/']).next())
+                .unwrap_or(&import.target);
+
+            let resolved: Vec<String> = self
+                .storage
+                .graph()
+                .find_symbols_by_name(short_name)
+                .iter()
+                .filter(|s| s.file_id != import.file_id)
+                .map(|s| s.id.clone())
+                .collect();
+
+            let targets = if resolved.is_empty() {
+                vec![import.target.clone()]
+            } else {
+                resolved
+            };
+            for target in targets {
+                if !seen_imports.insert((import.file_id.clone(), target.clone())) {
+                    continue;
+                }
+                self.storage.graph_mut().add_edge(
+                    Edge::new(&import.file_id, &target, EdgeKind::Imports)
+                        .with_location(import.file_id.clone(), import.line),
+                );
+                added += 1;
+            }
+        }
+
+        debug!(
+            "Linked {} edges from {} buffered references and {} imports",
+            added,
+            pending.len(),
+            pending_imports.len()
+        );
+        added
+    }
+
+    /// Annotate every symbol with the most recent author and commit for
+    /// its line range, via `git blame` per file.
+    ///
+    /// Opt-in (`IndexerConfig::enrich_blame`) because blame is slow on
+    /// large repositories. Untracked files and non-git roots are skipped.
+    /// Returns the number of symbols annotated.
+    pub fn enrich_blame(&mut self) -> usize {
+        let file_ids: Vec<String> = self.storage.graph().files.keys().cloned().collect();
+        let mut annotated = 0;
+
+        for file_id in file_ids {
+            let blame = match crate::blame::blame_file(&self.root_path, Path::new(&file_id)) {
+                Ok(blame) => blame,
+                Err(e) => {
+                    debug!("Skipping blame for {}: {}", file_id, e);
+                    continue;
+                }
+            };
+
+            let symbol_ids: Vec<String> = self
+                .storage
+                .graph()
+                .symbols_in_file(&file_id)
+                .iter()
+                .map(|s| s.id.clone())
+                .collect();
+
+            for symbol_id in symbol_ids {
+                let Some(symbol) = self.storage.graph_mut().symbols.get_mut(&symbol_id) else {
+                    continue;
+                };
+                if let Some((author, commit)) = crate::blame::most_recent_author(
+                    &blame,
+                    symbol.line_start,
+                    symbol.line_end,
+                ) {
+                    let metadata = symbol
+                        .metadata
+                        .get_or_insert_with(|| serde_json::json!({}));
+                    if let Some(map) = metadata.as_object_mut() {
+                        map.insert("last_author".to_string(), serde_json::json!(author));
+                        map.insert("last_commit".to_string(), serde_json::json!(commit));
+                        annotated += 1;
+                    }
+                }
+            }
+        }
+
+        info!("Blame enrichment annotated {} symbols", annotated);
+        annotated
+    }
+
     /// Save the graph to disk.
     pub fn save(&mut self) -> Result<()> {
         self.storage.save()?;
+        self.files_since_checkpoint = 0;
         info!(
             "Saved graph: {} symbols, {} files, {} edges",
             self.storage.graph().symbols.len(),
@@ -126,6 +431,11 @@ impl GraphBuilder {
         self.storage.graph().files.len()
     }
 
+    /// Compute aggregate graph statistics, including per-kind and per-language breakdowns.
+    pub fn stats(&self) -> crate::graph::GraphStats {
+        self.storage.graph().stats()
+    }
+
     /// Check if the graph is empty.
     pub fn is_empty(&self) -> bool {
         self.storage.graph().is_empty()
@@ -141,6 +451,49 @@ impl GraphBuilder {
         self.storage.graph().find_symbols_by_name(name)
     }
 
+    /// Find symbols by name, optionally filtered by kind and file-path prefix.
+    pub fn find_symbols_filtered(
+        &self,
+        name: &str,
+        kind: Option<SymbolKind>,
+        file_prefix: Option<&str>,
+    ) -> Vec<&SymbolNode> {
+        self.storage
+            .graph()
+            .find_symbols_filtered(name, kind, file_prefix)
+    }
+
+    /// Find symbols by name (case-insensitively), ranked by definition
+    /// quality and centrality. See `CodeGraph::find_symbols_ranked`.
+    pub fn find_symbols_ranked(
+        &self,
+        name: &str,
+        kind: Option<SymbolKind>,
+        file_prefix: Option<&str>,
+    ) -> Vec<&SymbolNode> {
+        self.storage
+            .graph()
+            .find_symbols_ranked(name, kind, file_prefix)
+    }
+
+    /// Find the program's entrypoints (main functions, tests, public
+    /// roots). See `CodeGraph::find_entrypoints`.
+    pub fn find_entrypoints(&self) -> Vec<(&SymbolNode, crate::graph::EntrypointKind)> {
+        self.storage.graph().find_entrypoints()
+    }
+
+    /// Find the symbol covering a line range in a file (narrowest wins).
+    pub fn symbol_covering(
+        &self,
+        file_path: &str,
+        line_start: usize,
+        line_end: usize,
+    ) -> Option<&SymbolNode> {
+        self.storage
+            .graph()
+            .symbol_covering(file_path, line_start, line_end)
+    }
+
     /// Get all symbols in a file.
     pub fn symbols_in_file(&self, file_path: &str) -> Vec<&SymbolNode> {
         let file_id = file_path.to_string();
@@ -159,11 +512,77 @@ impl GraphBuilder {
         self.storage.graph().find_callees(&id)
     }
 
+    /// Find tests covering a symbol.
+    pub fn find_tests(&self, symbol_id: &str) -> Vec<String> {
+        let id = symbol_id.to_string();
+        self.storage.graph().find_tests(&id)
+    }
+
+    /// Nested symbol outline for a file (modules → impls → methods).
+    pub fn file_outline(&self, file_path: &str) -> Vec<crate::graph::OutlineNode> {
+        self.storage.graph().file_outline(file_path)
+    }
+
+    /// What a file imports (outgoing `Imports` edges).
+    pub fn file_imports(&self, file_path: &str) -> Vec<crate::graph::Edge> {
+        self.storage.graph().file_imports(file_path)
+    }
+
+    /// Who imports a file's symbols (incoming `Imports` edges).
+    pub fn file_importers(&self, file_path: &str) -> Vec<crate::graph::Edge> {
+        self.storage.graph().file_importers(file_path)
+    }
+
+    /// Token-budgeted textual map of the repository (see
+    /// [`crate::graph::CodeGraph::repo_map`]).
+    pub fn repo_map(&self, max_tokens: usize) -> String {
+        self.storage.graph().repo_map(max_tokens)
+    }
+
+    /// Resolve the definition behind a reference position (see
+    /// [`crate::graph::CodeGraph::resolve_definition`]).
+    pub fn resolve_definition(
+        &self,
+        file: &str,
+        line: usize,
+        col: usize,
+    ) -> Option<crate::graph::SymbolNode> {
+        self.storage.graph().resolve_definition(file, line, col).cloned()
+    }
+
     /// Find all references to a symbol.
     pub fn find_references(&self, symbol_id: &str) -> Vec<crate::graph::Edge> {
         let id = symbol_id.to_string();
         self.storage.graph().find_references(&id)
     }
+
+    /// Get the one-hop neighborhood of a symbol.
+    pub fn neighbors(&self, symbol_id: &str) -> crate::graph::Neighborhood {
+        let id = symbol_id.to_string();
+        self.storage.graph().neighbors(&id)
+    }
+
+    /// Run a graph query DSL string against the graph.
+    pub fn query(&self, query: &str) -> Result<Vec<String>, crate::graph_query::QueryParseError> {
+        crate::graph_query::run_query(self.storage.graph(), query)
+    }
+
+    /// Find the file-level dependency path between two files.
+    pub fn file_dependency_path(&self, from_file: &str, to_file: &str) -> Option<Vec<String>> {
+        self.storage.graph().file_dependency_path(from_file, to_file)
+    }
+
+    /// Save the current graph as the baseline for future diffs.
+    pub fn save_diff_baseline(&self) -> Result<()> {
+        self.storage.save_diff_baseline()
+    }
+
+    /// Diff the current graph against the saved baseline.
+    ///
+    /// Returns `None` when no baseline has been saved yet.
+    pub fn diff_against_baseline(&self) -> Result<Option<crate::graph::GraphDiff>> {
+        self.storage.diff_against_baseline()
+    }
 }
 
 /// Convert a chunk type to a symbol kind.
@@ -179,6 +598,7 @@ fn chunk_type_to_symbol_kind(chunk_type: &ChunkType) -> SymbolKind {
         ChunkType::Module => SymbolKind::Module,
         ChunkType::Class => SymbolKind::Struct, // Treat class as struct
         ChunkType::Interface => SymbolKind::Interface,
+        ChunkType::Docstring => SymbolKind::Other, // prose, filtered out in add_file
     }
 }
 
@@ -192,7 +612,9 @@ fn chunk_to_symbol(chunk: &Chunk, file_id: &str) -> SymbolNode {
         file_id,
         chunk.metadata.line_start,
     )
-    .with_range(chunk.metadata.line_start, chunk.metadata.line_end);
+    .with_range(chunk.metadata.line_start, chunk.metadata.line_end)
+    .with_columns(chunk.metadata.column_start, chunk.metadata.column_end)
+    .with_visibility(chunk.metadata.visibility.as_str());
 
     if let Some(ref sig) = chunk.metadata.signature {
         symbol = symbol.with_signature(sig);
@@ -206,6 +628,166 @@ fn chunk_to_symbol(chunk: &Chunk, file_id: &str) -> SymbolNode {
     symbol
 }
 
+/// Heuristic: does this chunk define a test function?
+///
+/// Looks for a test attribute in the chunk content (`#[test]`,
+/// `#[tokio::test]`) or a `tests` segment in the scope, which covers the
+/// conventional `#[cfg(test)] mod tests` layout.
+fn is_test_chunk(chunk: &Chunk) -> bool {
+    if chunk.content.contains("#[test]") || chunk.content.contains("#[tokio::test]") {
+        return true;
+    }
+    chunk
+        .metadata
+        .scope
+        .as_deref()
+        .map(|scope| scope.split("::").any(|segment| segment == "tests"))
+        .unwrap_or(false)
+}
+
+/// Whether a symbol was marked as a test by `is_test_chunk` when added.
+fn symbol_is_test(symbol: &SymbolNode) -> bool {
+    symbol.is_test()
+}
+
+/// Extract names that look like call sites from chunk content.
+///
+/// Returns `(name, line_offset)` pairs for identifiers immediately followed
+/// by `(`, skipping language keywords and macro invocations (`name!`). This
+/// is a heuristic, not a parse: it trades precision for working uniformly
+/// across languages, and false positives are bounded because the second
+/// pass only links names that resolve to indexed symbols.
+fn extract_call_names(content: &str) -> Vec<(String, usize)> {
+    const KEYWORDS: &[&str] = &[
+        "fn", "if", "else", "while", "for", "loop", "match", "return", "let", "mut", "pub",
+        "use", "mod", "impl", "struct", "enum", "trait", "const", "static", "where", "async",
+        "await", "move", "def", "class", "function", "new", "switch", "catch", "typeof",
+        "sizeof", "assert",
+    ];
+
+    let mut names = Vec::new();
+    for (line_offset, line) in content.lines().enumerate() {
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i].is_ascii_alphabetic() || bytes[i] == b'_' {
+                let start = i;
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                    i += 1;
+                }
+                let ident = &line[start..i];
+
+                // Skip whitespace between the identifier and a call paren.
+                // Macro invocations (`ident!(`) fail this check and are
+                // deliberately not tracked.
+                let mut j = i;
+                while j < bytes.len() && bytes[j] == b' ' {
+                    j += 1;
+                }
+                if j < bytes.len() && bytes[j] == b'(' && !KEYWORDS.contains(&ident) {
+                    names.push((ident.to_string(), line_offset));
+                }
+            } else {
+                i += 1;
+            }
+        }
+    }
+    names
+}
+
+/// Extract import targets and their 1-indexed lines from source text.
+///
+/// Line-based like `extract_call_names`: `use`/`import`/`require`
+/// statements are syntactically line-oriented in the supported languages,
+/// so this avoids re-parsing the file. Rust brace groups and Python comma
+/// lists are expanded (`use a::{B, C}` yields `a::B` and `a::C`);
+/// multi-line groups are not followed.
+fn extract_import_targets(source: &str, language: &str) -> Vec<(String, usize)> {
+    let mut targets = Vec::new();
+
+    for (idx, raw) in source.lines().enumerate() {
+        let line = raw.trim();
+        let line_no = idx + 1;
+
+        match language {
+            "rust" => {
+                let Some(rest) = line
+                    .strip_prefix("pub use ")
+                    .or_else(|| line.strip_prefix("use "))
+                else {
+                    continue;
+                };
+                let rest = rest.trim_end_matches(';').trim();
+                if let Some((prefix, group)) = rest.split_once("::{") {
+                    for item in group.trim_end_matches('}').split(',') {
+                        // Drop `as alias` renames; keep the imported path
+                        let item = item.trim().split_whitespace().next().unwrap_or("");
+                        match item {
+                            "" | "*" => {}
+                            "self" => targets.push((prefix.to_string(), line_no)),
+                            _ => targets.push((format!("{}::{}", prefix, item), line_no)),
+                        }
+                    }
+                } else {
+                    let path = rest.split_whitespace().next().unwrap_or(rest);
+                    let path = path.trim_end_matches("::*").trim_end_matches("::");
+                    if !path.is_empty() {
+                        targets.push((path.to_string(), line_no));
+                    }
+                }
+            }
+            "python" => {
+                if let Some(rest) = line.strip_prefix("from ") {
+                    if let Some((module, names)) = rest.split_once(" import ") {
+                        let module = module.trim();
+                        for name in names.split(',') {
+                            let name = name.trim().split_whitespace().next().unwrap_or("");
+                            match name {
+                                "" => {}
+                                "*" => targets.push((module.to_string(), line_no)),
+                                _ => targets.push((format!("{}.{}", module, name), line_no)),
+                            }
+                        }
+                    }
+                } else if let Some(rest) = line.strip_prefix("import ") {
+                    for module in rest.split(',') {
+                        let module = module.trim().split_whitespace().next().unwrap_or("");
+                        if !module.is_empty() {
+                            targets.push((module.to_string(), line_no));
+                        }
+                    }
+                }
+            }
+            "javascript" | "typescript" => {
+                if line.starts_with("import ") || line.contains("require(") {
+                    if let Some(module) = first_quoted(line) {
+                        targets.push((module, line_no));
+                    }
+                }
+            }
+            "go" => {
+                if line.starts_with("import ") {
+                    if let Some(module) = first_quoted(line) {
+                        targets.push((module, line_no));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    targets
+}
+
+/// First single- or double-quoted string on the line, if any.
+fn first_quoted(line: &str) -> Option<String> {
+    let start = line.find(['\'', '"'])?;
+    let quote = line.as_bytes()[start] as char;
+    let rest = &line[start + 1..];
+    let end = rest.find(quote)?;
+    Some(rest[..end].to_string())
+}
+
 /// Convert file extension to language name.
 fn extension_to_language(ext: &str) -> &'static str {
     match ext {
@@ -242,8 +824,12 @@ mod tests {
                 signature: Some(format!("fn {}()", name)),
                 line_start: line,
                 line_end: line + 2,
+                column_start: 4,
+                column_end: 3 + name.len(),
                 module: None,
                 scope: None,
+                visibility: crate::chunker::Visibility::Private,
+                qualified_name: name.to_string(),
                 content_hash: "abc123".to_string(),
                 language: "rust".to_string(),
             },
@@ -282,6 +868,426 @@ mod tests {
         assert!(symbol.signature.is_some());
     }
 
+    #[test]
+    fn test_checkpoint_survives_crash() {
+        let temp = tempfile::TempDir::new().unwrap();
+
+        {
+            let mut builder = GraphBuilder::new(temp.path())
+                .unwrap()
+                .with_checkpoint_interval(2);
+
+            let chunks = vec![make_test_chunk("first", ChunkType::Function, 1)];
+            builder.add_file(&temp.path().join("a.rs"), &chunks).unwrap();
+            let chunks = vec![make_test_chunk("second", ChunkType::Function, 1)];
+            builder.add_file(&temp.path().join("b.rs"), &chunks).unwrap();
+
+            // Third file lands after the checkpoint and is never saved
+            let chunks = vec![make_test_chunk("third", ChunkType::Function, 1)];
+            builder.add_file(&temp.path().join("c.rs"), &chunks).unwrap();
+
+            // Simulated crash: builder dropped without save()
+        }
+
+        // The partial graph from the checkpoint loads cleanly
+        let recovered = GraphBuilder::new(temp.path()).unwrap();
+        assert_eq!(recovered.file_count(), 2);
+        assert_eq!(recovered.find_symbols_by_name("first").len(), 1);
+        assert!(recovered.find_symbols_by_name("third").is_empty());
+
+        // Atomic flush leaves no temp files behind
+        let graph_dir = temp.path().join(DEFAULT_GRAPH_DIR);
+        assert!(graph_dir.join("graph.json").exists());
+        assert!(!graph_dir.join("graph.tmp").exists());
+    }
+
+    fn make_chunk_with_content(
+        name: &str,
+        chunk_type: ChunkType,
+        line: usize,
+        content: &str,
+    ) -> Chunk {
+        let mut chunk = make_test_chunk(name, chunk_type, line);
+        chunk.content = content.to_string();
+        chunk.enriched_content = content.to_string();
+        chunk
+    }
+
+    #[test]
+    fn test_extract_call_names() {
+        let content = "fn main() {\n    let cfg = parse_config(path);\n    info!(\"done\");\n    run (cfg);\n}";
+        let names = extract_call_names(content);
+
+        // Keywords (fn, let) and macros (info!) are skipped; whitespace
+        // before the paren is tolerated
+        assert_eq!(
+            names,
+            vec![
+                ("main".to_string(), 0),
+                ("parse_config".to_string(), 1),
+                ("run".to_string(), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cross_crate_call_edge_linked() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut builder = GraphBuilder::new(temp.path()).unwrap();
+
+        // Workspace fixture: a library crate defining a function and a
+        // binary crate calling it
+        let lib_chunks = vec![make_chunk_with_content(
+            "parse_config",
+            ChunkType::Function,
+            1,
+            "pub fn parse_config(path: &str) -> Config {\n    Config::default()\n}",
+        )];
+        builder
+            .add_file(&temp.path().join("crates/core/src/lib.rs"), &lib_chunks)
+            .unwrap();
+
+        let bin_chunks = vec![make_chunk_with_content(
+            "main",
+            ChunkType::Function,
+            1,
+            "fn main() {\n    let cfg = parse_config(\"g3.toml\");\n}",
+        )];
+        builder
+            .add_file(&temp.path().join("crates/cli/src/main.rs"), &bin_chunks)
+            .unwrap();
+
+        let added = builder.link_references();
+        assert_eq!(added, 1);
+
+        let target_id = builder.find_symbols_by_name("parse_config")[0].id.clone();
+        let caller_id = builder.find_symbols_by_name("main")[0].id.clone();
+
+        // The cross-crate call resolved even though the crates were added
+        // separately
+        let callers = builder.find_callers(&target_id);
+        assert_eq!(callers, vec![caller_id]);
+
+        // The edge carries the call-site location in the calling crate
+        let edge = builder
+            .graph()
+            .edges
+            .iter()
+            .find(|e| e.kind == EdgeKind::Calls && e.target == target_id)
+            .unwrap();
+        assert_eq!(
+            edge.location_file.as_deref(),
+            Some("crates/cli/src/main.rs")
+        );
+        assert_eq!(edge.location_line, Some(2));
+
+        // Re-linking without new files is a no-op
+        assert_eq!(builder.link_references(), 0);
+    }
+
+    #[test]
+    fn test_repeated_calls_accumulate_edge_weight() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut builder = GraphBuilder::new(temp.path()).unwrap();
+
+        let chunks = vec![
+            make_chunk_with_content("helper", ChunkType::Function, 1, "fn helper() {}"),
+            make_chunk_with_content(
+                "busy_caller",
+                ChunkType::Function,
+                3,
+                "fn busy_caller() {\n    helper();\n    helper();\n    helper();\n}",
+            ),
+            make_chunk_with_content(
+                "light_caller",
+                ChunkType::Function,
+                9,
+                "fn light_caller() {\n    helper();\n}",
+            ),
+        ];
+        builder
+            .add_file(&temp.path().join("src/lib.rs"), &chunks)
+            .unwrap();
+
+        // One edge per caller, not per call site
+        assert_eq!(builder.link_references(), 2);
+
+        let helper_id = builder.find_symbols_by_name("helper")[0].id.clone();
+        let busy_id = builder.find_symbols_by_name("busy_caller")[0].id.clone();
+        let light_id = builder.find_symbols_by_name("light_caller")[0].id.clone();
+
+        // Three invocations collapse into one edge of weight 3
+        let edge = builder
+            .graph()
+            .edges
+            .iter()
+            .find(|e| {
+                e.kind == EdgeKind::Calls && e.source == busy_id && e.target == helper_id
+            })
+            .unwrap();
+        assert_eq!(edge.weight, 3);
+
+        // The heavier caller ranks first
+        let weighted = builder.graph().find_callers_weighted(&helper_id);
+        assert_eq!(weighted, vec![(busy_id, 3), (light_id, 1)]);
+    }
+
+    #[test]
+    fn test_extract_import_targets_rust_brace_group() {
+        let targets = extract_import_targets("use crate::graph::{CodeGraph, EdgeKind};\n", "rust");
+        assert_eq!(
+            targets,
+            vec![
+                ("crate::graph::CodeGraph".to_string(), 1),
+                ("crate::graph::EdgeKind".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rust_use_creates_import_edges() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut builder = GraphBuilder::new(temp.path()).unwrap();
+
+        let util_chunks = vec![make_chunk_with_content(
+            "helper",
+            ChunkType::Function,
+            1,
+            "pub fn helper() {}",
+        )];
+        builder
+            .add_file(&temp.path().join("src/util.rs"), &util_chunks)
+            .unwrap();
+
+        // Imports are read from the source on disk, so this file is real
+        let main_path = temp.path().join("src/main.rs");
+        std::fs::create_dir_all(main_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &main_path,
+            "use crate::util::helper;\nuse std::collections::HashMap;\n\nfn main() {}\n",
+        )
+        .unwrap();
+        let main_chunks = vec![make_chunk_with_content(
+            "main",
+            ChunkType::Function,
+            4,
+            "fn main() {}",
+        )];
+        builder.add_file(&main_path, &main_chunks).unwrap();
+        builder.link_references();
+
+        let imports = builder.file_imports("src/main.rs");
+        let helper_id = builder.find_symbols_by_name("helper")[0].id.clone();
+
+        // The workspace-local import resolved to the symbol node
+        assert!(imports
+            .iter()
+            .any(|e| e.target == helper_id && e.location_line == Some(1)));
+        // The std import kept its raw module path
+        assert!(imports
+            .iter()
+            .any(|e| e.target == "std::collections::HashMap" && e.location_line == Some(2)));
+
+        // The defining file sees who imports it
+        let importers = builder.file_importers("src/util.rs");
+        assert_eq!(importers.len(), 1);
+        assert_eq!(importers[0].source, "src/main.rs");
+    }
+
+    #[test]
+    fn test_python_import_creates_import_edges() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut builder = GraphBuilder::new(temp.path()).unwrap();
+
+        let app_path = temp.path().join("app.py");
+        std::fs::write(
+            &app_path,
+            "import os\nfrom json import dumps\n\ndef run():\n    pass\n",
+        )
+        .unwrap();
+        let chunks = vec![make_chunk_with_content(
+            "run",
+            ChunkType::Function,
+            4,
+            "def run():\n    pass",
+        )];
+        builder.add_file(&app_path, &chunks).unwrap();
+        builder.link_references();
+
+        let imports = builder.file_imports("app.py");
+        assert!(imports
+            .iter()
+            .any(|e| e.target == "os" && e.location_line == Some(1)));
+        assert!(imports
+            .iter()
+            .any(|e| e.target == "json.dumps" && e.location_line == Some(2)));
+    }
+
+    #[test]
+    fn test_typescript_import_creates_import_edges() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut builder = GraphBuilder::new(temp.path()).unwrap();
+
+        let app_path = temp.path().join("app.ts");
+        std::fs::write(
+            &app_path,
+            "import { readFile } from 'fs';\nconst util = require('./util');\n",
+        )
+        .unwrap();
+        let chunks = vec![make_chunk_with_content(
+            "handler",
+            ChunkType::Function,
+            4,
+            "function handler() {}",
+        )];
+        builder.add_file(&app_path, &chunks).unwrap();
+        builder.link_references();
+
+        let imports = builder.file_imports("app.ts");
+        assert!(imports
+            .iter()
+            .any(|e| e.target == "fs" && e.location_line == Some(1)));
+        assert!(imports
+            .iter()
+            .any(|e| e.target == "./util" && e.location_line == Some(2)));
+    }
+
+    #[test]
+    fn test_reindexed_file_drops_stale_references() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut builder = GraphBuilder::new(temp.path()).unwrap();
+
+        builder
+            .add_file(
+                &temp.path().join("lib.rs"),
+                &[make_chunk_with_content(
+                    "helper",
+                    ChunkType::Function,
+                    1,
+                    "pub fn helper() {}",
+                )],
+            )
+            .unwrap();
+        builder
+            .add_file(
+                &temp.path().join("main.rs"),
+                &[make_chunk_with_content(
+                    "main",
+                    ChunkType::Function,
+                    1,
+                    "fn main() {\n    helper();\n}",
+                )],
+            )
+            .unwrap();
+
+        // The call site disappears before linking runs; re-adding the file
+        // must discard the buffered reference
+        builder
+            .add_file(
+                &temp.path().join("main.rs"),
+                &[make_chunk_with_content(
+                    "main",
+                    ChunkType::Function,
+                    1,
+                    "fn main() {}",
+                )],
+            )
+            .unwrap();
+
+        assert_eq!(builder.link_references(), 0);
+        let target_id = builder.find_symbols_by_name("helper")[0].id.clone();
+        assert!(builder.find_callers(&target_id).is_empty());
+    }
+
+    #[test]
+    fn test_testedby_edge_links_tested_function_to_test() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut builder = GraphBuilder::new(temp.path()).unwrap();
+
+        builder
+            .add_file(
+                &temp.path().join("src/lib.rs"),
+                &[make_chunk_with_content(
+                    "add_numbers",
+                    ChunkType::Function,
+                    1,
+                    "pub fn add_numbers(a: i32, b: i32) -> i32 {\n    a + b\n}",
+                )],
+            )
+            .unwrap();
+
+        // A #[test] in the conventional tests module calling the function
+        let mut test_chunk = make_chunk_with_content(
+            "test_addition",
+            ChunkType::Function,
+            10,
+            "#[test]\nfn test_addition() {\n    assert_eq!(add_numbers(1, 2), 3);\n}",
+        );
+        test_chunk.metadata.scope = Some("tests".to_string());
+        builder
+            .add_file(&temp.path().join("src/math_test.rs"), &[test_chunk])
+            .unwrap();
+
+        // One Calls edge plus one TestedBy edge
+        assert_eq!(builder.link_references(), 2);
+
+        let tested_id = builder.find_symbols_by_name("add_numbers")[0].id.clone();
+        let test_id = builder.find_symbols_by_name("test_addition")[0].id.clone();
+
+        // Edge runs from the tested symbol to the test
+        let edge = builder
+            .graph()
+            .edges
+            .iter()
+            .find(|e| e.kind == EdgeKind::TestedBy)
+            .unwrap();
+        assert_eq!(edge.source, tested_id);
+        assert_eq!(edge.target, test_id);
+
+        assert_eq!(builder.find_tests(&tested_id), vec![test_id]);
+
+        // Re-linking does not duplicate either edge
+        assert_eq!(builder.link_references(), 0);
+    }
+
+    #[test]
+    fn test_enrich_blame_attaches_author_to_symbol() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .arg("-C")
+                .arg(temp.path())
+                .args(args)
+                .status()
+                .expect("git not available");
+            assert!(status.success(), "git {:?} failed", args);
+        };
+
+        // Fixture repo with one committed source file
+        git(&["init", "-q"]);
+        git(&["config", "user.name", "Alice Example"]);
+        git(&["config", "user.email", "alice@example.com"]);
+        std::fs::create_dir_all(temp.path().join("src")).unwrap();
+        std::fs::write(
+            temp.path().join("src/lib.rs"),
+            "pub fn greet() -> &'static str {\n    \"hello\"\n}\n",
+        )
+        .unwrap();
+        git(&["add", "-A"]);
+        git(&["commit", "-q", "-m", "add greet"]);
+
+        let mut builder = GraphBuilder::new(temp.path()).unwrap();
+        let chunks = vec![make_test_chunk("greet", ChunkType::Function, 1)];
+        builder.add_file(&temp.path().join("src/lib.rs"), &chunks).unwrap();
+
+        assert_eq!(builder.enrich_blame(), 1);
+
+        let symbol = &builder.find_symbols_by_name("greet")[0];
+        let metadata = symbol.metadata.as_ref().expect("metadata attached");
+        assert_eq!(metadata["last_author"], "Alice Example");
+        assert!(metadata["last_commit"].as_str().unwrap().len() >= 12);
+    }
+
     #[test]
     fn test_extension_to_language() {
         assert_eq!(extension_to_language("rs"), "rust");
@@ -0,0 +1,279 @@
+//! Mini query DSL for the knowledge graph.
+//!
+//! Lets an agent compose graph operations without a dedicated tool per query
+//! shape, e.g.:
+//!
+//! ```text
+//! callers(handle_request) & file(src/auth)
+//! callees(src/main.rs::main@10)
+//! ```
+//!
+//! Grammar (terms are intersected with `&`):
+//!
+//! ```text
+//! query := term ('&' term)*
+//! term  := op '(' arg ')'
+//! op    := callers | callees | references | implements | file
+//! ```
+//!
+//! Symbol arguments accept either a full symbol ID (`file::name@line`) or a
+//! bare name, which expands to every symbol with that name. `file()` matches
+//! symbols whose file path contains the given fragment.
+
+use crate::graph::{CodeGraph, SymbolId};
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Error produced when a query string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Query parse error: {0}")]
+pub struct QueryParseError(pub String);
+
+/// A parsed graph query expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryExpr {
+    /// Symbols that call the named symbol
+    Callers(String),
+    /// Symbols called by the named symbol
+    Callees(String),
+    /// Symbols with a non-structural edge to the named symbol
+    References(String),
+    /// `Implements` edges in either direction for the named symbol
+    Implements(String),
+    /// Symbols defined in files whose path contains the fragment
+    File(String),
+    /// Intersection of two sub-queries
+    And(Box<QueryExpr>, Box<QueryExpr>),
+}
+
+impl QueryExpr {
+    /// Evaluate the query against a graph, returning matching symbol IDs
+    /// in sorted order.
+    pub fn evaluate(&self, graph: &CodeGraph) -> Vec<SymbolId> {
+        let mut ids: Vec<SymbolId> = self.evaluate_set(graph).into_iter().collect();
+        ids.sort();
+        ids
+    }
+
+    fn evaluate_set(&self, graph: &CodeGraph) -> HashSet<SymbolId> {
+        match self {
+            QueryExpr::Callers(arg) => resolve_symbols(graph, arg)
+                .iter()
+                .flat_map(|id| graph.find_callers(id))
+                .collect(),
+            QueryExpr::Callees(arg) => resolve_symbols(graph, arg)
+                .iter()
+                .flat_map(|id| graph.find_callees(id))
+                .collect(),
+            QueryExpr::References(arg) => resolve_symbols(graph, arg)
+                .iter()
+                .flat_map(|id| graph.find_references(id))
+                .map(|e| e.source)
+                .filter(|id| graph.symbols.contains_key(id))
+                .collect(),
+            QueryExpr::Implements(arg) => resolve_symbols(graph, arg)
+                .iter()
+                .flat_map(|id| graph.neighbors(id).implements)
+                .collect(),
+            QueryExpr::File(fragment) => graph
+                .symbols
+                .values()
+                .filter(|s| s.file_id.contains(fragment.as_str()))
+                .map(|s| s.id.clone())
+                .collect(),
+            QueryExpr::And(left, right) => {
+                let left = left.evaluate_set(graph);
+                let right = right.evaluate_set(graph);
+                left.intersection(&right).cloned().collect()
+            }
+        }
+    }
+}
+
+/// Resolve a query argument to symbol IDs: an exact ID wins, otherwise the
+/// argument is treated as a name and expands to every matching symbol.
+fn resolve_symbols(graph: &CodeGraph, arg: &str) -> Vec<SymbolId> {
+    if graph.symbols.contains_key(arg) {
+        return vec![arg.to_string()];
+    }
+    graph
+        .find_symbols_by_name(arg)
+        .into_iter()
+        .map(|s| s.id.clone())
+        .collect()
+}
+
+/// Parse a query string into an expression tree.
+pub fn parse_query(input: &str) -> Result<QueryExpr, QueryParseError> {
+    if input.trim().is_empty() {
+        return Err(QueryParseError(
+            "empty query; expected e.g. callers(my_func) & file(src/auth)".to_string(),
+        ));
+    }
+
+    let mut expr: Option<QueryExpr> = None;
+    for raw_term in input.split('&') {
+        let term = parse_term(raw_term.trim())?;
+        expr = Some(match expr {
+            Some(prev) => QueryExpr::And(Box::new(prev), Box::new(term)),
+            None => term,
+        });
+    }
+
+    // split() always yields at least one term for non-empty input
+    Ok(expr.expect("at least one term"))
+}
+
+fn parse_term(term: &str) -> Result<QueryExpr, QueryParseError> {
+    if term.is_empty() {
+        return Err(QueryParseError(
+            "empty term; expected op(arg) on both sides of '&'".to_string(),
+        ));
+    }
+
+    // '(' and ')' are ASCII, so byte slicing at their positions is UTF-8 safe
+    let open = term.find('(').ok_or_else(|| {
+        QueryParseError(format!(
+            "expected '(' after operation in '{}'; terms look like callers(name)",
+            term
+        ))
+    })?;
+    if !term.ends_with(')') {
+        return Err(QueryParseError(format!("missing closing ')' in '{}'", term)));
+    }
+
+    let op = term[..open].trim();
+    let arg = term[open + 1..term.len() - 1].trim();
+    if arg.is_empty() {
+        return Err(QueryParseError(format!(
+            "'{}' requires an argument, e.g. {}(name)",
+            op, op
+        )));
+    }
+
+    match op {
+        "callers" => Ok(QueryExpr::Callers(arg.to_string())),
+        "callees" => Ok(QueryExpr::Callees(arg.to_string())),
+        "references" => Ok(QueryExpr::References(arg.to_string())),
+        "implements" => Ok(QueryExpr::Implements(arg.to_string())),
+        "file" => Ok(QueryExpr::File(arg.to_string())),
+        other => Err(QueryParseError(format!(
+            "unknown operation '{}'; expected callers, callees, references, implements, or file",
+            other
+        ))),
+    }
+}
+
+/// Parse and evaluate a query against a graph in one step.
+pub fn run_query(graph: &CodeGraph, query: &str) -> Result<Vec<SymbolId>, QueryParseError> {
+    Ok(parse_query(query)?.evaluate(graph))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{Edge, EdgeKind, FileNode, SymbolKind, SymbolNode};
+
+    #[test]
+    fn test_parse_single_term() {
+        let expr = parse_query("callers(process)").unwrap();
+        assert_eq!(expr, QueryExpr::Callers("process".to_string()));
+    }
+
+    #[test]
+    fn test_parse_intersection() {
+        let expr = parse_query("callers(process) & file(src/auth)").unwrap();
+        assert_eq!(
+            expr,
+            QueryExpr::And(
+                Box::new(QueryExpr::Callers("process".to_string())),
+                Box::new(QueryExpr::File("src/auth".to_string())),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_all_operations() {
+        assert_eq!(
+            parse_query("callees(x)").unwrap(),
+            QueryExpr::Callees("x".to_string())
+        );
+        assert_eq!(
+            parse_query("references(x)").unwrap(),
+            QueryExpr::References("x".to_string())
+        );
+        assert_eq!(
+            parse_query("implements(x)").unwrap(),
+            QueryExpr::Implements("x".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_are_helpful() {
+        let err = parse_query("").unwrap_err();
+        assert!(err.0.contains("empty query"));
+
+        let err = parse_query("callers process").unwrap_err();
+        assert!(err.0.contains("expected '('"));
+
+        let err = parse_query("callers(process").unwrap_err();
+        assert!(err.0.contains("missing closing ')'"));
+
+        let err = parse_query("callers()").unwrap_err();
+        assert!(err.0.contains("requires an argument"));
+
+        let err = parse_query("frobnicate(x)").unwrap_err();
+        assert!(err.0.contains("unknown operation 'frobnicate'"));
+
+        let err = parse_query("callers(a) & ").unwrap_err();
+        assert!(err.0.contains("empty term"));
+    }
+
+    fn sample_graph() -> CodeGraph {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/auth.rs", "rust"));
+        graph.add_file(FileNode::new("src/main.rs", "rust"));
+
+        let login = SymbolNode::new("login", SymbolKind::Function, "src/auth.rs", 10);
+        let main = SymbolNode::new("main", SymbolKind::Function, "src/main.rs", 1);
+        let verify = SymbolNode::new("verify", SymbolKind::Function, "src/auth.rs", 30);
+
+        let login_id = login.id.clone();
+        let main_id = main.id.clone();
+        let verify_id = verify.id.clone();
+
+        graph.add_symbol(login);
+        graph.add_symbol(main);
+        graph.add_symbol(verify);
+
+        graph.add_edge(Edge::new(&main_id, &login_id, EdgeKind::Calls));
+        graph.add_edge(Edge::new(&verify_id, &login_id, EdgeKind::Calls));
+
+        graph
+    }
+
+    #[test]
+    fn test_evaluate_callers_by_name() {
+        let graph = sample_graph();
+        let results = run_query(&graph, "callers(login)").unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().any(|id| id.contains("main")));
+        assert!(results.iter().any(|id| id.contains("verify")));
+    }
+
+    #[test]
+    fn test_evaluate_intersection_with_file_filter() {
+        let graph = sample_graph();
+        let results = run_query(&graph, "callers(login) & file(src/auth)").unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].contains("verify"));
+    }
+
+    #[test]
+    fn test_evaluate_with_full_symbol_id() {
+        let graph = sample_graph();
+        let results = run_query(&graph, "callees(src/main.rs::main@1)").unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].contains("login"));
+    }
+}
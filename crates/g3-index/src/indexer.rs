@@ -5,15 +5,16 @@
 
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use ignore::WalkBuilder;
 use sha2::{Digest, Sha256};
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
-use uuid::Uuid;
 
 use crate::chunker::{Chunk, CodeChunker};
 use crate::embeddings::EmbeddingProvider;
@@ -21,6 +22,7 @@ use crate::graph_builder::GraphBuilder;
 use crate::manifest::IndexManifest;
 use crate::qdrant::{Point, PointPayload, QdrantClient};
 use crate::search::BM25Index;
+use crate::work_queue::EmbeddingWorkQueue;
 
 /// Configuration for the indexer.
 #[derive(Debug, Clone)]
@@ -35,12 +37,84 @@ pub struct IndexerConfig {
     pub respect_gitignore: bool,
     /// File extensions to index
     pub extensions: Vec<String>,
+    /// Restrict indexing to these languages (e.g. "rust", "python").
+    /// When set, files whose detected language is not listed are skipped.
+    /// None indexes every supported language.
+    pub languages: Option<Vec<String>>,
     /// Maximum chunk tokens
     pub max_chunk_tokens: usize,
     /// Include context in chunks
     pub include_context: bool,
+    /// Prepend the file's import statements to each chunk's embedded text
+    /// (default false). Gives the embedding model type context without
+    /// changing the displayed chunk content.
+    pub include_imports: bool,
+    /// Emit each symbol's doc comment as a separate searchable chunk with
+    /// `kind: "docstring"` (default false). The code chunk is kept too;
+    /// the prose is embedded on its own so natural-language queries can
+    /// match the documentation directly.
+    pub index_docstrings: bool,
+    /// Maximum file size in bytes; larger files are skipped
+    /// (default 512KB, see [`DEFAULT_MAX_FILE_BYTES`])
+    pub max_file_bytes: u64,
+    /// Maximum chunks a single file may produce (default 1000, see
+    /// [`DEFAULT_MAX_CHUNKS_PER_FILE`]; None = unbounded). Files
+    /// exceeding it are re-chunked coarsely - top-level items only, then
+    /// a single file-level chunk - so machine-generated code with
+    /// thousands of tiny functions cannot explode the index.
+    pub max_chunks_per_file: Option<usize>,
+    /// Store chunk source code in the Qdrant payload (default true).
+    /// When false, only file/line metadata is stored and search re-reads
+    /// content from disk using the stored line range.
+    pub store_content: bool,
+    /// Redact secret-looking spans (AWS keys, private key blocks,
+    /// `.env`-style credentials) before content is embedded or stored
+    /// (default true). See the `redaction` module.
+    pub redact_secrets: bool,
+    /// Replace chunk content in log output with its length and a short
+    /// hash (default true). Structural fields (file, line, counts) are
+    /// still logged; only source text is kept out of log files.
+    pub redact_content_in_logs: bool,
+    /// Total embedding request timeout in seconds (default 120)
+    pub embedding_request_timeout_secs: u64,
+    /// Embedding connect timeout in seconds (default 10); kept short so a
+    /// dead host fails fast instead of blocking for the request timeout
+    pub embedding_connect_timeout_secs: u64,
+    /// Flush the partial knowledge graph every N files during long index
+    /// runs, so a crash loses at most N files of graph work (0 = only
+    /// persist at the end of the run)
+    pub graph_checkpoint_interval: usize,
+    /// Vector storage backend (default Qdrant). `InMemory` trades
+    /// persistence for zero setup: brute-force search over RAM-held
+    /// vectors, fine for small projects.
+    pub backend: crate::qdrant::VectorBackend,
+    /// Maximum total chunks to keep in the index (None = unbounded).
+    /// When exceeded, the least-recently-searched files are evicted so
+    /// the index behaves as a bounded cache on disk-limited machines.
+    pub max_chunks: Option<usize>,
+    /// Annotate graph symbols with their most recent author and commit
+    /// via `git blame` (default false - blame is slow on large repos)
+    pub enrich_blame: bool,
+    /// L2-normalize vectors before upserting (default true, since
+    /// collections use cosine distance). Keeps scores comparable when
+    /// embeddings from providers with different norm conventions share
+    /// a collection.
+    pub normalize_vectors: bool,
 }
 
+/// Default maximum file size for indexing (512KB).
+///
+/// Giant generated files waste embedding time and produce garbage chunks,
+/// so anything larger is skipped and counted in `IndexStats`.
+pub const DEFAULT_MAX_FILE_BYTES: u64 = 512 * 1024;
+
+/// Default maximum chunks per file.
+///
+/// Hand-written code stays well below this; only machine-generated files
+/// with thousands of tiny items hit it, and those are re-chunked coarsely
+/// instead of flooding the index.
+pub const DEFAULT_MAX_CHUNKS_PER_FILE: usize = 1000;
+
 impl Default for IndexerConfig {
     fn default() -> Self {
         Self {
@@ -55,12 +129,52 @@ impl Default for IndexerConfig {
                 "ts".to_string(),
                 "go".to_string(),
             ],
+            languages: None,
             max_chunk_tokens: 500,
             include_context: true,
+            include_imports: false,
+            index_docstrings: false,
+            max_file_bytes: DEFAULT_MAX_FILE_BYTES,
+            max_chunks_per_file: Some(DEFAULT_MAX_CHUNKS_PER_FILE),
+            store_content: true,
+            redact_secrets: true,
+            redact_content_in_logs: true,
+            embedding_request_timeout_secs: crate::embeddings::DEFAULT_REQUEST_TIMEOUT_SECS,
+            embedding_connect_timeout_secs: crate::embeddings::DEFAULT_CONNECT_TIMEOUT_SECS,
+            graph_checkpoint_interval: crate::graph_builder::DEFAULT_GRAPH_CHECKPOINT_INTERVAL,
+            backend: crate::qdrant::VectorBackend::default(),
+            max_chunks: None,
+            enrich_blame: false,
+            normalize_vectors: true,
         }
     }
 }
 
+/// Why a file was skipped during indexing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// File exceeds `IndexerConfig::max_file_bytes`
+    TooLarge,
+    /// File content looks binary (contains null bytes)
+    Binary,
+    /// File could not be chunked (unsupported language, parse failure)
+    Unparseable,
+    /// File's language is not in `IndexerConfig::languages`
+    LanguageExcluded,
+}
+
+/// A file that failed to parse during indexing.
+///
+/// Parse failures are per-file: the file is skipped and recorded here
+/// while the rest of the run continues.
+#[derive(Debug, Clone)]
+pub struct FailedFile {
+    /// Path of the failing file
+    pub path: PathBuf,
+    /// Error message from chunking/parsing
+    pub error: String,
+}
+
 /// Statistics about an indexing operation.
 #[derive(Debug, Default, Clone)]
 pub struct IndexStats {
@@ -72,12 +186,48 @@ pub struct IndexStats {
     pub chunks_updated: usize,
     /// Number of chunks deleted
     pub chunks_deleted: usize,
-    /// Number of files skipped (unsupported language)
+    /// Number of files skipped (any reason)
     pub files_skipped: usize,
+    /// Number of files skipped for exceeding the size limit
+    pub files_skipped_oversize: usize,
+    /// Number of files skipped as binary content
+    pub files_skipped_binary: usize,
+    /// Number of files that exceeded the per-file chunk limit and were
+    /// re-chunked coarsely (see `IndexerConfig::max_chunks_per_file`)
+    pub files_chunk_capped: usize,
     /// Total time in milliseconds
     pub duration_ms: u64,
     /// Number of embedding API calls
     pub embedding_calls: usize,
+    /// Number of chunks skipped because a previous interrupted run
+    /// already embedded and upserted them
+    pub chunks_resumed: usize,
+    /// Number of secret spans redacted before embedding
+    pub secrets_redacted: usize,
+    /// Files that failed to parse, with their errors
+    pub files_failed: Vec<FailedFile>,
+}
+
+impl IndexStats {
+    /// Record a skipped file with its reason.
+    fn record_skip(&mut self, reason: SkipReason) {
+        self.files_skipped += 1;
+        match reason {
+            SkipReason::TooLarge => self.files_skipped_oversize += 1,
+            SkipReason::Binary => self.files_skipped_binary += 1,
+            SkipReason::Unparseable => {}
+            SkipReason::LanguageExcluded => {}
+        }
+    }
+
+    /// Record a file that failed to parse; the run continues without it.
+    fn record_failure(&mut self, path: &Path, error: &str) {
+        self.record_skip(SkipReason::Unparseable);
+        self.files_failed.push(FailedFile {
+            path: path.to_path_buf(),
+            error: error.to_string(),
+        });
+    }
 }
 
 /// Main indexer that orchestrates codebase indexing.
@@ -90,18 +240,48 @@ pub struct Indexer<E: EmbeddingProvider> {
     bm25_index: Arc<RwLock<BM25Index>>,
     /// Optional graph builder for knowledge graph construction
     graph_builder: Option<RwLock<GraphBuilder>>,
+    /// True while an indexing run is in progress
+    indexing_active: Arc<AtomicBool>,
+    /// Persistent record of chunks already embedded, so an interrupted
+    /// run resumes instead of re-embedding everything
+    work_queue: Arc<RwLock<EmbeddingWorkQueue>>,
+}
+
+/// RAII guard that marks an indexing run as active for its lifetime.
+///
+/// The flag is reset when the guard is dropped, including on early
+/// returns from a failed run.
+struct IndexingGuard {
+    flag: Arc<AtomicBool>,
+}
+
+impl IndexingGuard {
+    fn new(flag: Arc<AtomicBool>) -> Self {
+        flag.store(true, Ordering::SeqCst);
+        Self { flag }
+    }
+}
+
+impl Drop for IndexingGuard {
+    fn drop(&mut self) {
+        self.flag.store(false, Ordering::SeqCst);
+    }
 }
 
 impl<E: EmbeddingProvider> Indexer<E> {
     /// Create a new indexer with the given configuration.
     pub fn new(config: IndexerConfig, embeddings: Arc<E>, qdrant: QdrantClient) -> Result<Self> {
-        let chunker = CodeChunker::new(config.max_chunk_tokens, config.include_context)?;
+        let chunker = CodeChunker::new(config.max_chunk_tokens, config.include_context)?
+            .with_import_context(config.include_imports)
+            .with_docstring_chunks(config.index_docstrings);
 
         // Try to initialize graph builder (non-fatal if it fails)
         let graph_builder = match GraphBuilder::new(&config.root_path) {
             Ok(gb) => {
                 info!("Graph builder initialized for knowledge graph construction");
-                Some(RwLock::new(gb))
+                Some(RwLock::new(
+                    gb.with_checkpoint_interval(config.graph_checkpoint_interval),
+                ))
             }
             Err(e) => {
                 warn!("Failed to initialize graph builder (continuing without): {}", e);
@@ -109,6 +289,10 @@ impl<E: EmbeddingProvider> Indexer<E> {
             }
         };
 
+        let work_queue = EmbeddingWorkQueue::load(EmbeddingWorkQueue::path_for_root(
+            &config.root_path,
+        ));
+
         Ok(Self {
             config,
             chunker,
@@ -117,6 +301,8 @@ impl<E: EmbeddingProvider> Indexer<E> {
             manifest: Arc::new(RwLock::new(IndexManifest::new())),
             bm25_index: Arc::new(RwLock::new(BM25Index::new())),
             graph_builder,
+            indexing_active: Arc::new(AtomicBool::new(false)),
+            work_queue: Arc::new(RwLock::new(work_queue)),
         })
     }
 
@@ -128,13 +314,42 @@ impl<E: EmbeddingProvider> Indexer<E> {
         manifest: IndexManifest,
         bm25_index: BM25Index,
     ) -> Result<Self> {
-        let chunker = CodeChunker::new(config.max_chunk_tokens, config.include_context)?;
+        let chunker = CodeChunker::new(config.max_chunk_tokens, config.include_context)?
+            .with_import_context(config.include_imports)
+            .with_docstring_chunks(config.index_docstrings);
+
+        // Detect stale chunk ids from an id scheme change; without a
+        // re-index, incremental updates would duplicate instead of overwrite
+        if !manifest.files.is_empty()
+            && manifest.chunk_id_scheme != crate::chunker::CHUNK_ID_SCHEME_VERSION
+        {
+            warn!(
+                "Chunk id scheme changed since last index (manifest v{}, current v{}) - \
+                 stored chunk ids are stale, run a full re-index",
+                manifest.chunk_id_scheme,
+                crate::chunker::CHUNK_ID_SCHEME_VERSION
+            );
+        }
+
+        // Detect stale vectors left behind by a model change before any work happens
+        if !manifest.embedding_config_matches(embeddings.model_name(), embeddings.dimensions()) {
+            warn!(
+                "Embedding config changed since last index (was '{}' @ {} dims, now '{}' @ {} dims) - \
+                 stored vectors are stale, run a full re-index",
+                manifest.model_name,
+                manifest.dimensions,
+                embeddings.model_name(),
+                embeddings.dimensions()
+            );
+        }
 
         // Try to initialize graph builder (non-fatal if it fails)
         let graph_builder = match GraphBuilder::new(&config.root_path) {
             Ok(gb) => {
                 info!("Graph builder initialized for knowledge graph construction");
-                Some(RwLock::new(gb))
+                Some(RwLock::new(
+                    gb.with_checkpoint_interval(config.graph_checkpoint_interval),
+                ))
             }
             Err(e) => {
                 warn!("Failed to initialize graph builder (continuing without): {}", e);
@@ -142,6 +357,10 @@ impl<E: EmbeddingProvider> Indexer<E> {
             }
         };
 
+        let work_queue = EmbeddingWorkQueue::load(EmbeddingWorkQueue::path_for_root(
+            &config.root_path,
+        ));
+
         Ok(Self {
             config,
             chunker,
@@ -150,14 +369,44 @@ impl<E: EmbeddingProvider> Indexer<E> {
             manifest: Arc::new(RwLock::new(manifest)),
             bm25_index: Arc::new(RwLock::new(bm25_index)),
             graph_builder,
+            indexing_active: Arc::new(AtomicBool::new(false)),
+            work_queue: Arc::new(RwLock::new(work_queue)),
         })
     }
 
     /// Index the entire codebase from scratch.
+    ///
+    /// Chunks are streamed into the embedding pipeline as the walker
+    /// discovers files, so search becomes partially usable before the full
+    /// scan completes. The on-disk manifest is only committed by the caller
+    /// after this returns, so a mid-scan crash cannot leave a "complete"
+    /// manifest behind.
     pub async fn index_all(&mut self, force: bool) -> Result<IndexStats> {
+        self.index_all_cancellable(force, CancellationToken::new())
+            .await
+    }
+
+    /// Index the entire codebase, aborting cleanly when `cancel` fires.
+    ///
+    /// On cancellation the walk stops, no further embedding batches are
+    /// sent, and the partial knowledge graph is still checkpointed so the
+    /// next run resumes from the manifest instead of starting over.
+    pub async fn index_all_cancellable(
+        &mut self,
+        force: bool,
+        cancel: CancellationToken,
+    ) -> Result<IndexStats> {
         let start = Instant::now();
         info!("Starting full codebase index of {:?}", self.config.root_path);
 
+        // Fail fast on a bad key or missing model before any chunking work
+        self.embeddings
+            .health_check()
+            .await
+            .context("Embedding provider is not usable; aborting before indexing")?;
+
+        let _indexing = IndexingGuard::new(self.indexing_active.clone());
+
         let mut stats = IndexStats::default();
 
         // Clear existing index if force
@@ -172,58 +421,129 @@ impl<E: EmbeddingProvider> Indexer<E> {
                     warn!("Failed to clear graph: {}", e);
                 }
             }
+            // Progress from prior runs points at vectors that no longer exist
+            if let Err(e) = self.work_queue.write().await.clear() {
+                warn!("Failed to clear embedding work queue: {}", e);
+            }
         } else {
             self.qdrant.ensure_collection().await?;
         }
 
-        // Collect all files to index
-        let files = self.collect_files()?;
-        info!("Found {} files to index", files.len());
+        // Stamp the embedding config and id scheme so the next startup can
+        // detect a model or derivation change
+        {
+            let mut manifest = self.manifest.write().await;
+            manifest.record_embedding_config(
+                self.embeddings.model_name(),
+                self.embeddings.dimensions(),
+            );
+            manifest.chunk_id_scheme = crate::chunker::CHUNK_ID_SCHEME_VERSION;
+        }
+
+        // Walk the tree and feed chunks into the embedding pipeline as files
+        // are discovered, instead of waiting for the full scan to complete
+        let walker = WalkBuilder::new(&self.config.root_path)
+            .hidden(true)
+            .git_ignore(self.config.respect_gitignore)
+            .git_global(self.config.respect_gitignore)
+            .build();
+
+        let mut pending: Vec<(Chunk, String)> = Vec::new(); // (chunk, file_hash)
+
+        for entry in walker.filter_map(|e| e.ok()) {
+            if cancel.is_cancelled() {
+                info!("Indexing cancelled; stopping walk");
+                break;
+            }
 
-        // Process files in batches
-        let mut all_chunks: Vec<(Chunk, String)> = Vec::new(); // (chunk, file_hash)
+            let path = entry.path();
+            if !self.is_indexable(path) {
+                continue;
+            }
 
-        for file_path in &files {
-            match self.process_file(file_path).await {
+            // Skip oversize or binary files before chunking
+            match self.check_skip(path) {
+                Ok(None) => {}
+                Ok(Some(reason)) => {
+                    debug!("Skipping file {:?}: {:?}", path, reason);
+                    stats.record_skip(reason);
+                    continue;
+                }
+                Err(e) => {
+                    debug!("Skipping unreadable file {:?}: {}", path, e);
+                    stats.record_skip(SkipReason::Unparseable);
+                    continue;
+                }
+            }
+
+            let file_path = path.to_path_buf();
+
+            match self.process_file(&file_path, &mut stats).await {
                 Ok((chunks, hash)) => {
                     stats.files_processed += 1;
 
                     // Add to knowledge graph if enabled
                     if let Some(ref gb) = self.graph_builder {
-                        if let Err(e) = gb.write().await.add_file(file_path, &chunks) {
+                        if let Err(e) = gb.write().await.add_file(&file_path, &chunks) {
                             debug!("Failed to add file to graph {:?}: {}", file_path, e);
                         }
                     }
 
                     for chunk in chunks {
-                        all_chunks.push((chunk, hash.clone()));
+                        pending.push((chunk, hash.clone()));
                     }
                 }
                 Err(e) => {
-                    debug!("Skipping file {:?}: {}", file_path, e);
-                    stats.files_skipped += 1;
+                    warn!("Failed to parse {:?}, continuing: {}", file_path, e);
+                    stats.record_failure(&file_path, &e.to_string());
                 }
             }
+
+            // Flush as soon as a full embedding batch has accumulated
+            if pending.len() >= self.config.embedding_batch_size {
+                stats.chunks_created += pending.len();
+                self.embed_and_upsert(&pending, &mut stats, &cancel).await?;
+                pending.clear();
+            }
         }
 
-        // Generate embeddings in batches and upsert
-        stats.chunks_created = all_chunks.len();
-        self.embed_and_upsert(&all_chunks, &mut stats).await?;
+        // Flush the remaining partial batch unless the run was cancelled
+        if !pending.is_empty() && !cancel.is_cancelled() {
+            stats.chunks_created += pending.len();
+            self.embed_and_upsert(&pending, &mut stats, &cancel).await?;
+        }
 
-        // Save the knowledge graph
+        // Save the knowledge graph (also on cancellation, so partial
+        // progress survives)
         if let Some(ref gb) = self.graph_builder {
-            if let Err(e) = gb.write().await.save() {
+            let mut gb_write = gb.write().await;
+            let linked = gb_write.link_references();
+            if self.config.enrich_blame {
+                gb_write.enrich_blame();
+            }
+            if let Err(e) = gb_write.save() {
                 warn!("Failed to save knowledge graph: {}", e);
             } else {
-                let gb_read = gb.read().await;
                 info!(
-                    "Knowledge graph updated: {} symbols, {} files",
-                    gb_read.symbol_count(),
-                    gb_read.file_count()
+                    "Knowledge graph updated: {} symbols, {} files, {} call edges linked",
+                    gb_write.symbol_count(),
+                    gb_write.file_count(),
+                    linked
                 );
             }
         }
 
+        // Stay under the configured size budget (no-op when unbounded)
+        stats.chunks_deleted += self.enforce_chunk_budget().await?;
+
+        // On clean completion the manifest is authoritative; cancelled
+        // runs keep their progress so the next run can resume
+        if !cancel.is_cancelled() {
+            if let Err(e) = self.work_queue.write().await.clear() {
+                warn!("Failed to clear embedding work queue: {}", e);
+            }
+        }
+
         stats.duration_ms = start.elapsed().as_millis() as u64;
         info!("Indexing complete: {:?}", stats);
         Ok(stats)
@@ -231,12 +551,32 @@ impl<E: EmbeddingProvider> Indexer<E> {
 
     /// Index only files that have changed since the last index.
     pub async fn index_incremental(&mut self) -> Result<IndexStats> {
+        self.index_incremental_cancellable(CancellationToken::new())
+            .await
+    }
+
+    /// Incremental index, aborting cleanly when `cancel` fires.
+    ///
+    /// Files already embedded before cancellation stay recorded in the
+    /// manifest; the rest are picked up by the next incremental run.
+    pub async fn index_incremental_cancellable(
+        &mut self,
+        cancel: CancellationToken,
+    ) -> Result<IndexStats> {
         let start = Instant::now();
         info!(
             "Starting incremental index of {:?}",
             self.config.root_path
         );
 
+        // Fail fast on a bad key or missing model before any chunking work
+        self.embeddings
+            .health_check()
+            .await
+            .context("Embedding provider is not usable; aborting before indexing")?;
+
+        let _indexing = IndexingGuard::new(self.indexing_active.clone());
+
         let mut stats = IndexStats::default();
         self.qdrant.ensure_collection().await?;
 
@@ -261,6 +601,11 @@ impl<E: EmbeddingProvider> Indexer<E> {
         let mut chunks_to_add: Vec<(Chunk, String)> = Vec::new();
 
         for file_path in &current_files {
+            if cancel.is_cancelled() {
+                info!("Incremental indexing cancelled; stopping scan");
+                break;
+            }
+
             let current_hash = Self::compute_file_hash(file_path)?;
 
             let needs_update = {
@@ -269,10 +614,25 @@ impl<E: EmbeddingProvider> Indexer<E> {
             };
 
             if needs_update {
+                // Skip oversize or binary files before chunking
+                match self.check_skip(file_path) {
+                    Ok(None) => {}
+                    Ok(Some(reason)) => {
+                        debug!("Skipping file {:?}: {:?}", file_path, reason);
+                        stats.record_skip(reason);
+                        continue;
+                    }
+                    Err(e) => {
+                        debug!("Skipping unreadable file {:?}: {}", file_path, e);
+                        stats.record_skip(SkipReason::Unparseable);
+                        continue;
+                    }
+                }
+
                 // Remove old chunks for this file
                 self.remove_file(file_path).await.ok();
 
-                match self.process_file(file_path).await {
+                match self.process_file(file_path, &mut stats).await {
                     Ok((chunks, hash)) => {
                         stats.files_processed += 1;
 
@@ -288,23 +648,40 @@ impl<E: EmbeddingProvider> Indexer<E> {
                         }
                     }
                     Err(e) => {
-                        debug!("Skipping file {:?}: {}", file_path, e);
-                        stats.files_skipped += 1;
+                        warn!("Failed to parse {:?}, continuing: {}", file_path, e);
+                        stats.record_failure(file_path, &e.to_string());
                     }
                 }
             }
         }
 
         stats.chunks_created = chunks_to_add.len();
-        self.embed_and_upsert(&chunks_to_add, &mut stats).await?;
+        self.embed_and_upsert(&chunks_to_add, &mut stats, &cancel)
+            .await?;
 
         // Save the knowledge graph
         if let Some(ref gb) = self.graph_builder {
-            if let Err(e) = gb.write().await.save() {
+            let mut gb_write = gb.write().await;
+            gb_write.link_references();
+            if self.config.enrich_blame {
+                gb_write.enrich_blame();
+            }
+            if let Err(e) = gb_write.save() {
                 warn!("Failed to save knowledge graph: {}", e);
             }
         }
 
+        // Stay under the configured size budget (no-op when unbounded)
+        stats.chunks_deleted += self.enforce_chunk_budget().await?;
+
+        // On clean completion the manifest is authoritative; cancelled
+        // runs keep their progress so the next run can resume
+        if !cancel.is_cancelled() {
+            if let Err(e) = self.work_queue.write().await.clear() {
+                warn!("Failed to clear embedding work queue: {}", e);
+            }
+        }
+
         stats.duration_ms = start.elapsed().as_millis() as u64;
         info!("Incremental indexing complete: {:?}", stats);
         Ok(stats)
@@ -312,18 +689,43 @@ impl<E: EmbeddingProvider> Indexer<E> {
 
     /// Index a single file.
     pub async fn index_file(&mut self, path: &Path) -> Result<usize> {
-        let (chunks, hash) = self.process_file(path).await?;
+        let mut stats = IndexStats::default();
+        let (chunks, hash) = self.process_file(path, &mut stats).await?;
         let chunk_count = chunks.len();
 
         let chunks_with_hash: Vec<(Chunk, String)> =
             chunks.into_iter().map(|c| (c, hash.clone())).collect();
 
-        let mut stats = IndexStats::default();
-        self.embed_and_upsert(&chunks_with_hash, &mut stats).await?;
+        self.embed_and_upsert(&chunks_with_hash, &mut stats, &CancellationToken::new())
+            .await?;
 
         Ok(chunk_count)
     }
 
+    /// Update only the knowledge graph for a changed file, skipping the
+    /// embedding/Qdrant update entirely.
+    ///
+    /// Used by the watcher's graph-eager mode: symbol navigation stays
+    /// fresh on every save while the expensive embedding work is deferred
+    /// to a debounced batch. Returns the number of chunks reflected into
+    /// the graph, or 0 when the graph is disabled.
+    pub async fn update_graph_for_file(&mut self, path: &Path) -> Result<usize> {
+        if self.graph_builder.is_none() {
+            return Ok(0);
+        }
+
+        let (chunks, _hash) = self.process_file(path, &mut IndexStats::default()).await?;
+
+        if let Some(ref gb) = self.graph_builder {
+            let mut gb = gb.write().await;
+            gb.add_file(path, &chunks)?;
+            gb.link_references();
+            gb.save()?;
+        }
+
+        Ok(chunks.len())
+    }
+
     /// Collect all files to index.
     fn collect_files(&self) -> Result<Vec<PathBuf>> {
         let mut files = Vec::new();
@@ -336,34 +738,109 @@ impl<E: EmbeddingProvider> Indexer<E> {
 
         for entry in walker.filter_map(|e| e.ok()) {
             let path = entry.path();
-            if !path.is_file() {
-                continue;
+            if self.is_indexable(path) {
+                files.push(path.to_path_buf());
             }
+        }
 
-            // Check extension
-            if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                if self.config.extensions.contains(&ext.to_string()) {
-                    // Skip common build/vendor directories
-                    let path_str = path.to_string_lossy();
-                    if !path_str.contains("/target/")
-                        && !path_str.contains("/node_modules/")
-                        && !path_str.contains("/.git/")
-                        && !path_str.contains("/vendor/")
-                        && !path_str.contains("/__pycache__/")
-                    {
-                        files.push(path.to_path_buf());
-                    }
-                }
+        Ok(files)
+    }
+
+    /// Check whether a path should be indexed.
+    ///
+    /// The file must have a configured extension and not live in a
+    /// common build/vendor directory.
+    fn is_indexable(&self, path: &Path) -> bool {
+        if !path.is_file() {
+            return false;
+        }
+
+        let Some(ext) = path.extension().and_then(|e| e.to_str()) else {
+            return false;
+        };
+        if !self.config.extensions.contains(&ext.to_string()) {
+            return false;
+        }
+
+        let path_str = path.to_string_lossy();
+        !path_str.contains("/target/")
+            && !path_str.contains("/node_modules/")
+            && !path_str.contains("/.git/")
+            && !path_str.contains("/vendor/")
+            && !path_str.contains("/__pycache__/")
+    }
+
+    /// Check whether a file should be skipped before chunking.
+    ///
+    /// Returns a reason when the file's language is excluded by config,
+    /// the file exceeds `max_file_bytes`, or it looks binary (null byte
+    /// within the first 8KB).
+    fn check_skip(&self, path: &Path) -> Result<Option<SkipReason>> {
+        if let Some(languages) = &self.config.languages {
+            let included = CodeChunker::detect_language(path)
+                .map(|lang| languages.iter().any(|l| l.eq_ignore_ascii_case(&lang)))
+                .unwrap_or(false);
+            if !included {
+                return Ok(Some(SkipReason::LanguageExcluded));
             }
         }
 
-        Ok(files)
+        let metadata = std::fs::metadata(path)?;
+        if metadata.len() > self.config.max_file_bytes {
+            return Ok(Some(SkipReason::TooLarge));
+        }
+
+        // Sniff the first 8KB for null bytes
+        use std::io::Read;
+        let mut file = std::fs::File::open(path)?;
+        let mut buf = [0u8; 8192];
+        let n = file.read(&mut buf)?;
+        if buf[..n].contains(&0) {
+            return Ok(Some(SkipReason::Binary));
+        }
+
+        Ok(None)
     }
 
     /// Process a single file: chunk it and compute hash.
-    async fn process_file(&mut self, path: &Path) -> Result<(Vec<Chunk>, String)> {
+    ///
+    /// Files whose chunk count exceeds `max_chunks_per_file` are
+    /// re-chunked coarsely: first top-level items only (dropping methods
+    /// extracted from impls and classes), and if that still exceeds the
+    /// limit, a single file-level chunk. Keeps pathological generated
+    /// files from flooding the index with thousands of tiny chunks.
+    async fn process_file(
+        &mut self,
+        path: &Path,
+        stats: &mut IndexStats,
+    ) -> Result<(Vec<Chunk>, String)> {
         let hash = Self::compute_file_hash(path)?;
-        let chunks = self.chunker.chunk_file(path)?;
+        let mut chunks = self.chunker.chunk_file(path)?;
+
+        if let Some(limit) = self.config.max_chunks_per_file {
+            if chunks.len() > limit {
+                let fine_count = chunks.len();
+                chunks.retain(|c| c.metadata.scope.is_none());
+                if chunks.len() > limit {
+                    let source = std::fs::read_to_string(path)?;
+                    let language = CodeChunker::detect_language(path)
+                        .ok_or_else(|| anyhow::anyhow!("Unsupported file type: {:?}", path))?;
+                    chunks = vec![self.chunker.whole_file_chunk(
+                        &source,
+                        &path.to_string_lossy(),
+                        &language,
+                    )];
+                }
+                warn!(
+                    "{:?} produced {} chunks (limit {}), falling back to {} coarser chunks",
+                    path,
+                    fine_count,
+                    limit,
+                    chunks.len()
+                );
+                stats.files_chunk_capped += 1;
+            }
+        }
 
         Ok((chunks, hash))
     }
@@ -377,10 +854,17 @@ impl<E: EmbeddingProvider> Indexer<E> {
     }
 
     /// Generate embeddings for chunks and upsert to Qdrant.
+    ///
+    /// The cancellation token is checked between batches: batches already
+    /// upserted stay in the index, the rest are skipped. After each
+    /// successful upsert the chunk ids are checkpointed to the persistent
+    /// work queue, so a restart after an interruption embeds only the
+    /// chunks that never made it into the store.
     async fn embed_and_upsert(
         &self,
         chunks: &[(Chunk, String)],
         stats: &mut IndexStats,
+        cancel: &CancellationToken,
     ) -> Result<()> {
         if chunks.is_empty() {
             return Ok(());
@@ -392,23 +876,87 @@ impl<E: EmbeddingProvider> Indexer<E> {
         let mut file_chunks: std::collections::HashMap<PathBuf, Vec<String>> =
             std::collections::HashMap::new();
 
-        for batch in chunks.chunks(batch_size) {
-            // Prepare texts for embedding
+        // Skip chunks a previous interrupted run already upserted; their
+        // ids still join `file_chunks` so per-file chunk lists in the
+        // manifest stay complete
+        let mut todo: Vec<&(Chunk, String)> = Vec::new();
+        {
+            let queue = self.work_queue.read().await;
+            for entry in chunks {
+                let id = entry.0.stable_id();
+                if queue.is_completed(&id) {
+                    stats.chunks_resumed += 1;
+                    file_chunks
+                        .entry(PathBuf::from(&entry.0.file_path))
+                        .or_default()
+                        .push(id);
+                } else {
+                    todo.push(entry);
+                }
+            }
+        }
+        if stats.chunks_resumed > 0 {
+            debug!(
+                "Resuming: {} of {} chunks already embedded by a previous run",
+                stats.chunks_resumed,
+                chunks.len()
+            );
+        }
+
+        for batch in todo.chunks(batch_size) {
+            if cancel.is_cancelled() {
+                debug!("Embedding cancelled; skipping remaining batches");
+                break;
+            }
+
+            // Prepare texts for embedding, redacting secrets before they
+            // leave the machine
             let texts: Vec<String> = batch
                 .iter()
-                .map(|(chunk, _)| chunk.enriched_content.clone())
+                .map(|(chunk, _)| {
+                    if self.config.redact_secrets {
+                        let (text, redacted) =
+                            crate::redaction::redact_secrets(&chunk.enriched_content);
+                        stats.secrets_redacted += redacted;
+                        text
+                    } else {
+                        chunk.enriched_content.clone()
+                    }
+                })
                 .collect();
 
             // Generate embeddings
-            let embeddings = self.embeddings.embed_batch(&texts).await?;
+            let mut embeddings = self.embeddings.embed_batch(&texts).await?;
             stats.embedding_calls += 1;
 
+            // Normalize so mixed-norm providers score consistently under
+            // cosine distance
+            if self.config.normalize_vectors {
+                for embedding in &mut embeddings {
+                    crate::qdrant::l2_normalize(embedding);
+                }
+            }
+
             // Create points for Qdrant
             let mut points = Vec::new();
             let mut bm25_index = self.bm25_index.write().await;
 
-            for ((chunk, hash), embedding) in batch.iter().zip(embeddings.into_iter()) {
-                let id = Uuid::new_v4().to_string();
+            for (((chunk, hash), embedding), text) in
+                batch.iter().zip(embeddings.into_iter()).zip(texts.into_iter())
+            {
+                let id = chunk.stable_id();
+
+                debug!(
+                    "Indexed chunk {} ({}:{}-{}): {}",
+                    chunk.metadata.name,
+                    chunk.file_path,
+                    chunk.metadata.line_start,
+                    chunk.metadata.line_end,
+                    crate::redaction::loggable_content(
+                        &chunk.content,
+                        self.config.redact_content_in_logs
+                    )
+                );
 
                 let payload = PointPayload {
                     file_path: chunk.file_path.clone(),
@@ -419,7 +967,19 @@ impl<E: EmbeddingProvider> Indexer<E> {
                     line_end: chunk.metadata.line_end,
                     module: chunk.metadata.module.clone(),
                     scope: chunk.metadata.scope.clone(),
-                    code: chunk.content.clone(),
+                    qualified_name: chunk.metadata.qualified_name.clone(),
+                    visibility: chunk.metadata.visibility.as_str().to_string(),
+                    column_start: chunk.metadata.column_start,
+                    column_end: chunk.metadata.column_end,
+                    modified_at: file_modified_at(&chunk.file_path),
+                    code: if !self.config.store_content {
+                        String::new()
+                    } else if self.config.redact_secrets {
+                        // Spans were already counted on the embedding side
+                        crate::redaction::redact_secrets(&chunk.content).0
+                    } else {
+                        chunk.content.clone()
+                    },
                 };
 
                 points.push(Point {
@@ -428,8 +988,8 @@ impl<E: EmbeddingProvider> Indexer<E> {
                     payload,
                 });
 
-                // Add to BM25 index
-                bm25_index.add_document(id.clone(), chunk.enriched_content.clone());
+                // Add to BM25 index (same redacted text that was embedded)
+                bm25_index.add_document(id.clone(), text);
 
                 // Track chunk IDs per file
                 let file_path = PathBuf::from(&chunk.file_path);
@@ -446,6 +1006,16 @@ impl<E: EmbeddingProvider> Indexer<E> {
 
             // Upsert to Qdrant
             self.qdrant.upsert_points(points).await?;
+
+            // Checkpoint progress so an interruption after this batch
+            // resumes without re-embedding it
+            {
+                let mut queue = self.work_queue.write().await;
+                queue.record_completed(batch.iter().map(|(chunk, _)| chunk.stable_id()));
+                if let Err(e) = queue.persist() {
+                    debug!("Failed to persist embedding work queue: {}", e);
+                }
+            }
         }
 
         // Update manifest with all chunk IDs
@@ -460,6 +1030,61 @@ impl<E: EmbeddingProvider> Indexer<E> {
         Ok(())
     }
 
+    /// Record that chunks from these files were returned by a search.
+    ///
+    /// Keeps the manifest's access times fresh so LRU eviction under
+    /// `max_chunks` spares the files the agent actually uses.
+    pub async fn mark_accessed(&self, paths: &[PathBuf]) {
+        let mut manifest = self.manifest.write().await;
+        for path in paths {
+            if manifest.files.contains_key(path) {
+                manifest.touch(path);
+            } else {
+                // Search results carry root-relative paths
+                manifest.touch(&self.config.root_path.join(path));
+            }
+        }
+    }
+
+    /// Evict least-recently-searched files until the index fits the
+    /// configured `max_chunks` budget.
+    ///
+    /// Never-searched files go first. Chunks are removed from Qdrant, BM25
+    /// and the manifest; the files are re-indexed normally if they are
+    /// touched again. Returns the number of chunks evicted (0 when no
+    /// budget is configured).
+    pub async fn enforce_chunk_budget(&self) -> Result<usize> {
+        let Some(max_chunks) = self.config.max_chunks else {
+            return Ok(0);
+        };
+
+        let victims = self.manifest.read().await.select_for_eviction(max_chunks);
+        if victims.is_empty() {
+            return Ok(0);
+        }
+
+        let mut evicted = 0;
+        for path in &victims {
+            let state = { self.manifest.write().await.remove_file(path) };
+            if let Some(state) = state {
+                evicted += state.chunk_count;
+                self.qdrant.delete_points(state.chunk_ids.clone()).await?;
+                let mut bm25 = self.bm25_index.write().await;
+                for id in &state.chunk_ids {
+                    bm25.remove_document(id);
+                }
+            }
+        }
+
+        info!(
+            "Evicted {} chunks from {} files to stay under the {}-chunk budget",
+            evicted,
+            victims.len(),
+            max_chunks
+        );
+        Ok(evicted)
+    }
+
     /// Remove a file from the index.
     pub async fn remove_file(&self, path: &Path) -> Result<()> {
         debug!("Removing file from index: {:?}", path);
@@ -509,11 +1134,7 @@ impl<E: EmbeddingProvider> Indexer<E> {
         IndexStats {
             files_processed: manifest.files.len(),
             chunks_created: chunks_count,
-            chunks_updated: 0,
-            chunks_deleted: 0,
-            files_skipped: 0,
-            duration_ms: 0,
-            embedding_calls: 0,
+            ..Default::default()
         }
     }
 
@@ -522,6 +1143,20 @@ impl<E: EmbeddingProvider> Indexer<E> {
         &self.config
     }
 
+    /// Report the `limit` largest stored chunks, ranked by the given sort key.
+    ///
+    /// Scrolls all payloads from Qdrant, so this is a diagnostic rather
+    /// than a hot-path operation.
+    pub async fn chunk_report(
+        &self,
+        limit: usize,
+        sort: crate::chunk_report::ChunkSortKey,
+    ) -> Result<Vec<crate::chunk_report::ChunkReportEntry>> {
+        let payloads = self.qdrant.scroll_payloads(256).await?;
+        let entries = payloads.iter().map(Into::into).collect();
+        Ok(crate::chunk_report::top_chunks(entries, limit, sort))
+    }
+
     /// Get access to the knowledge graph builder (if enabled).
     pub fn graph_builder(&self) -> Option<&RwLock<GraphBuilder>> {
         self.graph_builder.as_ref()
@@ -531,6 +1166,32 @@ impl<E: EmbeddingProvider> Indexer<E> {
     pub fn has_graph(&self) -> bool {
         self.graph_builder.is_some()
     }
+
+    /// Check whether an indexing run is currently in progress.
+    pub fn is_indexing(&self) -> bool {
+        self.indexing_active.load(Ordering::SeqCst)
+    }
+
+    /// Get a shared handle to the indexing-in-progress flag.
+    ///
+    /// The flag can be read without taking the indexer lock, so callers
+    /// can warn about potentially incomplete results while a run holds
+    /// the write lock.
+    pub fn indexing_flag(&self) -> Arc<AtomicBool> {
+        self.indexing_active.clone()
+    }
+}
+
+/// Last-modified time of a file as unix seconds, for the point payload.
+///
+/// Best-effort: None when the file is gone or the filesystem doesn't
+/// report modification times.
+fn file_modified_at(file_path: &str) -> Option<i64> {
+    std::fs::metadata(file_path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
 }
 
 #[cfg(test)]
@@ -545,6 +1206,24 @@ mod tests {
         assert_eq!(config.embedding_batch_size, 32);
         assert!(config.respect_gitignore);
         assert_eq!(config.extensions.len(), 5);
+        assert!(config.languages.is_none());
+        assert_eq!(config.max_file_bytes, DEFAULT_MAX_FILE_BYTES);
+        assert_eq!(
+            config.max_chunks_per_file,
+            Some(DEFAULT_MAX_CHUNKS_PER_FILE)
+        );
+        assert!(config.store_content);
+        assert!(config.redact_secrets);
+        assert!(config.redact_content_in_logs);
+        assert_eq!(config.backend, crate::qdrant::VectorBackend::Qdrant);
+        assert_eq!(
+            config.embedding_request_timeout_secs,
+            crate::embeddings::DEFAULT_REQUEST_TIMEOUT_SECS
+        );
+        assert_eq!(
+            config.embedding_connect_timeout_secs,
+            crate::embeddings::DEFAULT_CONNECT_TIMEOUT_SECS
+        );
     }
 
     #[test]
@@ -557,6 +1236,101 @@ mod tests {
         assert_eq!(stats.files_skipped, 0);
         assert_eq!(stats.duration_ms, 0);
         assert_eq!(stats.embedding_calls, 0);
+        assert!(stats.files_failed.is_empty());
+    }
+
+    #[test]
+    fn test_record_failure_tracks_file_and_error() {
+        let mut stats = IndexStats::default();
+        stats.record_failure(Path::new("src/bad.rs"), "invalid utf-8 sequence");
+
+        assert_eq!(stats.files_skipped, 1);
+        assert_eq!(stats.files_failed.len(), 1);
+        assert_eq!(stats.files_failed[0].path, PathBuf::from("src/bad.rs"));
+        assert!(stats.files_failed[0].error.contains("invalid utf-8"));
+    }
+
+    #[tokio::test]
+    async fn test_parse_failure_is_per_file() {
+        use crate::qdrant::QdrantConfig;
+        use std::io::Write;
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let temp = tempfile::tempdir().unwrap();
+        let config = IndexerConfig {
+            root_path: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let mut indexer = Indexer::new(config, Arc::new(MockEmbeddingProvider), qdrant).unwrap();
+
+        // Invalid UTF-8 makes chunking fail for this file only
+        let bad_path = temp.path().join("bad.rs");
+        let mut f = std::fs::File::create(&bad_path).unwrap();
+        f.write_all(&[0x66, 0x6e, 0x20, 0xC3, 0x28]).unwrap();
+        let mut stats = IndexStats::default();
+        assert!(indexer.process_file(&bad_path, &mut stats).await.is_err());
+
+        // The neighboring good file still chunks fine
+        let good_path = temp.path().join("good.rs");
+        std::fs::write(&good_path, "fn main() {}\n").unwrap();
+        let (_, hash) = indexer.process_file(&good_path, &mut stats).await.unwrap();
+        assert_eq!(hash.len(), 64);
+    }
+
+    #[tokio::test]
+    async fn test_chunk_limit_falls_back_to_coarser_chunks() {
+        use crate::chunker::ChunkType;
+        use crate::qdrant::QdrantConfig;
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let temp = tempfile::tempdir().unwrap();
+        let config = IndexerConfig {
+            root_path: temp.path().to_path_buf(),
+            max_chunks_per_file: Some(10),
+            ..Default::default()
+        };
+        let mut indexer = Indexer::new(config, Arc::new(MockEmbeddingProvider), qdrant).unwrap();
+        let mut stats = IndexStats::default();
+
+        // Too many methods: dropping to top-level items is enough
+        let mut source = String::from("struct Generated;\nimpl Generated {\n");
+        for i in 0..30 {
+            source.push_str(&format!("    fn m{}(&self) {{}}\n", i));
+        }
+        source.push_str("}\n");
+        let methods_path = temp.path().join("methods.rs");
+        std::fs::write(&methods_path, &source).unwrap();
+
+        let (chunks, _) = indexer
+            .process_file(&methods_path, &mut stats)
+            .await
+            .unwrap();
+        assert!(chunks.len() <= 10, "got {} chunks", chunks.len());
+        assert!(chunks.iter().all(|c| c.metadata.scope.is_none()));
+        assert_eq!(stats.files_chunk_capped, 1);
+
+        // Too many top-level functions: the whole file becomes one chunk
+        let mut source = String::new();
+        for i in 0..30 {
+            source.push_str(&format!("fn f{}() {{}}\n", i));
+        }
+        let flat_path = temp.path().join("functions.rs");
+        std::fs::write(&flat_path, &source).unwrap();
+
+        let (chunks, _) = indexer
+            .process_file(&flat_path, &mut stats)
+            .await
+            .unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].metadata.chunk_type, ChunkType::Module);
+        assert_eq!(chunks[0].metadata.name, "functions");
+        assert_eq!(chunks[0].metadata.line_start, 1);
+        assert_eq!(chunks[0].metadata.line_end, 30);
+        assert_eq!(stats.files_chunk_capped, 2);
     }
 
     #[test]
@@ -571,6 +1345,120 @@ mod tests {
         assert_eq!(hash.len(), 64); // SHA256 hex is 64 chars
     }
 
+    #[tokio::test]
+    async fn test_check_skip_oversize_and_binary() {
+        use crate::qdrant::QdrantConfig;
+        use std::io::Write;
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let temp = tempfile::tempdir().unwrap();
+        let config = IndexerConfig {
+            root_path: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let indexer = Indexer::new(config, Arc::new(MockEmbeddingProvider), qdrant).unwrap();
+
+        // A 1MB file exceeds the 512KB default limit
+        let big_path = temp.path().join("generated.rs");
+        std::fs::write(&big_path, vec![b'a'; 1024 * 1024]).unwrap();
+        assert_eq!(
+            indexer.check_skip(&big_path).unwrap(),
+            Some(SkipReason::TooLarge)
+        );
+
+        // Embedded null bytes mark a file as binary
+        let bin_path = temp.path().join("blob.rs");
+        let mut f = std::fs::File::create(&bin_path).unwrap();
+        f.write_all(b"fn main() {}\0\0binary").unwrap();
+        assert_eq!(
+            indexer.check_skip(&bin_path).unwrap(),
+            Some(SkipReason::Binary)
+        );
+
+        // A normal source file passes
+        let ok_path = temp.path().join("ok.rs");
+        std::fs::write(&ok_path, "fn main() {}\n").unwrap();
+        assert_eq!(indexer.check_skip(&ok_path).unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_language_filter_skips_excluded_files() {
+        use crate::qdrant::QdrantConfig;
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let temp = tempfile::tempdir().unwrap();
+        let config = IndexerConfig {
+            root_path: temp.path().to_path_buf(),
+            languages: Some(vec!["rust".to_string()]),
+            ..Default::default()
+        };
+        let indexer = Indexer::new(config, Arc::new(MockEmbeddingProvider), qdrant).unwrap();
+
+        let py_path = temp.path().join("script.py");
+        std::fs::write(&py_path, "def main():\n    pass\n").unwrap();
+        assert_eq!(
+            indexer.check_skip(&py_path).unwrap(),
+            Some(SkipReason::LanguageExcluded)
+        );
+
+        let rs_path = temp.path().join("main.rs");
+        std::fs::write(&rs_path, "fn main() {}\n").unwrap();
+        assert_eq!(indexer.check_skip(&rs_path).unwrap(), None);
+    }
+
+    #[test]
+    fn test_skip_reason_recording() {
+        let mut stats = IndexStats::default();
+        stats.record_skip(SkipReason::TooLarge);
+        stats.record_skip(SkipReason::Binary);
+        stats.record_skip(SkipReason::Unparseable);
+
+        assert_eq!(stats.files_skipped, 3);
+        assert_eq!(stats.files_skipped_oversize, 1);
+        assert_eq!(stats.files_skipped_binary, 1);
+    }
+
+    #[test]
+    fn test_indexing_guard_resets_flag_on_drop() {
+        let flag = Arc::new(AtomicBool::new(false));
+        {
+            let _guard = IndexingGuard::new(flag.clone());
+            assert!(flag.load(Ordering::SeqCst));
+        }
+        assert!(!flag.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_is_indexing_flag_with_mock_provider() {
+        use crate::qdrant::QdrantConfig;
+
+        // QdrantClient construction is lazy, so no server is needed here
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let temp = tempfile::tempdir().unwrap();
+        let config = IndexerConfig {
+            root_path: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let indexer = Indexer::new(config, Arc::new(MockEmbeddingProvider), qdrant).unwrap();
+
+        assert!(!indexer.is_indexing());
+
+        // The shared flag handle reflects an active run without needing
+        // to lock the indexer itself
+        let flag = indexer.indexing_flag();
+        {
+            let _guard = IndexingGuard::new(flag.clone());
+            assert!(indexer.is_indexing());
+        }
+        assert!(!indexer.is_indexing());
+    }
+
     // Mock embedding provider for tests
     struct MockEmbeddingProvider;
 
@@ -592,4 +1480,292 @@ mod tests {
             "mock"
         }
     }
+
+    // Mock provider that counts embed_batch calls
+    struct CountingEmbeddingProvider {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for CountingEmbeddingProvider {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![0.0; 4096])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(texts.iter().map(|_| vec![0.0; 4096]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            4096
+        }
+
+        fn model_name(&self) -> &str {
+            "counting-mock"
+        }
+    }
+
+    fn make_test_chunk(name: &str) -> Chunk {
+        use crate::chunker::{ChunkMetadata, ChunkType};
+        Chunk {
+            file_path: "src/lib.rs".to_string(),
+            content: format!("fn {}() {{}}", name),
+            enriched_content: format!("fn {}() {{}}", name),
+            metadata: ChunkMetadata {
+                chunk_type: ChunkType::Function,
+                name: name.to_string(),
+                signature: Some(format!("fn {}()", name)),
+                line_start: 1,
+                line_end: 1,
+                column_start: 4,
+                column_end: 3 + name.len(),
+                module: None,
+                scope: None,
+                visibility: crate::chunker::Visibility::Public,
+                qualified_name: name.to_string(),
+                content_hash: "hash".to_string(),
+                language: "rust".to_string(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_stops_embedding_calls() {
+        use crate::qdrant::QdrantConfig;
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let temp = tempfile::tempdir().unwrap();
+        let config = IndexerConfig {
+            root_path: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let provider = Arc::new(CountingEmbeddingProvider {
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let indexer = Indexer::new(config, provider.clone(), qdrant).unwrap();
+
+        let chunks: Vec<(Chunk, String)> = vec![
+            (make_test_chunk("first"), "hash".to_string()),
+            (make_test_chunk("second"), "hash".to_string()),
+        ];
+
+        // A cancelled token is checked before every batch, so no
+        // embedding calls go out
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let mut stats = IndexStats::default();
+        indexer
+            .embed_and_upsert(&chunks, &mut stats, &cancel)
+            .await
+            .unwrap();
+
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 0);
+        assert_eq!(stats.embedding_calls, 0);
+    }
+
+    // Mock provider that records every text it embeds
+    struct RecordingEmbeddingProvider {
+        embedded: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for RecordingEmbeddingProvider {
+        async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+            self.embedded.lock().unwrap().push(text.to_string());
+            Ok(vec![0.0; 4096])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            self.embedded.lock().unwrap().extend(texts.iter().cloned());
+            Ok(texts.iter().map(|_| vec![0.0; 4096]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            4096
+        }
+
+        fn model_name(&self) -> &str {
+            "recording-mock"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resume_embeds_only_remaining_chunks() {
+        use crate::memory_store::InMemoryVectorStore;
+
+        let temp = tempfile::tempdir().unwrap();
+        let config = IndexerConfig {
+            root_path: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        // Both "runs" talk to the same store, like a persistent Qdrant server
+        let store = Arc::new(RwLock::new(InMemoryVectorStore::new(4096)));
+
+        let chunks: Vec<(Chunk, String)> = ["first", "second", "third", "fourth"]
+            .iter()
+            .map(|name| (make_test_chunk(name), "hash".to_string()))
+            .collect();
+
+        // First run is interrupted after upserting two of the four chunks
+        let first = Indexer::new(
+            config.clone(),
+            Arc::new(MockEmbeddingProvider),
+            QdrantClient::with_store("test-resume", 4096, store.clone()),
+        )
+        .unwrap();
+        let mut stats = IndexStats::default();
+        first
+            .embed_and_upsert(&chunks[..2], &mut stats, &CancellationToken::new())
+            .await
+            .unwrap();
+        assert_eq!(stats.chunks_resumed, 0);
+
+        // A fresh indexer (as after a restart) loads the queue from disk
+        // and only embeds the chunks the interrupted run never reached
+        let provider = Arc::new(RecordingEmbeddingProvider {
+            embedded: std::sync::Mutex::new(Vec::new()),
+        });
+        let second = Indexer::new(
+            config,
+            provider.clone(),
+            QdrantClient::with_store("test-resume", 4096, store),
+        )
+        .unwrap();
+        let mut stats = IndexStats::default();
+        second
+            .embed_and_upsert(&chunks, &mut stats, &CancellationToken::new())
+            .await
+            .unwrap();
+
+        assert_eq!(stats.chunks_resumed, 2);
+        let embedded = provider.embedded.lock().unwrap();
+        assert_eq!(embedded.len(), 2);
+        assert!(embedded[0].contains("third"));
+        assert!(embedded[1].contains("fourth"));
+
+        // All four vectors are in the store despite the interruption
+        let ids: Vec<String> = chunks.iter().map(|(c, _)| c.stable_id()).collect();
+        let vectors = second.qdrant.get_vectors(&ids).await.unwrap();
+        assert_eq!(vectors.len(), 4);
+    }
+
+    // Mock provider returning non-unit vectors, as some providers do
+    struct NonUnitEmbeddingProvider;
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for NonUnitEmbeddingProvider {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(non_unit_vector())
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| non_unit_vector()).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            4096
+        }
+
+        fn model_name(&self) -> &str {
+            "non-unit-mock"
+        }
+    }
+
+    fn non_unit_vector() -> Vec<f32> {
+        let mut vector = vec![0.0; 4096];
+        vector[0] = 3.0;
+        vector[1] = 4.0;
+        vector
+    }
+
+    #[tokio::test]
+    async fn test_non_unit_vectors_normalized_before_storage() {
+        let temp = tempfile::tempdir().unwrap();
+        let config = IndexerConfig {
+            root_path: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        assert!(config.normalize_vectors);
+
+        let qdrant = QdrantClient::in_memory("test-normalize", 4096);
+        let indexer =
+            Indexer::new(config, Arc::new(NonUnitEmbeddingProvider), qdrant).unwrap();
+
+        let chunk = make_test_chunk("normalized");
+        let id = chunk.stable_id();
+        let chunks = vec![(chunk, "hash".to_string())];
+
+        let mut stats = IndexStats::default();
+        indexer
+            .embed_and_upsert(&chunks, &mut stats, &CancellationToken::new())
+            .await
+            .unwrap();
+
+        // The stored vector is unit length: [3, 4, 0, ...] -> [0.6, 0.8, 0, ...]
+        let vectors = indexer.qdrant.get_vectors(&[id.clone()]).await.unwrap();
+        let stored = vectors.get(&id).unwrap();
+        assert!((stored[0] - 0.6).abs() < 1e-6);
+        assert!((stored[1] - 0.8).abs() < 1e-6);
+        let norm: f32 = stored.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_chunk_budget_evicts_least_recently_searched_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let config = IndexerConfig {
+            root_path: temp.path().to_path_buf(),
+            max_chunks: Some(2),
+            ..Default::default()
+        };
+        let qdrant = QdrantClient::in_memory("test-budget", 4096);
+
+        let mut manifest = IndexManifest::new();
+        manifest.record_indexed(
+            PathBuf::from("stale.rs"),
+            "h1".to_string(),
+            vec!["c1".to_string(), "c2".to_string()],
+        );
+        manifest.record_indexed(
+            PathBuf::from("hot.rs"),
+            "h2".to_string(),
+            vec!["c3".to_string(), "c4".to_string()],
+        );
+        // hot.rs was searched; stale.rs never was
+        manifest.touch(Path::new("hot.rs"));
+
+        let mut bm25 = BM25Index::new();
+        for id in ["c1", "c2", "c3", "c4"] {
+            bm25.add_document(id.to_string(), format!("fn {}()", id));
+        }
+
+        let indexer = Indexer::with_state(
+            config,
+            Arc::new(MockEmbeddingProvider),
+            qdrant,
+            manifest,
+            bm25,
+        )
+        .unwrap();
+
+        let evicted = indexer.enforce_chunk_budget().await.unwrap();
+        assert_eq!(evicted, 2);
+
+        // The never-searched file was evicted, the searched one kept
+        let manifest = indexer.manifest().await;
+        assert!(!manifest.files.contains_key(Path::new("stale.rs")));
+        assert!(manifest.files.contains_key(Path::new("hot.rs")));
+        assert_eq!(manifest.total_chunks, 2);
+
+        // BM25 documents for evicted chunks are gone too
+        assert_eq!(indexer.bm25_index().read().await.len(), 2);
+
+        // A second pass is a no-op: already within budget
+        assert_eq!(indexer.enforce_chunk_budget().await.unwrap(), 0);
+    }
 }
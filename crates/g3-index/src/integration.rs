@@ -78,6 +78,12 @@ impl EnrichmentConfig {
     }
 }
 
+/// Cap on neighbor names attached to enriched result metadata.
+const MAX_NEIGHBORS_IN_METADATA: usize = 10;
+
+/// Score decay applied per hop when expanding seed hits through the graph.
+const GRAPH_EXPANSION_DECAY: f32 = 0.5;
+
 /// connector between LSP results and indexed chunks.
 #[derive(Debug, Clone)]
 pub struct IndexConnector {
@@ -239,6 +245,27 @@ impl IndexConnector {
                         "symbol_callees_count".to_string(),
                         serde_json::to_value(callees.len()).unwrap_or_default(),
                     );
+
+                    // Attach the direct neighborhood (bounded) so the common
+                    // "find it then explore it" flow needs no extra round-trip
+                    let caller_names: Vec<&str> = callers
+                        .iter()
+                        .take(MAX_NEIGHBORS_IN_METADATA)
+                        .filter_map(|id| self.graph.symbols.get(id).map(|s| s.name.as_str()))
+                        .collect();
+                    let callee_names: Vec<&str> = callees
+                        .iter()
+                        .take(MAX_NEIGHBORS_IN_METADATA)
+                        .filter_map(|id| self.graph.symbols.get(id).map(|s| s.name.as_str()))
+                        .collect();
+                    result.metadata.insert(
+                        "symbol_callers".to_string(),
+                        serde_json::to_value(caller_names).unwrap_or_default(),
+                    );
+                    result.metadata.insert(
+                        "symbol_callees".to_string(),
+                        serde_json::to_value(callee_names).unwrap_or_default(),
+                    );
                 }
             }
         }
@@ -410,6 +437,95 @@ impl IndexConnector {
         results
     }
 
+    /// Expand semantic seed hits through the graph (the
+    /// [`CrossIndexStrategy::SemanticThenGraph`] strategy).
+    ///
+    /// Seeds are resolved to graph symbols (by attached `symbol_id`, falling
+    /// back to a name lookup), then their callers and callees are reached by
+    /// breadth-first traversal up to `depth` hops. Each hop decays the score
+    /// by [`GRAPH_EXPANSION_DECAY`], so graph-reached symbols rank below the
+    /// hits that led to them. Answers "find the auth code and everything
+    /// that touches it".
+    pub fn expand_semantic_with_graph(
+        &self,
+        seeds: Vec<UnifiedSearchResult>,
+        depth: usize,
+    ) -> Vec<UnifiedSearchResult> {
+        // Best known score per symbol, seeded so expansion never re-adds a
+        // symbol the semantic search already found
+        let mut best_scores: HashMap<String, f32> = HashMap::new();
+        let mut frontier: Vec<(String, f32)> = Vec::new();
+
+        for seed in &seeds {
+            let symbol_id = seed.symbol_id.clone().or_else(|| {
+                seed.name.as_ref().and_then(|name| {
+                    self.graph
+                        .find_symbols_by_name(name)
+                        .first()
+                        .map(|s| s.id.clone())
+                })
+            });
+            if let Some(id) = symbol_id {
+                best_scores.insert(id.clone(), seed.score);
+                frontier.push((id, seed.score));
+            }
+        }
+
+        let mut reached: HashMap<String, f32> = HashMap::new();
+        for _hop in 0..depth {
+            let mut next_frontier = Vec::new();
+            for (id, score) in frontier {
+                let decayed = score * GRAPH_EXPANSION_DECAY;
+                let neighbors = self
+                    .graph
+                    .find_callers(&id)
+                    .into_iter()
+                    .chain(self.graph.find_callees(&id));
+                for neighbor in neighbors {
+                    if best_scores.get(&neighbor).is_some_and(|s| *s >= decayed) {
+                        continue;
+                    }
+                    best_scores.insert(neighbor.clone(), decayed);
+                    reached.insert(neighbor.clone(), decayed);
+                    next_frontier.push((neighbor, decayed));
+                }
+            }
+            frontier = next_frontier;
+            if frontier.is_empty() {
+                break;
+            }
+        }
+
+        let mut results = seeds;
+        for (id, score) in reached {
+            if let Some(symbol) = self.graph.symbols.get(&id) {
+                let mut result = UnifiedSearchResult::from_graph(
+                    id.clone(),
+                    symbol.file_id.clone(),
+                    symbol.line_start,
+                    symbol.line_end,
+                    String::new(),
+                    symbol.kind.label(),
+                    Some(symbol.name.clone()),
+                    symbol.signature.clone(),
+                    None,
+                    score,
+                    None,
+                    None,
+                );
+                result.symbol_id = Some(id);
+                results.push(result);
+            }
+        }
+
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        results
+    }
+
     /// Get the knowledge graph reference.
     pub fn graph(&self) -> &CodeGraph {
         &self.graph
@@ -448,6 +564,9 @@ pub enum CrossIndexStrategy {
     Ast,
     /// Graph-based traversal
     Graph,
+    /// Semantic search seeds symbols, then the graph expands to their
+    /// callers/callees with per-hop score decay
+    SemanticThenGraph,
 }
 
 impl CrossIndexQuery {
@@ -516,6 +635,117 @@ mod tests {
         assert_eq!(config.max_snippet_length, 1000);
     }
 
+    #[test]
+    fn test_enrich_result_attaches_neighbor_names() {
+        use crate::graph::{Edge, FileNode, SymbolKind, SymbolNode};
+
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/lib.rs", "rust"));
+
+        let caller = SymbolNode::new("handle_request", SymbolKind::Function, "src/lib.rs", 5);
+        let target = SymbolNode::new("validate", SymbolKind::Function, "src/lib.rs", 20);
+        let callee = SymbolNode::new("parse_token", SymbolKind::Function, "src/lib.rs", 40);
+        let caller_id = caller.id.clone();
+        let target_id = target.id.clone();
+        let callee_id = callee.id.clone();
+        graph.add_symbol(caller);
+        graph.add_symbol(target);
+        graph.add_symbol(callee);
+        graph.add_edge(Edge::new(&caller_id, &target_id, EdgeKind::Calls));
+        graph.add_edge(Edge::new(&target_id, &callee_id, EdgeKind::Calls));
+
+        let connector = IndexConnector::with_graph(graph);
+        let result = UnifiedSearchResult::from_vector(
+            "c1",
+            "src/lib.rs",
+            20,
+            30,
+            "fn validate() {}",
+            "function",
+            Some("validate".to_string()),
+            None,
+            None,
+            0.9,
+        );
+
+        let enriched = connector.enrich_result(result, false);
+
+        assert_eq!(
+            enriched.metadata["symbol_callers"],
+            serde_json::json!(["handle_request"])
+        );
+        assert_eq!(
+            enriched.metadata["symbol_callees"],
+            serde_json::json!(["parse_token"])
+        );
+        assert_eq!(enriched.metadata["symbol_callers_count"], serde_json::json!(1));
+    }
+
+    #[test]
+    fn test_semantic_then_graph_expansion() {
+        use crate::graph::{Edge, FileNode, SymbolKind, SymbolNode};
+
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/auth.rs", "rust"));
+
+        let auth = SymbolNode::new("authenticate", SymbolKind::Function, "src/auth.rs", 10);
+        let login = SymbolNode::new("login_handler", SymbolKind::Function, "src/auth.rs", 50);
+        let route = SymbolNode::new("route_request", SymbolKind::Function, "src/auth.rs", 90);
+        let auth_id = auth.id.clone();
+        let login_id = login.id.clone();
+        let route_id = route.id.clone();
+        graph.add_symbol(auth);
+        graph.add_symbol(login);
+        graph.add_symbol(route);
+        // route_request -> login_handler -> authenticate
+        graph.add_edge(Edge::new(&login_id, &auth_id, EdgeKind::Calls));
+        graph.add_edge(Edge::new(&route_id, &login_id, EdgeKind::Calls));
+
+        let connector = IndexConnector::with_graph(graph);
+
+        let mut seed = UnifiedSearchResult::from_vector(
+            "c1",
+            "src/auth.rs",
+            10,
+            30,
+            "fn authenticate() {}",
+            "function",
+            Some("authenticate".to_string()),
+            None,
+            None,
+            0.8,
+        );
+        seed.symbol_id = Some(auth_id.clone());
+
+        let results = connector.expand_semantic_with_graph(vec![seed], 2);
+
+        // Seed first, then one-hop caller, then two-hop caller
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].name.as_deref(), Some("authenticate"));
+        assert_eq!(results[0].score, 0.8);
+        assert_eq!(results[1].name.as_deref(), Some("login_handler"));
+        assert!((results[1].score - 0.4).abs() < 1e-6);
+        assert_eq!(results[2].name.as_deref(), Some("route_request"));
+        assert!((results[2].score - 0.2).abs() < 1e-6);
+
+        // Depth 1 stops after direct neighbors
+        let mut seed = UnifiedSearchResult::from_vector(
+            "c1",
+            "src/auth.rs",
+            10,
+            30,
+            "fn authenticate() {}",
+            "function",
+            Some("authenticate".to_string()),
+            None,
+            None,
+            0.8,
+        );
+        seed.symbol_id = Some(auth_id);
+        let results = connector.expand_semantic_with_graph(vec![seed], 1);
+        assert_eq!(results.len(), 2);
+    }
+
     #[test]
     fn test_index_connector_new() {
         let graph = CodeGraph::new();
@@ -9,37 +9,53 @@
 //! - Knowledge graph for code symbols, files, and cross-references
 //! - Persistence layer with incremental updates
 
+pub mod blame;
+pub mod chunk_report;
 pub mod chunker;
 pub mod embeddings;
 pub mod graph;
 pub mod graph_builder;
+pub mod graph_query;
 pub mod indexer;
 pub mod integration;
 pub mod manifest;
+pub mod memory_store;
 pub mod qdrant;
+pub mod redaction;
 pub mod reranker;
 pub mod search;
 pub mod storage;
 pub mod traverser;
 pub mod unified_index;
 pub mod watcher;
+pub mod work_queue;
 
 // Re-exports
-pub use chunker::{Chunk, ChunkMetadata, CodeChunker};
-pub use embeddings::EmbeddingProvider;
-pub use graph::{CodeGraph, Edge, EdgeKind, FileNode, GraphError, SymbolKind, SymbolNode};
+pub use blame::{blame_file, most_recent_author, BlameLine};
+pub use chunk_report::{top_chunks, ChunkReportEntry, ChunkSortKey};
+pub use chunker::{Chunk, ChunkMetadata, CodeChunker, Visibility, CHUNK_ID_SCHEME_VERSION};
+pub use embeddings::{AdaptiveBatchConfig, AdaptiveBatchSizer, EmbeddingProvider, RetryConfig};
+pub use graph::{
+    CodeGraph, Edge, EdgeKind, EntrypointKind, FileNode, GraphDiff, GraphError, GraphStats,
+    Neighborhood, OutlineNode, SignatureChange, SymbolKind, SymbolNode,
+    INTERCHANGE_SCHEMA_VERSION,
+};
 pub use graph_builder::GraphBuilder;
-pub use indexer::{Indexer, IndexerConfig, IndexStats};
+pub use graph_query::{parse_query, run_query, QueryExpr, QueryParseError};
+pub use indexer::{FailedFile, Indexer, IndexerConfig, IndexStats, SkipReason, DEFAULT_MAX_FILE_BYTES};
 pub use manifest::IndexManifest;
-pub use search::{BM25Index, HybridSearcher, SearchConfig, SearchResult, reciprocal_rank_fusion};
+pub use memory_store::InMemoryVectorStore;
+pub use redaction::{loggable_content, redact_secrets, REDACTION_MARKER};
+pub use search::{BM25Index, FusionMethod, HybridSearcher, MultiCollectionSearcher, ScoreExplanation, SearchConfig, SearchPreset, SearchResult, SimilarityExclusion, SimilarityMetric, reciprocal_rank_fusion};
 pub use storage::{
     DEFAULT_GRAPH_DIR, FileIndex, FileIndexEntry, GraphStorage, ScannedFile, SnapshotMetadata,
     UpdateStats,
 };
-pub use reranker::{ChatReranker, Reranker, RerankerDoc, RerankResult};
-pub use unified_index::{UnifiedIndex, UnifiedSearchResult, UnifiedSearchSource, QueryPlanner, QueryPlan, QueryStrategy};
-pub use traverser::{GraphTraverser, TraversalConfig, TraversalResult};
+pub use reranker::{ChatReranker, EnsembleReranker, Reranker, RerankerDoc, RerankResult};
+pub use unified_index::{UnifiedIndex, UnifiedSearchOutcome, UnifiedSearchResult, UnifiedSearchSource, QueryPlanner, QueryPlan, QueryStrategy};
+pub use traverser::{GraphTraverser, ModuleSubtree, SubtreeNode, TraversalConfig, TraversalResult};
 pub use integration::{CrossIndexQuery, CrossIndexStrategy, IndexConnector, EnrichmentConfig};
+pub use work_queue::EmbeddingWorkQueue;
 
 /// Default Qdrant collection name
 pub const DEFAULT_COLLECTION: &str = "g3-codebase";
@@ -23,6 +23,21 @@ pub struct IndexManifest {
 
     /// Total number of chunks in the index
     pub total_chunks: usize,
+
+    /// Embedding model that produced the stored vectors
+    /// (empty for manifests written before model tracking)
+    #[serde(default)]
+    pub model_name: String,
+
+    /// Embedding dimensions of the stored vectors (0 when unknown)
+    #[serde(default)]
+    pub dimensions: usize,
+
+    /// Chunk id scheme version the stored ids were derived with
+    /// (0 for manifests predating stable chunk ids).
+    /// See [`crate::chunker::CHUNK_ID_SCHEME_VERSION`].
+    #[serde(default)]
+    pub chunk_id_scheme: u32,
 }
 
 /// State of an indexed file.
@@ -39,6 +54,12 @@ pub struct FileState {
 
     /// IDs of chunks in Qdrant for this file
     pub chunk_ids: Vec<String>,
+
+    /// When chunks from this file were last returned by a search
+    /// (None until first accessed; such files are evicted first
+    /// when the index runs under a size budget)
+    #[serde(default)]
+    pub last_accessed: Option<SystemTime>,
 }
 
 impl IndexManifest {
@@ -49,6 +70,9 @@ impl IndexManifest {
             last_updated: None,
             files: HashMap::new(),
             total_chunks: 0,
+            model_name: String::new(),
+            dimensions: 0,
+            chunk_id_scheme: crate::chunker::CHUNK_ID_SCHEME_VERSION,
         }
     }
 
@@ -64,6 +88,23 @@ impl IndexManifest {
         std::fs::write(path, content).map_err(ManifestError::Io)
     }
 
+    /// Record the embedding configuration that produced the stored vectors.
+    pub fn record_embedding_config(&mut self, model_name: &str, dimensions: usize) {
+        self.model_name = model_name.to_string();
+        self.dimensions = dimensions;
+    }
+
+    /// Check whether the stored vectors match the given embedding configuration.
+    ///
+    /// If the model changed between index runs the stored vectors are
+    /// meaningless and the index must be rebuilt. Manifests written before
+    /// model tracking (empty model, zero dimensions) are treated as matching
+    /// so older indexes keep working.
+    pub fn embedding_config_matches(&self, model_name: &str, dimensions: usize) -> bool {
+        (self.model_name.is_empty() || self.model_name == model_name)
+            && (self.dimensions == 0 || self.dimensions == dimensions)
+    }
+
     /// Check if a file needs re-indexing.
     pub fn needs_update(&self, path: &Path, current_hash: &str) -> bool {
         match self.files.get(path) {
@@ -94,12 +135,52 @@ impl IndexManifest {
                 indexed_at: SystemTime::now(),
                 chunk_count,
                 chunk_ids,
+                last_accessed: None,
             },
         );
 
         self.last_updated = Some(SystemTime::now());
     }
 
+    /// Record that chunks from a file were returned by a search.
+    ///
+    /// Access times drive LRU eviction when the index runs under a size
+    /// budget. Unknown paths are ignored.
+    pub fn touch(&mut self, path: &Path) {
+        if let Some(state) = self.files.get_mut(path) {
+            state.last_accessed = Some(SystemTime::now());
+        }
+    }
+
+    /// Pick files to evict so the chunk count drops to `max_chunks`.
+    ///
+    /// Never-searched files go first (oldest index time breaking ties),
+    /// then files by least recent access. Returns an empty list when the
+    /// index is already within budget.
+    pub fn select_for_eviction(&self, max_chunks: usize) -> Vec<PathBuf> {
+        if self.total_chunks <= max_chunks {
+            return Vec::new();
+        }
+
+        let mut candidates: Vec<(&PathBuf, &FileState)> = self
+            .files
+            .iter()
+            .filter(|(_, state)| state.chunk_count > 0)
+            .collect();
+        candidates.sort_by_key(|(_, state)| (state.last_accessed, state.indexed_at));
+
+        let mut to_free = self.total_chunks - max_chunks;
+        let mut victims = Vec::new();
+        for (path, state) in candidates {
+            if to_free == 0 {
+                break;
+            }
+            victims.push(path.clone());
+            to_free = to_free.saturating_sub(state.chunk_count);
+        }
+        victims
+    }
+
     /// Remove a file from the manifest.
     pub fn remove_file(&mut self, path: &Path) -> Option<FileState> {
         if let Some(state) = self.files.remove(path) {
@@ -315,6 +396,94 @@ mod tests {
         assert_eq!(main_state.chunk_ids.len(), 2);
     }
 
+    #[test]
+    fn test_select_for_eviction_oldest_access_first() {
+        let mut manifest = IndexManifest::new();
+        manifest.record_indexed(
+            PathBuf::from("stale.rs"),
+            "h1".to_string(),
+            vec!["c1".to_string(), "c2".to_string()],
+        );
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        manifest.record_indexed(
+            PathBuf::from("cold.rs"),
+            "h2".to_string(),
+            vec!["c3".to_string(), "c4".to_string()],
+        );
+        manifest.record_indexed(
+            PathBuf::from("hot.rs"),
+            "h3".to_string(),
+            vec!["c5".to_string(), "c6".to_string()],
+        );
+
+        // cold.rs was searched once, hot.rs more recently; stale.rs never
+        manifest.touch(Path::new("cold.rs"));
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        manifest.touch(Path::new("hot.rs"));
+
+        // Within budget: nothing to evict
+        assert!(manifest.select_for_eviction(6).is_empty());
+
+        // Over budget by 2: only the never-searched file goes
+        assert_eq!(
+            manifest.select_for_eviction(4),
+            vec![PathBuf::from("stale.rs")]
+        );
+
+        // Over budget by 4: stale first, then least recently accessed
+        assert_eq!(
+            manifest.select_for_eviction(2),
+            vec![PathBuf::from("stale.rs"), PathBuf::from("cold.rs")]
+        );
+    }
+
+    #[test]
+    fn test_touch_unknown_path_ignored() {
+        let mut manifest = IndexManifest::new();
+        manifest.touch(Path::new("nope.rs"));
+        assert!(manifest.files.is_empty());
+    }
+
+    #[test]
+    fn test_embedding_config_mismatch_detected() {
+        let mut manifest = IndexManifest::new();
+        manifest.record_embedding_config("qwen/qwen3-embedding-8b", 4096);
+
+        assert_eq!(manifest.model_name, "qwen/qwen3-embedding-8b");
+        assert_eq!(manifest.dimensions, 4096);
+
+        // Matching config is accepted
+        assert!(manifest.embedding_config_matches("qwen/qwen3-embedding-8b", 4096));
+
+        // Changed model or dimensions is flagged
+        assert!(!manifest.embedding_config_matches("some/other-model", 4096));
+        assert!(!manifest.embedding_config_matches("qwen/qwen3-embedding-8b", 1024));
+    }
+
+    #[test]
+    fn test_embedding_config_legacy_manifest_matches() {
+        // Manifests written before model tracking have no embedding info
+        // and must not be flagged as mismatched
+        let manifest = IndexManifest::new();
+        assert!(manifest.embedding_config_matches("qwen/qwen3-embedding-8b", 4096));
+    }
+
+    #[test]
+    fn test_embedding_config_survives_save_load() {
+        use tempfile::tempdir;
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("manifest.json");
+
+        let mut manifest = IndexManifest::new();
+        manifest.record_embedding_config("qwen/qwen3-embedding-8b", 4096);
+        manifest.save(&path).unwrap();
+
+        let loaded = IndexManifest::load(&path).unwrap();
+        assert_eq!(loaded.model_name, "qwen/qwen3-embedding-8b");
+        assert_eq!(loaded.dimensions, 4096);
+    }
+
     #[test]
     fn test_manifest_load_nonexistent() {
         let result = IndexManifest::load(Path::new("/nonexistent/path/manifest.json"));
@@ -0,0 +1,269 @@
+//! In-memory vector store for Qdrant-free indexing.
+//!
+//! Small projects shouldn't need a running Qdrant server just to try
+//! semantic search. This module holds vectors in RAM and answers searches
+//! by brute-force cosine scan, which stays comfortably fast up to a few
+//! thousand chunks. Selected via `VectorBackend::InMemory`; nothing is
+//! persisted across restarts.
+
+use std::collections::HashMap;
+
+use crate::qdrant::{Point, PointPayload, SearchFilter, SearchHit};
+use crate::search::similarity::cosine;
+
+/// Brute-force vector store exposing the same operations as the Qdrant
+/// backend (search/upsert/delete/count), held entirely in RAM.
+pub struct InMemoryVectorStore {
+    /// Stored vectors and payloads keyed by point ID
+    points: HashMap<String, (Vec<f32>, PointPayload)>,
+    /// Expected vector dimensions
+    dimensions: usize,
+}
+
+impl InMemoryVectorStore {
+    /// Create an empty store for vectors of the given dimensions.
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            points: HashMap::new(),
+            dimensions,
+        }
+    }
+
+    /// Insert or replace points by ID.
+    pub fn upsert_points(&mut self, points: Vec<Point>) {
+        for point in points {
+            self.points.insert(point.id, (point.vector, point.payload));
+        }
+    }
+
+    /// Brute-force cosine search over all stored vectors.
+    ///
+    /// Results are ordered by descending similarity and truncated to
+    /// `limit`. Filter semantics match the Qdrant backend: path prefix,
+    /// chunk-type, visibility, and modified-time conditions must all hold.
+    pub fn search(
+        &self,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&SearchFilter>,
+    ) -> Vec<SearchHit> {
+        let mut hits: Vec<SearchHit> = self
+            .points
+            .iter()
+            .filter(|(_, (_, payload))| matches_filter(payload, filter))
+            .map(|(id, (vector, payload))| SearchHit {
+                id: id.clone(),
+                score: cosine(query_vector, vector),
+                payload: payload.clone(),
+            })
+            .collect();
+
+        hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        hits.truncate(limit);
+        hits
+    }
+
+    /// Remove points by ID. Unknown IDs are ignored.
+    pub fn delete_points(&mut self, ids: &[String]) {
+        for id in ids {
+            self.points.remove(id);
+        }
+    }
+
+    /// Number of stored points.
+    pub fn count(&self) -> usize {
+        self.points.len()
+    }
+
+    /// All stored payloads, in arbitrary order.
+    pub fn payloads(&self) -> Vec<PointPayload> {
+        self.points.values().map(|(_, p)| p.clone()).collect()
+    }
+
+    /// Stored vectors for a batch of point IDs. Unknown IDs are silently
+    /// absent from the returned map.
+    pub fn get_vectors(&self, ids: &[String]) -> HashMap<String, Vec<f32>> {
+        ids.iter()
+            .filter_map(|id| {
+                self.points
+                    .get(id)
+                    .map(|(vector, _)| (id.clone(), vector.clone()))
+            })
+            .collect()
+    }
+
+    /// Remove all stored points.
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Expected vector dimensions.
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+}
+
+/// Check a payload against the filter conditions (all must hold).
+fn matches_filter(payload: &PointPayload, filter: Option<&SearchFilter>) -> bool {
+    let Some(filter) = filter else {
+        return true;
+    };
+
+    if let Some(ref prefix) = filter.file_path_prefix {
+        if !payload.file_path.starts_with(prefix.as_str()) {
+            return false;
+        }
+    }
+
+    if let Some(ref chunk_types) = filter.chunk_types {
+        if !chunk_types.is_empty() && !chunk_types.contains(&payload.chunk_type) {
+            return false;
+        }
+    }
+
+    if filter.public_only && payload.visibility != "public" {
+        return false;
+    }
+
+    if filter.modified_after.is_some() || filter.modified_before.is_some() {
+        // Points indexed before modification times were captured can't
+        // satisfy a time-range filter
+        let Some(modified_at) = payload.modified_at else {
+            return false;
+        };
+        if filter.modified_after.is_some_and(|after| modified_at < after) {
+            return false;
+        }
+        if filter.modified_before.is_some_and(|before| modified_at > before) {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(id: &str, vector: Vec<f32>, file_path: &str, chunk_type: &str) -> Point {
+        Point {
+            id: id.to_string(),
+            vector,
+            payload: PointPayload {
+                file_path: file_path.to_string(),
+                chunk_type: chunk_type.to_string(),
+                name: id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_search_orders_by_cosine_similarity() {
+        let mut store = InMemoryVectorStore::new(3);
+        store.upsert_points(vec![
+            point("orthogonal", vec![0.0, 1.0, 0.0], "src/a.rs", "function"),
+            point("exact", vec![1.0, 0.0, 0.0], "src/b.rs", "function"),
+            point("close", vec![0.9, 0.1, 0.0], "src/c.rs", "function"),
+        ]);
+
+        let hits = store.search(&[1.0, 0.0, 0.0], 10, None);
+
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[0].id, "exact");
+        assert_eq!(hits[1].id, "close");
+        assert_eq!(hits[2].id, "orthogonal");
+        assert!((hits[0].score - 1.0).abs() < 1e-6);
+        assert!(hits[1].score > hits[2].score);
+    }
+
+    #[test]
+    fn test_search_respects_limit_and_filter() {
+        let mut store = InMemoryVectorStore::new(2);
+        store.upsert_points(vec![
+            point("a", vec![1.0, 0.0], "src/auth/mod.rs", "function"),
+            point("b", vec![0.9, 0.1], "src/auth/token.rs", "docstring"),
+            point("c", vec![0.8, 0.2], "src/net/mod.rs", "function"),
+        ]);
+
+        let filter = SearchFilter::new().with_path_prefix("src/auth".to_string());
+        let hits = store.search(&[1.0, 0.0], 10, Some(&filter));
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|h| h.payload.file_path.starts_with("src/auth")));
+
+        let filter = SearchFilter::new().docstrings_only();
+        let hits = store.search(&[1.0, 0.0], 10, Some(&filter));
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "b");
+
+        let hits = store.search(&[1.0, 0.0], 1, None);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "a");
+    }
+
+    #[test]
+    fn test_public_only_filter_excludes_private_symbols() {
+        let mut store = InMemoryVectorStore::new(2);
+        let mut public_fn = point("exposed", vec![1.0, 0.0], "src/lib.rs", "function");
+        public_fn.payload.visibility = "public".to_string();
+        let mut private_fn = point("hidden", vec![0.9, 0.1], "src/lib.rs", "function");
+        private_fn.payload.visibility = "private".to_string();
+        store.upsert_points(vec![public_fn, private_fn]);
+
+        let filter = SearchFilter::new().public_only();
+        let hits = store.search(&[1.0, 0.0], 10, Some(&filter));
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "exposed");
+
+        // Without the filter both functions come back
+        let hits = store.search(&[1.0, 0.0], 10, None);
+        assert_eq!(hits.len(), 2);
+    }
+
+    #[test]
+    fn test_modified_time_range_excludes_files_outside_window() {
+        let mut store = InMemoryVectorStore::new(2);
+        let mut recent = point("recent", vec![1.0, 0.0], "src/new.rs", "function");
+        recent.payload.modified_at = Some(2_000);
+        let mut old = point("old", vec![0.9, 0.1], "src/old.rs", "function");
+        old.payload.modified_at = Some(500);
+        // Indexed before modification times were captured
+        let legacy = point("legacy", vec![0.8, 0.2], "src/legacy.rs", "function");
+        store.upsert_points(vec![recent, old, legacy]);
+
+        let filter = SearchFilter::new().modified_after(1_000);
+        let hits = store.search(&[1.0, 0.0], 10, Some(&filter));
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "recent");
+
+        let filter = SearchFilter::new().modified_before(1_000);
+        let hits = store.search(&[1.0, 0.0], 10, Some(&filter));
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "old");
+
+        // Bounds are inclusive
+        let filter = SearchFilter::new().modified_after(500).modified_before(500);
+        let hits = store.search(&[1.0, 0.0], 10, Some(&filter));
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "old");
+    }
+
+    #[test]
+    fn test_upsert_replaces_and_delete_removes() {
+        let mut store = InMemoryVectorStore::new(2);
+        store.upsert_points(vec![point("a", vec![1.0, 0.0], "src/a.rs", "function")]);
+        assert_eq!(store.count(), 1);
+
+        // Re-upserting the same ID replaces the vector
+        store.upsert_points(vec![point("a", vec![0.0, 1.0], "src/a.rs", "function")]);
+        assert_eq!(store.count(), 1);
+        let vectors = store.get_vectors(&["a".to_string(), "missing".to_string()]);
+        assert_eq!(vectors.len(), 1);
+        assert_eq!(vectors["a"], vec![0.0, 1.0]);
+
+        store.delete_points(&["a".to_string()]);
+        assert_eq!(store.count(), 0);
+        assert!(store.payloads().is_empty());
+    }
+}
@@ -5,17 +5,94 @@
 
 use anyhow::{Context, Result};
 use qdrant_client::qdrant::{
-    Condition, CreateCollectionBuilder, DeletePointsBuilder, Distance, Filter, PointStruct,
-    PointsIdsList, QuantizationType, ScalarQuantizationBuilder, SearchPointsBuilder,
-    UpsertPointsBuilder, VectorParamsBuilder,
+    Condition, CreateCollectionBuilder, DeletePointsBuilder, Distance, Filter, GetPointsBuilder,
+    PointStruct, PointsIdsList, QuantizationType, Range, ScalarQuantizationBuilder,
+    ScrollPointsBuilder, SearchPointsBuilder, UpsertPointsBuilder, VectorParamsBuilder,
 };
 use qdrant_client::Qdrant;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::RwLock;
 use tracing::{debug, info};
 
-// Re-export QdrantError for use by other modules
-pub use qdrant_client::QdrantError;
+use crate::memory_store::InMemoryVectorStore;
+
+/// Structured errors for Qdrant operations.
+///
+/// Every variant names the operation and collection involved, so a
+/// failure can be diagnosed without re-running the request, and the
+/// message carries the most likely fix.
+#[derive(Error, Debug)]
+pub enum QdrantError {
+    /// The collection does not exist on the server
+    #[error("{operation} failed: collection '{collection}' not found - run index_codebase to create it")]
+    CollectionNotFound {
+        operation: &'static str,
+        collection: String,
+    },
+
+    /// A vector's length does not match the collection's dimensions
+    #[error("{operation} failed on '{collection}': vector '{point_id}' has {actual} dimensions but the collection expects {expected} - the embedding model likely changed, re-index to migrate the collection")]
+    DimensionMismatch {
+        operation: &'static str,
+        collection: String,
+        point_id: String,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// The server could not be reached
+    #[error("{operation} failed on '{collection}': cannot reach Qdrant ({message}) - is the server running?")]
+    Transport {
+        operation: &'static str,
+        collection: String,
+        message: String,
+    },
+
+    /// Any other failure reported by the Qdrant client
+    #[error("{operation} failed on '{collection}': {message}")]
+    Operation {
+        operation: &'static str,
+        collection: String,
+        message: String,
+    },
+}
+
+/// Classify a raw Qdrant client failure into a structured [`QdrantError`].
+///
+/// The gRPC client surfaces everything as strings, so classification is
+/// by message shape - enough to tell "create the collection" advice
+/// apart from "the server is down".
+fn classify_qdrant_failure(
+    operation: &'static str,
+    collection: &str,
+    message: String,
+) -> QdrantError {
+    let lower = message.to_lowercase();
+    if lower.contains("doesn't exist") || lower.contains("not found") {
+        QdrantError::CollectionNotFound {
+            operation,
+            collection: collection.to_string(),
+        }
+    } else if lower.contains("transport error")
+        || lower.contains("connection refused")
+        || lower.contains("tcp connect")
+    {
+        QdrantError::Transport {
+            operation,
+            collection: collection.to_string(),
+            message,
+        }
+    } else {
+        QdrantError::Operation {
+            operation,
+            collection: collection.to_string(),
+            message,
+        }
+    }
+}
 
 /// Metadata stored with each vector point.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +121,29 @@ pub struct PointPayload {
     /// Enclosing scope, e.g., "impl Foo" or "impl Trait for Bar"
     pub scope: Option<String>,
 
+    /// Fully-qualified symbol path, e.g., "auth::Foo::validate"
+    /// (empty for points indexed before qualified names existed)
+    pub qualified_name: String,
+
+    /// Symbol visibility: "public", "crate", or "private"
+    /// (empty for points indexed before visibility was captured)
+    #[serde(default)]
+    pub visibility: String,
+
+    /// Start column of the symbol's name identifier (1-indexed;
+    /// 1 for points indexed before columns were captured)
+    #[serde(default = "default_column")]
+    pub column_start: usize,
+
+    /// End column of the symbol's name identifier (1-indexed, inclusive)
+    #[serde(default = "default_column")]
+    pub column_end: usize,
+
+    /// Source file's last-modified time as unix seconds at index time
+    /// (None for points indexed before modification times were captured)
+    #[serde(default)]
+    pub modified_at: Option<i64>,
+
     /// The actual source code of this chunk
     pub code: String,
 }
@@ -59,11 +159,21 @@ impl Default for PointPayload {
             line_end: 0,
             module: None,
             scope: None,
+            qualified_name: String::new(),
+            visibility: String::new(),
+            column_start: 1,
+            column_end: 1,
+            modified_at: None,
             code: String::new(),
         }
     }
 }
 
+/// Column fallback for points stored before columns were captured.
+fn default_column() -> usize {
+    1
+}
+
 /// Configuration for connecting to Qdrant.
 #[derive(Debug, Clone)]
 pub struct QdrantConfig {
@@ -91,6 +201,42 @@ impl Default for QdrantConfig {
     }
 }
 
+/// Which vector storage backend to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VectorBackend {
+    /// Remote Qdrant server (default)
+    #[default]
+    Qdrant,
+    /// Brute-force in-memory store; no external service, nothing persisted
+    InMemory,
+}
+
+impl VectorBackend {
+    /// Parse a user-facing backend name.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "qdrant" => Some(Self::Qdrant),
+            "memory" | "in-memory" | "inmemory" => Some(Self::InMemory),
+            _ => None,
+        }
+    }
+}
+
+/// L2-normalize a vector in place.
+///
+/// Embedding providers differ in whether they return unit vectors;
+/// normalizing before upsert (and on the query side) keeps cosine
+/// scores comparable when mixed-norm vectors share a collection.
+/// Zero vectors are left untouched.
+pub fn l2_normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > f32::EPSILON {
+        for v in vector.iter_mut() {
+            *v /= norm;
+        }
+    }
+}
+
 /// A point to upsert into Qdrant.
 #[derive(Debug, Clone)]
 pub struct Point {
@@ -128,6 +274,15 @@ pub struct SearchFilter {
 
     /// Filter by programming language
     pub language: Option<String>,
+
+    /// Only return public symbols (`pub` in Rust, exported in JS/TS)
+    pub public_only: bool,
+
+    /// Only return chunks from files modified at or after this unix time
+    pub modified_after: Option<i64>,
+
+    /// Only return chunks from files modified at or before this unix time
+    pub modified_before: Option<i64>,
 }
 
 impl SearchFilter {
@@ -148,20 +303,64 @@ impl SearchFilter {
         self
     }
 
+    /// Restrict results to docstring chunks.
+    ///
+    /// Only returns prose extracted from doc comments (see
+    /// `ChunkType::Docstring`); requires the index to have been built with
+    /// docstring chunks enabled.
+    pub fn docstrings_only(self) -> Self {
+        self.with_chunk_types(vec!["docstring".to_string()])
+    }
+
     /// Filter by programming language.
     pub fn with_language(mut self, language: String) -> Self {
         self.language = Some(language);
         self
     }
+
+    /// Restrict results to public symbols.
+    ///
+    /// Useful for API-surface queries: excludes private and crate-visible
+    /// symbols. Points indexed before visibility was captured have an empty
+    /// visibility and are excluded too.
+    pub fn public_only(mut self) -> Self {
+        self.public_only = true;
+        self
+    }
+
+    /// Only return chunks from files modified at or after the given unix
+    /// time (seconds).
+    ///
+    /// Matched against the `modified_at` recorded per chunk at index time,
+    /// so it reflects the file's state when it was last indexed. Points
+    /// indexed before modification times were captured are excluded.
+    pub fn modified_after(mut self, unix_seconds: i64) -> Self {
+        self.modified_after = Some(unix_seconds);
+        self
+    }
+
+    /// Only return chunks from files modified at or before the given unix
+    /// time (seconds). See [`SearchFilter::modified_after`] for semantics.
+    pub fn modified_before(mut self, unix_seconds: i64) -> Self {
+        self.modified_before = Some(unix_seconds);
+        self
+    }
 }
 
 /// High-level Qdrant client for code search.
 pub struct QdrantClient {
-    client: Qdrant,
+    backend: Backend,
     collection_name: String,
     dimensions: usize,
 }
 
+/// Storage behind the client: a remote Qdrant server or a shared
+/// in-memory store.
+enum Backend {
+    Remote(Qdrant),
+    InMemory(Arc<RwLock<InMemoryVectorStore>>),
+}
+
 impl QdrantClient {
     /// Connect to Qdrant server.
     ///
@@ -178,12 +377,41 @@ impl QdrantClient {
             .context("Failed to connect to Qdrant")?;
 
         Ok(Self {
-            client,
+            backend: Backend::Remote(client),
             collection_name: collection_name.to_string(),
             dimensions,
         })
     }
 
+    /// Create a client backed by a fresh in-memory store (no server).
+    ///
+    /// Brute-force cosine search over RAM-held vectors; suitable for
+    /// small projects up to a few thousand chunks. Nothing is persisted
+    /// across restarts.
+    pub fn in_memory(collection_name: &str, dimensions: usize) -> Self {
+        Self::with_store(
+            collection_name,
+            dimensions,
+            Arc::new(RwLock::new(InMemoryVectorStore::new(dimensions))),
+        )
+    }
+
+    /// Create a client over an existing in-memory store.
+    ///
+    /// Indexer and searcher each hold their own client, so they must
+    /// share the store to see the same points.
+    pub fn with_store(
+        collection_name: &str,
+        dimensions: usize,
+        store: Arc<RwLock<InMemoryVectorStore>>,
+    ) -> Self {
+        Self {
+            backend: Backend::InMemory(store),
+            collection_name: collection_name.to_string(),
+            dimensions,
+        }
+    }
+
     /// Create a new Qdrant client from configuration.
     pub async fn from_config(config: &QdrantConfig) -> Result<Self> {
         let mut builder = Qdrant::from_url(&config.url)
@@ -198,16 +426,44 @@ impl QdrantClient {
         info!("Connected to Qdrant at {}", config.url);
 
         Ok(Self {
-            client,
+            backend: Backend::Remote(client),
             collection_name: config.collection_name.clone(),
             dimensions: config.dimensions,
         })
     }
 
+    /// Reject a vector whose length does not match the collection's
+    /// dimensions before it reaches the backend.
+    fn check_dimensions(
+        &self,
+        operation: &'static str,
+        point_id: &str,
+        vector_len: usize,
+    ) -> Result<(), QdrantError> {
+        if vector_len != self.dimensions {
+            return Err(QdrantError::DimensionMismatch {
+                operation,
+                collection: self.collection_name.clone(),
+                point_id: point_id.to_string(),
+                expected: self.dimensions,
+                actual: vector_len,
+            });
+        }
+        Ok(())
+    }
+
     /// Create collection if it doesn't exist (with scalar quantization for 4x compression).
     pub async fn ensure_collection(&self) -> Result<()> {
+        let client = match &self.backend {
+            Backend::Remote(client) => client,
+            // The in-memory store has nothing to create
+            Backend::InMemory(_) => return Ok(()),
+        };
+
         // Check if collection exists
-        let collections = self.client.list_collections().await?;
+        let collections = client.list_collections().await.map_err(|e| {
+            classify_qdrant_failure("ensure_collection", &self.collection_name, e.to_string())
+        })?;
         let exists = collections
             .collections
             .iter()
@@ -219,7 +475,7 @@ impl QdrantClient {
                 self.collection_name, self.dimensions
             );
 
-            self.client
+            client
                 .create_collection(
                     CreateCollectionBuilder::new(&self.collection_name)
                         .vectors_config(VectorParamsBuilder::new(
@@ -234,7 +490,13 @@ impl QdrantClient {
                         ),
                 )
                 .await
-                .context("Failed to create collection")?;
+                .map_err(|e| {
+                    classify_qdrant_failure(
+                        "create_collection",
+                        &self.collection_name,
+                        e.to_string(),
+                    )
+                })?;
 
             info!("Collection {} created successfully", self.collection_name);
         } else {
@@ -253,6 +515,18 @@ impl QdrantClient {
 
         debug!("Upserting {} points", points.len());
 
+        for point in &points {
+            self.check_dimensions("upsert_points", &point.id, point.vector.len())?;
+        }
+
+        let client = match &self.backend {
+            Backend::Remote(client) => client,
+            Backend::InMemory(store) => {
+                store.write().await.upsert_points(points);
+                return Ok(());
+            }
+        };
+
         let qdrant_points: Vec<PointStruct> = points
             .into_iter()
             .map(|p| {
@@ -262,13 +536,15 @@ impl QdrantClient {
             })
             .collect();
 
-        self.client
+        client
             .upsert_points(UpsertPointsBuilder::new(
                 &self.collection_name,
                 qdrant_points,
             ))
             .await
-            .context("Failed to upsert points")?;
+            .map_err(|e| {
+                classify_qdrant_failure("upsert_points", &self.collection_name, e.to_string())
+            })?;
 
         Ok(())
     }
@@ -287,6 +563,17 @@ impl QdrantClient {
     ) -> Result<Vec<SearchHit>> {
         debug!("Searching for {} similar vectors", limit);
 
+        self.check_dimensions("search", "query", query_vector.len())?;
+
+        let client = match &self.backend {
+            Backend::Remote(client) => client,
+            Backend::InMemory(store) => {
+                let hits = store.read().await.search(&query_vector, limit, filter.as_ref());
+                debug!("Found {} in-memory search hits", hits.len());
+                return Ok(hits);
+            }
+        };
+
         let mut search_builder =
             SearchPointsBuilder::new(&self.collection_name, query_vector, limit as u64)
                 .with_payload(true);
@@ -319,16 +606,32 @@ impl QdrantClient {
                 }
             }
 
+            if f.public_only {
+                conditions.push(Condition::matches("visibility", "public".to_string()));
+            }
+
+            if f.modified_after.is_some() || f.modified_before.is_some() {
+                conditions.push(Condition::range(
+                    "modified_at",
+                    Range {
+                        gte: f.modified_after.map(|t| t as f64),
+                        lte: f.modified_before.map(|t| t as f64),
+                        ..Default::default()
+                    },
+                ));
+            }
+
             if !conditions.is_empty() {
                 search_builder = search_builder.filter(Filter::must(conditions));
             }
         }
 
-        let results = self
-            .client
+        let results = client
             .search_points(search_builder)
             .await
-            .context("Failed to search points")?;
+            .map_err(|e| {
+                classify_qdrant_failure("search", &self.collection_name, e.to_string())
+            })?;
 
         let hits: Vec<SearchHit> = results
             .result
@@ -359,6 +662,112 @@ impl QdrantClient {
         Ok(hits)
     }
 
+    /// Scroll through all stored payloads, page by page.
+    ///
+    /// Used by diagnostics (e.g. the chunk size report) that need to
+    /// inspect every chunk rather than search for a few.
+    pub async fn scroll_payloads(&self, page_size: usize) -> Result<Vec<PointPayload>> {
+        let client = match &self.backend {
+            Backend::Remote(client) => client,
+            Backend::InMemory(store) => return Ok(store.read().await.payloads()),
+        };
+
+        let mut payloads = Vec::new();
+        let mut offset: Option<qdrant_client::qdrant::PointId> = None;
+
+        loop {
+            let mut builder = ScrollPointsBuilder::new(&self.collection_name)
+                .limit(page_size as u32)
+                .with_payload(true)
+                .with_vectors(false);
+            if let Some(off) = offset.take() {
+                builder = builder.offset(off);
+            }
+
+            let response = client.scroll(builder).await.map_err(|e| {
+                classify_qdrant_failure("scroll_payloads", &self.collection_name, e.to_string())
+            })?;
+
+            for point in &response.result {
+                payloads.push(qdrant_map_to_payload(&point.payload));
+            }
+
+            match response.next_page_offset {
+                Some(next) => offset = Some(next),
+                None => break,
+            }
+        }
+
+        debug!("Scrolled {} payloads", payloads.len());
+        Ok(payloads)
+    }
+
+    /// Retrieve stored vectors for a batch of point IDs.
+    ///
+    /// Used for local pairwise scoring (MMR diversity, vector dedup) where
+    /// candidate-to-candidate similarity can't be computed server-side.
+    /// IDs that don't exist in the collection are silently absent from the
+    /// returned map.
+    pub async fn get_vectors(&self, ids: &[String]) -> Result<HashMap<String, Vec<f32>>> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        debug!("Retrieving vectors for {} points", ids.len());
+
+        let client = match &self.backend {
+            Backend::Remote(client) => client,
+            Backend::InMemory(store) => return Ok(store.read().await.get_vectors(ids)),
+        };
+
+        let point_ids: Vec<_> = ids
+            .iter()
+            .cloned()
+            .map(qdrant_client::qdrant::PointId::from)
+            .collect();
+
+        let response = client
+            .get_points(
+                GetPointsBuilder::new(&self.collection_name, point_ids)
+                    .with_vectors(true)
+                    .with_payload(false),
+            )
+            .await
+            .map_err(|e| {
+                classify_qdrant_failure("get_vectors", &self.collection_name, e.to_string())
+            })?;
+
+        let mut vectors = HashMap::new();
+        for point in response.result {
+            let id = point
+                .id
+                .and_then(|id| match id.point_id_options {
+                    Some(qdrant_client::qdrant::point_id::PointIdOptions::Uuid(u)) => Some(u),
+                    Some(qdrant_client::qdrant::point_id::PointIdOptions::Num(n)) => {
+                        Some(n.to_string())
+                    }
+                    None => None,
+                });
+            let data = point.vectors.and_then(|v| match v.vectors_options {
+                Some(qdrant_client::qdrant::vectors_output::VectorsOptions::Vector(v)) => {
+                    match v.into_vector() {
+                        qdrant_client::qdrant::vector_output::Vector::Dense(dense) => {
+                            Some(dense.data)
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            });
+            if let (Some(id), Some(data)) = (id, data) {
+                vectors.insert(id, data);
+            }
+        }
+
+        debug!("Retrieved {} vectors", vectors.len());
+        Ok(vectors)
+    }
+
     /// Delete points by IDs.
     pub async fn delete_points(&self, ids: Vec<String>) -> Result<()> {
         if ids.is_empty() {
@@ -368,30 +777,46 @@ impl QdrantClient {
 
         debug!("Deleting {} points", ids.len());
 
+        let client = match &self.backend {
+            Backend::Remote(client) => client,
+            Backend::InMemory(store) => {
+                store.write().await.delete_points(&ids);
+                return Ok(());
+            }
+        };
+
         // Convert string IDs to point IDs
         let point_ids: Vec<_> = ids
             .into_iter()
             .map(qdrant_client::qdrant::PointId::from)
             .collect();
 
-        self.client
+        client
             .delete_points(
                 DeletePointsBuilder::new(&self.collection_name)
                     .points(PointsIdsList { ids: point_ids }),
             )
             .await
-            .context("Failed to delete points")?;
+            .map_err(|e| {
+                classify_qdrant_failure("delete_points", &self.collection_name, e.to_string())
+            })?;
 
         Ok(())
     }
 
     /// Count points in the collection.
     pub async fn count(&self) -> Result<usize> {
-        let info = self
-            .client
+        let client = match &self.backend {
+            Backend::Remote(client) => client,
+            Backend::InMemory(store) => return Ok(store.read().await.count()),
+        };
+
+        let info = client
             .collection_info(&self.collection_name)
             .await
-            .context("Failed to get collection info")?;
+            .map_err(|e| {
+                classify_qdrant_failure("count", &self.collection_name, e.to_string())
+            })?;
 
         let count = info
             .result
@@ -405,10 +830,24 @@ impl QdrantClient {
     pub async fn delete_collection(&self) -> Result<()> {
         info!("Deleting collection: {}", self.collection_name);
 
-        self.client
+        let client = match &self.backend {
+            Backend::Remote(client) => client,
+            Backend::InMemory(store) => {
+                store.write().await.clear();
+                return Ok(());
+            }
+        };
+
+        client
             .delete_collection(&self.collection_name)
             .await
-            .context("Failed to delete collection")?;
+            .map_err(|e| {
+                classify_qdrant_failure(
+                    "delete_collection",
+                    &self.collection_name,
+                    e.to_string(),
+                )
+            })?;
 
         Ok(())
     }
@@ -440,6 +879,10 @@ fn payload_to_qdrant_map(payload: &PointPayload) -> HashMap<String, qdrant_clien
         "name".to_string(),
         qdrant_client::qdrant::Value::from(payload.name.clone()),
     );
+    map.insert(
+        "qualified_name".to_string(),
+        qdrant_client::qdrant::Value::from(payload.qualified_name.clone()),
+    );
     map.insert(
         "line_start".to_string(),
         qdrant_client::qdrant::Value::from(payload.line_start as i64),
@@ -448,6 +891,18 @@ fn payload_to_qdrant_map(payload: &PointPayload) -> HashMap<String, qdrant_clien
         "line_end".to_string(),
         qdrant_client::qdrant::Value::from(payload.line_end as i64),
     );
+    map.insert(
+        "visibility".to_string(),
+        qdrant_client::qdrant::Value::from(payload.visibility.clone()),
+    );
+    map.insert(
+        "column_start".to_string(),
+        qdrant_client::qdrant::Value::from(payload.column_start as i64),
+    );
+    map.insert(
+        "column_end".to_string(),
+        qdrant_client::qdrant::Value::from(payload.column_end as i64),
+    );
     map.insert(
         "code".to_string(),
         qdrant_client::qdrant::Value::from(payload.code.clone()),
@@ -474,6 +929,13 @@ fn payload_to_qdrant_map(payload: &PointPayload) -> HashMap<String, qdrant_clien
         );
     }
 
+    if let Some(modified_at) = payload.modified_at {
+        map.insert(
+            "modified_at".to_string(),
+            qdrant_client::qdrant::Value::from(modified_at),
+        );
+    }
+
     map
 }
 
@@ -488,6 +950,11 @@ fn qdrant_map_to_payload(map: &HashMap<String, qdrant_client::qdrant::Value>) ->
         line_end: extract_integer(map.get("line_end")) as usize,
         module: map.get("module").and_then(|v| extract_string_opt(v)),
         scope: map.get("scope").and_then(|v| extract_string_opt(v)),
+        qualified_name: extract_string(map.get("qualified_name")),
+        visibility: extract_string(map.get("visibility")),
+        column_start: extract_column(map.get("column_start")),
+        column_end: extract_column(map.get("column_end")),
+        modified_at: map.get("modified_at").and_then(extract_integer_opt),
         code: extract_string(map.get("code")),
     }
 }
@@ -512,6 +979,14 @@ fn extract_string_opt(value: &qdrant_client::qdrant::Value) -> Option<String> {
     }
 }
 
+fn extract_integer_opt(value: &qdrant_client::qdrant::Value) -> Option<i64> {
+    if let Some(qdrant_client::qdrant::value::Kind::IntegerValue(i)) = &value.kind {
+        Some(*i)
+    } else {
+        None
+    }
+}
+
 fn extract_integer(value: Option<&qdrant_client::qdrant::Value>) -> i64 {
     value
         .and_then(|v| {
@@ -524,6 +999,15 @@ fn extract_integer(value: Option<&qdrant_client::qdrant::Value>) -> i64 {
         .unwrap_or(0)
 }
 
+/// Columns are 1-indexed, so a missing value falls back to 1 rather
+/// than the 0 that `extract_integer` reports for absent fields.
+fn extract_column(value: Option<&qdrant_client::qdrant::Value>) -> usize {
+    match extract_integer(value) {
+        0 => default_column(),
+        column => column as usize,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -542,6 +1026,79 @@ mod tests {
         assert!(payload.code.is_empty());
     }
 
+    #[test]
+    fn test_classify_collection_not_found() {
+        let error = classify_qdrant_failure(
+            "search",
+            "g3-codebase",
+            "Collection `g3-codebase` doesn't exist!".to_string(),
+        );
+        assert!(matches!(error, QdrantError::CollectionNotFound { .. }));
+        let message = error.to_string();
+        assert!(message.contains("g3-codebase"));
+        assert!(message.contains("index_codebase"), "message was: {}", message);
+    }
+
+    #[test]
+    fn test_classify_transport_error() {
+        let error = classify_qdrant_failure(
+            "upsert_points",
+            "g3-codebase",
+            "status: Unavailable, message: \"transport error: tcp connect error\"".to_string(),
+        );
+        assert!(matches!(error, QdrantError::Transport { .. }));
+        assert!(error.to_string().contains("is the server running?"));
+    }
+
+    #[test]
+    fn test_classify_other_failures_keep_operation_context() {
+        let error = classify_qdrant_failure(
+            "delete_points",
+            "g3-codebase",
+            "some unexpected failure".to_string(),
+        );
+        assert!(matches!(error, QdrantError::Operation { .. }));
+        let message = error.to_string();
+        assert!(message.starts_with("delete_points failed on 'g3-codebase'"));
+        assert!(message.contains("some unexpected failure"));
+    }
+
+    #[tokio::test]
+    async fn test_upsert_rejects_dimension_mismatch() {
+        let client = QdrantClient::in_memory("test-dims", 4);
+
+        let point = Point {
+            id: "p1".to_string(),
+            vector: vec![1.0, 0.0], // 2 dims against a 4-dim collection
+            payload: PointPayload::default(),
+        };
+
+        let error = client.upsert_points(vec![point]).await.unwrap_err();
+        let qdrant_error = error.downcast_ref::<QdrantError>().unwrap();
+        assert!(matches!(
+            qdrant_error,
+            QdrantError::DimensionMismatch {
+                expected: 4,
+                actual: 2,
+                ..
+            }
+        ));
+        assert!(error.to_string().contains("re-index"));
+    }
+
+    #[test]
+    fn test_l2_normalize_scales_to_unit_length() {
+        let mut vector = vec![3.0, 4.0];
+        l2_normalize(&mut vector);
+        assert!((vector[0] - 0.6).abs() < 1e-6);
+        assert!((vector[1] - 0.8).abs() < 1e-6);
+
+        // Zero vectors stay untouched instead of dividing by zero
+        let mut zero = vec![0.0, 0.0];
+        l2_normalize(&mut zero);
+        assert_eq!(zero, vec![0.0, 0.0]);
+    }
+
     #[test]
     fn test_search_filter_builder() {
         let filter = SearchFilter::new()
@@ -557,6 +1114,93 @@ mod tests {
         assert_eq!(filter.language, Some("rust".to_string()));
     }
 
+    #[test]
+    fn test_search_filter_docstrings_only() {
+        let filter = SearchFilter::new().docstrings_only();
+        assert_eq!(filter.chunk_types, Some(vec!["docstring".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_get_vectors_empty_ids_returns_empty_map() {
+        // Client construction is lazy, so no server is needed for the
+        // empty-input fast path.
+        let client = QdrantClient::connect("http://localhost:6334", "test", 4)
+            .await
+            .unwrap();
+        let vectors = client.get_vectors(&[]).await.unwrap();
+        assert!(vectors.is_empty());
+    }
+
+    #[test]
+    fn test_vector_backend_parse() {
+        assert_eq!(VectorBackend::parse("qdrant"), Some(VectorBackend::Qdrant));
+        assert_eq!(VectorBackend::parse("memory"), Some(VectorBackend::InMemory));
+        assert_eq!(
+            VectorBackend::parse("In-Memory"),
+            Some(VectorBackend::InMemory)
+        );
+        assert_eq!(VectorBackend::parse("redis"), None);
+        assert_eq!(VectorBackend::default(), VectorBackend::Qdrant);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_backend_roundtrip() {
+        let client = QdrantClient::in_memory("test", 3);
+        client.ensure_collection().await.unwrap();
+
+        client
+            .upsert_points(vec![
+                Point {
+                    id: "a".to_string(),
+                    vector: vec![1.0, 0.0, 0.0],
+                    payload: PointPayload {
+                        name: "exact".to_string(),
+                        ..Default::default()
+                    },
+                },
+                Point {
+                    id: "b".to_string(),
+                    vector: vec![0.0, 1.0, 0.0],
+                    payload: PointPayload {
+                        name: "orthogonal".to_string(),
+                        ..Default::default()
+                    },
+                },
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(client.count().await.unwrap(), 2);
+
+        let hits = client.search(vec![1.0, 0.0, 0.0], 10, None).await.unwrap();
+        assert_eq!(hits[0].payload.name, "exact");
+        assert_eq!(hits[1].payload.name, "orthogonal");
+
+        client.delete_points(vec!["a".to_string()]).await.unwrap();
+        assert_eq!(client.count().await.unwrap(), 1);
+
+        client.delete_collection().await.unwrap();
+        assert_eq!(client.count().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_clients_share_a_store() {
+        let store = Arc::new(RwLock::new(InMemoryVectorStore::new(2)));
+        let writer = QdrantClient::with_store("test", 2, store.clone());
+        let reader = QdrantClient::with_store("test", 2, store);
+
+        writer
+            .upsert_points(vec![Point {
+                id: "a".to_string(),
+                vector: vec![1.0, 0.0],
+                payload: PointPayload::default(),
+            }])
+            .await
+            .unwrap();
+
+        assert_eq!(reader.count().await.unwrap(), 1);
+    }
+
     #[test]
     fn test_payload_to_qdrant_map() {
         let payload = PointPayload {
@@ -568,6 +1212,11 @@ mod tests {
             line_end: 10,
             module: Some("crate".to_string()),
             scope: None,
+            qualified_name: "main".to_string(),
+            visibility: "private".to_string(),
+            column_start: 4,
+            column_end: 7,
+            modified_at: None,
             code: "fn main() { }".to_string(),
         };
 
@@ -581,6 +1230,9 @@ mod tests {
         assert!(map.contains_key("line_end"));
         assert!(map.contains_key("module"));
         assert!(!map.contains_key("scope")); // None values are not inserted
+        assert!(map.contains_key("visibility"));
+        assert!(map.contains_key("column_start"));
+        assert!(map.contains_key("column_end"));
         assert!(map.contains_key("code"));
     }
 
@@ -605,6 +1257,11 @@ mod tests {
             line_end: 25,
             module: Some("crate::module".to_string()),
             scope: Some("impl Foo".to_string()),
+            qualified_name: "module::MyStruct".to_string(),
+            visibility: "public".to_string(),
+            column_start: 12,
+            column_end: 19,
+            modified_at: Some(1_700_000_000),
             code: "pub struct MyStruct { field: i32 }".to_string(),
         };
 
@@ -619,6 +1276,11 @@ mod tests {
         assert_eq!(restored.line_end, original.line_end);
         assert_eq!(restored.module, original.module);
         assert_eq!(restored.scope, original.scope);
+        assert_eq!(restored.qualified_name, original.qualified_name);
+        assert_eq!(restored.visibility, original.visibility);
+        assert_eq!(restored.column_start, original.column_start);
+        assert_eq!(restored.column_end, original.column_end);
+        assert_eq!(restored.modified_at, original.modified_at);
         assert_eq!(restored.code, original.code);
     }
 
@@ -633,6 +1295,11 @@ mod tests {
             line_end: 5,
             module: None,
             scope: None,
+            qualified_name: "test_fn".to_string(),
+            visibility: "public".to_string(),
+            column_start: 5,
+            column_end: 11,
+            modified_at: None,
             code: "def test_fn(): pass".to_string(),
         };
 
@@ -643,6 +1310,7 @@ mod tests {
         assert_eq!(restored.signature, None);
         assert_eq!(restored.module, None);
         assert_eq!(restored.scope, None);
+        assert_eq!(restored.modified_at, None);
     }
 
     #[test]
@@ -651,6 +1319,9 @@ mod tests {
         assert!(filter.file_path_prefix.is_none());
         assert!(filter.chunk_types.is_none());
         assert!(filter.language.is_none());
+        assert!(!filter.public_only);
+        assert!(filter.modified_after.is_none());
+        assert!(filter.modified_before.is_none());
     }
 
     #[test]
@@ -7,6 +7,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tracing::{debug, warn};
 
 /// A document to be reranked.
@@ -21,6 +22,8 @@ pub struct RerankerDoc {
 pub struct RerankResult {
     pub id: String,
     pub relevant: bool,
+    /// Relevance score, higher is better. Binary rerankers emit 1.0/0.0.
+    pub score: f32,
 }
 
 /// Trait for reranking search results.
@@ -29,6 +32,22 @@ pub trait Reranker: Send + Sync {
     /// Judge relevance of documents against a query.
     /// Returns results indicating which documents are relevant.
     async fn rerank(&self, query: &str, documents: &[RerankerDoc]) -> Result<Vec<RerankResult>>;
+
+    /// Rerank and drop results scoring below `min_score` in one pass.
+    ///
+    /// The default implementation reranks everything and filters; scoring
+    /// rerankers (e.g. a local cross-encoder) can override it to prune
+    /// during inference instead of after.
+    async fn rerank_filtered(
+        &self,
+        query: &str,
+        documents: &[RerankerDoc],
+        min_score: f32,
+    ) -> Result<Vec<RerankResult>> {
+        let mut results = self.rerank(query, documents).await?;
+        results.retain(|r| r.score >= min_score);
+        Ok(results)
+    }
 }
 
 /// Chat completions-based reranker compatible with LM Studio, Ollama, and OpenAI-compatible APIs.
@@ -157,6 +176,7 @@ impl Reranker for ChatReranker {
                             RerankResult {
                                 id: doc.id.clone(),
                                 relevant,
+                                score: if relevant { 1.0 } else { 0.0 },
                             }
                         }
                         Err(e) => {
@@ -164,6 +184,7 @@ impl Reranker for ChatReranker {
                             RerankResult {
                                 id: doc.id.clone(),
                                 relevant: true, // Keep on error (don't filter)
+                                score: 1.0,
                             }
                         }
                     }
@@ -173,6 +194,7 @@ impl Reranker for ChatReranker {
                     RerankResult {
                         id: doc.id.clone(),
                         relevant: true, // Keep on error (don't filter)
+                        score: 1.0,
                     }
                 }
             };
@@ -184,6 +206,143 @@ impl Reranker for ChatReranker {
     }
 }
 
+/// Reranker that fuses the judgments of several member rerankers.
+///
+/// Members run concurrently and each member's scores are min-max normalized
+/// before being combined into a weighted sum, so one member's score scale
+/// cannot dominate the others. Results are returned ordered by combined
+/// score, highest first. Implements [`Reranker`] itself, so ensembles
+/// compose with any other reranker.
+pub struct EnsembleReranker {
+    members: Vec<(Box<dyn Reranker>, f32)>,
+    relevance_threshold: f32,
+}
+
+impl EnsembleReranker {
+    pub fn new() -> Self {
+        Self {
+            members: Vec::new(),
+            relevance_threshold: 0.5,
+        }
+    }
+
+    /// Add a member reranker with the given weight.
+    pub fn with_member(mut self, reranker: Box<dyn Reranker>, weight: f32) -> Self {
+        self.members.push((reranker, weight));
+        self
+    }
+
+    /// Combined scores at or above this threshold are judged relevant (default 0.5).
+    pub fn with_relevance_threshold(mut self, threshold: f32) -> Self {
+        self.relevance_threshold = threshold;
+        self
+    }
+
+    /// Min-max normalize scores into [0, 1]. When all scores are equal the
+    /// values are clamped instead of scaled, preserving binary 0/1 judgments.
+    fn normalize(scores: &mut [f32]) {
+        if scores.is_empty() {
+            return;
+        }
+        let min = scores.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = scores.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let range = max - min;
+        if range <= f32::EPSILON {
+            for s in scores.iter_mut() {
+                *s = s.clamp(0.0, 1.0);
+            }
+        } else {
+            for s in scores.iter_mut() {
+                *s = (*s - min) / range;
+            }
+        }
+    }
+}
+
+impl Default for EnsembleReranker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Reranker for EnsembleReranker {
+    async fn rerank(&self, query: &str, documents: &[RerankerDoc]) -> Result<Vec<RerankResult>> {
+        if self.members.is_empty() {
+            // No members to consult - keep everything (don't filter)
+            return Ok(documents
+                .iter()
+                .map(|doc| RerankResult {
+                    id: doc.id.clone(),
+                    relevant: true,
+                    score: 1.0,
+                })
+                .collect());
+        }
+
+        let futures: Vec<_> = self
+            .members
+            .iter()
+            .map(|(reranker, _)| reranker.rerank(query, documents))
+            .collect();
+        let outcomes = futures::future::join_all(futures).await;
+
+        let mut combined: HashMap<&str, f32> =
+            documents.iter().map(|doc| (doc.id.as_str(), 0.0)).collect();
+        let mut applied_weight = 0.0_f32;
+
+        for ((_, weight), outcome) in self.members.iter().zip(outcomes) {
+            let results = match outcome {
+                Ok(results) => results,
+                Err(e) => {
+                    warn!(error = %e, "Ensemble member failed, skipping its votes");
+                    continue;
+                }
+            };
+
+            let mut scores: Vec<f32> = results.iter().map(|r| r.score).collect();
+            Self::normalize(&mut scores);
+            for (result, score) in results.iter().zip(scores) {
+                if let Some(entry) = combined.get_mut(result.id.as_str()) {
+                    *entry += weight * score;
+                }
+            }
+            applied_weight += weight;
+        }
+
+        if applied_weight <= 0.0 {
+            // Every member failed - keep everything (don't filter)
+            return Ok(documents
+                .iter()
+                .map(|doc| RerankResult {
+                    id: doc.id.clone(),
+                    relevant: true,
+                    score: 1.0,
+                })
+                .collect());
+        }
+
+        let mut results: Vec<RerankResult> = documents
+            .iter()
+            .map(|doc| {
+                let score = combined.get(doc.id.as_str()).copied().unwrap_or(0.0) / applied_weight;
+                RerankResult {
+                    id: doc.id.clone(),
+                    relevant: score >= self.relevance_threshold,
+                    score,
+                }
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Ok(results)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,6 +385,163 @@ mod tests {
         assert_eq!(doc.content, "fn hello() {}");
     }
 
+    /// Mock reranker that returns a fixed score per document id.
+    struct ScriptedReranker {
+        scores: HashMap<String, f32>,
+    }
+
+    impl ScriptedReranker {
+        fn new(scores: &[(&str, f32)]) -> Self {
+            Self {
+                scores: scores
+                    .iter()
+                    .map(|(id, score)| (id.to_string(), *score))
+                    .collect(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Reranker for ScriptedReranker {
+        async fn rerank(
+            &self,
+            _query: &str,
+            documents: &[RerankerDoc],
+        ) -> Result<Vec<RerankResult>> {
+            Ok(documents
+                .iter()
+                .map(|doc| {
+                    let score = self.scores.get(&doc.id).copied().unwrap_or(0.0);
+                    RerankResult {
+                        id: doc.id.clone(),
+                        relevant: score > 0.0,
+                        score,
+                    }
+                })
+                .collect())
+        }
+    }
+
+    /// Mock reranker that always fails.
+    struct FailingReranker;
+
+    #[async_trait]
+    impl Reranker for FailingReranker {
+        async fn rerank(
+            &self,
+            _query: &str,
+            _documents: &[RerankerDoc],
+        ) -> Result<Vec<RerankResult>> {
+            anyhow::bail!("reranker unavailable")
+        }
+    }
+
+    fn docs(ids: &[&str]) -> Vec<RerankerDoc> {
+        ids.iter()
+            .map(|id| RerankerDoc {
+                id: id.to_string(),
+                content: format!("content for {id}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_normalize_scales_to_unit_range() {
+        let mut scores = vec![10.0, 20.0, 30.0];
+        EnsembleReranker::normalize(&mut scores);
+        assert_eq!(scores, vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_normalize_constant_scores_clamp() {
+        let mut scores = vec![1.0, 1.0];
+        EnsembleReranker::normalize(&mut scores);
+        assert_eq!(scores, vec![1.0, 1.0]);
+
+        let mut scores = vec![5.0, 5.0];
+        EnsembleReranker::normalize(&mut scores);
+        assert_eq!(scores, vec![1.0, 1.0]);
+
+        let mut empty: Vec<f32> = vec![];
+        EnsembleReranker::normalize(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_combined_ordering() {
+        // Member 1 prefers a > b > c, member 2 prefers b > c > a.
+        // With equal weights the fused ordering is b, a, c.
+        let m1 = ScriptedReranker::new(&[("a", 100.0), ("b", 50.0), ("c", 0.0)]);
+        let m2 = ScriptedReranker::new(&[("a", 0.0), ("b", 1.0), ("c", 0.5)]);
+        let ensemble = EnsembleReranker::new()
+            .with_member(Box::new(m1), 0.5)
+            .with_member(Box::new(m2), 0.5);
+
+        let results = ensemble.rerank("query", &docs(&["a", "b", "c"])).await.unwrap();
+        let order: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(order, vec!["b", "a", "c"]);
+        assert!(results[0].relevant); // b: 0.75
+        assert!(results[1].relevant); // a: 0.5
+        assert!(!results[2].relevant); // c: 0.25
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_normalization_prevents_scale_domination() {
+        // Member 1 scores in the hundreds, member 2 is binary. After
+        // normalization the binary member still moves the ordering.
+        let m1 = ScriptedReranker::new(&[("a", 100.0), ("b", 90.0), ("c", 0.0)]);
+        let m2 = ScriptedReranker::new(&[("a", 0.0), ("b", 1.0), ("c", 1.0)]);
+        let ensemble = EnsembleReranker::new()
+            .with_member(Box::new(m1), 0.5)
+            .with_member(Box::new(m2), 0.5);
+
+        let results = ensemble.rerank("query", &docs(&["a", "b", "c"])).await.unwrap();
+        assert_eq!(results[0].id, "b");
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_failed_member_is_skipped() {
+        let m1 = ScriptedReranker::new(&[("a", 1.0), ("b", 0.0)]);
+        let ensemble = EnsembleReranker::new()
+            .with_member(Box::new(m1), 0.5)
+            .with_member(Box::new(FailingReranker), 0.5);
+
+        let results = ensemble.rerank("query", &docs(&["a", "b"])).await.unwrap();
+        // Surviving member's weight is renormalized, so its normalized
+        // scores come through at full strength.
+        assert_eq!(results[0].id, "a");
+        assert!((results[0].score - 1.0).abs() < f32::EPSILON);
+        assert!(results[0].relevant);
+        assert!(!results[1].relevant);
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_no_members_keeps_everything() {
+        let ensemble = EnsembleReranker::new();
+        let results = ensemble.rerank("query", &docs(&["a", "b"])).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.relevant));
+    }
+
+    #[tokio::test]
+    async fn test_rerank_filtered_drops_results_below_cutoff() {
+        let reranker = ScriptedReranker::new(&[("a", 0.9), ("b", 0.4), ("c", 0.6)]);
+
+        let results = reranker
+            .rerank_filtered("query", &docs(&["a", "b", "c"]), 0.5)
+            .await
+            .unwrap();
+        let kept: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(kept, vec!["a", "c"]);
+
+        // A zero cutoff keeps everything, same as plain rerank
+        let all = reranker
+            .rerank_filtered("query", &docs(&["a", "b", "c"]), 0.0)
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 3);
+    }
+
     #[test]
     fn test_chat_reranker_new() {
         let reranker = ChatReranker::new(
@@ -18,6 +18,15 @@ pub struct Document {
     pub tokens: Vec<String>,
 }
 
+/// A query split into loose terms and quoted phrases
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ParsedQuery {
+    /// Bag-of-words terms outside quotes
+    terms: Vec<String>,
+    /// Quoted multi-word phrases that must appear adjacently
+    phrases: Vec<Vec<String>>,
+}
+
 /// BM25 search index
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BM25Index {
@@ -54,6 +63,35 @@ impl BM25Index {
             .collect()
     }
 
+    /// Split a query into loose terms and quoted phrases.
+    ///
+    /// Text inside double quotes becomes a phrase whose tokens must appear
+    /// adjacently in a document; everything else keeps bag-of-words
+    /// semantics. A single-token "phrase" degrades to a loose term.
+    fn parse_query(query: &str) -> ParsedQuery {
+        let mut parsed = ParsedQuery::default();
+        for (i, segment) in query.split('"').enumerate() {
+            let tokens = Self::tokenize(segment);
+            if i % 2 == 1 && tokens.len() > 1 {
+                parsed.phrases.push(tokens);
+            } else {
+                parsed.terms.extend(tokens);
+            }
+        }
+        parsed
+    }
+
+    /// Count adjacent occurrences of a phrase in a token sequence.
+    fn count_phrase_occurrences(tokens: &[String], phrase: &[String]) -> usize {
+        if phrase.is_empty() || tokens.len() < phrase.len() {
+            return 0;
+        }
+        tokens
+            .windows(phrase.len())
+            .filter(|window| window.iter().zip(phrase).all(|(a, b)| a == b))
+            .count()
+    }
+
     /// Add a document to the index
     pub fn add_document(&mut self, id: String, text: String) {
         let tokens = Self::tokenize(&text);
@@ -124,8 +162,8 @@ impl BM25Index {
         ((n - doc_freq + 0.5) / (doc_freq + 0.5) + 1.0).ln()
     }
 
-    /// Calculate BM25 score for a document given a query
-    fn score_document(&self, doc_id: &str, query_terms: &[String]) -> f64 {
+    /// Calculate BM25 score for a document given a parsed query
+    fn score_document(&self, doc_id: &str, query: &ParsedQuery) -> f64 {
         let doc = match self.documents.get(doc_id) {
             Some(d) => d,
             None => return 0.0,
@@ -141,7 +179,7 @@ impl BM25Index {
 
         let mut score = 0.0;
 
-        for term in query_terms {
+        for term in &query.terms {
             let idf = self.calculate_idf(term);
             let tf = term_freqs.get(term.as_str()).copied().unwrap_or(0) as f64;
 
@@ -152,14 +190,32 @@ impl BM25Index {
             }
         }
 
+        // A phrase only contributes when its tokens appear adjacently;
+        // scattered occurrences of the words score nothing. The phrase is
+        // scored as a pseudo-term whose IDF sums the member terms, so an
+        // exact match outranks bag-of-words hits on the same words.
+        for phrase in &query.phrases {
+            let tf = Self::count_phrase_occurrences(&doc.tokens, phrase) as f64;
+            if tf > 0.0 {
+                let idf: f64 = phrase.iter().map(|t| self.calculate_idf(t)).sum();
+                let numerator = tf * (K1 + 1.0);
+                let denominator = tf + K1 * (1.0 - B + B * (doc_length / self.avg_doc_length));
+                score += idf * (numerator / denominator);
+            }
+        }
+
         score
     }
 
-    /// Search the index and return ranked results
+    /// Search the index and return ranked results.
+    ///
+    /// Double-quoted spans in the query (e.g. `"connection pool"`) require
+    /// the words adjacent in the document; unquoted terms keep bag-of-words
+    /// behavior.
     pub fn search(&self, query: &str, limit: usize) -> Vec<(String, f64)> {
-        let query_terms = Self::tokenize(query);
+        let parsed = Self::parse_query(query);
 
-        if query_terms.is_empty() {
+        if parsed.terms.is_empty() && parsed.phrases.is_empty() {
             return Vec::new();
         }
 
@@ -167,7 +223,7 @@ impl BM25Index {
             .documents
             .keys()
             .map(|id| {
-                let score = self.score_document(id, &query_terms);
+                let score = self.score_document(id, &parsed);
                 (id.clone(), score)
             })
             .filter(|(_, score)| *score > 0.0)
@@ -438,6 +494,65 @@ mod tests {
         assert!((index.avg_doc_length - 2.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_parse_query_mixed_terms_and_phrases() {
+        let parsed = BM25Index::parse_query(r#"async "connection pool" timeout"#);
+        assert_eq!(parsed.terms, vec!["async".to_string(), "timeout".to_string()]);
+        assert_eq!(
+            parsed.phrases,
+            vec![vec!["connection".to_string(), "pool".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_parse_query_single_token_phrase_is_loose_term() {
+        let parsed = BM25Index::parse_query(r#""timeout""#);
+        assert_eq!(parsed.terms, vec!["timeout".to_string()]);
+        assert!(parsed.phrases.is_empty());
+    }
+
+    #[test]
+    fn test_quoted_phrase_requires_adjacency() {
+        let mut index = BM25Index::new();
+        index.add_document(
+            "adjacent".to_string(),
+            "the connection pool limits concurrent clients".to_string(),
+        );
+        index.add_document(
+            "scattered".to_string(),
+            "pool the workers and retry the connection later".to_string(),
+        );
+
+        // Quoted: only the document with the adjacent phrase scores
+        let results = index.search(r#""connection pool""#, 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "adjacent");
+
+        // Unquoted: bag-of-words still matches both
+        let results = index.search("connection pool", 10);
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_phrase_outranks_scattered_with_loose_terms() {
+        let mut index = BM25Index::new();
+        index.add_document(
+            "adjacent".to_string(),
+            "initialize the connection pool at startup".to_string(),
+        );
+        index.add_document(
+            "scattered".to_string(),
+            "drain the pool and drop each connection".to_string(),
+        );
+
+        // Mixed query: both docs match the loose terms, but the phrase
+        // contribution pushes the adjacent document on top
+        let results = index.search(r#"connection pool "connection pool""#, 10);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "adjacent");
+        assert!(results[0].1 > results[1].1);
+    }
+
     #[test]
     fn test_multiple_term_query() {
         let mut index = BM25Index::new();
@@ -0,0 +1,195 @@
+//! Retrieval-quality metrics for tuning search against a labeled query set.
+//!
+//! Weight and reranker changes are hard to judge from anecdotes: a tweak
+//! that fixes one query quietly breaks three others. Given queries labeled
+//! with their relevant chunk ids, this module scores a ranking with the
+//! standard retrieval metrics (MRR, recall@k, nDCG@k with binary
+//! relevance), so tuning becomes measurement instead of guesswork. See
+//! [`HybridSearcher::evaluate`](super::HybridSearcher::evaluate) for the
+//! end-to-end entry point.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+/// Aggregate retrieval-quality metrics over a labeled query set.
+///
+/// All metrics are means over the individual queries; `per_query` keeps
+/// the breakdown so regressions can be traced to specific queries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvalReport {
+    /// Number of queries evaluated
+    pub queries: usize,
+    /// Cutoff used for recall@k and nDCG@k
+    pub k: usize,
+    /// Mean reciprocal rank of the first relevant result
+    pub mrr: f64,
+    /// Mean fraction of relevant chunks retrieved in the top k
+    pub recall_at_k: f64,
+    /// Mean normalized discounted cumulative gain at k (binary relevance)
+    pub ndcg_at_k: f64,
+    /// Per-query breakdown, in input order
+    pub per_query: Vec<QueryEval>,
+}
+
+impl EvalReport {
+    /// Aggregate per-query metrics into a report (means over queries).
+    pub fn from_queries(per_query: Vec<QueryEval>, k: usize) -> Self {
+        let n = per_query.len();
+        let mean = |f: fn(&QueryEval) -> f64| {
+            if n == 0 {
+                0.0
+            } else {
+                per_query.iter().map(f).sum::<f64>() / n as f64
+            }
+        };
+
+        Self {
+            queries: n,
+            k,
+            mrr: mean(|q| q.reciprocal_rank),
+            recall_at_k: mean(|q| q.recall_at_k),
+            ndcg_at_k: mean(|q| q.ndcg_at_k),
+            per_query,
+        }
+    }
+}
+
+/// Metrics for a single labeled query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryEval {
+    /// The query text
+    pub query: String,
+    /// 1/rank of the first relevant result (0 when none was retrieved)
+    pub reciprocal_rank: f64,
+    /// Fraction of relevant chunks retrieved in the top k
+    pub recall_at_k: f64,
+    /// Normalized discounted cumulative gain at k (binary relevance)
+    pub ndcg_at_k: f64,
+}
+
+impl QueryEval {
+    /// Score one ranking against its labeled relevant ids.
+    ///
+    /// A query with no labeled ids scores zero on every metric; labeling
+    /// mistakes show up as a hard miss rather than silently inflating
+    /// the averages.
+    pub fn compute(query: &str, ranked: &[String], relevant: &[String], k: usize) -> Self {
+        let relevant: HashSet<&str> = relevant.iter().map(String::as_str).collect();
+        Self {
+            query: query.to_string(),
+            reciprocal_rank: reciprocal_rank(ranked, &relevant),
+            recall_at_k: recall_at_k(ranked, &relevant, k),
+            ndcg_at_k: ndcg_at_k(ranked, &relevant, k),
+        }
+    }
+}
+
+/// 1/rank of the first relevant id in `ranked` (1-indexed), 0 when none.
+pub fn reciprocal_rank(ranked: &[String], relevant: &HashSet<&str>) -> f64 {
+    ranked
+        .iter()
+        .position(|id| relevant.contains(id.as_str()))
+        .map(|pos| 1.0 / (pos + 1) as f64)
+        .unwrap_or(0.0)
+}
+
+/// Fraction of `relevant` retrieved within the top `k` of `ranked`.
+pub fn recall_at_k(ranked: &[String], relevant: &HashSet<&str>, k: usize) -> f64 {
+    if relevant.is_empty() {
+        return 0.0;
+    }
+    let retrieved = ranked
+        .iter()
+        .take(k)
+        .filter(|id| relevant.contains(id.as_str()))
+        .count();
+    retrieved as f64 / relevant.len() as f64
+}
+
+/// Normalized discounted cumulative gain at `k` with binary relevance.
+///
+/// Relevant results earn 1/log2(position+1) gain; the ideal ranking puts
+/// all relevant ids first, so nDCG is 1.0 exactly when they are.
+pub fn ndcg_at_k(ranked: &[String], relevant: &HashSet<&str>, k: usize) -> f64 {
+    let discount = |position: usize| 1.0 / ((position + 2) as f64).log2();
+
+    let dcg: f64 = ranked
+        .iter()
+        .take(k)
+        .enumerate()
+        .filter(|(_, id)| relevant.contains(id.as_str()))
+        .map(|(i, _)| discount(i))
+        .sum();
+
+    let ideal: f64 = (0..relevant.len().min(k)).map(discount).sum();
+    if ideal == 0.0 {
+        0.0
+    } else {
+        dcg / ideal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_reciprocal_rank_of_known_rankings() {
+        let relevant: HashSet<&str> = ["b"].into_iter().collect();
+
+        // Relevant at rank 1, rank 2, and absent
+        assert_eq!(reciprocal_rank(&ids(&["b", "a"]), &relevant), 1.0);
+        assert_eq!(reciprocal_rank(&ids(&["a", "b"]), &relevant), 0.5);
+        assert_eq!(reciprocal_rank(&ids(&["a", "c"]), &relevant), 0.0);
+    }
+
+    #[test]
+    fn test_recall_at_k_counts_only_top_k() {
+        let relevant: HashSet<&str> = ["a", "b"].into_iter().collect();
+        let ranked = ids(&["a", "x", "b"]);
+
+        assert_eq!(recall_at_k(&ranked, &relevant, 3), 1.0);
+        assert_eq!(recall_at_k(&ranked, &relevant, 2), 0.5);
+        assert_eq!(recall_at_k(&ranked, &HashSet::new(), 3), 0.0);
+    }
+
+    #[test]
+    fn test_ndcg_rewards_relevant_results_ranked_early() {
+        let relevant: HashSet<&str> = ["a"].into_iter().collect();
+
+        // Ideal ranking scores 1.0; pushing the hit to rank 2 discounts it
+        assert!((ndcg_at_k(&ids(&["a", "x"]), &relevant, 2) - 1.0).abs() < 1e-9);
+        let demoted = ndcg_at_k(&ids(&["x", "a"]), &relevant, 2);
+        assert!((demoted - 1.0 / 3.0_f64.log2()).abs() < 1e-9);
+        assert_eq!(ndcg_at_k(&ids(&["x", "y"]), &relevant, 2), 0.0);
+    }
+
+    #[test]
+    fn test_report_averages_known_mrr() {
+        // First query hits at rank 1 (rr 1.0), second at rank 2 (rr 0.5)
+        let per_query = vec![
+            QueryEval::compute("q1", &ids(&["a", "b"]), &ids(&["a"]), 5),
+            QueryEval::compute("q2", &ids(&["x", "b"]), &ids(&["b"]), 5),
+        ];
+        let report = EvalReport::from_queries(per_query, 5);
+
+        assert_eq!(report.queries, 2);
+        assert!((report.mrr - 0.75).abs() < 1e-9);
+        assert!((report.recall_at_k - 1.0).abs() < 1e-9);
+        assert_eq!(report.per_query[1].reciprocal_rank, 0.5);
+    }
+
+    #[test]
+    fn test_empty_query_set_reports_zeros() {
+        let report = EvalReport::from_queries(Vec::new(), 10);
+        assert_eq!(report.queries, 0);
+        assert_eq!(report.mrr, 0.0);
+        assert_eq!(report.recall_at_k, 0.0);
+        assert_eq!(report.ndcg_at_k, 0.0);
+    }
+}
@@ -5,19 +5,28 @@
 //! using Reciprocal Rank Fusion (RRF).
 
 pub mod bm25;
+pub mod eval;
+pub mod multi;
+pub mod similarity;
 
 pub use bm25::BM25Index;
+pub use eval::{EvalReport, QueryEval};
+pub use multi::MultiCollectionSearcher;
+pub use similarity::SimilarityMetric;
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, warn};
 
 use crate::embeddings::EmbeddingProvider;
-use crate::qdrant::{QdrantClient, SearchFilter, SearchHit};
+use crate::graph::CodeGraph;
+use crate::qdrant::{PointPayload, QdrantClient, SearchFilter, SearchHit};
 use crate::reranker::{Reranker, RerankerDoc};
 
 /// A search result with relevance score and metadata.
@@ -31,6 +40,13 @@ pub struct SearchResult {
     pub start_line: usize,
     /// End line of the matched code
     pub end_line: usize,
+    /// Start column of the symbol's name identifier (1-indexed; 1 for
+    /// chunks indexed before columns were captured)
+    #[serde(default = "default_column")]
+    pub start_col: usize,
+    /// End column of the symbol's name identifier (1-indexed, inclusive)
+    #[serde(default = "default_column")]
+    pub end_col: usize,
     /// The matching code content
     pub content: String,
     /// Kind of code element (function, class, etc.)
@@ -41,12 +57,119 @@ pub struct SearchResult {
     pub signature: Option<String>,
     /// Enclosing scope (e.g., "impl Foo")
     pub scope: Option<String>,
+    /// Fully-qualified symbol path (e.g., "auth::Foo::validate"), when indexed
+    pub qualified_name: Option<String>,
+    /// Id of the knowledge-graph symbol covering this chunk's line range,
+    /// when a graph is available. Lets callers pivot straight to graph
+    /// tools (callers, references) without a name lookup.
+    #[serde(default)]
+    pub symbol_id: Option<String>,
+    /// Qualified name of the nearest enclosing graph symbol (e.g.
+    /// "Session::login") when the matched lines sit inside a larger definition
+    #[serde(default)]
+    pub enclosing_symbol: Option<String>,
+    /// Signature of that enclosing symbol, so a fragment from deep inside a
+    /// method body still shows what it belongs to
+    #[serde(default)]
+    pub enclosing_signature: Option<String>,
+    /// Collection/repo the result came from, set by cross-repo search
+    /// (None for single-collection searches)
+    #[serde(default)]
+    pub repo: Option<String>,
+    /// Score breakdown for relevance debugging, populated only when
+    /// `SearchConfig::explain` is on
+    #[serde(default)]
+    pub explanation: Option<ScoreExplanation>,
     /// Combined relevance score (higher is better)
     pub score: f32,
     /// Vector similarity score component
     pub vector_score: Option<f32>,
     /// BM25 score component (if applicable)
     pub bm25_score: Option<f32>,
+    /// True when content was re-read from disk and the file no longer
+    /// matches the indexed line range (content may be truncated or empty)
+    #[serde(default)]
+    pub stale: bool,
+}
+
+/// Column fallback for results deserialized from before columns were captured.
+fn default_column() -> usize {
+    1
+}
+
+impl SearchResult {
+    /// Attach the nearest enclosing graph symbol to this result.
+    ///
+    /// Sets `symbol_id`, `enclosing_symbol` (qualified name) and
+    /// `enclosing_signature` from the narrowest symbol covering the matched
+    /// line range, so a match deep inside a method body still shows the
+    /// method it belongs to.
+    pub fn attach_enclosing_symbol(&mut self, graph: &CodeGraph) {
+        match graph.symbol_covering(&self.file_path, self.start_line, self.end_line) {
+            Some(symbol) => {
+                self.symbol_id = Some(symbol.id.clone());
+                self.enclosing_symbol = graph.qualified_symbol_name(&symbol.id);
+                self.enclosing_signature = symbol.signature.clone();
+            }
+            None => {
+                self.symbol_id = None;
+                self.enclosing_symbol = None;
+                self.enclosing_signature = None;
+            }
+        }
+    }
+}
+
+/// Breakdown of how a result's final score was assembled.
+///
+/// Answers "did vector or BM25 drive this ranking?" when tuning weights:
+/// the RRF contributions sum to the pre-boost score, and `recency_boost`
+/// is the multiplier applied on top (1.0 = none).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreExplanation {
+    /// 1-indexed rank in the vector candidate list (None = not retrieved)
+    pub vector_rank: Option<usize>,
+    /// Raw cosine similarity from the vector search
+    pub vector_score: Option<f32>,
+    /// Weighted RRF contribution from the vector ranking
+    pub vector_rrf: f32,
+    /// 1-indexed rank in the BM25 candidate list (None = not retrieved)
+    pub bm25_rank: Option<usize>,
+    /// Raw BM25 score
+    pub bm25_score: Option<f64>,
+    /// Weighted RRF contribution from the BM25 ranking
+    pub bm25_rrf: f32,
+    /// Recency multiplier applied to the fused score (1.0 = no boost)
+    pub recency_boost: f32,
+}
+
+/// How vector and BM25 rankings are fused into a single score.
+///
+/// RRF is rank-based and robust to incomparable score scales, which makes
+/// it the safe default. The score-based methods preserve the margins
+/// within each source, which can outperform RRF on corpora where raw
+/// scores carry real signal, at the cost of sensitivity to scale quirks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FusionMethod {
+    /// Reciprocal Rank Fusion: `weight / (k + rank)` per source (default)
+    #[default]
+    Rrf,
+    /// Weighted sum of min-max normalized scores per source
+    WeightedSum,
+    /// CombSUM: unweighted sum of min-max normalized scores
+    CombSum,
+}
+
+impl FusionMethod {
+    /// Parse a user-facing method name.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "rrf" => Some(Self::Rrf),
+            "weighted-sum" | "weighted_sum" | "weightedsum" => Some(Self::WeightedSum),
+            "combsum" | "comb-sum" | "comb_sum" => Some(Self::CombSum),
+            _ => None,
+        }
+    }
 }
 
 /// Configuration for hybrid search.
@@ -60,10 +183,29 @@ pub struct SearchConfig {
     pub bm25_weight: f32,
     /// Minimum score threshold
     pub min_score: f32,
+    /// Minimum raw cosine similarity for the vector component (None = no
+    /// floor). Applied before fusion, so BM25 keyword matches cannot boost
+    /// semantically-irrelevant chunks back into the results.
+    pub min_vector_score: Option<f32>,
     /// Enable hybrid search (vector + BM25)
     pub hybrid: bool,
     /// RRF k parameter (default 60)
     pub rrf_k: f32,
+    /// How vector and BM25 rankings are combined (default RRF)
+    pub fusion: FusionMethod,
+    /// Keep only the highest-scoring chunk per file (default false).
+    /// Broadens file coverage for "which files are relevant" queries.
+    pub dedupe_by_file: bool,
+    /// Strength of the recency boost (0.0 = off, the default). Scores are
+    /// multiplied by `1 + weight * decay(file age)`, so recently-modified
+    /// files edge out identical matches in untouched ones. Requires a
+    /// `FileIndex` attached via `with_file_index`.
+    pub recency_weight: f32,
+    /// Number of top candidates to send to the reranker (None = all retrieved)
+    pub rerank_top_n: Option<usize>,
+    /// Populate per-result score explanations (default false). Adds a
+    /// little bookkeeping per result, so leave off outside of debugging.
+    pub explain: bool,
 }
 
 impl Default for SearchConfig {
@@ -73,8 +215,75 @@ impl Default for SearchConfig {
             vector_weight: 0.7,
             bm25_weight: 0.3,
             min_score: 0.0,
+            min_vector_score: None,
             hybrid: true,
             rrf_k: 60.0,
+            fusion: FusionMethod::default(),
+            dedupe_by_file: false,
+            recency_weight: 0.0,
+            rerank_top_n: None,
+            explain: false,
+        }
+    }
+}
+
+/// Tuned search profiles for common use cases.
+///
+/// Presets spare users from hand-tuning RRF weights: pick the profile that
+/// matches the query intent and get sensible parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchPreset {
+    /// Navigating code by meaning ("where is retry handled"): vector-heavy
+    /// with a semantic floor so keyword noise can't surface irrelevant code
+    CodeNavigation,
+    /// Exact identifiers and quoted phrases: BM25-heavy, so literal token
+    /// and phrase matches outrank loosely-related code
+    KeywordExact,
+    /// Natural-language documentation queries: searches docstring chunks
+    /// (requires an index built with `index_docstrings`)
+    DocSearch,
+}
+
+impl SearchPreset {
+    /// Parse a preset from a user-facing mode string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "code" | "code-nav" | "navigation" => Some(SearchPreset::CodeNavigation),
+            "keyword" | "exact" => Some(SearchPreset::KeywordExact),
+            "docs" | "doc" | "docstring" => Some(SearchPreset::DocSearch),
+            _ => None,
+        }
+    }
+
+    /// Filter implied by this preset, if any.
+    pub fn filter(&self) -> Option<SearchFilter> {
+        match self {
+            SearchPreset::DocSearch => Some(SearchFilter::new().docstrings_only()),
+            _ => None,
+        }
+    }
+}
+
+impl SearchConfig {
+    /// Build a tuned config for a common use case.
+    pub fn preset(preset: SearchPreset) -> Self {
+        match preset {
+            SearchPreset::CodeNavigation => Self {
+                vector_weight: 0.85,
+                bm25_weight: 0.15,
+                min_vector_score: Some(0.25),
+                ..Self::default()
+            },
+            SearchPreset::KeywordExact => Self {
+                vector_weight: 0.2,
+                bm25_weight: 0.8,
+                ..Self::default()
+            },
+            SearchPreset::DocSearch => Self {
+                vector_weight: 0.75,
+                bm25_weight: 0.25,
+                ..Self::default()
+            },
         }
     }
 }
@@ -111,13 +320,282 @@ pub fn reciprocal_rank_fusion(
     results
 }
 
+/// Min-max normalize a ranking's scores into [0, 1].
+///
+/// A constant list (max == min) maps every score to 1.0, so a
+/// single-candidate source still contributes fully.
+fn min_max_normalized(results: &[(String, f32)]) -> HashMap<String, f32> {
+    let mut normalized = HashMap::new();
+    if results.is_empty() {
+        return normalized;
+    }
+    let mut min = f32::INFINITY;
+    let mut max = f32::NEG_INFINITY;
+    for (_, score) in results {
+        min = min.min(*score);
+        max = max.max(*score);
+    }
+    let range = max - min;
+    for (id, score) in results {
+        let value = if range > f32::EPSILON {
+            (score - min) / range
+        } else {
+            1.0
+        };
+        normalized.insert(id.clone(), value);
+    }
+    normalized
+}
+
+/// Weighted sum of min-max normalized scores from each source.
+///
+/// Candidates missing from a source contribute nothing for it, so a
+/// chunk only BM25 found competes on its keyword score alone.
+pub fn weighted_sum_fusion(
+    vector_results: &[(String, f32)],
+    bm25_results: &[(String, f64)],
+    vector_weight: f32,
+    bm25_weight: f32,
+) -> Vec<(String, f32)> {
+    let bm25_scores: Vec<(String, f32)> = bm25_results
+        .iter()
+        .map(|(id, score)| (id.clone(), *score as f32))
+        .collect();
+
+    let mut scores: HashMap<String, f32> = HashMap::new();
+    for (id, value) in min_max_normalized(vector_results) {
+        *scores.entry(id).or_default() += vector_weight * value;
+    }
+    for (id, value) in min_max_normalized(&bm25_scores) {
+        *scores.entry(id).or_default() += bm25_weight * value;
+    }
+
+    let mut results: Vec<(String, f32)> = scores.into_iter().collect();
+    results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    results
+}
+
+/// CombSUM: unweighted sum of min-max normalized scores.
+pub fn comb_sum_fusion(
+    vector_results: &[(String, f32)],
+    bm25_results: &[(String, f64)],
+) -> Vec<(String, f32)> {
+    weighted_sum_fusion(vector_results, bm25_results, 1.0, 1.0)
+}
+
+/// Fuse the two source rankings with the configured method.
+pub fn fuse_rankings(
+    method: FusionMethod,
+    vector_results: &[(String, f32)],
+    bm25_results: &[(String, f64)],
+    rrf_k: f32,
+    vector_weight: f32,
+    bm25_weight: f32,
+) -> Vec<(String, f32)> {
+    match method {
+        FusionMethod::Rrf => reciprocal_rank_fusion(
+            vector_results,
+            bm25_results,
+            rrf_k,
+            vector_weight,
+            bm25_weight,
+        ),
+        FusionMethod::WeightedSum => {
+            weighted_sum_fusion(vector_results, bm25_results, vector_weight, bm25_weight)
+        }
+        FusionMethod::CombSum => comb_sum_fusion(vector_results, bm25_results),
+    }
+}
+
+/// Pre-fusion filter dropping vector hits below a cosine-similarity floor.
+///
+/// Dropped candidates are excluded entirely, so a BM25 keyword match
+/// cannot boost a semantically-irrelevant chunk back into the results.
+fn filter_by_vector_score(hits: Vec<SearchHit>, min_vector_score: Option<f32>) -> Vec<SearchHit> {
+    let Some(min) = min_vector_score else {
+        return hits;
+    };
+    hits.into_iter().filter(|hit| hit.score >= min).collect()
+}
+
+/// Build a score breakdown for one candidate, mirroring the RRF formula.
+///
+/// The per-source contributions assume RRF fusion; with a score-based
+/// [`FusionMethod`] the ranks are still informative but the RRF terms no
+/// longer sum to the fused score.
+///
+/// With BM25 results present, the per-source contributions use the same
+/// `weight / (k + rank)` terms as `reciprocal_rank_fusion` and sum to the
+/// fused score. In vector-only mode the final score is the raw similarity,
+/// so it is reported whole as the vector contribution.
+fn explain_score(
+    id: &str,
+    vector_results: &[(String, f32)],
+    bm25_results: Option<&[(String, f64)]>,
+    config: &SearchConfig,
+) -> ScoreExplanation {
+    let vector_pos = vector_results.iter().position(|(vid, _)| vid == id);
+    let vector_score = vector_pos.map(|pos| vector_results[pos].1);
+
+    let bm25_pos = bm25_results.and_then(|bm25| bm25.iter().position(|(bid, _)| bid == id));
+    let bm25_score = bm25_pos.and_then(|pos| bm25_results.map(|bm25| bm25[pos].1));
+
+    let (vector_rrf, bm25_rrf) = if bm25_results.is_some() {
+        (
+            vector_pos
+                .map(|pos| config.vector_weight / (config.rrf_k + pos as f32 + 1.0))
+                .unwrap_or(0.0),
+            bm25_pos
+                .map(|pos| config.bm25_weight / (config.rrf_k + pos as f32 + 1.0))
+                .unwrap_or(0.0),
+        )
+    } else {
+        (vector_score.unwrap_or(0.0), 0.0)
+    };
+
+    ScoreExplanation {
+        vector_rank: vector_pos.map(|pos| pos + 1),
+        vector_score,
+        vector_rrf,
+        bm25_rank: bm25_pos.map(|pos| pos + 1),
+        bm25_score,
+        bm25_rrf,
+        recency_boost: 1.0,
+    }
+}
+
+/// Post-fusion filter keeping only the best-ranked chunk per file.
+///
+/// Results must already be sorted by descending score; the first chunk
+/// seen for each `file_path` wins and later ones are dropped.
+fn best_chunk_per_file(results: Vec<SearchResult>) -> Vec<SearchResult> {
+    let mut seen_files = std::collections::HashSet::new();
+    results
+        .into_iter()
+        .filter(|r| seen_files.insert(r.file_path.clone()))
+        .collect()
+}
+
+/// Exclusion options for `find_similar`.
+///
+/// Keeps the query's own chunk (and optionally its whole file) out of
+/// similarity results.
+#[derive(Debug, Clone, Default)]
+pub struct SimilarityExclusion {
+    /// Chunk ID to exclude (the origin chunk)
+    pub exclude_id: Option<String>,
+    /// File path to exclude entirely (the origin file)
+    pub exclude_file: Option<String>,
+}
+
+impl SimilarityExclusion {
+    /// Create a new empty exclusion.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exclude a specific chunk by ID.
+    pub fn with_exclude_id(mut self, id: impl Into<String>) -> Self {
+        self.exclude_id = Some(id.into());
+        self
+    }
+
+    /// Exclude all chunks from a file.
+    pub fn with_exclude_file(mut self, file_path: impl Into<String>) -> Self {
+        self.exclude_file = Some(file_path.into());
+        self
+    }
+
+    /// Check whether a result should be excluded.
+    fn excludes(&self, result: &SearchResult) -> bool {
+        if let Some(ref id) = self.exclude_id {
+            if &result.id == id {
+                return true;
+            }
+        }
+        if let Some(ref file) = self.exclude_file {
+            if &result.file_path == file {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Filter excluded results out of a result list.
+    pub fn apply(&self, results: Vec<SearchResult>) -> Vec<SearchResult> {
+        results.into_iter().filter(|r| !self.excludes(r)).collect()
+    }
+}
+
+/// Re-read chunk content from disk by line range.
+///
+/// Used when the index was built with `store_content: false`. Returns the
+/// content and a staleness flag that is true when the file is missing or
+/// the stored line range no longer fits the file (content is truncated to
+/// what is available, or empty).
+fn read_content_from_disk(
+    root: &Path,
+    file_path: &str,
+    line_start: usize,
+    line_end: usize,
+) -> (String, bool) {
+    let path = Path::new(file_path);
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        root.join(path)
+    };
+
+    let Ok(text) = std::fs::read_to_string(&path) else {
+        debug!("Content file missing or unreadable: {:?}", path);
+        return (String::new(), true);
+    };
+
+    let lines: Vec<&str> = text.lines().collect();
+    if line_start == 0 || line_start > lines.len() {
+        return (String::new(), true);
+    }
+
+    let end = line_end.min(lines.len());
+    let stale = line_end > lines.len();
+    (lines[line_start - 1..end].join("\n"), stale)
+}
+
 /// Hybrid searcher combining vector and BM25 search.
+///
+/// Safe to share across tasks: cloning is cheap (all heavyweight members —
+/// embedding client, Qdrant connection, BM25 index — sit behind `Arc`s), so
+/// concurrent searches reuse the same HTTP connection pools instead of
+/// reconnecting per call.
 pub struct HybridSearcher<E: EmbeddingProvider + ?Sized> {
     config: SearchConfig,
     embeddings: Arc<E>,
-    qdrant: QdrantClient,
+    qdrant: Arc<QdrantClient>,
     bm25_index: Arc<RwLock<BM25Index>>,
     reranker: Option<Arc<dyn Reranker>>,
+    /// Workspace root for re-reading content from disk when the index
+    /// was built without stored content
+    content_root: Option<PathBuf>,
+    /// File metadata for the recency boost (see `SearchConfig::recency_weight`)
+    file_index: Option<Arc<crate::storage::FileIndex>>,
+}
+
+/// Age at which the recency boost halves (one week).
+const RECENCY_HALF_LIFE_SECS: f32 = 7.0 * 24.0 * 3600.0;
+
+// Derived Clone would require `E: Clone`; only the `Arc` handles are cloned.
+impl<E: EmbeddingProvider + ?Sized> Clone for HybridSearcher<E> {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            embeddings: Arc::clone(&self.embeddings),
+            qdrant: Arc::clone(&self.qdrant),
+            bm25_index: Arc::clone(&self.bm25_index),
+            reranker: self.reranker.clone(),
+            content_root: self.content_root.clone(),
+            file_index: self.file_index.clone(),
+        }
+    }
 }
 
 impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
@@ -131,9 +609,11 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
         Self {
             config,
             embeddings,
-            qdrant,
+            qdrant: Arc::new(qdrant),
             bm25_index,
             reranker: None,
+            content_root: None,
+            file_index: None,
         }
     }
 
@@ -148,9 +628,11 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
         Self {
             config,
             embeddings,
-            qdrant,
+            qdrant: Arc::new(qdrant),
             bm25_index,
             reranker,
+            content_root: None,
+            file_index: None,
         }
     }
 
@@ -163,9 +645,51 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
         Self {
             config,
             embeddings,
-            qdrant,
+            qdrant: Arc::new(qdrant),
             bm25_index: Arc::new(RwLock::new(BM25Index::new())),
             reranker: None,
+            content_root: None,
+            file_index: None,
+        }
+    }
+
+    /// Attach a reranker applied to the top retrieved candidates.
+    pub fn with_reranker(mut self, reranker: Arc<dyn Reranker>) -> Self {
+        self.reranker = Some(reranker);
+        self
+    }
+
+    /// Set the workspace root used to re-read chunk content from disk.
+    ///
+    /// Required for indexes built with `store_content: false`; without it
+    /// such results come back with empty content.
+    pub fn with_content_root(mut self, root: PathBuf) -> Self {
+        self.content_root = Some(root);
+        self
+    }
+
+    /// Attach file metadata used by the recency boost.
+    ///
+    /// Without it, `SearchConfig::recency_weight` has no effect.
+    pub fn with_file_index(mut self, file_index: Arc<crate::storage::FileIndex>) -> Self {
+        self.file_index = Some(file_index);
+        self
+    }
+
+    /// Resolve the content for a hit, falling back to disk when the index
+    /// was built without stored content.
+    fn resolve_content(&self, payload: &PointPayload) -> (String, bool) {
+        if !payload.code.is_empty() {
+            return (payload.code.clone(), false);
+        }
+        match &self.content_root {
+            Some(root) => read_content_from_disk(
+                root,
+                &payload.file_path,
+                payload.line_start,
+                payload.line_end,
+            ),
+            None => (String::new(), false),
         }
     }
 
@@ -181,20 +705,135 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
         &self,
         query: &str,
         filter: Option<SearchFilter>,
+    ) -> Result<Vec<SearchResult>> {
+        self.search_cancellable(query, filter, CancellationToken::new())
+            .await
+    }
+
+    /// Hybrid search embedding the query with a different provider.
+    ///
+    /// Lets a higher-quality model embed important queries against an
+    /// index built with a cheaper one. The override must produce vectors
+    /// of the same dimensions as the index; incompatible providers are
+    /// rejected before any network call.
+    pub async fn search_with_query_provider(
+        &self,
+        query: &str,
+        filter: Option<SearchFilter>,
+        query_embeddings: &dyn EmbeddingProvider,
+    ) -> Result<Vec<SearchResult>> {
+        self.search_cancellable_with_provider(
+            query,
+            filter,
+            CancellationToken::new(),
+            Some(query_embeddings),
+        )
+        .await
+    }
+
+    /// Hybrid search that aborts when `cancel` fires.
+    ///
+    /// The token is checked before the embedding call and before the Qdrant
+    /// query, so a cancelled search stops without issuing further network
+    /// requests. Cancellation surfaces as an error.
+    pub async fn search_cancellable(
+        &self,
+        query: &str,
+        filter: Option<SearchFilter>,
+        cancel: CancellationToken,
+    ) -> Result<Vec<SearchResult>> {
+        self.search_cancellable_with_provider(query, filter, cancel, None)
+            .await
+    }
+
+    /// Measure retrieval quality against a labeled query set.
+    ///
+    /// Each entry pairs a query with the chunk ids considered relevant
+    /// for it. Every query runs through the normal hybrid search pipeline
+    /// (including any configured reranker), and the resulting ranking is
+    /// scored with MRR, recall@k, and nDCG@k (binary relevance). Lets
+    /// weight and reranker changes be compared on numbers instead of
+    /// anecdotes; see the [`eval`] module for the metric definitions.
+    pub async fn evaluate(
+        &self,
+        queries: &[(String, Vec<String>)],
+        k: usize,
+    ) -> Result<EvalReport> {
+        let mut per_query = Vec::with_capacity(queries.len());
+        for (query, relevant) in queries {
+            let ranked: Vec<String> = self
+                .search(query, None)
+                .await?
+                .into_iter()
+                .map(|r| r.id)
+                .collect();
+            per_query.push(QueryEval::compute(query, &ranked, relevant, k));
+        }
+        Ok(EvalReport::from_queries(per_query, k))
+    }
+
+    /// Embed the query, using the override provider when given.
+    ///
+    /// Validates that an override's dimensions match the index before
+    /// embedding, so a mismatched model fails with a clear error instead
+    /// of garbage similarity scores. The vector is normalized to match
+    /// the unit-length vectors the indexer stores.
+    async fn embed_query(
+        &self,
+        query: &str,
+        query_embeddings: Option<&dyn EmbeddingProvider>,
+    ) -> Result<Vec<f32>> {
+        let mut query_vector = match query_embeddings {
+            Some(provider) => {
+                let expected = self.qdrant.dimensions();
+                if provider.dimensions() != expected {
+                    anyhow::bail!(
+                        "Query embedding provider '{}' produces {}-dimensional vectors, \
+                         but the index was built with {} dimensions",
+                        provider.model_name(),
+                        provider.dimensions(),
+                        expected
+                    );
+                }
+                provider.embed(query).await?
+            }
+            None => self.embeddings.embed(query).await?,
+        };
+        crate::qdrant::l2_normalize(&mut query_vector);
+        Ok(query_vector)
+    }
+
+    /// Cancellable hybrid search with an optional query-embedding override.
+    async fn search_cancellable_with_provider(
+        &self,
+        query: &str,
+        filter: Option<SearchFilter>,
+        cancel: CancellationToken,
+        query_embeddings: Option<&dyn EmbeddingProvider>,
     ) -> Result<Vec<SearchResult>> {
         debug!("Searching for: {}", query);
 
+        if cancel.is_cancelled() {
+            anyhow::bail!("Search cancelled");
+        }
+
         // Generate embedding for the query
-        let query_vector = self.embeddings.embed(query).await?;
+        let query_vector = self.embed_query(query, query_embeddings).await?;
 
         // Fetch more results for fusion
         let fetch_limit = self.config.limit * 3;
 
-        // Search Qdrant for vector similarity
+        if cancel.is_cancelled() {
+            anyhow::bail!("Search cancelled");
+        }
+
+        // Search Qdrant for vector similarity, dropping hits below the
+        // cosine floor before fusion
         let vector_hits = self
             .qdrant
             .search(query_vector, fetch_limit, filter)
             .await?;
+        let vector_hits = filter_by_vector_score(vector_hits, self.config.min_vector_score);
 
         // Convert to (id, score) pairs for RRF
         let vector_results: Vec<(String, f32)> = vector_hits
@@ -209,34 +848,57 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
             .collect();
 
         // Get BM25 results if hybrid search is enabled
-        let final_ranking = if self.config.hybrid && !self.bm25_index.read().await.is_empty() {
+        let bm25_results = if self.config.hybrid && !self.bm25_index.read().await.is_empty() {
             let bm25_index = self.bm25_index.read().await;
-            let bm25_results = bm25_index.search(query, fetch_limit);
+            Some(bm25_index.search(query, fetch_limit))
+        } else {
+            None
+        };
 
+        let final_ranking = match &bm25_results {
             // Apply RRF fusion
-            reciprocal_rank_fusion(
+            Some(bm25) => fuse_rankings(
+                self.config.fusion,
                 &vector_results,
-                &bm25_results,
+                bm25,
                 self.config.rrf_k,
                 self.config.vector_weight,
                 self.config.bm25_weight,
-            )
-        } else {
+            ),
             // Vector-only search
-            vector_results
+            None => vector_results.clone(),
         };
 
-        // Convert ranking to SearchResults
+        // Convert ranking to SearchResults. When deduping by file, walk the
+        // full ranking so later chunks from new files can fill the limit.
+        let take = if self.config.dedupe_by_file {
+            final_ranking.len()
+        } else {
+            self.config.limit
+        };
         let mut results: Vec<SearchResult> = Vec::new();
 
-        for (id, combined_score) in final_ranking.iter().take(self.config.limit) {
+        for (id, combined_score) in final_ranking.iter().take(take) {
             if let Some(hit) = hits_map.get(id) {
+                let (content, stale) = self.resolve_content(&hit.payload);
+                let explanation = if self.config.explain {
+                    Some(explain_score(
+                        id,
+                        &vector_results,
+                        bm25_results.as_deref(),
+                        &self.config,
+                    ))
+                } else {
+                    None
+                };
                 let result = SearchResult {
                     id: id.clone(),
                     file_path: hit.payload.file_path.clone(),
                     start_line: hit.payload.line_start,
                     end_line: hit.payload.line_end,
-                    content: hit.payload.code.clone(),
+                    start_col: hit.payload.column_start,
+                    end_col: hit.payload.column_end,
+                    content,
                     kind: hit.payload.chunk_type.clone(),
                     name: if hit.payload.name.is_empty() {
                         None
@@ -245,9 +907,20 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
                     },
                     signature: hit.payload.signature.clone(),
                     scope: hit.payload.scope.clone(),
+                    qualified_name: if hit.payload.qualified_name.is_empty() {
+                        None
+                    } else {
+                        Some(hit.payload.qualified_name.clone())
+                    },
                     score: *combined_score,
                     vector_score: Some(hit.score),
                     bm25_score: None, // Could compute if needed
+                    symbol_id: None,
+                    enclosing_symbol: None,
+                    enclosing_signature: None,
+                    repo: None,
+                    explanation,
+                    stale,
                 };
                 results.push(result);
             }
@@ -256,9 +929,130 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
         // Filter by minimum score
         results.retain(|r| r.score >= self.config.min_score);
 
+        // Nudge recently-modified files up when configured
+        self.apply_recency_boost(&mut results);
+
+        // Post-fusion dedupe: best chunk per file, then truncate
+        if self.config.dedupe_by_file {
+            results = best_chunk_per_file(results);
+            results.truncate(self.config.limit);
+        }
+
+        // Rerank the retrieved candidates when a reranker is configured
+        self.apply_reranker(query, &mut results).await;
+
         Ok(results)
     }
 
+    /// Boost recently-modified files' scores in place.
+    ///
+    /// Each result's score is multiplied by `1 + weight * decay`, where the
+    /// decay halves with every week of file age. Files touched today edge
+    /// out identical matches in untouched files without letting recency
+    /// overwhelm relevance. No-op when the weight is 0 or no `FileIndex`
+    /// is attached; files missing from the index get no boost.
+    fn apply_recency_boost(&self, results: &mut [SearchResult]) {
+        let weight = self.config.recency_weight;
+        if weight <= 0.0 {
+            return;
+        }
+        let Some(ref file_index) = self.file_index else {
+            return;
+        };
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        for result in results.iter_mut() {
+            if let Some(entry) = file_index.get(&result.file_path) {
+                let age_secs = now.saturating_sub(entry.modified_at) as f32;
+                let decay = 0.5_f32.powf(age_secs / RECENCY_HALF_LIFE_SECS);
+                let boost = 1.0 + weight * decay;
+                result.score *= boost;
+                if let Some(ref mut explanation) = result.explanation {
+                    explanation.recency_boost = boost;
+                }
+            }
+        }
+
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    /// Rerank the top candidates in place when a reranker is configured.
+    ///
+    /// The first `rerank_top_n` results (all of them when unset) are judged
+    /// by the reranker: irrelevant candidates are dropped and the survivors
+    /// are reordered by reranker score, highest first. Results beyond the
+    /// candidate window keep their fusion order after the reranked block.
+    /// On reranker failure the original ordering is kept.
+    ///
+    /// Returns true when reranking was applied.
+    async fn apply_reranker(&self, query: &str, results: &mut Vec<SearchResult>) -> bool {
+        let Some(reranker) = &self.reranker else {
+            return false;
+        };
+
+        let top_n = self
+            .config
+            .rerank_top_n
+            .unwrap_or(results.len())
+            .min(results.len());
+        let candidates: Vec<RerankerDoc> = results
+            .iter()
+            .take(top_n)
+            .map(|r| RerankerDoc {
+                id: r.id.clone(),
+                content: r.content.clone(),
+            })
+            .collect();
+
+        if candidates.is_empty() {
+            return false;
+        }
+
+        match reranker.rerank(query, &candidates).await {
+            Ok(rerank_results) => {
+                let scores: HashMap<String, f32> = rerank_results
+                    .iter()
+                    .map(|r| (r.id.clone(), r.score))
+                    .collect();
+                let relevant_ids: std::collections::HashSet<String> = rerank_results
+                    .iter()
+                    .filter(|r| r.relevant)
+                    .map(|r| r.id.clone())
+                    .collect();
+
+                debug!(
+                    "Reranker kept {}/{} candidates",
+                    relevant_ids.len(),
+                    candidates.len()
+                );
+
+                let tail = results.split_off(top_n);
+                results.retain(|r| relevant_ids.contains(&r.id));
+                results.sort_by(|a, b| {
+                    let a_score = scores.get(&a.id).copied().unwrap_or(0.0);
+                    let b_score = scores.get(&b.id).copied().unwrap_or(0.0);
+                    b_score
+                        .partial_cmp(&a_score)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                results.extend(tail);
+                true
+            }
+            Err(e) => {
+                warn!("Reranking failed, keeping original results: {}", e);
+                false
+            }
+        }
+    }
+
     /// Search with custom vector/BM25 weights (used by query-adaptive weighting).
     ///
     /// Same as `search()` but uses the provided weights instead of config defaults.
@@ -272,13 +1066,15 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
     ) -> Result<Vec<SearchResult>> {
         debug!("Searching with adaptive weights: vector={}, bm25={}", vector_weight, bm25_weight);
 
-        let query_vector = self.embeddings.embed(query).await?;
+        let mut query_vector = self.embeddings.embed(query).await?;
+        crate::qdrant::l2_normalize(&mut query_vector);
         let fetch_limit = self.config.limit * 3;
 
         let vector_hits = self
             .qdrant
             .search(query_vector, fetch_limit, filter)
             .await?;
+        let vector_hits = filter_by_vector_score(vector_hits, self.config.min_vector_score);
 
         let vector_results: Vec<(String, f32)> = vector_hits
             .iter()
@@ -294,7 +1090,8 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
             let bm25_index = self.bm25_index.read().await;
             let bm25_results = bm25_index.search(query, fetch_limit);
 
-            reciprocal_rank_fusion(
+            fuse_rankings(
+                self.config.fusion,
                 &vector_results,
                 &bm25_results,
                 self.config.rrf_k,
@@ -309,12 +1106,15 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
 
         for (id, combined_score) in final_ranking.iter().take(self.config.limit) {
             if let Some(hit) = hits_map.get(id) {
+                let (content, stale) = self.resolve_content(&hit.payload);
                 let result = SearchResult {
                     id: id.clone(),
                     file_path: hit.payload.file_path.clone(),
                     start_line: hit.payload.line_start,
                     end_line: hit.payload.line_end,
-                    content: hit.payload.code.clone(),
+                    start_col: hit.payload.column_start,
+                    end_col: hit.payload.column_end,
+                    content,
                     kind: hit.payload.chunk_type.clone(),
                     name: if hit.payload.name.is_empty() {
                         None
@@ -323,9 +1123,20 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
                     },
                     signature: hit.payload.signature.clone(),
                     scope: hit.payload.scope.clone(),
+                    qualified_name: if hit.payload.qualified_name.is_empty() {
+                        None
+                    } else {
+                        Some(hit.payload.qualified_name.clone())
+                    },
                     score: *combined_score,
                     vector_score: Some(hit.score),
                     bm25_score: None,
+                    symbol_id: None,
+                    enclosing_symbol: None,
+                    enclosing_signature: None,
+                    repo: None,
+                    explanation: None,
+                    stale,
                 };
                 results.push(result);
             }
@@ -333,6 +1144,9 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
 
         results.retain(|r| r.score >= self.config.min_score);
 
+        // Nudge recently-modified files up when configured
+        self.apply_recency_boost(&mut results);
+
         // Apply reranker if configured
         if let Some(reranker) = &self.reranker {
             let top_n = rerank_limit.unwrap_or(results.len());
@@ -371,12 +1185,22 @@ impl<E: EmbeddingProvider + ?Sized> HybridSearcher<E> {
     }
 
     /// Find code similar to the given code snippet.
+    ///
+    /// An optional exclusion keeps the origin chunk (or its whole file)
+    /// out of the results, so "find duplicates of this" doesn't just
+    /// return the query itself as the top hit.
     pub async fn find_similar(
         &self,
         code: &str,
         filter: Option<SearchFilter>,
+        exclusion: Option<SimilarityExclusion>,
     ) -> Result<Vec<SearchResult>> {
-        self.search(code, filter).await
+        let results = self.search(code, filter).await?;
+
+        match exclusion {
+            Some(exclusion) => Ok(exclusion.apply(results)),
+            None => Ok(results),
+        }
     }
 
     /// Add a document to the BM25 index.
@@ -410,6 +1234,39 @@ mod tests {
         assert!(config.hybrid);
     }
 
+    #[test]
+    fn test_code_navigation_preset() {
+        let config = SearchConfig::preset(SearchPreset::CodeNavigation);
+        assert!((config.vector_weight - 0.85).abs() < f32::EPSILON);
+        assert!((config.bm25_weight - 0.15).abs() < f32::EPSILON);
+        assert_eq!(config.min_vector_score, Some(0.25));
+        assert!(SearchPreset::CodeNavigation.filter().is_none());
+    }
+
+    #[test]
+    fn test_keyword_exact_preset() {
+        let config = SearchConfig::preset(SearchPreset::KeywordExact);
+        assert!((config.vector_weight - 0.2).abs() < f32::EPSILON);
+        assert!((config.bm25_weight - 0.8).abs() < f32::EPSILON);
+        assert_eq!(config.min_vector_score, None);
+    }
+
+    #[test]
+    fn test_doc_search_preset_filters_docstrings() {
+        let config = SearchConfig::preset(SearchPreset::DocSearch);
+        assert!((config.vector_weight - 0.75).abs() < f32::EPSILON);
+        let filter = SearchPreset::DocSearch.filter().unwrap();
+        assert_eq!(filter.chunk_types, Some(vec!["docstring".to_string()]));
+    }
+
+    #[test]
+    fn test_search_preset_parse() {
+        assert_eq!(SearchPreset::parse("code"), Some(SearchPreset::CodeNavigation));
+        assert_eq!(SearchPreset::parse("EXACT"), Some(SearchPreset::KeywordExact));
+        assert_eq!(SearchPreset::parse("docs"), Some(SearchPreset::DocSearch));
+        assert_eq!(SearchPreset::parse("fuzzy"), None);
+    }
+
     #[test]
     fn test_rrf_fusion() {
         // Vector results: doc1 (rank 0), doc2 (rank 1), doc3 (rank 2)
@@ -523,6 +1380,78 @@ mod tests {
         assert!(diff_low_k > diff_high_k);
     }
 
+    #[test]
+    fn test_fusion_methods_produce_different_orderings() {
+        // Vector leader "a" barely beats "b"; BM25 only knows "c" and "b"
+        let vector_results = vec![
+            ("a".to_string(), 0.90f32),
+            ("b".to_string(), 0.89f32),
+            ("c".to_string(), 0.30f32),
+            ("d".to_string(), 0.10f32),
+        ];
+        let bm25_results = vec![("c".to_string(), 9.0f64), ("b".to_string(), 8.0f64)];
+
+        let top = |ranking: Vec<(String, f32)>| -> Vec<String> {
+            ranking.into_iter().map(|(id, _)| id).collect()
+        };
+
+        // RRF is rank-based: "b" wins by sitting near the top of both lists
+        let rrf = top(fuse_rankings(
+            FusionMethod::Rrf,
+            &vector_results,
+            &bm25_results,
+            60.0,
+            0.7,
+            0.3,
+        ));
+        assert_eq!(rrf, vec!["b", "c", "a", "d"]);
+
+        // Weighted-sum keeps raw-score margins: "a"'s vector lead survives
+        // because "b"'s normalized BM25 score is the source minimum (0.0)
+        let weighted = top(fuse_rankings(
+            FusionMethod::WeightedSum,
+            &vector_results,
+            &bm25_results,
+            60.0,
+            0.7,
+            0.3,
+        ));
+        assert_eq!(weighted, vec!["a", "b", "c", "d"]);
+
+        // CombSUM drops the weights, so BM25's favorite "c" pulls ahead
+        let comb = top(fuse_rankings(
+            FusionMethod::CombSum,
+            &vector_results,
+            &bm25_results,
+            60.0,
+            0.7,
+            0.3,
+        ));
+        assert_eq!(comb, vec!["c", "a", "b", "d"]);
+    }
+
+    #[test]
+    fn test_weighted_sum_constant_scores_normalize_to_one() {
+        // A single-candidate source still contributes its full weight
+        let vector_results = vec![("a".to_string(), 0.42f32)];
+        let bm25_results: Vec<(String, f64)> = vec![];
+
+        let fused = weighted_sum_fusion(&vector_results, &bm25_results, 0.7, 0.3);
+        assert_eq!(fused.len(), 1);
+        assert!((fused[0].1 - 0.7).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_fusion_method_parse() {
+        assert_eq!(FusionMethod::parse("rrf"), Some(FusionMethod::Rrf));
+        assert_eq!(
+            FusionMethod::parse("weighted-sum"),
+            Some(FusionMethod::WeightedSum)
+        );
+        assert_eq!(FusionMethod::parse("CombSUM"), Some(FusionMethod::CombSum));
+        assert_eq!(FusionMethod::parse("bogus"), None);
+    }
+
     #[test]
     fn test_search_config_custom() {
         let config = SearchConfig {
@@ -530,8 +1459,14 @@ mod tests {
             vector_weight: 0.6,
             bm25_weight: 0.4,
             min_score: 0.5,
+            min_vector_score: None,
             hybrid: false,
             rrf_k: 30.0,
+            fusion: FusionMethod::Rrf,
+            dedupe_by_file: false,
+            recency_weight: 0.0,
+            rerank_top_n: None,
+            explain: false,
         };
 
         assert_eq!(config.limit, 20);
@@ -542,6 +1477,63 @@ mod tests {
         assert!((config.rrf_k - 30.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_explanation_contributions_sum_to_final_score() {
+        let vector_results = vec![
+            ("a".to_string(), 0.9),
+            ("b".to_string(), 0.8),
+            ("c".to_string(), 0.7),
+        ];
+        let bm25_results = vec![("b".to_string(), 12.0), ("a".to_string(), 8.0)];
+        let config = SearchConfig::default();
+
+        let fused = reciprocal_rank_fusion(
+            &vector_results,
+            &bm25_results,
+            config.rrf_k,
+            config.vector_weight,
+            config.bm25_weight,
+        );
+
+        // Each explanation's per-source contributions reproduce the fused
+        // score exactly
+        for (id, score) in &fused {
+            let explanation = explain_score(id, &vector_results, Some(&bm25_results), &config);
+            assert!(
+                (explanation.vector_rrf + explanation.bm25_rrf - score).abs() < 1e-6,
+                "contributions for {} don't sum to fused score",
+                id
+            );
+        }
+
+        // Ranks and raw scores are reported from each source
+        let explanation = explain_score("b", &vector_results, Some(&bm25_results), &config);
+        assert_eq!(explanation.vector_rank, Some(2));
+        assert_eq!(explanation.vector_score, Some(0.8));
+        assert_eq!(explanation.bm25_rank, Some(1));
+        assert_eq!(explanation.bm25_score, Some(12.0));
+        assert!((explanation.recency_boost - 1.0).abs() < f32::EPSILON);
+
+        // Vector-only result: not retrieved by BM25, so that side is empty
+        let explanation = explain_score("c", &vector_results, Some(&bm25_results), &config);
+        assert_eq!(explanation.bm25_rank, None);
+        assert!((explanation.bm25_rrf - 0.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_explanation_vector_only_reports_raw_similarity() {
+        let vector_results = vec![("a".to_string(), 0.9)];
+        let config = SearchConfig::default();
+
+        // Without BM25 fusion the final score is the raw similarity, so the
+        // vector contribution carries it whole
+        let explanation = explain_score("a", &vector_results, None, &config);
+        assert_eq!(explanation.vector_rank, Some(1));
+        assert!((explanation.vector_rrf - 0.9).abs() < f32::EPSILON);
+        assert_eq!(explanation.bm25_rank, None);
+        assert!((explanation.bm25_rrf - 0.0).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn test_search_result_struct() {
         let result = SearchResult {
@@ -549,14 +1541,23 @@ mod tests {
             file_path: "src/lib.rs".to_string(),
             start_line: 10,
             end_line: 25,
+            start_col: 4,
+            end_col: 8,
             content: "fn hello() {}".to_string(),
             kind: "function".to_string(),
             name: Some("hello".to_string()),
             signature: Some("fn hello()".to_string()),
             scope: Some("impl Foo".to_string()),
+            qualified_name: Some("Foo::hello".to_string()),
             score: 0.85,
             vector_score: Some(0.9),
             bm25_score: Some(0.75),
+            symbol_id: None,
+            enclosing_symbol: None,
+            enclosing_signature: None,
+            repo: None,
+            explanation: None,
+            stale: false,
         };
 
         assert_eq!(result.id, "chunk-123");
@@ -574,14 +1575,23 @@ mod tests {
             file_path: "test.py".to_string(),
             start_line: 1,
             end_line: 5,
+            start_col: 1,
+            end_col: 1,
             content: "def test(): pass".to_string(),
             kind: "function".to_string(),
             name: None,
             signature: None,
             scope: None,
+            qualified_name: None,
             score: 0.5,
             vector_score: None,
             bm25_score: None,
+            symbol_id: None,
+            enclosing_symbol: None,
+            enclosing_signature: None,
+            repo: None,
+            explanation: None,
+            stale: false,
         };
 
         assert!(result.name.is_none());
@@ -591,6 +1601,285 @@ mod tests {
         assert!(result.bm25_score.is_none());
     }
 
+    #[test]
+    fn test_attach_enclosing_symbol_reports_method_signature() {
+        use crate::graph::{FileNode, SymbolKind, SymbolNode};
+
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/auth.rs", "rust"));
+
+        let session = SymbolNode::new("Session", SymbolKind::Struct, "src/auth.rs", 10)
+            .with_range(10, 60);
+        let session_id = session.id.clone();
+        graph.add_symbol(session);
+
+        let login = SymbolNode::new("login", SymbolKind::Method, "src/auth.rs", 20)
+            .with_range(20, 30)
+            .with_signature("fn login(&mut self, password: &str) -> Result<Token>")
+            .with_parent(session_id);
+        graph.add_symbol(login);
+
+        // Match deep inside the method body, without its own metadata
+        let mut result = make_result("chunk-1", "src/auth.rs");
+        result.start_line = 24;
+        result.end_line = 26;
+        result.attach_enclosing_symbol(&graph);
+
+        assert_eq!(result.enclosing_symbol.as_deref(), Some("Session::login"));
+        assert_eq!(
+            result.enclosing_signature.as_deref(),
+            Some("fn login(&mut self, password: &str) -> Result<Token>")
+        );
+        assert!(result.symbol_id.is_some());
+
+        // A match outside any symbol clears the enclosing fields
+        let mut outside = make_result("chunk-2", "src/auth.rs");
+        outside.start_line = 80;
+        outside.end_line = 85;
+        outside.enclosing_symbol = Some("stale".to_string());
+        outside.attach_enclosing_symbol(&graph);
+        assert!(outside.enclosing_symbol.is_none());
+        assert!(outside.enclosing_signature.is_none());
+    }
+
+    fn make_result(id: &str, file_path: &str) -> SearchResult {
+        SearchResult {
+            id: id.to_string(),
+            file_path: file_path.to_string(),
+            start_line: 1,
+            end_line: 10,
+            start_col: 1,
+            end_col: 1,
+            content: "fn example() {}".to_string(),
+            kind: "function".to_string(),
+            name: Some("example".to_string()),
+            signature: None,
+            scope: None,
+            qualified_name: None,
+            score: 0.8,
+            vector_score: None,
+            bm25_score: None,
+            symbol_id: None,
+            enclosing_symbol: None,
+            enclosing_signature: None,
+            repo: None,
+            explanation: None,
+            stale: false,
+        }
+    }
+
+    #[test]
+    fn test_search_config_default_no_dedupe() {
+        assert!(!SearchConfig::default().dedupe_by_file);
+    }
+
+    #[test]
+    fn test_best_chunk_per_file_keeps_higher_scored() {
+        // Ranked order: two chunks from a.rs, then one from b.rs
+        let mut first = make_result("chunk1", "src/a.rs");
+        first.score = 0.9;
+        let mut second = make_result("chunk2", "src/a.rs");
+        second.score = 0.5;
+        let third = make_result("chunk3", "src/b.rs");
+
+        let deduped = best_chunk_per_file(vec![first, second, third]);
+
+        // a.rs collapses to its best chunk; b.rs survives untouched
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].id, "chunk1");
+        assert!((deduped[0].score - 0.9).abs() < f32::EPSILON);
+        assert_eq!(deduped[1].id, "chunk3");
+    }
+
+    fn make_hit(id: &str, score: f32) -> SearchHit {
+        SearchHit {
+            id: id.to_string(),
+            score,
+            payload: PointPayload::default(),
+        }
+    }
+
+    #[test]
+    fn test_filter_by_vector_score_drops_low_similarity() {
+        let hits = vec![make_hit("a", 0.9), make_hit("b", 0.5), make_hit("c", 0.8)];
+
+        let filtered = filter_by_vector_score(hits, Some(0.75));
+        let ids: Vec<&str> = filtered.iter().map(|h| h.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "c"]);
+
+        // No floor keeps everything
+        let hits = vec![make_hit("a", 0.9), make_hit("b", 0.1)];
+        assert_eq!(filter_by_vector_score(hits, None).len(), 2);
+    }
+
+    #[test]
+    fn test_min_vector_score_excludes_keyword_only_match() {
+        // "b" matches on keywords (top BM25 rank) but its cosine similarity
+        // is below the floor. After pre-fusion filtering it has no payload
+        // to resolve, so BM25 alone cannot surface it.
+        let hits = vec![make_hit("a", 0.9), make_hit("b", 0.4)];
+        let vector_hits = filter_by_vector_score(hits, Some(0.75));
+
+        let vector_results: Vec<(String, f32)> = vector_hits
+            .iter()
+            .map(|hit| (hit.id.clone(), hit.score))
+            .collect();
+        let hits_map: HashMap<String, &SearchHit> = vector_hits
+            .iter()
+            .map(|hit| (hit.id.clone(), hit))
+            .collect();
+
+        let bm25_results = vec![("b".to_string(), 9.0f64), ("a".to_string(), 1.0f64)];
+        let fused = reciprocal_rank_fusion(&vector_results, &bm25_results, 60.0, 0.7, 0.3);
+
+        let surfaced: Vec<&str> = fused
+            .iter()
+            .filter(|(id, _)| hits_map.contains_key(id))
+            .map(|(id, _)| id.as_str())
+            .collect();
+        assert_eq!(surfaced, vec!["a"]);
+    }
+
+    #[test]
+    fn test_similarity_exclusion_by_id() {
+        let results = vec![
+            make_result("origin", "src/a.rs"),
+            make_result("other", "src/b.rs"),
+        ];
+
+        let exclusion = SimilarityExclusion::new().with_exclude_id("origin");
+        let filtered = exclusion.apply(results);
+
+        // The source chunk is absent from the results
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "other");
+    }
+
+    #[test]
+    fn test_similarity_exclusion_by_file() {
+        let results = vec![
+            make_result("chunk1", "src/a.rs"),
+            make_result("chunk2", "src/a.rs"),
+            make_result("chunk3", "src/b.rs"),
+        ];
+
+        let exclusion = SimilarityExclusion::new().with_exclude_file("src/a.rs");
+        let filtered = exclusion.apply(results);
+
+        // All chunks from the origin file are absent
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "chunk3");
+    }
+
+    #[test]
+    fn test_similarity_exclusion_empty_keeps_all() {
+        let results = vec![
+            make_result("chunk1", "src/a.rs"),
+            make_result("chunk2", "src/b.rs"),
+        ];
+
+        let filtered = SimilarityExclusion::new().apply(results);
+        assert_eq!(filtered.len(), 2);
+    }
+
+    // Mock embedding provider for tests
+    struct MockEmbeddingProvider;
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for MockEmbeddingProvider {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![0.0; 4096])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| vec![0.0; 4096]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            4096
+        }
+
+        fn model_name(&self) -> &str {
+            "mock"
+        }
+    }
+
+    #[test]
+    fn test_read_content_from_disk_in_range() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(temp.path().join("lib.rs"), "line1\nline2\nline3\nline4\n").unwrap();
+
+        let (content, stale) = read_content_from_disk(temp.path(), "lib.rs", 2, 3);
+        assert_eq!(content, "line2\nline3");
+        assert!(!stale);
+    }
+
+    #[test]
+    fn test_read_content_from_disk_truncated_is_stale() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(temp.path().join("lib.rs"), "line1\nline2\n").unwrap();
+
+        // File shrank since indexing: range extends past EOF
+        let (content, stale) = read_content_from_disk(temp.path(), "lib.rs", 2, 10);
+        assert_eq!(content, "line2");
+        assert!(stale);
+
+        // Range entirely past EOF
+        let (content, stale) = read_content_from_disk(temp.path(), "lib.rs", 5, 10);
+        assert!(content.is_empty());
+        assert!(stale);
+    }
+
+    #[test]
+    fn test_read_content_from_disk_missing_file() {
+        let temp = tempfile::tempdir().unwrap();
+        let (content, stale) = read_content_from_disk(temp.path(), "deleted.rs", 1, 5);
+        assert!(content.is_empty());
+        assert!(stale);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_content_both_modes() {
+        use crate::qdrant::QdrantConfig;
+
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(temp.path().join("main.rs"), "fn main() {\n    run();\n}\n").unwrap();
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let searcher = HybridSearcher::new_with_empty_bm25(
+            SearchConfig::default(),
+            Arc::new(MockEmbeddingProvider),
+            qdrant,
+        )
+        .with_content_root(temp.path().to_path_buf());
+
+        // store_content: true - payload carries the code, no disk access
+        let stored = PointPayload {
+            file_path: "main.rs".to_string(),
+            code: "fn main() { run(); }".to_string(),
+            line_start: 1,
+            line_end: 3,
+            ..Default::default()
+        };
+        let (content, stale) = searcher.resolve_content(&stored);
+        assert_eq!(content, "fn main() { run(); }");
+        assert!(!stale);
+
+        // store_content: false - empty payload code, content re-read from disk
+        let metadata_only = PointPayload {
+            file_path: "main.rs".to_string(),
+            code: String::new(),
+            line_start: 1,
+            line_end: 3,
+            ..Default::default()
+        };
+        let (content, stale) = searcher.resolve_content(&metadata_only);
+        assert_eq!(content, "fn main() {\n    run();\n}");
+        assert!(!stale);
+    }
+
     #[test]
     fn test_rrf_ordering_stability() {
         // Test that RRF produces consistent ordering
@@ -615,4 +1904,308 @@ mod tests {
             assert!((r1.1 - r2.1).abs() < f32::EPSILON);
         }
     }
+
+    // Mock reranker returning a fixed score per document id
+    struct ScriptedReranker {
+        scores: HashMap<String, f32>,
+    }
+
+    #[async_trait::async_trait]
+    impl Reranker for ScriptedReranker {
+        async fn rerank(
+            &self,
+            _query: &str,
+            documents: &[RerankerDoc],
+        ) -> Result<Vec<crate::reranker::RerankResult>> {
+            Ok(documents
+                .iter()
+                .map(|doc| {
+                    let score = self.scores.get(&doc.id).copied().unwrap_or(0.0);
+                    crate::reranker::RerankResult {
+                        id: doc.id.clone(),
+                        relevant: score > 0.0,
+                        score,
+                    }
+                })
+                .collect())
+        }
+    }
+
+    async fn searcher_with_reranker(
+        config: SearchConfig,
+        reranker: Option<Arc<dyn Reranker>>,
+    ) -> HybridSearcher<MockEmbeddingProvider> {
+        use crate::qdrant::QdrantConfig;
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        HybridSearcher::new_with_reranker(
+            config,
+            Arc::new(MockEmbeddingProvider),
+            qdrant,
+            Arc::new(RwLock::new(BM25Index::new())),
+            reranker,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_apply_reranker_reorders_results() {
+        let reranker = ScriptedReranker {
+            scores: [
+                ("a".to_string(), 0.2),
+                ("b".to_string(), 0.9),
+                ("c".to_string(), 0.5),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        let searcher =
+            searcher_with_reranker(SearchConfig::default(), Some(Arc::new(reranker))).await;
+
+        let mut results = vec![
+            make_result("a", "src/a.rs"),
+            make_result("b", "src/b.rs"),
+            make_result("c", "src/c.rs"),
+        ];
+        let applied = searcher.apply_reranker("query", &mut results).await;
+
+        assert!(applied);
+        let order: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(order, vec!["b", "c", "a"]);
+    }
+
+    #[tokio::test]
+    async fn test_apply_reranker_without_reranker_keeps_order() {
+        let searcher = searcher_with_reranker(SearchConfig::default(), None).await;
+
+        let mut results = vec![
+            make_result("a", "src/a.rs"),
+            make_result("b", "src/b.rs"),
+        ];
+        let applied = searcher.apply_reranker("query", &mut results).await;
+
+        assert!(!applied);
+        assert_eq!(results[0].id, "a");
+        assert_eq!(results[1].id, "b");
+    }
+
+    #[tokio::test]
+    async fn test_apply_reranker_window_drops_irrelevant_keeps_tail() {
+        // Only the top-2 window is judged: "a" is irrelevant and dropped,
+        // "b" survives, and "c" rides along after the reranked block.
+        let reranker = ScriptedReranker {
+            scores: [("a".to_string(), 0.0), ("b".to_string(), 1.0)]
+                .into_iter()
+                .collect(),
+        };
+        let config = SearchConfig {
+            rerank_top_n: Some(2),
+            ..SearchConfig::default()
+        };
+        let searcher = searcher_with_reranker(config, Some(Arc::new(reranker))).await;
+
+        let mut results = vec![
+            make_result("a", "src/a.rs"),
+            make_result("b", "src/b.rs"),
+            make_result("c", "src/c.rs"),
+        ];
+        let applied = searcher.apply_reranker("query", &mut results).await;
+
+        assert!(applied);
+        let order: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(order, vec!["b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_tasks_share_one_searcher() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+        assert_shareable::<HybridSearcher<MockEmbeddingProvider>>();
+
+        use crate::qdrant::QdrantConfig;
+
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(temp.path().join("lib.rs"), "fn alpha() {}\nfn beta() {}\n").unwrap();
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let searcher = HybridSearcher::new_with_empty_bm25(
+            SearchConfig::default(),
+            Arc::new(MockEmbeddingProvider),
+            qdrant,
+        )
+        .with_content_root(temp.path().to_path_buf());
+
+        // Clones share the embedding client, Qdrant connection, and BM25
+        // index; every task must see consistent results without reconnecting.
+        let mut handles = Vec::new();
+        for _ in 0..8 {
+            let searcher = searcher.clone();
+            handles.push(tokio::spawn(async move {
+                let embedding = searcher.embeddings.embed("query").await.unwrap();
+                assert_eq!(embedding.len(), 4096);
+
+                let payload = PointPayload {
+                    file_path: "lib.rs".to_string(),
+                    line_start: 1,
+                    line_end: 2,
+                    ..Default::default()
+                };
+                let (content, stale) = searcher.resolve_content(&payload);
+                assert_eq!(content, "fn alpha() {}\nfn beta() {}");
+                assert!(!stale);
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recency_boost_promotes_recently_modified_file() {
+        use crate::qdrant::QdrantConfig;
+        use crate::storage::{FileIndex, FileIndexEntry};
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let mut file_index = FileIndex::new();
+        file_index.upsert(FileIndexEntry {
+            path: "src/fresh.rs".to_string(),
+            modified_at: now,
+            symbol_count: 1,
+            content_hash: "a".to_string(),
+        });
+        file_index.upsert(FileIndexEntry {
+            path: "src/stale.rs".to_string(),
+            modified_at: now.saturating_sub(90 * 24 * 3600), // three months old
+            symbol_count: 1,
+            content_hash: "b".to_string(),
+        });
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let config = SearchConfig {
+            recency_weight: 0.3,
+            ..SearchConfig::default()
+        };
+        let searcher = HybridSearcher::new_with_empty_bm25(
+            config,
+            Arc::new(MockEmbeddingProvider),
+            qdrant,
+        )
+        .with_file_index(Arc::new(file_index));
+
+        // Equal fusion scores: the recently-modified file must come out on top
+        let mut results = vec![
+            make_result("stale", "src/stale.rs"),
+            make_result("fresh", "src/fresh.rs"),
+        ];
+        searcher.apply_recency_boost(&mut results);
+
+        assert_eq!(results[0].id, "fresh");
+        assert!(results[0].score > results[1].score);
+    }
+
+    #[tokio::test]
+    async fn test_recency_boost_off_by_default() {
+        use crate::qdrant::QdrantConfig;
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let searcher = HybridSearcher::new_with_empty_bm25(
+            SearchConfig::default(),
+            Arc::new(MockEmbeddingProvider),
+            qdrant,
+        );
+
+        let mut results = vec![
+            make_result("a", "src/a.rs"),
+            make_result("b", "src/b.rs"),
+        ];
+        let before: Vec<f32> = results.iter().map(|r| r.score).collect();
+        searcher.apply_recency_boost(&mut results);
+        let after: Vec<f32> = results.iter().map(|r| r.score).collect();
+        assert_eq!(before, after);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_search_returns_error_without_network_calls() {
+        use crate::qdrant::QdrantConfig;
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let searcher = HybridSearcher::new_with_empty_bm25(
+            SearchConfig::default(),
+            Arc::new(MockEmbeddingProvider),
+            qdrant,
+        );
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        // The pre-embedding check fires, so no Qdrant server is needed
+        let err = searcher
+            .search_cancellable("query", None, cancel)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("cancelled"));
+    }
+
+    /// Query-override provider whose dimensions disagree with the index
+    struct TinyEmbeddingProvider;
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for TinyEmbeddingProvider {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![0.0; 8])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| vec![0.0; 8]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            8
+        }
+
+        fn model_name(&self) -> &str {
+            "tiny-mock"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_provider_with_mismatched_dimensions_is_rejected() {
+        // In-memory backend so the compatible-override search below can
+        // actually run without a Qdrant server
+        let qdrant = QdrantClient::in_memory("test", 4096);
+        let searcher = HybridSearcher::new_with_empty_bm25(
+            SearchConfig::default(),
+            Arc::new(MockEmbeddingProvider),
+            qdrant,
+        );
+
+        // An 8-dimensional override cannot query a 4096-dimensional index
+        let err = searcher
+            .search_with_query_provider("query", None, &TinyEmbeddingProvider)
+            .await
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("tiny-mock"), "got: {}", message);
+        assert!(message.contains("8-dimensional"), "got: {}", message);
+        assert!(message.contains("4096 dimensions"), "got: {}", message);
+
+        // A compatible override passes validation and searches normally
+        let results = searcher
+            .search_with_query_provider("query", None, &MockEmbeddingProvider)
+            .await
+            .unwrap();
+        assert!(results.is_empty());
+    }
 }
@@ -0,0 +1,266 @@
+//! Cross-repo search across multiple Qdrant collections.
+//!
+//! `MultiCollectionSearcher` holds one `QdrantClient` per collection (one
+//! collection per repo), fans a query out to all of them, and fuses the
+//! per-collection rankings with RRF so results compete fairly no matter
+//! which collection they came from. Every merged result carries the name
+//! of its source collection in [`SearchResult::repo`].
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use tracing::debug;
+
+use crate::embeddings::EmbeddingProvider;
+use crate::qdrant::{QdrantClient, SearchFilter, SearchHit};
+
+use super::{SearchConfig, SearchResult};
+
+/// A Qdrant collection tagged with the repo it indexes.
+struct NamedCollection {
+    repo: String,
+    qdrant: Arc<QdrantClient>,
+}
+
+/// Searches several collections at once and merges the results.
+///
+/// The query is embedded once and sent to every registered collection;
+/// each collection's ranking contributes to the fused score via RRF, so
+/// a strong match in a small repo is not drowned out by a large one.
+pub struct MultiCollectionSearcher<E: EmbeddingProvider + ?Sized> {
+    config: SearchConfig,
+    embeddings: Arc<E>,
+    collections: Vec<NamedCollection>,
+}
+
+impl<E: EmbeddingProvider + ?Sized> MultiCollectionSearcher<E> {
+    /// Create a searcher with no collections registered yet.
+    pub fn new(config: SearchConfig, embeddings: Arc<E>) -> Self {
+        Self {
+            config,
+            embeddings,
+            collections: Vec::new(),
+        }
+    }
+
+    /// Register a collection under a repo name (builder style).
+    pub fn with_collection(mut self, repo: impl Into<String>, qdrant: Arc<QdrantClient>) -> Self {
+        self.collections.push(NamedCollection {
+            repo: repo.into(),
+            qdrant,
+        });
+        self
+    }
+
+    /// Names of the registered repos, in registration order.
+    pub fn collection_names(&self) -> Vec<&str> {
+        self.collections.iter().map(|c| c.repo.as_str()).collect()
+    }
+
+    /// Search all registered collections and return the fused ranking.
+    ///
+    /// Results are merged with RRF over the per-collection rankings and
+    /// truncated to `config.limit`. Each result's `repo` field names the
+    /// collection it came from.
+    pub async fn search(
+        &self,
+        query: &str,
+        filter: Option<SearchFilter>,
+    ) -> Result<Vec<SearchResult>> {
+        debug!(
+            "Searching {} collections for: {}",
+            self.collections.len(),
+            query
+        );
+
+        if self.collections.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut query_vector = self.embeddings.embed(query).await?;
+        crate::qdrant::l2_normalize(&mut query_vector);
+
+        // Fetch more results per collection for fusion
+        let fetch_limit = self.config.limit * 3;
+
+        // Hits keyed by (repo, point id) so ids can collide across repos
+        // without clobbering each other
+        let mut hits_map: HashMap<(String, String), SearchHit> = HashMap::new();
+        let mut rankings: Vec<Vec<(String, String)>> = Vec::new();
+
+        for collection in &self.collections {
+            let hits = collection
+                .qdrant
+                .search(query_vector.clone(), fetch_limit, filter.clone())
+                .await?;
+            let hits = super::filter_by_vector_score(hits, self.config.min_vector_score);
+
+            let mut ranking = Vec::with_capacity(hits.len());
+            for hit in hits {
+                let key = (collection.repo.clone(), hit.id.clone());
+                ranking.push(key.clone());
+                hits_map.insert(key, hit);
+            }
+            rankings.push(ranking);
+        }
+
+        // RRF over the merged candidate set: each collection's ranking is
+        // one voting source, all weighted equally
+        let mut scores: HashMap<(String, String), f32> = HashMap::new();
+        for ranking in &rankings {
+            for (rank, key) in ranking.iter().enumerate() {
+                let rrf = 1.0 / (self.config.rrf_k + rank as f32 + 1.0);
+                *scores.entry(key.clone()).or_insert(0.0) += rrf;
+            }
+        }
+
+        let mut fused: Vec<((String, String), f32)> = scores.into_iter().collect();
+        fused.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut results: Vec<SearchResult> = Vec::new();
+        for ((repo, id), combined_score) in fused.into_iter().take(self.config.limit) {
+            let Some(hit) = hits_map.get(&(repo.clone(), id.clone())) else {
+                continue;
+            };
+            results.push(SearchResult {
+                id,
+                file_path: hit.payload.file_path.clone(),
+                start_line: hit.payload.line_start,
+                end_line: hit.payload.line_end,
+                start_col: hit.payload.column_start,
+                end_col: hit.payload.column_end,
+                content: hit.payload.code.clone(),
+                kind: hit.payload.chunk_type.clone(),
+                name: if hit.payload.name.is_empty() {
+                    None
+                } else {
+                    Some(hit.payload.name.clone())
+                },
+                signature: hit.payload.signature.clone(),
+                scope: hit.payload.scope.clone(),
+                qualified_name: if hit.payload.qualified_name.is_empty() {
+                    None
+                } else {
+                    Some(hit.payload.qualified_name.clone())
+                },
+                symbol_id: None,
+                enclosing_symbol: None,
+                enclosing_signature: None,
+                repo: Some(repo),
+                explanation: None,
+                score: combined_score,
+                vector_score: Some(hit.score),
+                bm25_score: None,
+                stale: false,
+            });
+        }
+
+        results.retain(|r| r.score >= self.config.min_score);
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qdrant::{Point, PointPayload};
+
+    struct MockEmbeddingProvider;
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for MockEmbeddingProvider {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![1.0, 0.0, 0.0])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| vec![1.0, 0.0, 0.0]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            3
+        }
+
+        fn model_name(&self) -> &str {
+            "mock"
+        }
+    }
+
+    async fn seeded_collection(name: &str, points: Vec<(&str, Vec<f32>, &str)>) -> Arc<QdrantClient> {
+        let client = QdrantClient::in_memory(name, 3);
+        client.ensure_collection().await.unwrap();
+        client
+            .upsert_points(
+                points
+                    .into_iter()
+                    .map(|(id, vector, file)| Point {
+                        id: id.to_string(),
+                        vector,
+                        payload: PointPayload {
+                            file_path: file.to_string(),
+                            name: id.to_string(),
+                            ..Default::default()
+                        },
+                    })
+                    .collect(),
+            )
+            .await
+            .unwrap();
+        Arc::new(client)
+    }
+
+    #[tokio::test]
+    async fn test_fuses_two_collections_with_provenance() {
+        let backend = seeded_collection(
+            "repo-backend",
+            vec![
+                ("b1", vec![1.0, 0.0, 0.0], "src/auth.rs"),
+                ("b2", vec![0.0, 1.0, 0.0], "src/db.rs"),
+            ],
+        )
+        .await;
+        let frontend = seeded_collection(
+            "repo-frontend",
+            vec![("f1", vec![0.9, 0.1, 0.0], "src/login.ts")],
+        )
+        .await;
+
+        let searcher = MultiCollectionSearcher::new(
+            SearchConfig::default(),
+            Arc::new(MockEmbeddingProvider),
+        )
+        .with_collection("backend", backend)
+        .with_collection("frontend", frontend);
+
+        assert_eq!(searcher.collection_names(), vec!["backend", "frontend"]);
+
+        let results = searcher.search("auth", None).await.unwrap();
+        assert_eq!(results.len(), 3);
+
+        // Both top-ranked candidates got the same RRF score (rank 1 in
+        // their own collection), and every result names its source repo
+        let backend_hit = results.iter().find(|r| r.id == "b1").unwrap();
+        assert_eq!(backend_hit.repo.as_deref(), Some("backend"));
+        assert_eq!(backend_hit.file_path, "src/auth.rs");
+
+        let frontend_hit = results.iter().find(|r| r.id == "f1").unwrap();
+        assert_eq!(frontend_hit.repo.as_deref(), Some("frontend"));
+        assert_eq!(frontend_hit.file_path, "src/login.ts");
+
+        // The weaker backend match ranks below both collection leaders
+        assert_eq!(results[2].id, "b2");
+        assert!(results[2].score < backend_hit.score);
+    }
+
+    #[tokio::test]
+    async fn test_empty_searcher_returns_no_results() {
+        let searcher = MultiCollectionSearcher::new(
+            SearchConfig::default(),
+            Arc::new(MockEmbeddingProvider),
+        );
+        let results = searcher.search("anything", None).await.unwrap();
+        assert!(results.is_empty());
+    }
+}
@@ -0,0 +1,93 @@
+//! Local pairwise similarity between stored vectors.
+//!
+//! Qdrant scores query-to-document similarity server-side, but features
+//! like MMR diversity and vector-based dedup need candidate-to-candidate
+//! scores computed locally after a batch vector fetch.
+
+use serde::{Deserialize, Serialize};
+
+/// Similarity function used for local pairwise scoring.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SimilarityMetric {
+    /// Cosine similarity (magnitude-invariant, in [-1, 1])
+    #[default]
+    Cosine,
+    /// Raw dot product (magnitude-sensitive)
+    Dot,
+}
+
+impl SimilarityMetric {
+    /// Score a pair of vectors with this metric.
+    ///
+    /// Returns 0.0 for mismatched lengths or empty vectors.
+    pub fn score(&self, a: &[f32], b: &[f32]) -> f32 {
+        match self {
+            SimilarityMetric::Cosine => cosine(a, b),
+            SimilarityMetric::Dot => dot(a, b),
+        }
+    }
+}
+
+/// Dot product of two vectors.
+///
+/// Returns 0.0 when lengths differ so callers don't need to pre-validate.
+pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Cosine similarity of two vectors.
+///
+/// Returns 0.0 when lengths differ or either vector has zero magnitude.
+pub fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    let dot_product = dot(a, b);
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot_product / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_known_vectors() {
+        assert_eq!(dot(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]), 32.0);
+        assert_eq!(dot(&[1.0, 0.0], &[0.0, 1.0]), 0.0);
+    }
+
+    #[test]
+    fn test_cosine_known_vectors() {
+        // Identical direction scores 1.0 regardless of magnitude
+        assert!((cosine(&[1.0, 2.0], &[2.0, 4.0]) - 1.0).abs() < 1e-6);
+        // Orthogonal vectors score 0.0
+        assert!(cosine(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-6);
+        // Opposite direction scores -1.0
+        assert!((cosine(&[1.0, 0.0], &[-1.0, 0.0]) + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_mismatched_and_zero_vectors_score_zero() {
+        assert_eq!(dot(&[1.0, 2.0], &[1.0]), 0.0);
+        assert_eq!(cosine(&[1.0, 2.0], &[1.0]), 0.0);
+        assert_eq!(cosine(&[0.0, 0.0], &[1.0, 2.0]), 0.0);
+    }
+
+    #[test]
+    fn test_metric_dispatch() {
+        let a = [3.0, 4.0];
+        let b = [3.0, 4.0];
+        assert_eq!(SimilarityMetric::Dot.score(&a, &b), 25.0);
+        assert!((SimilarityMetric::Cosine.score(&a, &b) - 1.0).abs() < 1e-6);
+        assert_eq!(SimilarityMetric::default(), SimilarityMetric::Cosine);
+    }
+}
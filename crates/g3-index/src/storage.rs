@@ -14,7 +14,7 @@ use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, warn};
 
-use crate::graph::{CodeGraph, FileNode, SymbolNode};
+use crate::graph::{CodeGraph, FileNode, GraphDiff, SymbolNode};
 
 /// Default graph storage directory.
 pub const DEFAULT_GRAPH_DIR: &str = ".g3-index/graph";
@@ -28,6 +28,9 @@ pub const INDEX_FILE: &str = "file_index.json";
 /// Snapshot directory.
 pub const SNAPSHOT_DIR: &str = "snapshots";
 
+/// Baseline graph file used for diffing (full graph copy).
+pub const DIFF_BASELINE_FILE: &str = "diff_baseline.json";
+
 /// Maximum snapshots to keep.
 pub const MAX_SNAPSHOTS: usize = 10;
 
@@ -113,6 +116,13 @@ pub struct SnapshotMetadata {
     pub symbol_count: usize,
     /// Number of files.
     pub file_count: usize,
+    /// Embedding model in use when the snapshot was taken
+    /// (empty for snapshots written before model tracking).
+    #[serde(default)]
+    pub model_name: String,
+    /// Embedding dimensions in use when the snapshot was taken (0 when unknown).
+    #[serde(default)]
+    pub dimensions: usize,
 }
 
 /// Persistence layer for CodeGraph.
@@ -125,6 +135,10 @@ pub struct GraphStorage {
     index: FileIndex,
     /// Is dirty (needs save)?
     dirty: bool,
+    /// Embedding model recorded into snapshots (empty when unknown).
+    embedding_model: String,
+    /// Embedding dimensions recorded into snapshots (0 when unknown).
+    embedding_dimensions: usize,
 }
 
 impl GraphStorage {
@@ -137,9 +151,23 @@ impl GraphStorage {
             graph: CodeGraph::new(),
             index: FileIndex::new(),
             dirty: false,
+            embedding_model: String::new(),
+            embedding_dimensions: 0,
         }
     }
 
+    /// Record the embedding configuration into future snapshots, so a model
+    /// change between index runs can be detected against stored vectors.
+    pub fn with_embedding_config(
+        mut self,
+        model_name: impl Into<String>,
+        dimensions: usize,
+    ) -> Self {
+        self.embedding_model = model_name.into();
+        self.embedding_dimensions = dimensions;
+        self
+    }
+
     /// Initialize storage (load from disk or create new).
     pub fn init<P: AsRef<Path>>(storage_dir: P) -> Result<Self> {
         let storage_dir = storage_dir.as_ref();
@@ -166,6 +194,8 @@ impl GraphStorage {
                 graph,
                 index,
                 dirty: false,
+                embedding_model: String::new(),
+                embedding_dimensions: 0,
             })
         } else {
             info!("No existing graph found, creating new one");
@@ -237,16 +267,14 @@ impl GraphStorage {
         // Save graph
         let graph_json = serde_json::to_string_pretty(&self.graph)
             .context("Failed to serialize graph")?;
-        
-        fs::write(&graph_path, graph_json)
-            .with_context(|| format!("Failed to write graph file: {:?}", graph_path))?;
+
+        write_atomic(&graph_path, &graph_json)?;
 
         // Save index
         let index_json = serde_json::to_string_pretty(&self.index)
             .context("Failed to serialize index")?;
-        
-        fs::write(&index_path, index_json)
-            .with_context(|| format!("Failed to write index file: {:?}", index_path))?;
+
+        write_atomic(&index_path, &index_json)?;
 
         self.dirty = false;
         
@@ -274,6 +302,8 @@ impl GraphStorage {
             created_at: now(),
             symbol_count: self.graph.symbols.len(),
             file_count: self.graph.files.len(),
+            model_name: self.embedding_model.clone(),
+            dimensions: self.embedding_dimensions,
         };
 
         let snapshot_data = serde_json::to_string_pretty(&snapshot_meta)
@@ -370,6 +400,54 @@ impl GraphStorage {
         Ok(())
     }
 
+    /// Save the current graph as the baseline for future diffs.
+    ///
+    /// Unlike versioned snapshots (which only store metadata), the baseline
+    /// is a full graph copy so it can be compared symbol-by-symbol later.
+    pub fn save_diff_baseline(&self) -> Result<()> {
+        fs::create_dir_all(&self.storage_dir)
+            .with_context(|| format!("Failed to create storage directory: {:?}", self.storage_dir))?;
+
+        let baseline_path = self.storage_dir.join(DIFF_BASELINE_FILE);
+        let baseline_json = serde_json::to_string_pretty(&self.graph)
+            .context("Failed to serialize baseline graph")?;
+
+        fs::write(&baseline_path, baseline_json)
+            .with_context(|| format!("Failed to write baseline file: {:?}", baseline_path))?;
+
+        info!(
+            "Saved diff baseline: {} symbols, {} files",
+            self.graph.symbols.len(),
+            self.graph.files.len()
+        );
+        Ok(())
+    }
+
+    /// Load the saved baseline graph, if one exists.
+    pub fn load_diff_baseline(&self) -> Result<Option<CodeGraph>> {
+        let baseline_path = self.storage_dir.join(DIFF_BASELINE_FILE);
+        if !baseline_path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&baseline_path)
+            .with_context(|| format!("Failed to read baseline file: {:?}", baseline_path))?;
+
+        let baseline: CodeGraph = serde_json::from_str(&content)
+            .context("Failed to parse baseline JSON")?;
+
+        Ok(Some(baseline))
+    }
+
+    /// Diff the current graph against the saved baseline.
+    ///
+    /// Returns `None` when no baseline has been saved yet.
+    pub fn diff_against_baseline(&self) -> Result<Option<GraphDiff>> {
+        Ok(self
+            .load_diff_baseline()?
+            .map(|baseline| baseline.diff(&self.graph)))
+    }
+
     /// Incremental update: update only changed files.
     ///
     /// Compares current file system state with stored index and updates
@@ -560,6 +638,20 @@ pub struct ScannedFile {
     pub symbols: Vec<SymbolNode>,
 }
 
+/// Write contents to a sibling temp file, then rename into place.
+///
+/// The rename is atomic on the same filesystem, so a crash mid-write never
+/// leaves a truncated graph or index file behind — readers see either the
+/// old version or the new one.
+fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents)
+        .with_context(|| format!("Failed to write temp file: {:?}", tmp_path))?;
+    fs::rename(&tmp_path, path)
+        .with_context(|| format!("Failed to replace file: {:?}", path))?;
+    Ok(())
+}
+
 /// Get current time as Unix timestamp.
 fn now() -> u64 {
     SystemTime::now()
@@ -88,6 +88,41 @@ impl TraversalConfig {
     }
 }
 
+/// A node in a module subtree: one symbol and the symbols nested inside it.
+#[derive(Debug, Clone)]
+pub struct SubtreeNode {
+    /// Symbol ID in the graph
+    pub symbol_id: String,
+    /// Symbol name
+    pub name: String,
+    /// Symbol kind label ("fn", "struct", ...)
+    pub kind: String,
+    /// File the symbol lives in
+    pub file_id: String,
+    /// Start line in the source file
+    pub line_start: usize,
+    /// End line in the source file
+    pub line_end: usize,
+    /// Symbols contained within this one (methods in an impl, etc.)
+    pub children: Vec<SubtreeNode>,
+}
+
+/// Hierarchical collection of every symbol under a file prefix.
+///
+/// Produced by [`GraphTraverser::collect_subtree`]; underpins module
+/// documentation and summaries where a flat symbol list loses structure.
+#[derive(Debug, Clone)]
+pub struct ModuleSubtree {
+    /// The file prefix the subtree was collected for
+    pub file_prefix: String,
+    /// Number of matching files
+    pub file_count: usize,
+    /// Total number of symbols in the subtree
+    pub symbol_count: usize,
+    /// Top-level symbols, ordered by file then line
+    pub roots: Vec<SubtreeNode>,
+}
+
 /// Graph traverser for the codebase knowledge graph.
 pub struct GraphTraverser {
     config: TraversalConfig,
@@ -592,6 +627,7 @@ impl GraphTraverser {
                         kind: edge.kind,
                         location_file: edge.location_file.clone(),
                         location_line: edge.location_line,
+                        weight: edge.weight,
                     };
                     subgraph.add_edge(new_edge);
 
@@ -605,11 +641,97 @@ impl GraphTraverser {
 
         subgraph
     }
+
+    /// Collect every symbol whose file matches `file_prefix` as a tree.
+    ///
+    /// Containment is derived from line-range nesting within each file (the
+    /// method inside an impl block becomes a child of the impl's symbol), so
+    /// the result preserves module → type → method structure rather than a
+    /// flat list. Nesting deeper than the configured `max_depth` is
+    /// flattened into the nearest allowed ancestor.
+    pub fn collect_subtree(&self, graph: &CodeGraph, file_prefix: &str) -> ModuleSubtree {
+        let mut file_ids: Vec<&String> = graph
+            .files
+            .keys()
+            .filter(|id| id.starts_with(file_prefix))
+            .collect();
+        file_ids.sort();
+
+        let mut roots = Vec::new();
+        let mut symbol_count = 0;
+
+        for file_id in &file_ids {
+            let mut symbols = graph.symbols_in_file(file_id);
+            // Outer symbols first: by start line, widest range breaking ties
+            symbols.sort_by(|a, b| {
+                a.line_start
+                    .cmp(&b.line_start)
+                    .then(b.line_end.cmp(&a.line_end))
+            });
+
+            let mut file_roots: Vec<SubtreeNode> = Vec::new();
+            let mut stack: Vec<SubtreeNode> = Vec::new();
+
+            for symbol in symbols {
+                symbol_count += 1;
+                let node = SubtreeNode {
+                    symbol_id: symbol.id.clone(),
+                    name: symbol.name.clone(),
+                    kind: symbol.kind.label().to_string(),
+                    file_id: symbol.file_id.clone(),
+                    line_start: symbol.line_start,
+                    line_end: symbol.line_end,
+                    children: Vec::new(),
+                };
+
+                // Unwind to the nearest ancestor whose range covers this symbol
+                while let Some(top) = stack.last() {
+                    let covers = top.line_start <= symbol.line_start
+                        && top.line_end >= symbol.line_end;
+                    if covers {
+                        break;
+                    }
+                    let finished = stack.pop().unwrap();
+                    Self::attach(finished, &mut stack, &mut file_roots);
+                }
+
+                if stack.len() < self.config.max_depth {
+                    stack.push(node);
+                } else {
+                    // Depth limit reached: flatten into the current ancestor
+                    Self::attach(node, &mut stack, &mut file_roots);
+                }
+            }
+
+            while let Some(finished) = stack.pop() {
+                Self::attach(finished, &mut stack, &mut file_roots);
+            }
+
+            roots.extend(file_roots);
+        }
+
+        ModuleSubtree {
+            file_prefix: file_prefix.to_string(),
+            file_count: file_ids.len(),
+            symbol_count,
+            roots,
+        }
+    }
+
+    /// Attach a finished subtree node to its parent on the stack, or to the
+    /// file's root list when no parent remains.
+    fn attach(node: SubtreeNode, stack: &mut Vec<SubtreeNode>, roots: &mut Vec<SubtreeNode>) {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => roots.push(node),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::SymbolKind;
 
     #[test]
     fn test_traversal_config_default() {
@@ -643,4 +765,98 @@ mod tests {
         let traverser = GraphTraverser::with_config(config);
         assert_eq!(traverser.config.max_depth, 5);
     }
+
+    fn nested_module_graph() -> CodeGraph {
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/auth/mod.rs", "rust"));
+        graph.add_file(FileNode::new("src/auth/token.rs", "rust"));
+        graph.add_file(FileNode::new("src/other.rs", "rust"));
+
+        // mod.rs: a struct enclosing two methods
+        graph.add_symbol(
+            SymbolNode::new("Session", SymbolKind::Struct, "src/auth/mod.rs", 10)
+                .with_range(10, 60),
+        );
+        graph.add_symbol(
+            SymbolNode::new("login", SymbolKind::Method, "src/auth/mod.rs", 20).with_range(20, 30),
+        );
+        graph.add_symbol(
+            SymbolNode::new("logout", SymbolKind::Method, "src/auth/mod.rs", 40).with_range(40, 50),
+        );
+
+        // token.rs: a top-level function
+        graph.add_symbol(
+            SymbolNode::new("parse_token", SymbolKind::Function, "src/auth/token.rs", 5)
+                .with_range(5, 15),
+        );
+
+        // Outside the prefix, must not appear
+        graph.add_symbol(
+            SymbolNode::new("render", SymbolKind::Function, "src/other.rs", 1).with_range(1, 9),
+        );
+
+        graph
+    }
+
+    #[test]
+    fn test_collect_subtree_nests_by_line_range() {
+        let graph = nested_module_graph();
+        let traverser = GraphTraverser::new();
+
+        let subtree = traverser.collect_subtree(&graph, "src/auth");
+
+        assert_eq!(subtree.file_prefix, "src/auth");
+        assert_eq!(subtree.file_count, 2);
+        assert_eq!(subtree.symbol_count, 4);
+
+        // Files are sorted, so mod.rs roots come before token.rs roots
+        assert_eq!(subtree.roots.len(), 2);
+        let session = &subtree.roots[0];
+        assert_eq!(session.name, "Session");
+        assert_eq!(session.kind, "struct");
+        assert_eq!(session.children.len(), 2);
+        assert_eq!(session.children[0].name, "login");
+        assert_eq!(session.children[1].name, "logout");
+        assert!(session.children[0].children.is_empty());
+
+        let parse_token = &subtree.roots[1];
+        assert_eq!(parse_token.name, "parse_token");
+        assert!(parse_token.children.is_empty());
+
+        // src/other.rs is excluded entirely
+        fn contains(nodes: &[SubtreeNode], name: &str) -> bool {
+            nodes
+                .iter()
+                .any(|n| n.name == name || contains(&n.children, name))
+        }
+        assert!(!contains(&subtree.roots, "render"));
+    }
+
+    #[test]
+    fn test_collect_subtree_respects_max_depth() {
+        let graph = nested_module_graph();
+        let traverser = GraphTraverser::with_config(TraversalConfig::new().with_max_depth(1));
+
+        let subtree = traverser.collect_subtree(&graph, "src/auth/mod.rs");
+
+        // With max_depth 1 the methods cannot open a deeper level; they
+        // flatten into the struct's children and nest no further
+        assert_eq!(subtree.symbol_count, 3);
+        assert_eq!(subtree.roots.len(), 1);
+        let session = &subtree.roots[0];
+        assert_eq!(session.name, "Session");
+        assert_eq!(session.children.len(), 2);
+        assert!(session.children.iter().all(|c| c.children.is_empty()));
+    }
+
+    #[test]
+    fn test_collect_subtree_empty_prefix_match() {
+        let graph = nested_module_graph();
+        let traverser = GraphTraverser::new();
+
+        let subtree = traverser.collect_subtree(&graph, "src/missing");
+        assert_eq!(subtree.file_count, 0);
+        assert_eq!(subtree.symbol_count, 0);
+        assert!(subtree.roots.is_empty());
+    }
 }
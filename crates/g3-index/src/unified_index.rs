@@ -42,11 +42,13 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
-use tracing::debug;
+use tokio::time::timeout;
+use tracing::{debug, warn};
 
 use crate::embeddings::EmbeddingProvider;
 use crate::graph::{CodeGraph, EdgeKind};
@@ -64,6 +66,13 @@ pub struct UnifiedSearchResult {
     pub start_line: usize,
     /// End line of the matched code (1-indexed)
     pub end_line: usize,
+    /// Start column of the symbol's name identifier (1-indexed; 1 for
+    /// results from sources without column information)
+    #[serde(default = "default_column")]
+    pub start_col: usize,
+    /// End column of the symbol's name identifier (1-indexed, inclusive)
+    #[serde(default = "default_column")]
+    pub end_col: usize,
     /// The matching code content (truncated for large results)
     pub content: String,
     /// Kind of code element (function, class, etc.)
@@ -74,6 +83,10 @@ pub struct UnifiedSearchResult {
     pub signature: Option<String>,
     /// Enclosing scope (e.g., "impl Foo")
     pub scope: Option<String>,
+    /// Id of the knowledge-graph symbol covering this result's line range,
+    /// when one exists
+    #[serde(default)]
+    pub symbol_id: Option<String>,
     /// Combined relevance score (higher is better)
     pub score: f32,
     /// Source of this result (semantic, lexical, ast, graph)
@@ -98,6 +111,11 @@ pub enum UnifiedSearchSource {
     Lsp,
 }
 
+/// Column fallback for sources that only report whole-line ranges.
+fn default_column() -> usize {
+    1
+}
+
 impl UnifiedSearchResult {
     /// Create a result from vector search data.
     pub fn from_vector(
@@ -117,11 +135,14 @@ impl UnifiedSearchResult {
             file_path: file_path.into(),
             start_line,
             end_line,
+            start_col: 1,
+            end_col: 1,
             content: content.into(),
             kind: kind.into(),
             name,
             signature,
             scope,
+            symbol_id: None,
             score,
             source: UnifiedSearchSource::Semantic,
             metadata: HashMap::new(),
@@ -146,11 +167,14 @@ impl UnifiedSearchResult {
             file_path: file_path.into(),
             start_line,
             end_line,
+            start_col: 1,
+            end_col: 1,
             content: content.into(),
             kind: kind.into(),
             name,
             signature,
             scope,
+            symbol_id: None,
             score,
             source: UnifiedSearchSource::Lexical,
             metadata: HashMap::new(),
@@ -175,11 +199,14 @@ impl UnifiedSearchResult {
             file_path: file_path.into(),
             start_line,
             end_line,
+            start_col: 1,
+            end_col: 1,
             content: content.into(),
             kind: kind.into(),
             name,
             signature,
             scope,
+            symbol_id: None,
             score,
             source: UnifiedSearchSource::Ast,
             metadata: HashMap::new(),
@@ -216,11 +243,14 @@ impl UnifiedSearchResult {
             file_path: file_path.into(),
             start_line,
             end_line,
+            start_col: 1,
+            end_col: 1,
             content: content.into(),
             kind: kind.into(),
             name,
             signature,
             scope,
+            symbol_id: None,
             score,
             source: UnifiedSearchSource::Graph,
             metadata,
@@ -511,6 +541,21 @@ impl QueryPlan {
     }
 }
 
+/// Outcome of a deadline-bounded unified search.
+///
+/// Alongside the merged results, records which backends contributed and
+/// which ran out of time, so callers can tell a thin result set apart
+/// from a degraded one (see [`UnifiedIndex::unified_search_with_deadline`]).
+#[derive(Debug, Clone)]
+pub struct UnifiedSearchOutcome {
+    /// Merged results from every backend that completed in time
+    pub results: Vec<UnifiedSearchResult>,
+    /// Backends that completed within the deadline
+    pub completed_sources: Vec<UnifiedSearchSource>,
+    /// Backends cut off by the deadline
+    pub timed_out_sources: Vec<UnifiedSearchSource>,
+}
+
 /// Unified index providing a single interface for all search capabilities.
 pub struct UnifiedIndex {
     /// Hybrid searcher for vector + lexical search
@@ -521,6 +566,8 @@ pub struct UnifiedIndex {
     bm25_index: Arc<RwLock<BM25Index>>,
     /// Query planner for automatic strategy selection
     planner: QueryPlanner,
+    /// Cached repo maps, keyed by token budget
+    repo_map_cache: std::sync::RwLock<HashMap<usize, String>>,
 }
 
 impl std::fmt::Debug for UnifiedIndex {
@@ -559,6 +606,7 @@ impl UnifiedIndex {
             graph,
             bm25_index,
             planner,
+            repo_map_cache: std::sync::RwLock::new(HashMap::new()),
         }
     }
 
@@ -582,28 +630,39 @@ impl UnifiedIndex {
             .search(query, filter)
             .await?
             .into_iter()
-            .map(|r| UnifiedSearchResult {
-                id: r.id,
-                file_path: r.file_path,
-                start_line: r.start_line,
-                end_line: r.end_line,
-                content: r.content,
-                kind: r.kind,
-                name: r.name,
-                signature: r.signature,
-                scope: r.scope,
-                score: r.score,
-                source: UnifiedSearchSource::Semantic,
-                metadata: {
-                    let mut m = HashMap::new();
-                    if let Some(vector_score) = r.vector_score {
-                        m.insert("vector_score".to_string(), serde_json::to_value(vector_score).unwrap_or_default());
-                    }
-                    if let Some(bm25_score) = r.bm25_score {
-                        m.insert("bm25_score".to_string(), serde_json::to_value(bm25_score).unwrap_or_default());
-                    }
-                    m
-                },
+            .map(|r| {
+                // Correlate the chunk's line range with the graph so callers
+                // can pivot straight to graph queries (callers, references)
+                let symbol_id = self
+                    .graph
+                    .symbol_covering(&r.file_path, r.start_line, r.end_line)
+                    .map(|s| s.id.clone());
+                UnifiedSearchResult {
+                    id: r.id,
+                    file_path: r.file_path,
+                    start_line: r.start_line,
+                    end_line: r.end_line,
+                    start_col: r.start_col,
+                    end_col: r.end_col,
+                    content: r.content,
+                    kind: r.kind,
+                    name: r.name,
+                    signature: r.signature,
+                    scope: r.scope,
+                    symbol_id,
+                    score: r.score,
+                    source: UnifiedSearchSource::Semantic,
+                    metadata: {
+                        let mut m = HashMap::new();
+                        if let Some(vector_score) = r.vector_score {
+                            m.insert("vector_score".to_string(), serde_json::to_value(vector_score).unwrap_or_default());
+                        }
+                        if let Some(bm25_score) = r.bm25_score {
+                            m.insert("bm25_score".to_string(), serde_json::to_value(bm25_score).unwrap_or_default());
+                        }
+                        m
+                    },
+                }
             })
             .collect();
 
@@ -634,11 +693,14 @@ impl UnifiedIndex {
                     file_path: format!("chunk-{}", id),
                     start_line: 1,
                     end_line: 1,
+                    start_col: 1,
+                    end_col: 1,
                     content: String::new(),
                     kind: "chunk".to_string(),
                     name: None,
                     signature: None,
                     scope: None,
+                    symbol_id: None,
                     score: score as f32,
                     source: UnifiedSearchSource::Lexical,
                     metadata: {
@@ -949,6 +1011,103 @@ impl UnifiedIndex {
         Ok(all_results)
     }
 
+    /// Unified search with a per-search deadline and best-effort results.
+    ///
+    /// The planned backends run concurrently, each bounded by `deadline`;
+    /// whatever completed in time is returned and a stalled backend (a
+    /// slow Qdrant, a hung reranker) only costs its own contribution
+    /// instead of hanging the whole search. A backend that fails outright
+    /// is logged and dropped - it appears in neither source list.
+    pub async fn unified_search_with_deadline(
+        &self,
+        query: &str,
+        deadline: Duration,
+    ) -> Result<UnifiedSearchOutcome> {
+        let plan = self.planner.plan_query(query);
+
+        debug!(?plan, ?deadline, "Executing deadline-bounded query");
+
+        let semantic = async {
+            if plan.includes_semantic() {
+                Some(timeout(deadline, self.search_semantic(query, None)).await)
+            } else {
+                None
+            }
+        };
+        let lexical = async {
+            if plan.includes_lexical() {
+                Some(timeout(deadline, self.search_lexical(query)).await)
+            } else {
+                None
+            }
+        };
+        let ast = async {
+            if plan.includes_ast() {
+                Some(timeout(deadline, self.search_ast(query)).await)
+            } else {
+                None
+            }
+        };
+
+        let (semantic, lexical, ast) = tokio::join!(semantic, lexical, ast);
+
+        let mut outcome = UnifiedSearchOutcome {
+            results: Vec::new(),
+            completed_sources: Vec::new(),
+            timed_out_sources: Vec::new(),
+        };
+
+        for (source, run) in [
+            (UnifiedSearchSource::Semantic, semantic),
+            (UnifiedSearchSource::Lexical, lexical),
+            (UnifiedSearchSource::Ast, ast),
+        ] {
+            match run {
+                // Backend not part of the plan
+                None => {}
+                Some(Ok(Ok(results))) => {
+                    outcome.completed_sources.push(source);
+                    outcome.results.extend(results);
+                }
+                Some(Ok(Err(e))) => {
+                    warn!(?source, error = %e, "Search backend failed; dropping its results");
+                }
+                Some(Err(_)) => {
+                    warn!(?source, ?deadline, "Search backend missed the deadline");
+                    outcome.timed_out_sources.push(source);
+                }
+            }
+        }
+
+        Ok(outcome)
+    }
+
+    /// Compact textual map of the repository for priming an LLM's
+    /// context: directories and files ranked by graph centrality, with
+    /// their most-referenced symbols.
+    ///
+    /// The map stays within `max_tokens` (~4 chars/token) and is cached
+    /// per budget; call [`Self::invalidate_repo_map_cache`] after the
+    /// underlying index changes.
+    pub fn repo_map(&self, max_tokens: usize) -> String {
+        if let Some(cached) = self.repo_map_cache.read().unwrap().get(&max_tokens) {
+            return cached.clone();
+        }
+
+        let map = self.graph.repo_map(max_tokens);
+        self.repo_map_cache
+            .write()
+            .unwrap()
+            .insert(max_tokens, map.clone());
+        map
+    }
+
+    /// Drop all cached repo maps so the next call re-renders from the
+    /// current graph. Call this after an index update.
+    pub fn invalidate_repo_map_cache(&self) {
+        self.repo_map_cache.write().unwrap().clear();
+    }
+
     /// Get the query planner.
     pub fn planner(&self) -> &QueryPlanner {
         &self.planner
@@ -1104,6 +1263,146 @@ mod tests {
         assert!(!graph_plan.includes_lexical());
     }
 
+    // Embedding provider stub; repo_map only touches the graph
+    struct NoopEmbeddingProvider;
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for NoopEmbeddingProvider {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![0.0; 4])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| vec![0.0; 4]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            4
+        }
+
+        fn model_name(&self) -> &str {
+            "noop"
+        }
+    }
+
+    #[test]
+    fn test_repo_map_ranks_central_symbols_within_budget() {
+        use crate::graph::{Edge, FileNode, SymbolKind, SymbolNode};
+
+        let mut graph = CodeGraph::new();
+        graph.add_file(FileNode::new("src/core/engine.rs", "rust"));
+        graph.add_file(FileNode::new("src/util/misc.rs", "rust"));
+
+        let dispatch = SymbolNode::new("dispatch", SymbolKind::Function, "src/core/engine.rs", 10)
+            .with_visibility("public");
+        let dispatch_id = dispatch.id.clone();
+        graph.add_symbol(dispatch);
+        graph.add_symbol(
+            SymbolNode::new("tidy", SymbolKind::Function, "src/util/misc.rs", 5)
+                .with_visibility("public"),
+        );
+        graph.add_symbol(
+            SymbolNode::new("helper", SymbolKind::Function, "src/util/misc.rs", 20)
+                .with_visibility("private"),
+        );
+
+        // Three callers make dispatch the most central symbol
+        for line in [30, 40, 50] {
+            let caller =
+                SymbolNode::new(format!("caller_{}", line), SymbolKind::Function, "src/core/engine.rs", line)
+                    .with_visibility("public");
+            let caller_id = caller.id.clone();
+            graph.add_symbol(caller);
+            graph.add_edge(Edge::new(caller_id, dispatch_id.clone(), EdgeKind::Calls));
+        }
+
+        let unified = UnifiedIndex::new(
+            Arc::new(NoopEmbeddingProvider),
+            QdrantClient::in_memory("test-repo-map", 4),
+            Arc::new(RwLock::new(BM25Index::new())),
+            graph,
+            QueryPlanner::new(),
+        );
+
+        let map = unified.repo_map(500);
+
+        // Stays within the ~4 chars/token budget
+        assert!(map.len() <= 500 * 4);
+
+        // The most central symbol is named, with its fan-in
+        assert!(map.contains("dispatch (3)"));
+        // The central directory ranks above the peripheral one
+        assert!(map.find("src/core").unwrap() < map.find("src/util").unwrap());
+        // Private symbols are left out
+        assert!(!map.contains("helper"));
+
+        // A second call is served from the cache, identically
+        assert_eq!(unified.repo_map(500), map);
+        unified.invalidate_repo_map_cache();
+        assert_eq!(unified.repo_map(500), map);
+
+        // A tight budget truncates at line granularity but never exceeds
+        let small = unified.repo_map(15);
+        assert!(small.len() <= 15 * 4);
+        assert!(small.starts_with("# Repo map:"));
+    }
+
+    /// Embedding provider that stalls long enough to blow any deadline
+    struct StalledEmbeddingProvider;
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for StalledEmbeddingProvider {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            tokio::time::sleep(Duration::from_secs(30)).await;
+            Ok(vec![0.0; 4])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            tokio::time::sleep(Duration::from_secs(30)).await;
+            Ok(texts.iter().map(|_| vec![0.0; 4]).collect())
+        }
+
+        fn dimensions(&self) -> usize {
+            4
+        }
+
+        fn model_name(&self) -> &str {
+            "stalled"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deadline_search_returns_fast_backends_and_flags_slow_one() {
+        let bm25_index = Arc::new(RwLock::new(BM25Index::new()));
+        bm25_index
+            .write()
+            .await
+            .add_document("chunk-1".to_string(), "find similar code paths".to_string());
+
+        // Semantic search stalls in the embedding call; lexical is local
+        let unified = UnifiedIndex::new(
+            Arc::new(StalledEmbeddingProvider),
+            QdrantClient::in_memory("test-deadline", 4),
+            bm25_index,
+            CodeGraph::new(),
+            QueryPlanner::new(),
+        );
+
+        let outcome = unified
+            .unified_search_with_deadline("find similar code", Duration::from_millis(50))
+            .await
+            .unwrap();
+
+        // Lexical completed; the stalled semantic backend was cut off
+        assert_eq!(outcome.completed_sources, vec![UnifiedSearchSource::Lexical]);
+        assert_eq!(outcome.timed_out_sources, vec![UnifiedSearchSource::Semantic]);
+        assert!(!outcome.results.is_empty());
+        assert!(outcome
+            .results
+            .iter()
+            .all(|r| r.source == UnifiedSearchSource::Lexical));
+    }
+
     #[test]
     fn test_unified_search_result_from_graph() {
         let result = UnifiedSearchResult::from_graph(
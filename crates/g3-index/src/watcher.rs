@@ -4,7 +4,7 @@
 //! re-indexing when source files change. It includes debouncing
 //! to batch rapid file changes and avoid excessive re-indexing.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -56,6 +56,15 @@ pub struct WatcherConfig {
 
     /// Whether to respect .gitignore
     pub respect_gitignore: bool,
+
+    /// Update the knowledge graph immediately on change and defer the
+    /// embedding/Qdrant update to a debounced batch. Graph lookups stay
+    /// fresh on every save while embedding spend is throttled.
+    pub graph_eager_embed_lazy: bool,
+
+    /// How long to wait after the last change before running the deferred
+    /// embedding batch (only used with `graph_eager_embed_lazy`)
+    pub embed_debounce: Duration,
 }
 
 impl Default for WatcherConfig {
@@ -72,6 +81,8 @@ impl Default for WatcherConfig {
                 "go".to_string(),
             ],
             respect_gitignore: true,
+            graph_eager_embed_lazy: false,
+            embed_debounce: Duration::from_secs(10),
         }
     }
 }
@@ -287,6 +298,69 @@ impl FileWatcher {
     }
 }
 
+/// Deferred embedding work accumulated in graph-eager mode.
+///
+/// Paths collect here while the graph is updated eagerly; the batch is
+/// ready to run once `embed_debounce` has elapsed since the last
+/// recorded change, so a burst of saves costs one embedding pass.
+#[derive(Debug)]
+pub struct EmbedBatch {
+    pending: HashSet<PathBuf>,
+    last_change: Option<Instant>,
+    debounce: Duration,
+}
+
+impl EmbedBatch {
+    /// Create an empty batch with the given debounce window.
+    pub fn new(debounce: Duration) -> Self {
+        Self {
+            pending: HashSet::new(),
+            last_change: None,
+            debounce,
+        }
+    }
+
+    /// Record a changed path, resetting the debounce window.
+    pub fn record(&mut self, path: PathBuf) {
+        self.record_at(path, Instant::now());
+    }
+
+    /// Record a changed path at an explicit time (injected for tests).
+    pub fn record_at(&mut self, path: PathBuf, now: Instant) {
+        self.pending.insert(path);
+        self.last_change = Some(now);
+    }
+
+    /// Whether the debounce window has elapsed since the last change.
+    pub fn is_ready(&self) -> bool {
+        self.is_ready_at(Instant::now())
+    }
+
+    /// Readiness check at an explicit time (injected for tests).
+    pub fn is_ready_at(&self, now: Instant) -> bool {
+        match self.last_change {
+            Some(last) => now.duration_since(last) >= self.debounce,
+            None => false,
+        }
+    }
+
+    /// Take all pending paths, leaving the batch empty.
+    pub fn drain(&mut self) -> Vec<PathBuf> {
+        self.last_change = None;
+        self.pending.drain().collect()
+    }
+
+    /// Number of pending paths.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Whether the batch has no pending paths.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
 /// Background indexing service that watches for file changes.
 pub struct IndexingService<E: EmbeddingProvider + 'static> {
     watcher: FileWatcher,
@@ -301,8 +375,14 @@ impl<E: EmbeddingProvider + 'static> IndexingService<E> {
 
     /// Run the indexing service in the background.
     pub async fn run(mut self) -> Result<(), WatcherError> {
+        let graph_eager = self.watcher.config.graph_eager_embed_lazy;
+        let embed_debounce = self.watcher.config.embed_debounce;
         let mut rx = self.watcher.start()?;
 
+        if graph_eager {
+            return self.run_graph_eager(&mut rx, embed_debounce).await;
+        }
+
         while let Some(event) = rx.recv().await {
             match event {
                 FileEvent::Created(path) | FileEvent::Modified(path) => {
@@ -339,6 +419,86 @@ impl<E: EmbeddingProvider + 'static> IndexingService<E> {
 
         Ok(())
     }
+
+    /// Event loop for graph-eager mode: the knowledge graph is refreshed
+    /// as soon as a change arrives (so `graph_find_*` lookups stay fresh),
+    /// while embedding/Qdrant updates accumulate into a debounced batch.
+    async fn run_graph_eager(
+        &self,
+        rx: &mut mpsc::Receiver<FileEvent>,
+        embed_debounce: Duration,
+    ) -> Result<(), WatcherError> {
+        let mut batch = EmbedBatch::new(embed_debounce);
+        let tick_interval = Duration::from_millis(100);
+
+        loop {
+            match tokio::time::timeout(tick_interval, rx.recv()).await {
+                Ok(Some(event)) => match event {
+                    FileEvent::Created(path) | FileEvent::Modified(path) => {
+                        tracing::debug!("File changed (graph-eager): {:?}", path);
+                        self.refresh_graph(&path).await;
+                        batch.record(path);
+                    }
+                    FileEvent::Deleted(path) => {
+                        tracing::debug!("File deleted: {:?}", path);
+                        let indexer = self.indexer.read().await;
+                        if let Err(e) = indexer.remove_file(&path).await {
+                            tracing::error!("Failed to remove {:?} from index: {}", path, e);
+                        }
+                    }
+                    FileEvent::Renamed(old_path, new_path) => {
+                        tracing::debug!("File renamed: {:?} -> {:?}", old_path, new_path);
+                        {
+                            let indexer = self.indexer.read().await;
+                            if let Err(e) = indexer.remove_file(&old_path).await {
+                                tracing::error!(
+                                    "Failed to remove {:?} from index: {}",
+                                    old_path,
+                                    e
+                                );
+                            }
+                        }
+                        self.refresh_graph(&new_path).await;
+                        batch.record(new_path);
+                    }
+                },
+                Ok(None) => {
+                    // Channel closed: run whatever is still pending and exit
+                    self.flush_embeds(batch.drain()).await;
+                    return Ok(());
+                }
+                Err(_) => {
+                    // Timeout - check whether the batch is due
+                }
+            }
+
+            if batch.is_ready() {
+                self.flush_embeds(batch.drain()).await;
+            }
+        }
+    }
+
+    /// Apply a graph-only update for a changed file.
+    async fn refresh_graph(&self, path: &Path) {
+        let mut indexer = self.indexer.write().await;
+        if let Err(e) = indexer.update_graph_for_file(path).await {
+            tracing::error!("Failed to update graph for {:?}: {}", path, e);
+        }
+    }
+
+    /// Run the deferred embedding batch for the given paths.
+    async fn flush_embeds(&self, paths: Vec<PathBuf>) {
+        if paths.is_empty() {
+            return;
+        }
+        tracing::debug!("Running deferred embedding batch for {} file(s)", paths.len());
+        let mut indexer = self.indexer.write().await;
+        for path in paths {
+            if let Err(e) = indexer.index_file(&path).await {
+                tracing::error!("Failed to index {:?}: {}", path, e);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -352,6 +512,8 @@ mod tests {
         assert_eq!(config.debounce, Duration::from_millis(500));
         assert!(config.extensions.contains(&"rs".to_string()));
         assert!(config.respect_gitignore);
+        assert!(!config.graph_eager_embed_lazy);
+        assert_eq!(config.embed_debounce, Duration::from_secs(10));
     }
 
     #[test]
@@ -451,6 +613,93 @@ mod tests {
         assert!(matches!(merged, FileEvent::Created(_)));
     }
 
+    #[test]
+    fn test_embed_batch_debounce_from_injected_timing() {
+        let mut batch = EmbedBatch::new(Duration::from_secs(10));
+        assert!(batch.is_empty());
+        assert!(!batch.is_ready());
+
+        let start = Instant::now();
+        batch.record_at(PathBuf::from("src/lib.rs"), start);
+        batch.record_at(PathBuf::from("src/lib.rs"), start); // dedup
+        batch.record_at(PathBuf::from("src/main.rs"), start);
+        assert_eq!(batch.len(), 2);
+
+        // Still inside the debounce window
+        assert!(!batch.is_ready_at(start + Duration::from_secs(5)));
+
+        // A fresh change resets the window
+        batch.record_at(PathBuf::from("src/lib.rs"), start + Duration::from_secs(8));
+        assert!(!batch.is_ready_at(start + Duration::from_secs(12)));
+
+        // Quiet period elapsed
+        assert!(batch.is_ready_at(start + Duration::from_secs(18)));
+
+        let drained = batch.drain();
+        assert_eq!(drained.len(), 2);
+        assert!(batch.is_empty());
+        assert!(!batch.is_ready_at(start + Duration::from_secs(30)));
+    }
+
+    #[tokio::test]
+    async fn test_graph_updates_before_embedding_batch_fires() {
+        use crate::indexer::IndexerConfig;
+        use crate::qdrant::{QdrantClient, QdrantConfig};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Embedding provider that records whether it was ever called
+        struct CountingEmbeddingProvider {
+            calls: AtomicUsize,
+        }
+
+        #[async_trait::async_trait]
+        impl EmbeddingProvider for CountingEmbeddingProvider {
+            async fn embed(&self, _text: &str) -> anyhow::Result<Vec<f32>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                Ok(vec![0.0; 4096])
+            }
+
+            async fn embed_batch(&self, texts: &[String]) -> anyhow::Result<Vec<Vec<f32>>> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                Ok(texts.iter().map(|_| vec![0.0; 4096]).collect())
+            }
+
+            fn dimensions(&self) -> usize {
+                4096
+            }
+
+            fn model_name(&self) -> &str {
+                "counting-mock"
+            }
+        }
+
+        let dir = tempdir().unwrap();
+        let source = dir.path().join("example.rs");
+        std::fs::write(&source, "fn synthetic_change() {}\n").unwrap();
+
+        let qdrant = QdrantClient::from_config(&QdrantConfig::default())
+            .await
+            .unwrap();
+        let provider = Arc::new(CountingEmbeddingProvider {
+            calls: AtomicUsize::new(0),
+        });
+        let config = IndexerConfig {
+            root_path: dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let mut indexer = Indexer::new(config, provider.clone(), qdrant).unwrap();
+
+        // The eager path refreshes the graph without touching embeddings
+        let chunks = indexer.update_graph_for_file(&source).await.unwrap();
+        assert!(chunks > 0);
+
+        let gb = indexer.graph_builder().unwrap().read().await;
+        let stats = gb.stats();
+        assert_eq!(stats.file_count, 1);
+        assert!(stats.symbol_count > 0);
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 0);
+    }
+
     #[test]
     fn test_event_path_extraction() {
         let path = PathBuf::from("/test/file.rs");
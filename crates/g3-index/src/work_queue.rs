@@ -0,0 +1,168 @@
+//! Persistent embedding progress for crash-resilient indexing.
+//!
+//! Embedding is the slow, paid part of an indexing run. Without
+//! chunk-granular progress tracking, an interruption after embedding
+//! 9,000 of 10,000 chunks means re-embedding everything on restart: the
+//! manifest is only written after the run completes. The work queue
+//! records each chunk's stable id as soon as its batch is upserted, so a
+//! resumed run skips chunks that already made it into the vector store
+//! and only embeds the remainder.
+//!
+//! The file lives under the same `.g3-index/` state directory as the
+//! graph and is cleared once a run completes cleanly (at which point the
+//! manifest becomes authoritative). A missing or corrupt file degrades
+//! to an empty queue: the worst case is re-embedding, never a wrong
+//! index.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// Default location of the queue file, relative to the indexed root.
+pub const DEFAULT_QUEUE_FILE: &str = ".g3-index/pending_embeddings.json";
+
+/// Persisted portion of the queue.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct QueueState {
+    /// Stable chunk ids whose vectors were successfully upserted.
+    completed: HashSet<String>,
+}
+
+/// Tracks which chunks have already been embedded and upserted, keyed by
+/// stable chunk id, and persists that set across process restarts.
+#[derive(Debug)]
+pub struct EmbeddingWorkQueue {
+    path: PathBuf,
+    state: QueueState,
+}
+
+impl EmbeddingWorkQueue {
+    /// Queue file location for an indexed root directory.
+    pub fn path_for_root(root: &Path) -> PathBuf {
+        root.join(DEFAULT_QUEUE_FILE)
+    }
+
+    /// Load the queue from disk, or start empty if the file is missing
+    /// or unreadable (re-embedding is always safe).
+    pub fn load(path: PathBuf) -> Self {
+        let state = match std::fs::read_to_string(&path) {
+            Ok(content) => match serde_json::from_str(&content) {
+                Ok(state) => state,
+                Err(e) => {
+                    warn!(
+                        "Corrupt embedding work queue at {:?} ({}); starting fresh",
+                        path, e
+                    );
+                    QueueState::default()
+                }
+            },
+            Err(_) => QueueState::default(),
+        };
+        if !state.completed.is_empty() {
+            debug!(
+                "Resuming embedding work queue: {} chunks already completed",
+                state.completed.len()
+            );
+        }
+        Self { path, state }
+    }
+
+    /// Whether this chunk's vector was already upserted by a previous run.
+    pub fn is_completed(&self, chunk_id: &str) -> bool {
+        self.state.completed.contains(chunk_id)
+    }
+
+    /// Record chunks whose batch was successfully upserted.
+    pub fn record_completed(&mut self, chunk_ids: impl IntoIterator<Item = String>) {
+        self.state.completed.extend(chunk_ids);
+    }
+
+    /// Number of completed chunks currently tracked.
+    pub fn len(&self) -> usize {
+        self.state.completed.len()
+    }
+
+    /// True when no completed chunks are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.state.completed.is_empty()
+    }
+
+    /// Write the queue to disk so a crash after this point can resume.
+    pub fn persist(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create state dir {:?}", parent))?;
+        }
+        let json = serde_json::to_string(&self.state)?;
+        std::fs::write(&self.path, json)
+            .with_context(|| format!("Failed to write embedding work queue {:?}", self.path))?;
+        Ok(())
+    }
+
+    /// Drop all tracked progress and remove the on-disk file.
+    ///
+    /// Called when a run completes cleanly (the manifest now owns the
+    /// truth) or when a force re-index invalidates prior progress.
+    pub fn clear(&mut self) -> Result<()> {
+        self.state.completed.clear();
+        match std::fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e).with_context(|| {
+                format!("Failed to remove embedding work queue {:?}", self.path)
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_persist_and_reload_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = EmbeddingWorkQueue::path_for_root(dir.path());
+
+        let mut queue = EmbeddingWorkQueue::load(path.clone());
+        assert!(queue.is_empty());
+        queue.record_completed(vec!["id-1".to_string(), "id-2".to_string()]);
+        queue.persist().unwrap();
+
+        let reloaded = EmbeddingWorkQueue::load(path);
+        assert_eq!(reloaded.len(), 2);
+        assert!(reloaded.is_completed("id-1"));
+        assert!(!reloaded.is_completed("id-3"));
+    }
+
+    #[test]
+    fn test_clear_removes_file_and_tolerates_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = EmbeddingWorkQueue::path_for_root(dir.path());
+
+        let mut queue = EmbeddingWorkQueue::load(path.clone());
+        queue.record_completed(vec!["id-1".to_string()]);
+        queue.persist().unwrap();
+        assert!(path.exists());
+
+        queue.clear().unwrap();
+        assert!(!path.exists());
+        assert!(queue.is_empty());
+
+        // Clearing again with no file on disk is fine
+        queue.clear().unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_file_loads_as_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = EmbeddingWorkQueue::path_for_root(dir.path());
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "not json").unwrap();
+
+        let queue = EmbeddingWorkQueue::load(path);
+        assert!(queue.is_empty());
+    }
+}
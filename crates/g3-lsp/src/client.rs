@@ -8,18 +8,22 @@ use crate::types::{path_to_uri, LspError, LspLocation, LspPosition, LspServerCon
 use lsp_types::{
     CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
     CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams,
-    ClientCapabilities, DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse,
+    ClientCapabilities, DidOpenTextDocumentParams, DocumentSymbol, DocumentSymbolParams,
+    DocumentSymbolResponse,
     GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams, HoverProviderCapability,
     ImplementationProviderCapability, InitializeParams, InitializedParams, Location,
-    OneOf, ReferenceContext, ReferenceParams, ServerCapabilities, SymbolInformation,
-    TextDocumentClientCapabilities, TextDocumentIdentifier, TextDocumentPositionParams,
-    WindowClientCapabilities, WorkspaceSymbolParams,
+    OneOf, ReferenceContext, ReferenceParams, RenameParams, ServerCapabilities,
+    SymbolInformation,
+    TextDocumentClientCapabilities, TextDocumentIdentifier, TextDocumentItem,
+    TextDocumentPositionParams,
+    WindowClientCapabilities, WorkspaceEdit, WorkspaceSymbolParams,
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{ChildStdin, ChildStdout};
 use tokio::sync::{Mutex, RwLock};
@@ -66,6 +70,83 @@ struct PendingRequest {
     sender: tokio::sync::oneshot::Sender<String>,
 }
 
+/// How long a prepared call-hierarchy item stays valid for reuse.
+const PREPARE_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Cache of prepared call-hierarchy items keyed by (path, position).
+///
+/// Preparing is a full server round-trip, and interactive call-graph
+/// exploration typically asks for incoming and outgoing calls of the same
+/// position back to back. A short TTL keeps repeated queries from
+/// re-preparing while the item could already have gone stale from edits.
+pub(crate) struct PrepareCache {
+    entries: HashMap<(PathBuf, u32, u32), PreparedEntry>,
+    ttl: Duration,
+}
+
+/// A cached prepare result with its insertion time.
+struct PreparedEntry {
+    items: Vec<CallHierarchyItem>,
+    cached_at: Instant,
+}
+
+impl PrepareCache {
+    /// Create an empty cache with the given TTL.
+    fn new(ttl: Duration) -> Self {
+        Self {
+            entries: HashMap::new(),
+            ttl,
+        }
+    }
+
+    /// Look up a prepared item for the position, if still fresh.
+    fn get(&self, file: &Path, line: u32, character: u32) -> Option<Vec<CallHierarchyItem>> {
+        self.get_at(file, line, character, Instant::now())
+    }
+
+    /// Lookup at an explicit time (injected for tests).
+    fn get_at(
+        &self,
+        file: &Path,
+        line: u32,
+        character: u32,
+        now: Instant,
+    ) -> Option<Vec<CallHierarchyItem>> {
+        let entry = self
+            .entries
+            .get(&(file.to_path_buf(), line, character))?;
+        if now.duration_since(entry.cached_at) >= self.ttl {
+            return None;
+        }
+        Some(entry.items.clone())
+    }
+
+    /// Store a prepared item, evicting anything already expired.
+    fn insert(&mut self, file: PathBuf, line: u32, character: u32, items: Vec<CallHierarchyItem>) {
+        self.insert_at(file, line, character, items, Instant::now());
+    }
+
+    /// Insertion at an explicit time (injected for tests).
+    fn insert_at(
+        &mut self,
+        file: PathBuf,
+        line: u32,
+        character: u32,
+        items: Vec<CallHierarchyItem>,
+        now: Instant,
+    ) {
+        self.entries
+            .retain(|_, e| now.duration_since(e.cached_at) < self.ttl);
+        self.entries.insert(
+            (file, line, character),
+            PreparedEntry {
+                items,
+                cached_at: now,
+            },
+        );
+    }
+}
+
 /// LSP client for a single language server connection.
 pub struct LspClient {
     /// Server configuration.
@@ -84,6 +165,8 @@ pub struct LspClient {
     root_uri: Url,
     /// Handle to the reader task.
     reader_handle: tokio::task::JoinHandle<()>,
+    /// Cache of prepared call-hierarchy items.
+    prepare_cache: RwLock<PrepareCache>,
 }
 
 impl LspClient {
@@ -120,6 +203,7 @@ impl LspClient {
             server_capabilities: None,
             root_uri: root_uri.clone(),
             reader_handle,
+            prepare_cache: RwLock::new(PrepareCache::new(PREPARE_CACHE_TTL)),
         };
 
         // Perform initialization
@@ -512,6 +596,41 @@ impl LspClient {
         }
     }
 
+    /// Rename the symbol at the given position across the workspace.
+    ///
+    /// Line and character are 1-indexed (user-facing). Returns the edit set
+    /// computed by the server; the caller decides how (and whether) to apply it.
+    pub async fn rename(
+        &self,
+        file: &Path,
+        line: u32,
+        character: u32,
+        new_name: &str,
+    ) -> Result<WorkspaceEdit, LspError> {
+        let uri = path_to_uri(file)?;
+        let position = LspPosition::new(line, character).to_lsp_position();
+
+        debug!(
+            file = %file.display(),
+            line = line,
+            character = character,
+            new_name = new_name,
+            "Renaming symbol"
+        );
+
+        let params = RenameParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position,
+            },
+            new_name: new_name.to_string(),
+            work_done_progress_params: Default::default(),
+        };
+
+        let response: Option<WorkspaceEdit> = self.request("textDocument/rename", params).await?;
+        Ok(response.unwrap_or_default())
+    }
+
     /// Get hover information for the symbol at the given position.
     ///
     /// Line and character are 1-indexed (user-facing).
@@ -594,8 +713,17 @@ impl LspClient {
     }
 
     /// Search for symbols across the workspace.
-    pub async fn workspace_symbols(&self, query: &str) -> Result<Vec<SymbolInformation>, LspError> {
-        debug!(query = query, "Searching workspace symbols");
+    ///
+    /// Results are ranked by fuzzy match quality against the query (exact
+    /// name matches first) rather than server order, and truncated to
+    /// `limit`. The result carries a `truncated` flag when more symbols
+    /// matched than were returned.
+    pub async fn workspace_symbols(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<WorkspaceSymbolsResult, LspError> {
+        debug!(query = query, limit = limit, "Searching workspace symbols");
 
         let params = WorkspaceSymbolParams {
             query: query.to_string(),
@@ -606,11 +734,11 @@ impl LspClient {
         let response: Option<lsp_types::WorkspaceSymbolResponse> =
             self.request("workspace/symbol", params).await?;
 
-        match response {
-            Some(lsp_types::WorkspaceSymbolResponse::Flat(symbols)) => Ok(symbols),
+        let symbols = match response {
+            Some(lsp_types::WorkspaceSymbolResponse::Flat(symbols)) => symbols,
             Some(lsp_types::WorkspaceSymbolResponse::Nested(symbols)) => {
                 // Convert WorkspaceSymbol to SymbolInformation
-                Ok(symbols
+                symbols
                     .into_iter()
                     .filter_map(|ws| {
                         let location = match ws.location {
@@ -630,10 +758,12 @@ impl LspClient {
                             container_name: ws.container_name,
                         })
                     })
-                    .collect())
+                    .collect()
             }
-            None => Ok(Vec::new()),
-        }
+            None => Vec::new(),
+        };
+
+        Ok(rank_and_truncate_symbols(query, symbols, limit))
     }
 
     /// Go to implementation of an interface or abstract method.
@@ -689,13 +819,26 @@ impl LspClient {
 
     /// Prepare call hierarchy at a position.
     ///
-    /// Line and character are 1-indexed (user-facing).
+    /// Prepared items are cached by (path, position) for a short TTL so
+    /// that asking for incoming and outgoing calls around the same
+    /// function only prepares once. Line and character are 1-indexed
+    /// (user-facing).
     pub async fn prepare_call_hierarchy(
         &self,
         file: &Path,
         line: u32,
         character: u32,
     ) -> Result<Vec<CallHierarchyItem>, LspError> {
+        if let Some(items) = self.prepare_cache.read().await.get(file, line, character) {
+            debug!(
+                file = %file.display(),
+                line = line,
+                character = character,
+                "Reusing cached call hierarchy items"
+            );
+            return Ok(items);
+        }
+
         let uri = path_to_uri(file)?;
         let position = LspPosition::new(line, character).to_lsp_position();
 
@@ -718,7 +861,13 @@ impl LspClient {
             .request("textDocument/prepareCallHierarchy", params)
             .await?;
 
-        Ok(response.unwrap_or_default())
+        let items = response.unwrap_or_default();
+        self.prepare_cache
+            .write()
+            .await
+            .insert(file.to_path_buf(), line, character, items.clone());
+
+        Ok(items)
     }
 
     /// Get incoming calls to a call hierarchy item.
@@ -785,6 +934,19 @@ impl LspClient {
         }
     }
 
+    /// Open a document on the server (`textDocument/didOpen`).
+    ///
+    /// Pre-opening nudges lazily-indexing servers like rust-analyzer to
+    /// start analyzing the file before the first query, so later requests
+    /// against it are fast.
+    pub async fn open_document(&self, file: &Path) -> Result<(), LspError> {
+        let text = tokio::fs::read_to_string(file).await?;
+        let params = did_open_params(file, self.config.language_id.clone(), text)?;
+
+        debug!(file = %file.display(), "Opening document");
+        self.notify("textDocument/didOpen", params).await
+    }
+
     /// Get the language ID for this client.
     pub fn language_id(&self) -> &str {
         &self.config.language_id
@@ -795,3 +957,235 @@ impl LspClient {
         &self.root_uri
     }
 }
+
+/// Result of a workspace symbol search.
+#[derive(Debug, Clone)]
+pub struct WorkspaceSymbolsResult {
+    /// Symbols ordered by fuzzy match quality (best first).
+    pub symbols: Vec<SymbolInformation>,
+    /// True when the server returned more symbols than the requested limit.
+    pub truncated: bool,
+}
+
+/// Build the `textDocument/didOpen` parameters for a file.
+pub(crate) fn did_open_params(
+    file: &Path,
+    language_id: String,
+    text: String,
+) -> Result<DidOpenTextDocumentParams, LspError> {
+    Ok(DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: path_to_uri(file)?,
+            language_id,
+            version: 0,
+            text,
+        },
+    })
+}
+
+/// Score how well a symbol name matches the query. Higher is better;
+/// `None` means no match at all. Tiers: exact > case-insensitive exact >
+/// prefix > case-insensitive prefix > substring > in-order subsequence.
+/// Within a tier, shorter names score higher.
+fn fuzzy_match_score(query: &str, name: &str) -> Option<i32> {
+    let len_penalty = name.len().min(100) as i32;
+    if name == query {
+        return Some(1000);
+    }
+    let query_lower = query.to_lowercase();
+    let name_lower = name.to_lowercase();
+    if name_lower == query_lower {
+        return Some(900);
+    }
+    if name.starts_with(query) {
+        return Some(800 - len_penalty);
+    }
+    if name_lower.starts_with(&query_lower) {
+        return Some(700 - len_penalty);
+    }
+    if name_lower.contains(&query_lower) {
+        return Some(500 - len_penalty);
+    }
+    // In-order subsequence match (e.g. "wsym" matches "workspace_symbols")
+    let mut name_chars = name_lower.chars();
+    if query_lower
+        .chars()
+        .all(|q| name_chars.any(|n| n == q))
+    {
+        return Some(100 - len_penalty);
+    }
+    None
+}
+
+/// Rank symbols by fuzzy match quality against the query, then truncate
+/// to `limit`. Symbols that don't match at all sink to the end in server
+/// order rather than being dropped, since some servers apply their own
+/// filtering semantics.
+fn rank_and_truncate_symbols(
+    query: &str,
+    mut symbols: Vec<SymbolInformation>,
+    limit: usize,
+) -> WorkspaceSymbolsResult {
+    symbols.sort_by_key(|s| -(fuzzy_match_score(query, &s.name).unwrap_or(i32::MIN + 1)));
+    let truncated = symbols.len() > limit;
+    symbols.truncate(limit);
+    WorkspaceSymbolsResult { symbols, truncated }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lsp_types::SymbolKind;
+
+    fn symbol(name: &str) -> SymbolInformation {
+        #[allow(deprecated)]
+        SymbolInformation {
+            name: name.to_string(),
+            kind: SymbolKind::FUNCTION,
+            tags: None,
+            deprecated: None,
+            location: Location {
+                uri: Url::parse("file:///src/lib.rs").unwrap(),
+                range: lsp_types::Range::default(),
+            },
+            container_name: None,
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_match_score_tiers() {
+        let exact = fuzzy_match_score("parse", "parse").unwrap();
+        let ci_exact = fuzzy_match_score("parse", "Parse").unwrap();
+        let prefix = fuzzy_match_score("parse", "parse_config").unwrap();
+        let substring = fuzzy_match_score("parse", "try_parse_config").unwrap();
+        let subsequence = fuzzy_match_score("prs", "parse").unwrap();
+        assert!(exact > ci_exact);
+        assert!(ci_exact > prefix);
+        assert!(prefix > substring);
+        assert!(substring > subsequence);
+        assert_eq!(fuzzy_match_score("parse", "format"), None);
+    }
+
+    #[test]
+    fn test_shorter_names_win_within_tier() {
+        let short = fuzzy_match_score("parse", "parse_one").unwrap();
+        let long = fuzzy_match_score("parse", "parse_configuration_file").unwrap();
+        assert!(short > long);
+    }
+
+    #[test]
+    fn test_exact_match_ranks_first() {
+        // Mocked server response in arbitrary (server) order.
+        let response = vec![
+            symbol("parse_config"),
+            symbol("ParseError"),
+            symbol("parse"),
+            symbol("try_parse_config"),
+        ];
+
+        let result = rank_and_truncate_symbols("parse", response, 10);
+        assert_eq!(result.symbols[0].name, "parse");
+        assert_eq!(result.symbols[1].name, "parse_config");
+        assert!(!result.truncated);
+    }
+
+    #[test]
+    fn test_truncation_sets_flag() {
+        let response = vec![
+            symbol("parse"),
+            symbol("parse_config"),
+            symbol("parse_args"),
+        ];
+
+        let result = rank_and_truncate_symbols("parse", response, 2);
+        assert_eq!(result.symbols.len(), 2);
+        assert_eq!(result.symbols[0].name, "parse");
+        assert!(result.truncated);
+    }
+
+    #[test]
+    fn test_did_open_params_for_each_path() {
+        // One didOpen payload per warmup path, carrying the file URI and text
+        let paths = ["/project/src/lib.rs", "/project/src/main.rs"];
+        for path in paths {
+            let params = did_open_params(
+                Path::new(path),
+                "rust".to_string(),
+                "fn main() {}".to_string(),
+            )
+            .unwrap();
+
+            assert_eq!(params.text_document.uri.path(), path);
+            assert_eq!(params.text_document.language_id, "rust");
+            assert_eq!(params.text_document.version, 0);
+            assert_eq!(params.text_document.text, "fn main() {}");
+        }
+    }
+
+    fn hierarchy_item(name: &str) -> CallHierarchyItem {
+        CallHierarchyItem {
+            name: name.to_string(),
+            kind: SymbolKind::FUNCTION,
+            tags: None,
+            detail: None,
+            uri: Url::parse("file:///src/lib.rs").unwrap(),
+            range: lsp_types::Range::default(),
+            selection_range: lsp_types::Range::default(),
+            data: None,
+        }
+    }
+
+    #[test]
+    fn test_two_directional_queries_prepare_once() {
+        let mut cache = PrepareCache::new(Duration::from_secs(30));
+        let start = Instant::now();
+        let file = Path::new("/src/lib.rs");
+        let mut prepares = 0;
+
+        // Incoming then outgoing query around the same position: the
+        // second lookup hits the cache instead of re-preparing
+        for _ in 0..2 {
+            match cache.get_at(file, 10, 4, start) {
+                Some(items) => assert_eq!(items[0].name, "handler"),
+                None => {
+                    prepares += 1;
+                    cache.insert_at(file.to_path_buf(), 10, 4, vec![hierarchy_item("handler")], start);
+                }
+            }
+        }
+
+        assert_eq!(prepares, 1);
+    }
+
+    #[test]
+    fn test_prepare_cache_keyed_by_position_and_expires() {
+        let mut cache = PrepareCache::new(Duration::from_secs(30));
+        let start = Instant::now();
+        let file = Path::new("/src/lib.rs");
+        cache.insert_at(file.to_path_buf(), 10, 4, vec![hierarchy_item("handler")], start);
+
+        // A different position misses
+        assert!(cache.get_at(file, 11, 4, start).is_none());
+        assert!(cache
+            .get_at(Path::new("/src/other.rs"), 10, 4, start)
+            .is_none());
+
+        // Fresh within the TTL, gone after it
+        assert!(cache
+            .get_at(file, 10, 4, start + Duration::from_secs(29))
+            .is_some());
+        assert!(cache
+            .get_at(file, 10, 4, start + Duration::from_secs(31))
+            .is_none());
+    }
+
+    #[test]
+    fn test_non_matching_symbols_sink_but_survive() {
+        let response = vec![symbol("format"), symbol("parse")];
+
+        let result = rank_and_truncate_symbols("parse", response, 10);
+        assert_eq!(result.symbols[0].name, "parse");
+        assert_eq!(result.symbols[1].name, "format");
+        assert!(!result.truncated);
+    }
+}
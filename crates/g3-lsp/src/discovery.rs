@@ -165,4 +165,47 @@ mod tests {
 
         assert!(default_server_config("unknown").is_none());
     }
+
+    #[test]
+    fn test_default_server_config_per_language_command() {
+        assert_eq!(
+            default_server_config("typescript").unwrap().command,
+            "typescript-language-server"
+        );
+        assert_eq!(
+            default_server_config("python").unwrap().command,
+            "pyright-langserver"
+        );
+        assert_eq!(default_server_config("go").unwrap().command, "gopls");
+        assert_eq!(default_server_config("c").unwrap().command, "clangd");
+        assert_eq!(default_server_config("cpp").unwrap().command, "clangd");
+    }
+
+    #[test]
+    fn test_detect_project_picks_preset_for_fixture() {
+        // (root marker, source file, expected language, expected server)
+        let fixtures = [
+            ("Cargo.toml", "src/main.rs", "rust", "rust-analyzer"),
+            ("package.json", "src/app.ts", "typescript", "typescript-language-server"),
+            ("pyproject.toml", "pkg/main.py", "python", "pyright-langserver"),
+            ("go.mod", "cmd/main.go", "go", "gopls"),
+            ("CMakeLists.txt", "src/main.cpp", "cpp", "clangd"),
+        ];
+
+        for (marker, source, expected_language, expected_command) in fixtures {
+            let temp = tempfile::tempdir().unwrap();
+            std::fs::write(temp.path().join(marker), "").unwrap();
+            let source_path = temp.path().join(source);
+            std::fs::create_dir_all(source_path.parent().unwrap()).unwrap();
+            std::fs::write(&source_path, "").unwrap();
+
+            let (language, root) = detect_project(&source_path)
+                .unwrap_or_else(|| panic!("no project detected for {}", source));
+            assert_eq!(language, expected_language);
+            assert_eq!(root, temp.path());
+
+            let config = default_server_config(&language).unwrap();
+            assert_eq!(config.command, expected_command);
+        }
+    }
 }
@@ -49,7 +49,7 @@ pub mod transport;
 pub mod types;
 
 // Re-exports for convenient access
-pub use client::LspClient;
+pub use client::{LspClient, WorkspaceSymbolsResult};
 pub use discovery::{default_server_config, detect_language, detect_project, find_project_root, root_markers};
 pub use lifecycle::{health_check, HealthStatus};
 pub use manager::{LspManager, LspManagerConfig, ServerStatus};
@@ -57,8 +57,10 @@ pub use types::{LspError, LspLocation, LspPosition, LspServerConfig};
 
 // Re-export commonly used lsp-types for consumers
 pub use lsp_types::{
-    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, DocumentSymbol,
-    Hover, HoverContents, Location, MarkedString, MarkupContent, SymbolInformation, SymbolKind,
+    AnnotatedTextEdit, CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall,
+    DocumentChangeOperation, DocumentChanges, DocumentSymbol, Hover, HoverContents, Location,
+    MarkedString, MarkupContent, OneOf, Position, Range, SymbolInformation, SymbolKind,
+    TextDocumentEdit, TextEdit, WorkspaceEdit,
 };
 
 // Re-export url::Url for convenience
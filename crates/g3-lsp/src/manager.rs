@@ -14,6 +14,7 @@ use crate::types::{LspError, LspServerConfig};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, info, warn};
 
@@ -26,6 +27,11 @@ pub struct LspManagerConfig {
     pub custom_servers: HashMap<String, LspServerConfig>,
     /// Request timeout in milliseconds.
     pub timeout_ms: u64,
+    /// Shut down servers idle for this long, reclaiming their memory.
+    /// `None` (the default) keeps servers alive for the whole session.
+    /// Reaped servers are restarted transparently on the next request,
+    /// re-opening any files that were warmed up before.
+    pub idle_timeout_ms: Option<u64>,
 }
 
 impl Default for LspManagerConfig {
@@ -34,6 +40,7 @@ impl Default for LspManagerConfig {
             auto_start: true,
             custom_servers: HashMap::new(),
             timeout_ms: 30000,
+            idle_timeout_ms: None,
         }
     }
 }
@@ -61,8 +68,23 @@ impl LspManagerConfig {
         self.timeout_ms = timeout_ms;
         self
     }
+
+    /// Shut down servers after this many milliseconds without requests.
+    pub fn with_idle_timeout(mut self, idle_timeout_ms: u64) -> Self {
+        self.idle_timeout_ms = Some(idle_timeout_ms);
+        self
+    }
+
+    /// Keep servers alive indefinitely (the default).
+    pub fn without_idle_timeout(mut self) -> Self {
+        self.idle_timeout_ms = None;
+        self
+    }
 }
 
+/// Maximum number of files opened concurrently during warmup.
+const WARMUP_CONCURRENCY: usize = 4;
+
 /// Key for identifying a specific server instance.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ServerKey {
@@ -79,6 +101,17 @@ impl ServerKey {
     }
 }
 
+/// A running server together with when it last served a request.
+struct ServerEntry {
+    client: Arc<Mutex<LspClient>>,
+    last_used: Instant,
+}
+
+/// Whether a server has been idle long enough to reap.
+fn is_idle(last_used: Instant, now: Instant, timeout: Duration) -> bool {
+    now.saturating_duration_since(last_used) >= timeout
+}
+
 /// Manages multiple LSP server connections.
 ///
 /// Servers are keyed by (language, root_path) tuple, allowing multiple
@@ -87,7 +120,9 @@ pub struct LspManager {
     /// Configuration.
     config: LspManagerConfig,
     /// Active clients by (language, root_path).
-    clients: RwLock<HashMap<ServerKey, Arc<Mutex<LspClient>>>>,
+    clients: RwLock<HashMap<ServerKey, ServerEntry>>,
+    /// Files opened during warmup, replayed when a reaped server restarts.
+    warmed_files: RwLock<HashMap<ServerKey, Vec<PathBuf>>>,
 }
 
 impl LspManager {
@@ -96,6 +131,7 @@ impl LspManager {
         Self {
             config,
             clients: RwLock::new(HashMap::new()),
+            warmed_files: RwLock::new(HashMap::new()),
         }
     }
 
@@ -134,16 +170,20 @@ impl LspManager {
 
         let key = ServerKey::new(&language, &root_path);
 
+        // Opportunistically reclaim memory from other idle servers
+        self.reap_idle_servers().await;
+
         // Check if we already have a client
         {
-            let clients = self.clients.read().await;
-            if let Some(client) = clients.get(&key) {
+            let mut clients = self.clients.write().await;
+            if let Some(entry) = clients.get_mut(&key) {
+                entry.last_used = Instant::now();
                 debug!(
                     language = %language,
                     root = %root_path.display(),
                     "Returning existing LSP client"
                 );
-                return Ok(client.clone());
+                return Ok(entry.client.clone());
             }
         }
 
@@ -176,15 +216,87 @@ impl LspManager {
         let client = LspClient::start(config, &root_path).await?;
         let client = Arc::new(Mutex::new(client));
 
+        // If this server was reaped earlier, replay its warmup so the
+        // restart is transparent to the caller
+        let warmed = self.warmed_files.read().await.get(&key).cloned();
+        if let Some(paths) = warmed {
+            let guard = client.lock().await;
+            for path in &paths {
+                let _ = guard.open_document(path).await;
+            }
+            debug!(
+                count = paths.len(),
+                language = %language,
+                "Re-warmed restarted LSP server"
+            );
+        }
+
         // Store in map
         {
             let mut clients = self.clients.write().await;
-            clients.insert(key, client.clone());
+            clients.insert(
+                key,
+                ServerEntry {
+                    client: client.clone(),
+                    last_used: Instant::now(),
+                },
+            );
         }
 
         Ok(client)
     }
 
+    /// Shut down servers that have been idle longer than the configured
+    /// timeout. A no-op when no idle timeout is configured. Returns the
+    /// number of servers shut down.
+    pub async fn reap_idle_servers(&self) -> usize {
+        self.reap_idle_servers_at(Instant::now()).await
+    }
+
+    /// Clock-injectable body of [`reap_idle_servers`], used by tests.
+    ///
+    /// Servers whose client handle is still held elsewhere are skipped -
+    /// an in-flight request means the server is not actually idle.
+    pub async fn reap_idle_servers_at(&self, now: Instant) -> usize {
+        let Some(timeout_ms) = self.config.idle_timeout_ms else {
+            return 0;
+        };
+        let timeout = Duration::from_millis(timeout_ms);
+
+        let expired: Vec<(ServerKey, Arc<Mutex<LspClient>>)> = {
+            let mut clients = self.clients.write().await;
+            let keys: Vec<ServerKey> = clients
+                .iter()
+                .filter(|(_, e)| {
+                    is_idle(e.last_used, now, timeout) && Arc::strong_count(&e.client) == 1
+                })
+                .map(|(k, _)| k.clone())
+                .collect();
+            keys.into_iter()
+                .filter_map(|k| clients.remove(&k).map(|e| (k, e.client)))
+                .collect()
+        };
+
+        let count = expired.len();
+        for (key, client) in expired {
+            info!(
+                language = %key.language,
+                root = %key.root_path.display(),
+                "Shutting down idle LSP server"
+            );
+            if let Ok(mutex) = Arc::try_unwrap(client) {
+                if let Err(e) = mutex.into_inner().shutdown().await {
+                    warn!(
+                        language = %key.language,
+                        error = %e,
+                        "Failed to shutdown idle LSP server"
+                    );
+                }
+            }
+        }
+        count
+    }
+
     /// Explicitly start a server for a language at a root path.
     ///
     /// This will start a server even if auto_start is disabled.
@@ -228,7 +340,13 @@ impl LspManager {
 
         // Store
         let mut clients = self.clients.write().await;
-        clients.insert(key, client);
+        clients.insert(
+            key,
+            ServerEntry {
+                client,
+                last_used: Instant::now(),
+            },
+        );
 
         Ok(())
     }
@@ -244,7 +362,7 @@ impl LspManager {
         // Remove from map
         let client = {
             let mut clients = self.clients.write().await;
-            clients.remove(&key)
+            clients.remove(&key).map(|e| e.client)
         };
 
         // Shutdown if found
@@ -276,7 +394,10 @@ impl LspManager {
 
         let clients: Vec<_> = {
             let mut clients_guard = self.clients.write().await;
-            clients_guard.drain().collect()
+            clients_guard
+                .drain()
+                .map(|(key, entry)| (key, entry.client))
+                .collect()
         };
 
         let mut errors = Vec::new();
@@ -322,8 +443,8 @@ impl LspManager {
         let clients = self.clients.read().await;
         let mut statuses = Vec::new();
 
-        for (key, client) in clients.iter() {
-            let client_guard = client.lock().await;
+        for (key, entry) in clients.iter() {
+            let client_guard = entry.client.lock().await;
             let health = health_check(&client_guard).await;
 
             statuses.push(ServerStatus {
@@ -354,6 +475,82 @@ impl LspManager {
         self.start_server(language, root_path).await
     }
 
+    /// Warm up servers by pre-opening a set of files.
+    ///
+    /// Sends `textDocument/didOpen` for each path so lazily-indexing
+    /// servers (e.g. rust-analyzer) begin analysis before the first real
+    /// query. Files are opened in batches of [`WARMUP_CONCURRENCY`].
+    /// Failures are logged and skipped so one unreadable file doesn't
+    /// abort the whole warmup.
+    ///
+    /// # Returns
+    /// The number of files that were successfully opened.
+    pub async fn warmup(&self, paths: &[PathBuf]) -> usize {
+        let mut opened = 0;
+
+        for batch in paths.chunks(WARMUP_CONCURRENCY) {
+            let mut handles = Vec::new();
+
+            for path in batch {
+                let client = match self.get_client_for_file(path).await {
+                    Ok(client) => client,
+                    Err(e) => {
+                        debug!(
+                            file = %path.display(),
+                            error = %e,
+                            "Skipping warmup: no client available"
+                        );
+                        continue;
+                    }
+                };
+
+                let path = path.clone();
+                let task_path = path.clone();
+                let handle = tokio::spawn(async move {
+                    let guard = client.lock().await;
+                    match guard.open_document(&task_path).await {
+                        Ok(()) => true,
+                        Err(e) => {
+                            warn!(
+                                file = %task_path.display(),
+                                error = %e,
+                                "Failed to open file during warmup"
+                            );
+                            false
+                        }
+                    }
+                });
+                handles.push((path, handle));
+            }
+
+            for (path, handle) in handles {
+                if let Ok(true) = handle.await {
+                    opened += 1;
+                    // Remember the file so an idle-reaped server can be
+                    // re-warmed transparently on restart
+                    if let Some(key) = Self::key_for_file(&path) {
+                        let mut warmed = self.warmed_files.write().await;
+                        let paths = warmed.entry(key).or_default();
+                        if !paths.contains(&path) {
+                            paths.push(path);
+                        }
+                    }
+                }
+            }
+        }
+
+        debug!(opened = opened, total = paths.len(), "LSP warmup complete");
+        opened
+    }
+
+    /// Compute the server key for a file, if its language and project
+    /// root can be determined.
+    fn key_for_file(file_path: &Path) -> Option<ServerKey> {
+        let language = detect_language(file_path)?;
+        let root_path = find_project_root(file_path, root_markers(&language))?;
+        Some(ServerKey::new(&language, &root_path))
+    }
+
     /// Get the number of active servers.
     pub async fn server_count(&self) -> usize {
         self.clients.read().await.len()
@@ -426,6 +623,59 @@ mod tests {
         assert!(config.custom_servers.contains_key("rust"));
     }
 
+    #[test]
+    fn test_idle_timeout_disabled_by_default() {
+        assert_eq!(LspManagerConfig::default().idle_timeout_ms, None);
+        let config = LspManagerConfig::new()
+            .with_idle_timeout(300_000)
+            .without_idle_timeout();
+        assert_eq!(config.idle_timeout_ms, None);
+    }
+
+    #[test]
+    fn test_is_idle_with_mocked_clock() {
+        let timeout = Duration::from_millis(300_000);
+        let last_used = Instant::now();
+
+        // Just used: not idle
+        assert!(!is_idle(last_used, last_used, timeout));
+        // Idle period not yet elapsed
+        assert!(!is_idle(
+            last_used,
+            last_used + Duration::from_millis(299_999),
+            timeout
+        ));
+        // Idle period elapsed: eligible for shutdown
+        assert!(is_idle(
+            last_used,
+            last_used + Duration::from_millis(300_000),
+            timeout
+        ));
+        // A last_used in the future (clock skew) saturates to zero
+        assert!(!is_idle(last_used + timeout, last_used, timeout));
+    }
+
+    #[tokio::test]
+    async fn test_reap_is_noop_when_idle_timeout_disabled() {
+        let manager = LspManager::new(LspManagerConfig::new().with_auto_start(false));
+        let far_future = Instant::now() + Duration::from_secs(86400);
+        assert_eq!(manager.reap_idle_servers_at(far_future).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_skips_files_without_clients() {
+        // With auto_start disabled and no running servers, every file is
+        // skipped rather than failing the warmup
+        let manager = LspManager::new(LspManagerConfig::new().with_auto_start(false));
+        let opened = manager
+            .warmup(&[
+                PathBuf::from("/nonexistent/a.rs"),
+                PathBuf::from("/nonexistent/b.rs"),
+            ])
+            .await;
+        assert_eq!(opened, 0);
+    }
+
     #[test]
     fn test_server_key() {
         let key1 = ServerKey::new("rust", PathBuf::from("/project/a"));
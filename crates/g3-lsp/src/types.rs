@@ -294,6 +294,25 @@ mod tests {
 
         let config = LspServerConfig::typescript();
         assert_eq!(config.language_id, "typescript");
+        assert_eq!(config.command, "typescript-language-server");
         assert!(config.args.contains(&"--stdio".to_string()));
+        assert!(config.file_extensions.contains(&"tsx".to_string()));
+
+        let config = LspServerConfig::python();
+        assert_eq!(config.language_id, "python");
+        assert_eq!(config.command, "pyright-langserver");
+        assert!(config.args.contains(&"--stdio".to_string()));
+        assert!(config.file_extensions.contains(&"py".to_string()));
+
+        let config = LspServerConfig::go();
+        assert_eq!(config.language_id, "go");
+        assert_eq!(config.command, "gopls");
+        assert!(config.file_extensions.contains(&"go".to_string()));
+
+        let config = LspServerConfig::clangd();
+        assert_eq!(config.language_id, "cpp");
+        assert_eq!(config.command, "clangd");
+        assert!(config.file_extensions.contains(&"c".to_string()));
+        assert!(config.file_extensions.contains(&"hpp".to_string()));
     }
 }
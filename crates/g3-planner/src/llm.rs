@@ -115,7 +115,8 @@ pub async fn create_planner_provider(
                 zai_config.web_search_count,
                 zai_config.web_search_recency.clone(),
                 zai_config.web_search_content_size.clone(),
-            )?;
+            )?
+            .with_leaked_thinking_stripping(zai_config.strip_leaked_thinking.unwrap_or(true));
             Ok(Box::new(provider))
         }
         "gemini" => {
@@ -3,6 +3,8 @@
 //! This module provides common helpers used by multiple LLM providers
 //! for handling Server-Sent Events (SSE) streaming responses.
 
+use std::time::Instant;
+
 use crate::{CompletionChunk, ToolCall, Usage};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -46,6 +48,100 @@ pub fn is_incomplete_json_error(error: &serde_json::Error, data: &str) -> bool {
     looks_incomplete || missing_terminator
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Stream Metrics
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Throughput metrics for a completed streaming response.
+#[derive(Debug, Clone, Default)]
+pub struct StreamMetrics {
+    /// Time from request send to first content chunk, in milliseconds.
+    /// None if the stream produced no content.
+    pub time_to_first_token_ms: Option<u64>,
+    /// Total stream duration in milliseconds
+    pub duration_ms: u64,
+    /// Completion tokens reported by the provider (0 if unknown)
+    pub completion_tokens: u32,
+    /// Generation throughput, measured from first token to stream end.
+    /// None if token count or timing is unavailable.
+    pub tokens_per_second: Option<f32>,
+}
+
+/// Tracks timing during streaming response parsing.
+///
+/// Providers create a tracker when the request is sent, mark the first
+/// content chunk as it arrives, and call [`finish`](Self::finish) when the
+/// stream completes. The `*_at` variants take explicit timestamps so the
+/// math is testable without real delays.
+#[derive(Debug, Clone)]
+pub struct StreamMetricsTracker {
+    started_at: Instant,
+    first_token_at: Option<Instant>,
+}
+
+impl StreamMetricsTracker {
+    /// Start tracking now.
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// Start tracking from an explicit timestamp.
+    pub fn starting_at(started_at: Instant) -> Self {
+        Self {
+            started_at,
+            first_token_at: None,
+        }
+    }
+
+    /// Record the first content chunk. Subsequent calls are no-ops.
+    pub fn mark_first_token(&mut self) {
+        self.mark_first_token_at(Instant::now());
+    }
+
+    /// Record the first content chunk at an explicit timestamp.
+    pub fn mark_first_token_at(&mut self, now: Instant) {
+        if self.first_token_at.is_none() {
+            self.first_token_at = Some(now);
+        }
+    }
+
+    /// Finalize metrics now.
+    pub fn finish(&self, completion_tokens: u32) -> StreamMetrics {
+        self.finish_at(Instant::now(), completion_tokens)
+    }
+
+    /// Finalize metrics at an explicit timestamp.
+    pub fn finish_at(&self, now: Instant, completion_tokens: u32) -> StreamMetrics {
+        let duration_ms = now.duration_since(self.started_at).as_millis() as u64;
+        let time_to_first_token_ms = self
+            .first_token_at
+            .map(|t| t.duration_since(self.started_at).as_millis() as u64);
+
+        // Throughput over the generation span, not the full request
+        // (TTFT is dominated by prompt processing, not generation).
+        let generation_start = self.first_token_at.unwrap_or(self.started_at);
+        let generation_secs = now.duration_since(generation_start).as_secs_f32();
+        let tokens_per_second = if completion_tokens > 0 && generation_secs > 0.0 {
+            Some(completion_tokens as f32 / generation_secs)
+        } else {
+            None
+        };
+
+        StreamMetrics {
+            time_to_first_token_ms,
+            duration_ms,
+            completion_tokens,
+            tokens_per_second,
+        }
+    }
+}
+
+impl Default for StreamMetricsTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Completion Chunk Helpers
 // ─────────────────────────────────────────────────────────────────────────────
@@ -180,3 +276,47 @@ pub fn make_tool_streaming_active() -> CompletionChunk {
         reasoning_content: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_stream_metrics_from_injected_timing() {
+        let t0 = Instant::now();
+        let mut tracker = StreamMetricsTracker::starting_at(t0);
+        tracker.mark_first_token_at(t0 + Duration::from_millis(250));
+
+        // 100 tokens generated over 2 seconds after the first token
+        let metrics = tracker.finish_at(t0 + Duration::from_millis(2250), 100);
+
+        assert_eq!(metrics.time_to_first_token_ms, Some(250));
+        assert_eq!(metrics.duration_ms, 2250);
+        assert_eq!(metrics.completion_tokens, 100);
+        let tps = metrics.tokens_per_second.unwrap();
+        assert!((tps - 50.0).abs() < 0.1, "expected ~50 tok/s, got {}", tps);
+    }
+
+    #[test]
+    fn test_stream_metrics_first_token_marked_once() {
+        let t0 = Instant::now();
+        let mut tracker = StreamMetricsTracker::starting_at(t0);
+        tracker.mark_first_token_at(t0 + Duration::from_millis(100));
+        tracker.mark_first_token_at(t0 + Duration::from_millis(900));
+
+        let metrics = tracker.finish_at(t0 + Duration::from_secs(1), 10);
+        assert_eq!(metrics.time_to_first_token_ms, Some(100));
+    }
+
+    #[test]
+    fn test_stream_metrics_without_tokens_or_content() {
+        let t0 = Instant::now();
+        let tracker = StreamMetricsTracker::starting_at(t0);
+
+        let metrics = tracker.finish_at(t0 + Duration::from_secs(1), 0);
+        assert_eq!(metrics.time_to_first_token_ms, None);
+        assert_eq!(metrics.duration_ms, 1000);
+        assert!(metrics.tokens_per_second.is_none());
+    }
+}
@@ -71,12 +71,13 @@ use serde_json::json;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error};
 
 use crate::{
     streaming::{
         decode_utf8_streaming, make_final_chunk_full, make_text_chunk, make_tool_chunk,
-        make_tool_streaming_active, make_tool_streaming_hint,
+        make_tool_streaming_active, make_tool_streaming_hint, StreamMetrics, StreamMetricsTracker,
     },
     CompletionChunk, CompletionRequest, CompletionResponse, CompletionStream, LLMProvider, Message,
     MessageRole, Tool, ToolCall, Usage,
@@ -88,6 +89,13 @@ const ZAI_DEFAULT_BASE_URL: &str = "https://api.z.ai/api/paas/v4";
 /// Default model for Z.ai
 const ZAI_DEFAULT_MODEL: &str = "glm-4.7";
 
+/// Default total request timeout in seconds.
+const ZAI_DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 300;
+
+/// Default connect timeout in seconds. Kept short so a dead host fails
+/// fast instead of blocking for the full request timeout.
+const ZAI_DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
 /// Z.ai provider implementation
 #[derive(Clone)]
 pub struct ZaiProvider {
@@ -110,6 +118,12 @@ pub struct ZaiProvider {
     web_search_recency: Option<String>,
     /// Content size: "medium" or "high" (default: "medium")
     web_search_content_size: Option<String>,
+    /// Strip leaked `<think>` blocks out of user-facing content
+    strip_leaked_thinking: bool,
+    /// Total request timeout applied to the HTTP client
+    request_timeout: Duration,
+    /// Connect timeout applied to the HTTP client
+    connect_timeout: Duration,
 }
 
 impl ZaiProvider {
@@ -157,10 +171,9 @@ impl ZaiProvider {
         web_search_recency: Option<String>,
         web_search_content_size: Option<String>,
     ) -> Result<Self> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(300))
-            .build()
-            .map_err(|e| anyhow!("Failed to create HTTP client: {}", e))?;
+        let request_timeout = Duration::from_secs(ZAI_DEFAULT_REQUEST_TIMEOUT_SECS);
+        let connect_timeout = Duration::from_secs(ZAI_DEFAULT_CONNECT_TIMEOUT_SECS);
+        let client = Self::build_client(request_timeout, connect_timeout)?;
 
         let model = model.unwrap_or_else(|| ZAI_DEFAULT_MODEL.to_string());
         let base_url = base_url.unwrap_or_else(|| ZAI_DEFAULT_BASE_URL.to_string());
@@ -185,9 +198,47 @@ impl ZaiProvider {
             web_search_count,
             web_search_recency,
             web_search_content_size,
+            strip_leaked_thinking: true,
+            request_timeout,
+            connect_timeout,
         })
     }
 
+    /// Control stripping of `<think>`/`<thinking>` blocks that some GLM
+    /// models leak into `content` instead of `reasoning_content`.
+    ///
+    /// Enabled by default; the stripped text is captured as reasoning so
+    /// preserved thinking still sees it. Disable only to debug raw model
+    /// output.
+    pub fn with_leaked_thinking_stripping(mut self, enabled: bool) -> Self {
+        self.strip_leaked_thinking = enabled;
+        self
+    }
+
+    /// Build the HTTP client with the given timeouts.
+    fn build_client(request_timeout: Duration, connect_timeout: Duration) -> Result<Client> {
+        Client::builder()
+            .timeout(request_timeout)
+            .connect_timeout(connect_timeout)
+            .build()
+            .map_err(|e| anyhow!("Failed to create HTTP client: {}", e))
+    }
+
+    /// Override the request and connect timeouts, rebuilding the HTTP client.
+    ///
+    /// The connect timeout bounds how long a dead host can stall; the
+    /// request timeout bounds the total exchange including streaming.
+    pub fn with_timeouts(
+        mut self,
+        request_timeout: Duration,
+        connect_timeout: Duration,
+    ) -> Result<Self> {
+        self.client = Self::build_client(request_timeout, connect_timeout)?;
+        self.request_timeout = request_timeout;
+        self.connect_timeout = connect_timeout;
+        Ok(self)
+    }
+
     /// Create the request body for the Z.ai API.
     fn create_request_body(
         &self,
@@ -278,7 +329,8 @@ impl ZaiProvider {
         &self,
         mut stream: impl futures_util::Stream<Item = reqwest::Result<Bytes>> + Unpin,
         tx: mpsc::Sender<Result<CompletionChunk>>,
-    ) -> Option<Usage> {
+    ) -> (Option<Usage>, StreamMetrics) {
+        let mut metrics = StreamMetricsTracker::new();
         let mut buffer = String::new();
         let mut byte_buffer = Vec::new();
         let mut accumulated_usage: Option<Usage> = None;
@@ -286,6 +338,9 @@ impl ZaiProvider {
         let mut accumulated_reasoning = String::new();
         let mut last_finish_reason: Option<String> = None;
         let mut tool_calls_started: bool = false;
+        // Separates leaked <think> blocks from content, including tags
+        // that straddle chunk boundaries
+        let mut think_filter = self.strip_leaked_thinking.then(ThinkTagFilter::new);
 
         while let Some(chunk_result) = stream.next().await {
             match chunk_result {
@@ -312,6 +367,17 @@ impl ZaiProvider {
                             if data == "[DONE]" {
                                 debug!("Received stream completion marker");
 
+                                // Release any text held back as a possible
+                                // partial think tag
+                                if let Some(filter) = think_filter.as_mut() {
+                                    let (clean, leaked) = filter.flush();
+                                    accumulated_reasoning.push_str(&leaked);
+                                    if !clean.is_empty() {
+                                        let chunk = make_text_chunk(clean);
+                                        let _ = tx.send(Ok(chunk)).await;
+                                    }
+                                }
+
                                 // Send final chunk with any remaining un-emitted tool calls
                                 let tool_calls = if current_tool_calls.is_empty() {
                                     vec![]
@@ -339,7 +405,7 @@ impl ZaiProvider {
                                 );
                                 let _ = tx.send(Ok(final_chunk)).await;
 
-                                return accumulated_usage;
+                                return finish_stream(accumulated_usage, &metrics);
                             }
 
                             // Parse the JSON data
@@ -350,6 +416,7 @@ impl ZaiProvider {
                                         // Handle reasoning_content (Z.ai-specific thinking)
                                         if let Some(reasoning) = &choice.delta.reasoning_content {
                                             if !reasoning.is_empty() {
+                                                metrics.mark_first_token();
                                                 accumulated_reasoning.push_str(reasoning);
                                                 debug!(
                                                     "Received reasoning content: {} chars",
@@ -363,16 +430,33 @@ impl ZaiProvider {
                                         // Handle regular content
                                         if let Some(content) = &choice.delta.content {
                                             if !content.is_empty() {
+                                                metrics.mark_first_token();
                                                 if tool_calls_started {
                                                     // After tool calls begin, redirect content
                                                     // to reasoning (GLM-5 interleaves CoT text
                                                     // with tool call deltas)
                                                     accumulated_reasoning.push_str(content);
+                                                } else if let Some(filter) = think_filter.as_mut() {
+                                                    let (clean, leaked) = filter.push(content);
+                                                    if !leaked.is_empty() {
+                                                        debug!(
+                                                            "Stripped {} chars of leaked thinking from stream",
+                                                            leaked.len()
+                                                        );
+                                                        accumulated_reasoning.push_str(&leaked);
+                                                    }
+                                                    if !clean.is_empty() {
+                                                        let chunk = make_text_chunk(clean);
+                                                        if tx.send(Ok(chunk)).await.is_err() {
+                                                            debug!("Receiver dropped, stopping stream");
+                                                            return finish_stream(accumulated_usage, &metrics);
+                                                        }
+                                                    }
                                                 } else {
                                                     let chunk = make_text_chunk(content.clone());
                                                     if tx.send(Ok(chunk)).await.is_err() {
                                                         debug!("Receiver dropped, stopping stream");
-                                                        return accumulated_usage;
+                                                        return finish_stream(accumulated_usage, &metrics);
                                                     }
                                                 }
                                             }
@@ -405,7 +489,7 @@ impl ZaiProvider {
                                                                 let hint = make_tool_streaming_hint(name.clone());
                                                                 if tx.send(Ok(hint)).await.is_err() {
                                                                     debug!("Receiver dropped, stopping stream");
-                                                                    return accumulated_usage;
+                                                                    return finish_stream(accumulated_usage, &metrics);
                                                                 }
                                                             }
                                                             tool_call.name = Some(name.clone());
@@ -417,7 +501,7 @@ impl ZaiProvider {
                                                             let active = make_tool_streaming_active();
                                                             if tx.send(Ok(active)).await.is_err() {
                                                                 debug!("Receiver dropped, stopping stream");
-                                                                return accumulated_usage;
+                                                                return finish_stream(accumulated_usage, &metrics);
                                                             }
                                                         }
                                                     }
@@ -433,7 +517,7 @@ impl ZaiProvider {
                                                                 make_tool_chunk(vec![completed]);
                                                             if tx.send(Ok(chunk)).await.is_err() {
                                                                 debug!("Receiver dropped, stopping stream");
-                                                                return accumulated_usage;
+                                                                return finish_stream(accumulated_usage, &metrics);
                                                             }
                                                             current_tool_calls[index].emitted =
                                                                 true;
@@ -462,7 +546,7 @@ impl ZaiProvider {
                                                 let chunk = make_tool_chunk(tool_calls);
                                                 if tx.send(Ok(chunk)).await.is_err() {
                                                     debug!("Receiver dropped, stopping stream");
-                                                    return accumulated_usage;
+                                                    return finish_stream(accumulated_usage, &metrics);
                                                 }
                                             }
                                         }
@@ -489,11 +573,21 @@ impl ZaiProvider {
                 Err(e) => {
                     error!("Stream error: {}", e);
                     let _ = tx.send(Err(anyhow!("Stream error: {}", e))).await;
-                    return accumulated_usage;
+                    return finish_stream(accumulated_usage, &metrics);
                 }
             }
         }
 
+        // Release any text held back as a possible partial think tag
+        if let Some(filter) = think_filter.as_mut() {
+            let (clean, leaked) = filter.flush();
+            accumulated_reasoning.push_str(&leaked);
+            if !clean.is_empty() {
+                let chunk = make_text_chunk(clean);
+                let _ = tx.send(Ok(chunk)).await;
+            }
+        }
+
         // Send final chunk if we haven't already
         let tool_calls: Vec<ToolCall> = current_tool_calls
             .iter()
@@ -511,28 +605,40 @@ impl ZaiProvider {
         let final_chunk = make_final_chunk_full(tool_calls, accumulated_usage.clone(), stop_reason, reasoning);
         let _ = tx.send(Ok(final_chunk)).await;
 
-        accumulated_usage
+        finish_stream(accumulated_usage, &metrics)
     }
-}
 
-#[async_trait::async_trait]
-impl LLMProvider for ZaiProvider {
-    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse> {
+    /// Stream a completion, bounded by a caller-supplied cancellation token.
+    ///
+    /// When the token fires mid-stream (user interrupt, UI closed), the
+    /// spawned parsing task stops promptly and the upstream SSE connection
+    /// is dropped instead of being read to completion in the background —
+    /// freeing the connection and not burning generation quota on output
+    /// nobody will see. `stream()` delegates here with a token that never
+    /// fires.
+    pub async fn stream_with_cancellation(
+        &self,
+        request: CompletionRequest,
+        cancellation_token: CancellationToken,
+    ) -> Result<CompletionStream> {
         debug!(
-            "Processing Z.ai completion request with {} messages",
+            "Processing Z.ai streaming request with {} messages",
             request.messages.len()
         );
 
         let body = self.create_request_body(
             &request.messages,
             request.tools.as_deref(),
-            false,
+            true,
             request.max_tokens,
             request.temperature,
             request.disable_thinking,
         );
 
-        debug!("Sending request to Z.ai API: model={}", self.model);
+        debug!(
+            "Sending streaming request to Z.ai API: model={}",
+            self.model
+        );
 
         let response = self
             .client
@@ -542,7 +648,7 @@ impl LLMProvider for ZaiProvider {
             .json(&body)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to send request to Z.ai API: {}", e))?;
+            .map_err(|e| anyhow!("Failed to send streaming request to Z.ai API: {}", e))?;
 
         let status = response.status();
         if !status.is_success() {
@@ -553,57 +659,196 @@ impl LLMProvider for ZaiProvider {
             return Err(anyhow!("Z.ai API error {}: {}", status, error_text));
         }
 
-        let zai_response: ZaiResponse = response
-            .json()
-            .await
-            .map_err(|e| anyhow!("Failed to parse Z.ai response: {}", e))?;
+        let stream = response.bytes_stream();
+        let (tx, rx) = mpsc::channel(100);
 
-        // Extract content from the response
-        let content = zai_response
-            .choices
-            .first()
-            .and_then(|choice| choice.message.content.clone())
-            .unwrap_or_default();
+        self.spawn_stream_task(stream, tx, cancellation_token);
 
-        let usage = Usage {
-            prompt_tokens: zai_response.usage.prompt_tokens,
-            completion_tokens: zai_response.usage.completion_tokens,
-            total_tokens: zai_response.usage.total_tokens,
-            cache_creation_tokens: 0,
-            cache_read_tokens: 0,
-        };
+        Ok(ReceiverStream::new(rx))
+    }
 
-        debug!(
-            "Z.ai completion successful: {} tokens generated",
-            usage.completion_tokens
-        );
+    /// Spawn the SSE parsing task, racing it against cancellation.
+    ///
+    /// Cancelling drops the parse future, which owns both the upstream
+    /// byte stream (closing the HTTP connection) and the chunk sender
+    /// (ending the consumer's stream).
+    fn spawn_stream_task(
+        &self,
+        stream: impl futures_util::Stream<Item = reqwest::Result<Bytes>> + Unpin + Send + 'static,
+        tx: mpsc::Sender<Result<CompletionChunk>>,
+        cancellation_token: CancellationToken,
+    ) {
+        let provider = self.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    debug!("Z.ai stream cancelled; dropping upstream connection");
+                }
+                (usage, metrics) = provider.parse_streaming_response(stream, tx) => {
+                    if let Some(usage) = usage {
+                        debug!(
+                            "Stream completed with usage - prompt: {}, completion: {}, total: {}",
+                            usage.prompt_tokens, usage.completion_tokens, usage.total_tokens
+                        );
+                    }
+                    if let Some(tps) = metrics.tokens_per_second {
+                        debug!(
+                            "Stream throughput: {:.1} tok/s, ttft {}ms, total {}ms",
+                            tps,
+                            metrics.time_to_first_token_ms.unwrap_or(0),
+                            metrics.duration_ms
+                        );
+                    }
+                }
+            }
+        });
+    }
+}
 
-        Ok(CompletionResponse {
-            content,
-            usage,
-            model: zai_response.model,
-        })
+/// Pair the accumulated usage with finalized stream metrics.
+fn finish_stream(
+    usage: Option<Usage>,
+    metrics: &StreamMetricsTracker,
+) -> (Option<Usage>, StreamMetrics) {
+    let completion_tokens = usage.as_ref().map(|u| u.completion_tokens).unwrap_or(0);
+    (usage, metrics.finish(completion_tokens))
+}
+
+/// Opening tags recognized as leaked thinking blocks.
+const THINK_OPEN_TAGS: &[&str] = &["<think>", "<thinking>"];
+
+/// Closing tags matching [`THINK_OPEN_TAGS`].
+const THINK_CLOSE_TAGS: &[&str] = &["</think>", "</thinking>"];
+
+/// Stateful filter separating leaked thinking blocks from real content.
+///
+/// Some GLM responses emit `<think>...</think>` reasoning inside the
+/// `content` field instead of `reasoning_content`. Feeding deltas through
+/// `push` splits them into user-facing content and captured reasoning;
+/// text that could be the start of a tag split across chunk boundaries is
+/// held back until the next delta (or `flush`) disambiguates it.
+struct ThinkTagFilter {
+    /// Undecided carry-over, at most a partial tag plus block interior
+    pending: String,
+    /// Currently inside a think block
+    in_think: bool,
+}
+
+impl ThinkTagFilter {
+    fn new() -> Self {
+        Self {
+            pending: String::new(),
+            in_think: false,
+        }
     }
 
-    async fn stream(&self, request: CompletionRequest) -> Result<CompletionStream> {
+    /// Feed a content delta; returns `(content, reasoning)` ready to emit.
+    fn push(&mut self, input: &str) -> (String, String) {
+        self.pending.push_str(input);
+        let mut content = String::new();
+        let mut reasoning = String::new();
+
+        loop {
+            let tags = if self.in_think {
+                THINK_CLOSE_TAGS
+            } else {
+                THINK_OPEN_TAGS
+            };
+            let out = if self.in_think {
+                &mut reasoning
+            } else {
+                &mut content
+            };
+
+            if let Some((idx, tag)) = find_first_tag(&self.pending, tags) {
+                out.push_str(&self.pending[..idx]);
+                self.pending.drain(..idx + tag.len());
+                self.in_think = !self.in_think;
+            } else {
+                // Emit everything except a suffix that may grow into a tag
+                let keep = partial_tag_suffix_len(&self.pending, tags);
+                let emit_to = self.pending.len() - keep;
+                out.push_str(&self.pending[..emit_to]);
+                self.pending.drain(..emit_to);
+                break;
+            }
+        }
+
+        (content, reasoning)
+    }
+
+    /// Release held-back text at end of stream.
+    ///
+    /// A partial tag that never completed is real content; an unclosed
+    /// think block stays reasoning.
+    fn flush(&mut self) -> (String, String) {
+        let pending = std::mem::take(&mut self.pending);
+        if self.in_think {
+            (String::new(), pending)
+        } else {
+            (pending, String::new())
+        }
+    }
+}
+
+/// Earliest occurrence of any of `tags` in `text`.
+fn find_first_tag<'a>(text: &str, tags: &[&'a str]) -> Option<(usize, &'a str)> {
+    tags.iter()
+        .filter_map(|tag| text.find(tag).map(|idx| (idx, *tag)))
+        .min_by_key(|(idx, _)| *idx)
+}
+
+/// Length of the longest suffix of `text` that is a proper prefix of any
+/// tag, i.e. text that must be held back across a chunk boundary.
+fn partial_tag_suffix_len(text: &str, tags: &[&str]) -> usize {
+    let max = tags
+        .iter()
+        .map(|tag| tag.len() - 1)
+        .max()
+        .unwrap_or(0)
+        .min(text.len());
+    for len in (1..=max).rev() {
+        if !text.is_char_boundary(text.len() - len) {
+            continue;
+        }
+        let suffix = &text[text.len() - len..];
+        if tags.iter().any(|tag| tag.starts_with(suffix)) {
+            return len;
+        }
+    }
+    0
+}
+
+/// Strip leaked think blocks from a complete (non-streaming) response.
+///
+/// Returns `(content, reasoning)`.
+fn strip_think_blocks(content: &str) -> (String, String) {
+    let mut filter = ThinkTagFilter::new();
+    let (mut clean, mut reasoning) = filter.push(content);
+    let (rest_content, rest_reasoning) = filter.flush();
+    clean.push_str(&rest_content);
+    reasoning.push_str(&rest_reasoning);
+    (clean, reasoning)
+}
+
+#[async_trait::async_trait]
+impl LLMProvider for ZaiProvider {
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse> {
         debug!(
-            "Processing Z.ai streaming request with {} messages",
+            "Processing Z.ai completion request with {} messages",
             request.messages.len()
         );
 
         let body = self.create_request_body(
             &request.messages,
             request.tools.as_deref(),
-            true,
+            false,
             request.max_tokens,
             request.temperature,
             request.disable_thinking,
         );
 
-        debug!(
-            "Sending streaming request to Z.ai API: model={}",
-            self.model
-        );
+        debug!("Sending request to Z.ai API: model={}", self.model);
 
         let response = self
             .client
@@ -613,7 +858,7 @@ impl LLMProvider for ZaiProvider {
             .json(&body)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to send streaming request to Z.ai API: {}", e))?;
+            .map_err(|e| anyhow!("Failed to send request to Z.ai API: {}", e))?;
 
         let status = response.status();
         if !status.is_success() {
@@ -624,22 +869,58 @@ impl LLMProvider for ZaiProvider {
             return Err(anyhow!("Z.ai API error {}: {}", status, error_text));
         }
 
-        let stream = response.bytes_stream();
-        let (tx, rx) = mpsc::channel(100);
+        let zai_response: ZaiResponse = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse Z.ai response: {}", e))?;
 
-        // Spawn task to process the stream
-        let provider = self.clone();
-        tokio::spawn(async move {
-            let usage = provider.parse_streaming_response(stream, tx).await;
-            if let Some(usage) = usage {
+        // Extract content from the response
+        let content = zai_response
+            .choices
+            .first()
+            .and_then(|choice| choice.message.content.clone())
+            .unwrap_or_default();
+
+        // Some GLM responses leak <think> blocks into content instead of
+        // reasoning_content; strip them so they never reach the user
+        let content = if self.strip_leaked_thinking {
+            let (clean, leaked) = strip_think_blocks(&content);
+            if !leaked.is_empty() {
                 debug!(
-                    "Stream completed with usage - prompt: {}, completion: {}, total: {}",
-                    usage.prompt_tokens, usage.completion_tokens, usage.total_tokens
+                    "Stripped {} chars of leaked thinking from Z.ai content",
+                    leaked.len()
                 );
             }
-        });
+            clean
+        } else {
+            content
+        };
 
-        Ok(ReceiverStream::new(rx))
+        let usage = Usage {
+            prompt_tokens: zai_response.usage.prompt_tokens,
+            completion_tokens: zai_response.usage.completion_tokens,
+            total_tokens: zai_response.usage.total_tokens,
+            cache_creation_tokens: 0,
+            cache_read_tokens: 0,
+        };
+
+        debug!(
+            "Z.ai completion successful: {} tokens generated",
+            usage.completion_tokens
+        );
+
+        Ok(CompletionResponse {
+            content,
+            usage,
+            model: zai_response.model,
+        })
+    }
+
+    async fn stream(&self, request: CompletionRequest) -> Result<CompletionStream> {
+        // Without a caller-supplied token the stream is only bounded by
+        // the connection itself
+        self.stream_with_cancellation(request, CancellationToken::new())
+            .await
     }
 
     fn name(&self) -> &str {
@@ -896,6 +1177,201 @@ mod tests {
         assert_eq!(provider.model(), ZAI_DEFAULT_MODEL);
     }
 
+    #[test]
+    fn test_default_timeouts() {
+        let provider = ZaiProvider::new(
+            "test-api-key".to_string(),
+            None,
+            None,
+            None,
+            None,
+            false,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            provider.request_timeout,
+            Duration::from_secs(ZAI_DEFAULT_REQUEST_TIMEOUT_SECS)
+        );
+        assert_eq!(
+            provider.connect_timeout,
+            Duration::from_secs(ZAI_DEFAULT_CONNECT_TIMEOUT_SECS)
+        );
+    }
+
+    #[test]
+    fn test_custom_timeouts() {
+        let provider = ZaiProvider::new(
+            "test-api-key".to_string(),
+            None,
+            None,
+            None,
+            None,
+            false,
+            false,
+        )
+        .unwrap()
+        .with_timeouts(Duration::from_secs(60), Duration::from_secs(5))
+        .unwrap();
+
+        assert_eq!(provider.request_timeout, Duration::from_secs(60));
+        assert_eq!(provider.connect_timeout, Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_parse_streaming_response_reports_metrics() {
+        let provider = ZaiProvider::new(
+            "test-api-key".to_string(),
+            None,
+            None,
+            None,
+            None,
+            false,
+            false,
+        )
+        .unwrap();
+
+        let sse = concat!(
+            "data: {\"choices\":[{\"delta\":{\"content\":\"hello\"},\"finish_reason\":null}]}\n",
+            "data: {\"choices\":[],\"usage\":{\"prompt_tokens\":5,\"completion_tokens\":42,\"total_tokens\":47}}\n",
+            "data: [DONE]\n",
+        );
+        let stream =
+            futures_util::stream::iter(vec![Ok::<Bytes, reqwest::Error>(Bytes::from(sse))]);
+        let (tx, mut rx) = mpsc::channel(16);
+
+        let (usage, metrics) = provider.parse_streaming_response(stream, tx).await;
+
+        assert_eq!(usage.unwrap().completion_tokens, 42);
+        assert_eq!(metrics.completion_tokens, 42);
+        // First token was marked when the content chunk arrived
+        assert!(metrics.time_to_first_token_ms.is_some());
+        assert!(metrics.tokens_per_second.is_some());
+
+        let first = rx.recv().await.unwrap().unwrap();
+        assert_eq!(first.content, "hello");
+    }
+
+    #[test]
+    fn test_strip_think_blocks_inline() {
+        let (content, reasoning) =
+            strip_think_blocks("Hello <think>weighing the options here</think>world");
+        assert_eq!(content, "Hello world");
+        assert_eq!(reasoning, "weighing the options here");
+
+        // No tags: content passes through untouched
+        let (content, reasoning) = strip_think_blocks("plain answer with a < b comparison");
+        assert_eq!(content, "plain answer with a < b comparison");
+        assert_eq!(reasoning, "");
+    }
+
+    #[test]
+    fn test_think_filter_handles_tags_across_chunk_boundaries() {
+        let mut filter = ThinkTagFilter::new();
+        let mut content = String::new();
+        let mut reasoning = String::new();
+
+        for delta in ["Before <thi", "nk>hidden reaso", "ning</th", "ink> after"] {
+            let (c, r) = filter.push(delta);
+            content.push_str(&c);
+            reasoning.push_str(&r);
+        }
+        let (c, r) = filter.flush();
+        content.push_str(&c);
+        reasoning.push_str(&r);
+
+        assert_eq!(content, "Before  after");
+        assert_eq!(reasoning, "hidden reasoning");
+    }
+
+    #[test]
+    fn test_think_filter_flush_releases_partial_tag_as_content() {
+        let mut filter = ThinkTagFilter::new();
+        let (content, _) = filter.push("ends mid-tag <thin");
+        assert_eq!(content, "ends mid-tag ");
+
+        // The would-be tag never completed, so it was real content
+        let (rest, reasoning) = filter.flush();
+        assert_eq!(rest, "<thin");
+        assert_eq!(reasoning, "");
+    }
+
+    #[tokio::test]
+    async fn test_streaming_strips_inline_think_block() {
+        let provider = ZaiProvider::new(
+            "test-api-key".to_string(),
+            None,
+            None,
+            None,
+            None,
+            false,
+            false,
+        )
+        .unwrap();
+
+        // The think block leaks into `content` and straddles two SSE events
+        let sse = concat!(
+            "data: {\"choices\":[{\"delta\":{\"content\":\"Answer: <think>leaked \"},\"finish_reason\":null}]}\n",
+            "data: {\"choices\":[{\"delta\":{\"content\":\"chain of thought</think>42\"},\"finish_reason\":\"stop\"}]}\n",
+            "data: [DONE]\n",
+        );
+        let stream =
+            futures_util::stream::iter(vec![Ok::<Bytes, reqwest::Error>(Bytes::from(sse))]);
+        let (tx, mut rx) = mpsc::channel(16);
+
+        provider.parse_streaming_response(stream, tx).await;
+
+        let mut content = String::new();
+        let mut reasoning = String::new();
+        while let Some(chunk) = rx.recv().await {
+            let chunk = chunk.unwrap();
+            content.push_str(&chunk.content);
+            if let Some(r) = chunk.reasoning_content {
+                reasoning.push_str(&r);
+            }
+        }
+
+        assert_eq!(content, "Answer: 42");
+        assert!(!content.contains("think"));
+        // The stripped block was captured as reasoning on the final chunk
+        assert_eq!(reasoning, "leaked chain of thought");
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_stops_chunk_emission_mid_stream() {
+        let provider = ZaiProvider::new(
+            "test-api-key".to_string(),
+            None,
+            None,
+            None,
+            None,
+            false,
+            false,
+        )
+        .unwrap();
+
+        // One event arrives, then the upstream stream stays open forever
+        let first =
+            "data: {\"choices\":[{\"delta\":{\"content\":\"partial\"},\"finish_reason\":null}]}\n";
+        let stream = futures_util::stream::iter(vec![Ok::<Bytes, reqwest::Error>(Bytes::from(
+            first,
+        ))])
+        .chain(futures_util::stream::pending());
+        let (tx, mut rx) = mpsc::channel(16);
+        let token = CancellationToken::new();
+
+        provider.spawn_stream_task(stream, tx, token.clone());
+
+        let chunk = rx.recv().await.unwrap().unwrap();
+        assert_eq!(chunk.content, "partial");
+
+        token.cancel();
+
+        // The parse task was dropped: no further chunks, channel closed
+        assert!(rx.recv().await.is_none());
+    }
+
     #[test]
     fn test_message_conversion() {
         let messages = vec![